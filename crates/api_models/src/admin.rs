@@ -6,6 +6,7 @@ use common_utils::{
     errors::{self, CustomResult},
     ext_traits::Encode,
     id_type, link_utils, pii,
+    types::MinorUnit,
 };
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
@@ -248,6 +249,44 @@ pub struct AuthenticationConnectorDetails {
     pub three_ds_requestor_url: String,
 }
 
+/// Policy for automatically retrying the failed payments of a business profile
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts made for a payment, including the first attempt
+    #[schema(minimum = 1, example = 3)]
+    pub max_attempts: u8,
+
+    /// Whether a failed payment is retried on the same connector, or on the next eligible connector
+    #[serde(default)]
+    #[schema(value_type = RetryConnectorOrder, default = "cross_connector")]
+    pub connector_order: api_enums::RetryConnectorOrder,
+
+    /// The payment methods for which failed payments are retried, payments of all payment methods are retried if not set
+    #[schema(value_type = Option<Vec<PaymentMethod>>, example = json!(["card"]))]
+    pub payment_methods: Option<Vec<api_enums::PaymentMethod>>,
+
+    /// The amount ranges for which failed payments are retried, payments of all amounts are retried if not set
+    pub amount_ranges: Option<Vec<RetryAmountRange>>,
+
+    /// The total time in milliseconds which the attempts of a payment may take, failed payments are not retried once it is exceeded
+    #[schema(example = 10000)]
+    pub latency_budget_in_ms: Option<u32>,
+}
+
+/// An inclusive range of payment amounts, in the lowest denomination of the currency of the payment
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RetryAmountRange {
+    /// The minimum amount of the range, the range has no lower bound if not set
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub min_amount: Option<MinorUnit>,
+
+    /// The maximum amount of the range, the range has no upper bound if not set
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_amount: Option<MinorUnit>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct MerchantAccountMetadata {
     pub compatible_connector: Option<api_enums::Connector>,
@@ -765,6 +804,9 @@ pub struct MerchantConnectorCreate {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Fees charged by the connector, used to estimate the cost of routing a payment through it
+    pub fee_schedule: Option<ConnectorFeeSchedule>,
+
     #[schema(value_type = Option<ConnectorStatus>, example = "inactive")]
     // By default the ConnectorStatus is Active
     pub status: Option<api_enums::ConnectorStatus>,
@@ -909,6 +951,9 @@ pub struct MerchantConnectorCreate {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Fees charged by the connector, used to estimate the cost of routing a payment through it
+    pub fee_schedule: Option<ConnectorFeeSchedule>,
+
     #[schema(value_type = Option<ConnectorStatus>, example = "inactive")]
     pub status: Option<api_enums::ConnectorStatus>,
 
@@ -1129,6 +1174,9 @@ pub struct MerchantConnectorResponse {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Fees charged by the connector, used to estimate the cost of routing a payment through it
+    pub fee_schedule: Option<ConnectorFeeSchedule>,
+
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: api_enums::ConnectorStatus,
 
@@ -1251,6 +1299,9 @@ pub struct MerchantConnectorResponse {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Fees charged by the connector, used to estimate the cost of routing a payment through it
+    pub fee_schedule: Option<ConnectorFeeSchedule>,
+
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: api_enums::ConnectorStatus,
 
@@ -1553,6 +1604,14 @@ pub struct MerchantConnectorUpdate {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Fees charged by the connector, used to estimate the cost of routing a payment through it. Setting it to `null` removes the fee schedule
+    #[serde(
+        default,
+        deserialize_with = "common_utils::custom_serde::nullable::deserialize"
+    )]
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<Option<ConnectorFeeSchedule>>,
+
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: Option<api_enums::ConnectorStatus>,
 }
@@ -1630,6 +1689,14 @@ pub struct MerchantConnectorUpdate {
     #[schema(value_type = Option<Object>)]
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
 
+    /// Fees charged by the connector, used to estimate the cost of routing a payment through it. Setting it to `null` removes the fee schedule
+    #[serde(
+        default,
+        deserialize_with = "common_utils::custom_serde::nullable::deserialize"
+    )]
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<Option<ConnectorFeeSchedule>>,
+
     #[schema(value_type = ConnectorStatus, example = "inactive")]
     pub status: Option<api_enums::ConnectorStatus>,
 
@@ -1697,6 +1764,59 @@ pub struct FrmPaymentMethodType {
     #[schema(value_type = FrmAction)]
     pub action: api_enums::FrmAction,
 }
+/// Fees charged by a connector for processing payments
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFeeSchedule {
+    /// Currency in which the fixed fees of the rules are charged
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// Fee rules of the connector. The most specific rule matching the payment is applied, and
+    /// the first of them when several rules are equally specific
+    pub rules: Vec<ConnectorFeeRule>,
+}
+
+/// Fee charged by a connector for the payments matching all the conditions of the rule. A
+/// condition which is not provided matches every payment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFeeRule {
+    /// Payment method of the payment
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<common_enums::PaymentMethod>,
+    /// Payment method type of the payment, which is the card type (credit or debit) for cards
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    /// Card network of the payment
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<common_enums::CardNetwork>,
+    /// Countries of the billing address of the payment
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["US", "CA"]))]
+    pub countries: Option<Vec<api_enums::CountryAlpha2>>,
+    /// Fixed fee charged per payment, in the minor unit of the currency of the fee schedule
+    #[schema(value_type = i64, example = 30)]
+    pub fixed_fee: common_utils::types::MinorUnit,
+    /// Fee charged as a percentage of the amount of the payment, in basis points (hundredths of a
+    /// percent). A fee of 2.9% is 290 basis points
+    #[schema(example = 290)]
+    pub percentage_fee_basis_points: u32,
+}
+
+impl ConnectorFeeRule {
+    /// Number of conditions of the rule, rules with more conditions being more specific
+    pub fn get_specificity(&self) -> usize {
+        [
+            self.payment_method.is_some(),
+            self.payment_method_type.is_some(),
+            self.card_network.is_some(),
+            self.countries.is_some(),
+        ]
+        .into_iter()
+        .filter(|is_present| *is_present)
+        .count()
+    }
+}
+
 /// Details of all the payment methods enabled for the connector for the given merchant account
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
@@ -1941,6 +2061,13 @@ pub struct BusinessProfileCreate {
     /// These key-value pairs are sent as additional custom headers in the outgoing webhook request. It is recommended not to use more than four key-value pairs.
     #[schema(value_type = Option<Object>, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
     pub outgoing_webhook_custom_http_headers: Option<HashMap<String, String>>,
+
+    /// The policy for retrying failed payments of the business profile, payments are retried as per the GSM configuration if not set
+    pub retry_policy: Option<RetryPolicy>,
+
+    /// The scheme used to sign the outgoing webhooks of the business profile, the legacy scheme is used if not set
+    #[schema(value_type = Option<WebhookSignatureScheme>, example = "v1")]
+    pub webhook_signature_scheme: Option<api_enums::WebhookSignatureScheme>,
 }
 
 #[nutype::nutype(
@@ -2024,6 +2151,13 @@ pub struct BusinessProfileCreate {
     /// These key-value pairs are sent as additional custom headers in the outgoing webhook request. It is recommended not to use more than four key-value pairs.
     #[schema(value_type = Option<Object>, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
     pub outgoing_webhook_custom_http_headers: Option<HashMap<String, String>>,
+
+    /// The policy for retrying failed payments of the business profile, payments are retried as per the GSM configuration if not set
+    pub retry_policy: Option<RetryPolicy>,
+
+    /// The scheme used to sign the outgoing webhooks of the business profile, the legacy scheme is used if not set
+    #[schema(value_type = Option<WebhookSignatureScheme>, example = "v1")]
+    pub webhook_signature_scheme: Option<api_enums::WebhookSignatureScheme>,
 }
 
 #[cfg(all(
@@ -2124,6 +2258,18 @@ pub struct BusinessProfileResponse {
     /// These key-value pairs are sent as additional custom headers in the outgoing webhook request.
     #[schema(value_type = Option<Object>, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
     pub outgoing_webhook_custom_http_headers: Option<HashMap<String, Secret<String>>>,
+
+    /// The policy for retrying failed payments of the business profile, payments are retried as per the GSM configuration if not set
+    pub retry_policy: Option<RetryPolicy>,
+
+    /// The scheme used to sign the outgoing webhooks of the business profile
+    #[schema(value_type = WebhookSignatureScheme, example = "v1")]
+    pub webhook_signature_scheme: api_enums::WebhookSignatureScheme,
+
+    /// Time until which outgoing webhooks are additionally signed with the previous `payment_response_hash_key`, following a rotation of the key
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(all(feature = "v2", feature = "business_profile_v2"))]
@@ -2205,6 +2351,18 @@ pub struct BusinessProfileResponse {
     #[schema(value_type = Option<Object>, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
     pub outgoing_webhook_custom_http_headers: Option<HashMap<String, Secret<String>>>,
 
+    /// The policy for retrying failed payments of the business profile, payments are retried as per the GSM configuration if not set
+    pub retry_policy: Option<RetryPolicy>,
+
+    /// The scheme used to sign the outgoing webhooks of the business profile
+    #[schema(value_type = WebhookSignatureScheme, example = "v1")]
+    pub webhook_signature_scheme: api_enums::WebhookSignatureScheme,
+
+    /// Time until which outgoing webhooks are additionally signed with the previous `payment_response_hash_key`, following a rotation of the key
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
+
     /// Will be used to determine the time till which your payment will be active once the payment session starts
     #[schema(value_type = Option<u32>, example = 900)]
     pub order_fulfillment_time: Option<OrderFulfillmentTime>,
@@ -2304,6 +2462,18 @@ pub struct BusinessProfileUpdate {
     /// These key-value pairs are sent as additional custom headers in the outgoing webhook request. It is recommended not to use more than four key-value pairs.
     #[schema(value_type = Option<Object>, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
     pub outgoing_webhook_custom_http_headers: Option<HashMap<String, String>>,
+
+    /// The policy for retrying failed payments of the business profile, payments are retried as per the GSM configuration if not set. Setting it to `null` removes the policy
+    #[serde(
+        default,
+        deserialize_with = "common_utils::custom_serde::nullable::deserialize"
+    )]
+    #[schema(value_type = Option<RetryPolicy>)]
+    pub retry_policy: Option<Option<RetryPolicy>>,
+
+    /// The scheme used to sign the outgoing webhooks of the business profile, the legacy scheme is used if not set
+    #[schema(value_type = Option<WebhookSignatureScheme>, example = "v1")]
+    pub webhook_signature_scheme: Option<api_enums::WebhookSignatureScheme>,
 }
 
 #[cfg(all(feature = "v2", feature = "business_profile_v2"))]
@@ -2384,6 +2554,18 @@ pub struct BusinessProfileUpdate {
     /// These key-value pairs are sent as additional custom headers in the outgoing webhook request. It is recommended not to use more than four key-value pairs.
     #[schema(value_type = Option<Object>, example = r#"{ "key1": "value-1", "key2": "value-2" }"#)]
     pub outgoing_webhook_custom_http_headers: Option<HashMap<String, String>>,
+
+    /// The policy for retrying failed payments of the business profile, payments are retried as per the GSM configuration if not set. Setting it to `null` removes the policy
+    #[serde(
+        default,
+        deserialize_with = "common_utils::custom_serde::nullable::deserialize"
+    )]
+    #[schema(value_type = Option<RetryPolicy>)]
+    pub retry_policy: Option<Option<RetryPolicy>>,
+
+    /// The scheme used to sign the outgoing webhooks of the business profile, the legacy scheme is used if not set
+    #[schema(value_type = Option<WebhookSignatureScheme>, example = "v1")]
+    pub webhook_signature_scheme: Option<api_enums::WebhookSignatureScheme>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -2542,6 +2724,40 @@ pub struct ConnectorAgnosticMitChoice {
 
 impl common_utils::events::ApiEventMetric for ConnectorAgnosticMitChoice {}
 
+/// The request body for rotating the key used to sign the outgoing webhooks of a business profile
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSigningKeyRotateRequest {
+    /// The new `payment_response_hash_key`. A random key is generated if not specified.
+    #[schema(value_type = Option<String>, max_length = 255)]
+    pub payment_response_hash_key: Option<Secret<String>>,
+
+    /// The time in seconds for which outgoing webhooks are additionally signed with the previous key when the `v1` webhook signature scheme is used. Defaults to 86400 seconds (24 hours).
+    #[schema(maximum = 604800, example = 86400)]
+    pub rotation_window_in_secs: Option<u32>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookSigningKeyRotateRequest {}
+
+/// The response body for rotating the key used to sign the outgoing webhooks of a business profile
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookSigningKeyRotateResponse {
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: String,
+
+    /// The new `payment_response_hash_key`.
+    #[schema(value_type = String, max_length = 255)]
+    pub payment_response_hash_key: Secret<String>,
+
+    /// Time until which outgoing webhooks are additionally signed with the previous `payment_response_hash_key`.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookSigningKeyRotateResponse {}
+
 impl common_utils::events::ApiEventMetric for payment_methods::PaymentMethodMigrate {}
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
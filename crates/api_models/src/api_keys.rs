@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
+use crate::user_role::Permission;
+
 /// The request body for creating an API Key.
 #[derive(Debug, Deserialize, ToSchema, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -23,6 +25,16 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions granted to the API Key. If not provided, the API Key has access to all
+    /// the operations available to the merchant account.
+    #[schema(example = json!(["RefundRead", "RefundWrite"]))]
+    pub permissions: Option<Vec<Permission>>,
+
+    /// The IP addresses or CIDR ranges the API Key can be used from. If not provided, the API Key
+    /// can be used from any IP address.
+    #[schema(example = json!(["203.0.113.0/24", "2001:db8::/32"]))]
+    pub ip_allowlist: Option<Vec<String>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +72,21 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permissions granted to the API Key. The API Key has access to all the operations
+    /// available to the merchant account if this is not set.
+    #[schema(example = json!(["RefundRead", "RefundWrite"]))]
+    pub permissions: Option<Vec<Permission>>,
+
+    /// The IP addresses or CIDR ranges the API Key can be used from. The API Key can be used from
+    /// any IP address if this is not set.
+    #[schema(example = json!(["203.0.113.0/24", "2001:db8::/32"]))]
+    pub ip_allowlist: Option<Vec<String>>,
+
+    /// The identifier of the API Key this API Key was issued to replace, if it was issued by
+    /// rotating another API Key.
+    #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub predecessor_key_id: Option<String>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,12 +129,32 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
-    /*
-    /// The date and time indicating when the API Key was last used.
+
+    /// The permissions granted to the API Key. The API Key has access to all the operations
+    /// available to the merchant account if this is not set.
+    #[schema(example = json!(["RefundRead", "RefundWrite"]))]
+    pub permissions: Option<Vec<Permission>>,
+
+    /// The IP addresses or CIDR ranges the API Key can be used from. The API Key can be used from
+    /// any IP address if this is not set.
+    #[schema(example = json!(["203.0.113.0/24", "2001:db8::/32"]))]
+    pub ip_allowlist: Option<Vec<String>>,
+
+    /// The identifier of the API Key this API Key was issued to replace, if it was issued by
+    /// rotating another API Key.
+    #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub predecessor_key_id: Option<String>,
+
+    /// The identifier of the API Key which replaces this API Key, if this API Key has been
+    /// rotated. A rotated API Key remains valid until its expiration date.
+    #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub successor_key_id: Option<String>,
+
+    /// The date and time indicating when the API Key was last used. This is only tracked for
+    /// API Keys which have been rotated, to help identify when they can be safely retired.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub last_used: Option<PrimitiveDateTime>,
-    */
 }
 
 /// The request body for updating an API Key.
@@ -130,6 +177,41 @@ pub struct UpdateApiKeyRequest {
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
 
+    /// The permissions granted to the API Key. Replaces the existing set of permissions when
+    /// provided. An empty list or `null` gives the API Key access to all the operations again.
+    #[serde(default, deserialize_with = "custom_serde::nullable::deserialize")]
+    #[schema(value_type = Option<Vec<Permission>>, example = json!(["RefundRead", "RefundWrite"]))]
+    pub permissions: Option<Option<Vec<Permission>>>,
+
+    /// The IP addresses or CIDR ranges the API Key can be used from. Replaces the existing
+    /// allowlist when provided. An empty list or `null` allows the API Key to be used from any IP
+    /// address again.
+    #[serde(default, deserialize_with = "custom_serde::nullable::deserialize")]
+    #[schema(value_type = Option<Vec<String>>, example = json!(["203.0.113.0/24", "2001:db8::/32"]))]
+    pub ip_allowlist: Option<Option<Vec<String>>>,
+
+    #[serde(skip_deserializing)]
+    pub key_id: String,
+
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+/// The request body for rotating an API Key.
+#[derive(Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RotateApiKeyRequest {
+    /// The number of seconds the rotated API Key remains valid for, after which it is revoked.
+    /// Defaults to the grace period configured on the server, and cannot exceed 30 days.
+    #[schema(example = 86400)]
+    pub grace_period_in_secs: Option<u32>,
+
+    /// An expiration date for the new API Key. Defaults to the expiration date of the rotated
+    /// API Key.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    pub expiration: Option<ApiKeyExpiration>,
+
     #[serde(skip_deserializing)]
     pub key_id: String,
 
@@ -138,6 +220,22 @@ pub struct UpdateApiKeyRequest {
     pub merchant_id: common_utils::id_type::MerchantId,
 }
 
+/// The response body for rotating an API Key.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RotateApiKeyResponse {
+    /// The new API Key which replaces the rotated API Key.
+    pub successor: CreateApiKeyResponse,
+
+    /// The identifier of the rotated API Key.
+    #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub predecessor_key_id: String,
+
+    /// The time until which the rotated API Key remains valid, after which it is revoked.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub predecessor_expires_at: PrimitiveDateTime,
+}
+
 /// The response body for revoking an API Key.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct RevokeApiKeyResponse {
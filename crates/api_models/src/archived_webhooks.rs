@@ -0,0 +1,118 @@
+use common_enums::ArchivedWebhookStatus;
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The constraints to apply when listing archived incoming webhooks.
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct ArchivedWebhookListConstraints {
+    /// Filter webhooks received for the specified merchant connector account.
+    pub merchant_connector_id: Option<String>,
+
+    /// Filter webhooks by the outcome of processing them.
+    pub status: Option<ArchivedWebhookStatus>,
+
+    /// Filter webhooks received after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Filter webhooks received before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Include at most the specified number of webhooks.
+    pub limit: Option<u16>,
+
+    /// Include webhooks after the specified offset.
+    pub offset: Option<u16>,
+}
+
+/// The response body for an archived incoming webhook.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ArchivedWebhookResponse {
+    /// The identifier for the archived webhook.
+    #[schema(max_length = 64, example = "whin_N4FqVcJxXsh2XDC9NrnJ")]
+    pub webhook_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile the webhook was received for.
+    #[schema(max_length = 64, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: String,
+
+    /// The identifier for the merchant connector account the webhook was received for.
+    #[schema(max_length = 128, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: String,
+
+    /// The connector the webhook was received from.
+    #[schema(example = "stripe")]
+    pub connector: String,
+
+    /// The event ID assigned to the webhook by the connector, or a hash of the webhook body for
+    /// connectors which do not assign event IDs. Webhooks with the same key are considered to be
+    /// duplicates of each other.
+    pub deduplication_key: String,
+
+    /// The type of event the webhook notified of.
+    #[schema(example = "payment_intent_success")]
+    pub event_type: String,
+
+    /// The outcome of the most recent attempt to process the webhook.
+    pub status: ArchivedWebhookStatus,
+
+    /// Whether the source of the webhook was verified when it was received. Replays of the
+    /// webhook are processed with the same outcome of source verification.
+    pub source_verified: bool,
+
+    /// The number of times the webhook has been replayed.
+    pub replay_count: u32,
+
+    /// Time at which the webhook was most recently replayed.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_replayed_at: Option<PrimitiveDateTime>,
+
+    /// Time at which the webhook was received.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArchivedWebhookListRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub constraints: ArchivedWebhookListConstraints,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArchivedWebhookReplayRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub webhook_id: String,
+}
+
+impl ApiEventMetric for ArchivedWebhookListRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for ArchivedWebhookReplayRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for ArchivedWebhookResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
@@ -0,0 +1,73 @@
+use common_enums::{AuditAction, AuditEntityType};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The constraints to apply when filtering audit logs.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogListConstraints {
+    /// Filter audit logs of the specified kind of entity.
+    pub entity_type: Option<AuditEntityType>,
+
+    /// Filter audit logs of the entity with the specified identifier.
+    pub entity_id: Option<String>,
+
+    /// Filter audit logs of changes made by the specified actor, such as `user:<user_id>` or
+    /// `api_key:<key_id>`.
+    pub actor: Option<String>,
+
+    /// Filter audit logs created after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Filter audit logs created before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Include at most the specified number of audit logs.
+    pub limit: Option<u16>,
+
+    /// Include audit logs after the specified offset.
+    pub offset: Option<u16>,
+}
+
+/// The response body for each item when listing audit logs.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuditLogResponse {
+    /// The identifier for the audit log.
+    #[schema(max_length = 64, example = "audit_018e31720d1b7a2b82677d3032cab959")]
+    pub audit_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = Option<String>)]
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+
+    /// The user or the API key which made the change, such as `user:<user_id>` or
+    /// `api_key:<key_id>`.
+    #[schema(max_length = 255, example = "api_key:dev_Ml4oY2gBbLBJkhWzV5S0")]
+    pub actor: Option<String>,
+
+    /// The kind of entity which was changed.
+    pub entity_type: AuditEntityType,
+
+    /// The identifier for the entity which was changed.
+    #[schema(max_length = 255, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub entity_id: String,
+
+    /// The change which was made to the entity.
+    pub action: AuditAction,
+
+    /// The fields of the entity which changed, keyed by their path, with their values before and
+    /// after the change. Secrets are masked.
+    #[schema(value_type = Object, example = json!({"profile_name": {"before": "default", "after": "primary"}}))]
+    pub diff: serde_json::Value,
+
+    /// The IP address the change was made from.
+    #[schema(max_length = 64, example = "203.0.113.7")]
+    pub source_ip: Option<String>,
+
+    /// Time at which the change was made.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
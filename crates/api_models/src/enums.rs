@@ -613,7 +613,9 @@ mod test {
 pub enum RetryAction {
     /// Payment can be retried from the client side until the payment is successful or payment expires or the attempts(configured by the merchant) for payment are exhausted
     ManualRetry,
-    /// Denotes that the payment is requeued
+    /// Denotes that the payment is requeued. This is only set internally when a requeued payment
+    /// is confirmed again, and cannot be specified in requests.
+    #[serde(skip_deserializing)]
     Requeue,
 }
 
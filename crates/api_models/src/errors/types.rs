@@ -93,6 +93,8 @@ pub enum ApiErrorResponse {
     InternalServerError(ApiError),
     NotImplemented(ApiError),
     ConnectorError(ApiError, #[serde(skip_serializing)] StatusCode),
+    /// The number of seconds after which the request can be retried
+    TooManyRequests(ApiError, #[serde(skip_serializing)] u64),
     NotFound(ApiError),
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
@@ -126,7 +128,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -144,7 +147,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
-            | Self::ConnectorError(i, _) => i,
+            | Self::ConnectorError(i, _)
+            | Self::TooManyRequests(i, _) => i,
         }
     }
 
@@ -163,6 +167,7 @@ impl ApiErrorResponse {
             Self::InternalServerError(_) => "api",
             Self::DomainError(_) => "blocked",
             Self::ConnectorError(_, _) => "connector",
+            Self::TooManyRequests(_, _) => "rate_limit",
         }
     }
 }
@@ -27,6 +27,7 @@ use crate::{
         outgoing_webhook_event::OutgoingWebhookLogsRequest, sdk_events::*, search::*, *,
     },
     api_keys::*,
+    audit_log::*,
     cards_info::*,
     disputes::*,
     files::*,
@@ -102,6 +103,10 @@ impl_api_event_type!(
         ApplepayMerchantResponse,
         ApplepayVerifiedDomainsResponse,
         UpdateApiKeyRequest,
+        RotateApiKeyRequest,
+        RotateApiKeyResponse,
+        AuditLogListConstraints,
+        AuditLogResponse,
         GetApiEventFiltersRequest,
         ApiEventFiltersResponse,
         GetInfoResponse,
@@ -37,3 +37,27 @@ impl ApiEventMetric for gsm::GsmResponse {
         Some(ApiEventsType::Gsm)
     }
 }
+
+impl ApiEventMetric for gsm::GsmImportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
+
+impl ApiEventMetric for gsm::GsmImportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
+
+impl ApiEventMetric for gsm::GsmExportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
+
+impl ApiEventMetric for gsm::GsmExportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
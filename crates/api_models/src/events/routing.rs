@@ -2,9 +2,12 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::routing::{
     LinkedRoutingConfigRetrieveResponse, MerchantRoutingAlgorithm, ProfileDefaultRoutingConfig,
-    RoutingAlgorithmId, RoutingConfigRequest, RoutingDictionaryRecord, RoutingKind,
-    RoutingLinkWrapper, RoutingPayloadWrapper, RoutingRetrieveLinkQuery,
-    RoutingRetrieveLinkQueryWrapper, RoutingRetrieveQuery,
+    RoutingActivationHistoryQuery, RoutingActivationRecord, RoutingActivationScheduleWrapper,
+    RoutingAlgorithmDiff, RoutingAlgorithmId, RoutingConfigRequest, RoutingDictionaryRecord,
+    RoutingDiffQuery, RoutingKind, RoutingLinkWrapper, RoutingPayloadWrapper,
+    RoutingRetrieveLinkQuery, RoutingRetrieveLinkQueryWrapper, RoutingRetrieveQuery,
+    RoutingRollbackRequest, RoutingScheduledActivation, RoutingSimulationRequest,
+    RoutingSimulationResponse,
 };
 
 impl ApiEventMetric for RoutingKind {
@@ -76,3 +79,57 @@ impl ApiEventMetric for RoutingRetrieveLinkQueryWrapper {
         Some(ApiEventsType::Routing)
     }
 }
+
+impl ApiEventMetric for RoutingSimulationRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingSimulationResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingActivationRecord {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingActivationHistoryQuery {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingRollbackRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingActivationScheduleWrapper {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingScheduledActivation {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingDiffQuery {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingAlgorithmDiff {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
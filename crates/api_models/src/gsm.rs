@@ -1,8 +1,8 @@
 use utoipa::ToSchema;
 
-use crate::enums::Connector;
+use crate::enums::{Connector, GsmMatchType};
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct GsmCreateRequest {
     /// The connector through which payment has gone through
     pub connector: Connector,
@@ -26,6 +26,9 @@ pub struct GsmCreateRequest {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
+    /// how the message of the rule is matched against the message received from the connector
+    #[serde(default)]
+    pub match_type: GsmMatchType,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -88,6 +91,8 @@ pub struct GsmUpdateRequest {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
+    /// how the message of the rule is matched against the message received from the connector
+    pub match_type: Option<GsmMatchType>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -141,4 +146,92 @@ pub struct GsmResponse {
     pub unified_code: Option<String>,
     /// error message unified across the connectors
     pub unified_message: Option<String>,
+    /// how the message of the rule is matched against the message received from the connector
+    pub match_type: GsmMatchType,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmImportRequest {
+    /// The rules to be created, or updated if a rule with the same key already exists
+    pub rules: Vec<GsmCreateRequest>,
+    /// Only validate the rules, without creating or updating any of them
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct GsmImportQuery {
+    /// Only validate the rules, without creating or updating any of them
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmImportResponse {
+    /// Indicates if the rules were only validated
+    pub dry_run: bool,
+    /// Indicates if the rules were written. No rule is written if any of them is invalid
+    pub applied: bool,
+    /// The number of rules which would be or were created
+    pub created: usize,
+    /// The number of rules which would be or were updated
+    pub updated: usize,
+    /// The number of invalid rules
+    pub invalid: usize,
+    /// The outcome of every rule, in the order of the import
+    pub rules: Vec<GsmImportRuleResult>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmImportRuleResult {
+    /// The position of the rule in the import, starting from 1
+    pub line_number: usize,
+    /// The connector through which payment has gone through
+    pub connector: String,
+    /// The flow in which the code and message occurred for a connector
+    pub flow: String,
+    /// The sub_flow in which the code and message occurred  for a connector
+    pub sub_flow: String,
+    /// code received from the connector
+    pub code: String,
+    /// message received from the connector
+    pub message: String,
+    /// The action taken, or which would be taken, for the rule
+    pub action: GsmImportAction,
+    /// The reasons for which the rule is invalid
+    pub errors: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, strum::Display, PartialEq, Eq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GsmImportAction {
+    Create,
+    Update,
+    Invalid,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum GsmExportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmExportRequest {
+    /// The format of the export
+    #[serde(default)]
+    pub format: GsmExportFormat,
+    /// Only export the rules of this connector
+    pub connector: Option<Connector>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmExportResponse {
+    /// The rules, ordered by connector, flow, sub_flow, code and message
+    pub rules: Vec<GsmResponse>,
 }
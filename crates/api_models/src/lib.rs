@@ -2,6 +2,8 @@ pub mod admin;
 pub mod analytics;
 pub mod api_keys;
 pub mod apple_pay_certificates_migration;
+pub mod archived_webhooks;
+pub mod audit_log;
 pub mod blocklist;
 pub mod cards_info;
 pub mod conditional_configs;
@@ -36,5 +38,6 @@ pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
+pub mod webhook_endpoints;
 pub mod webhook_events;
 pub mod webhooks;
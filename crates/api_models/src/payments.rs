@@ -3892,6 +3892,14 @@ pub struct PaymentsResponse {
         example = "Custom_Order_id_123"
     )]
     pub merchant_order_reference_id: Option<String>,
+
+    /// Explanation of how the connector of the payment attempt was chosen, returned only while retrieving the payment
+    #[auth_based]
+    pub routing_decision_trace: Option<crate::routing::RoutingDecisionTrace>,
+
+    /// The retry policy of the business profile which applied to the payment attempt
+    #[auth_based]
+    pub retry_policy: Option<crate::admin::RetryPolicy>,
 }
 
 /// Fee information to be charged on the payment being collected
@@ -4350,6 +4358,10 @@ pub struct PaymentsRetrieveRequest {
     pub expand_captures: Option<bool>,
     /// If enabled provides list of attempts linked to payment intent
     pub expand_attempts: Option<bool>,
+    /// Whether the routing decision trace of the payment attempt is included in the response. This
+    /// is set by the retrieve route for merchants, and cannot be specified in requests.
+    #[serde(skip)]
+    pub expose_routing_decision_trace: bool,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
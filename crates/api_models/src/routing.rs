@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use common_utils::{errors::ParsingError, ext_traits::ValueExt, pii};
 pub use euclid::{
-    dssa::types::EuclidAnalysable,
+    dssa::types::{AnalysisWarning, EuclidAnalysable},
     frontend::{
         ast,
         dir::{DirKeyKind, EuclidDirFilter},
@@ -13,7 +13,7 @@ use utoipa::ToSchema;
 
 use crate::enums::{RoutableConnectors, TransactionType};
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum ConnectorSelection {
     Priority(Vec<RoutableConnectorChoice>),
@@ -29,6 +29,13 @@ impl ConnectorSelection {
             }
         }
     }
+
+    fn has_uniform_stickiness_key(&self) -> bool {
+        match self {
+            Self::Priority(_) => true,
+            Self::VolumeSplit(splits) => has_uniform_stickiness_key(splits),
+        }
+    }
 }
 #[cfg(all(feature = "v2", feature = "routing_v2"))]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -94,6 +101,9 @@ pub struct MerchantRoutingAlgorithm {
     pub created_at: i64,
     pub modified_at: i64,
     pub algorithm_for: TransactionType,
+    /// The program of an advanced routing algorithm, in the text syntax of the routing DSL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub program_text: Option<String>,
 }
 
 impl EuclidDirFilter for ConnectorSelection {
@@ -126,6 +136,14 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::CardRedirectType,
         DirKeyKind::BankTransferType,
         DirKeyKind::RealTimePaymentType,
+        DirKeyKind::HourOfDay,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::DateRange,
+        DirKeyKind::CardIssuerCountry,
+        DirKeyKind::CardIssuer,
+        DirKeyKind::CardProductType,
+        DirKeyKind::PrepaidCard,
+        DirKeyKind::CommercialCard,
     ];
 }
 
@@ -156,10 +174,112 @@ impl EuclidAnalysable for ConnectorSelection {
     }
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct ConnectorVolumeSplit {
     pub connector: RoutableConnectorChoice,
     pub split: u8,
+    /// Attribute of the payment used to pick the split deterministically, so that payments sharing it are routed to the same connector. The split is picked at random when it is not provided
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stickiness_key: Option<VolumeSplitStickinessKey>,
+}
+
+/// Attribute of the payment on which a volume split is made sticky
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum VolumeSplitStickinessKey {
+    /// The customer of the payment
+    CustomerId,
+    /// The fingerprint of the card used for the payment, or the saved payment method when the
+    /// payment has no card fingerprint
+    CardFingerprint,
+    /// A key of the metadata of the payment
+    Metadata(String),
+}
+
+/// All the connectors of a volume split have to be sticky on the same attribute, as the split is
+/// picked once for all of them
+fn has_uniform_stickiness_key(splits: &[ConnectorVolumeSplit]) -> bool {
+    let stickiness_key = splits
+        .first()
+        .and_then(|split| split.stickiness_key.as_ref());
+    splits
+        .iter()
+        .all(|split| split.stickiness_key.as_ref() == stickiness_key)
+}
+
+/// A volume split is written in the text syntax of the routing DSL followed by the attribute it is
+/// sticky on, as in `[50% stripe, 50% adyen] by customer_id`
+impl ast::printer::EuclidPrintable for ConnectorSelection {
+    fn print_output(&self) -> Result<String, ast::printer::PrintError> {
+        let selection = match self {
+            Self::Priority(choices) => ast::ConnectorSelection::Priority(
+                choices
+                    .iter()
+                    .cloned()
+                    .map(ast::RoutableConnectorChoice::from)
+                    .collect(),
+            ),
+            Self::VolumeSplit(splits) => ast::ConnectorSelection::VolumeSplit(
+                splits
+                    .iter()
+                    .map(|split| ast::ConnectorVolumeSplit {
+                        connector: split.connector.clone().into(),
+                        split: split.split,
+                    })
+                    .collect(),
+            ),
+        };
+        let output = ast::printer::EuclidPrintable::print_output(&selection)?;
+
+        let stickiness_key = match self {
+            Self::Priority(_) => None,
+            Self::VolumeSplit(splits) => splits
+                .first()
+                .and_then(|split| split.stickiness_key.as_ref()),
+        };
+
+        match stickiness_key {
+            Some(stickiness_key) => Ok(format!(
+                "{output} by {}",
+                ast::printer::print_tagged_value(stickiness_key)?
+            )),
+            None => Ok(output),
+        }
+    }
+}
+
+#[cfg(feature = "ast_parser")]
+impl ast::parser::EuclidParsable for ConnectorSelection {
+    fn parse_output(input: &str) -> ast::parser::ParseResult<&str, Self> {
+        let (input, selection) =
+            <ast::ConnectorSelection as ast::parser::EuclidParsable>::parse_output(input)?;
+
+        match selection {
+            ast::ConnectorSelection::Priority(choices) => Ok((
+                input,
+                Self::Priority(
+                    choices
+                        .into_iter()
+                        .map(RoutableConnectorChoice::from)
+                        .collect(),
+                ),
+            )),
+            ast::ConnectorSelection::VolumeSplit(splits) => {
+                let (input, stickiness_key) =
+                    ast::parser::stickiness_key::<VolumeSplitStickinessKey>(input)?;
+                let splits = splits
+                    .into_iter()
+                    .map(|split| ConnectorVolumeSplit {
+                        connector: split.connector.into(),
+                        split: split.split,
+                        stickiness_key: stickiness_key.clone(),
+                    })
+                    .collect();
+
+                Ok((input, Self::VolumeSplit(splits)))
+            }
+        }
+    }
 }
 
 /// Routable Connector chosen for a payment
@@ -204,6 +324,32 @@ impl From<RoutableConnectorChoice> for ast::ConnectorChoice {
     }
 }
 
+impl From<RoutableConnectorChoice> for ast::RoutableConnectorChoice {
+    fn from(value: RoutableConnectorChoice) -> Self {
+        Self {
+            choice_kind: match value.choice_kind {
+                RoutableChoiceKind::OnlyConnector => ast::RoutableChoiceKind::OnlyConnector,
+                RoutableChoiceKind::FullStruct => ast::RoutableChoiceKind::FullStruct,
+            },
+            connector: value.connector,
+            merchant_connector_id: value.merchant_connector_id,
+        }
+    }
+}
+
+impl From<ast::RoutableConnectorChoice> for RoutableConnectorChoice {
+    fn from(value: ast::RoutableConnectorChoice) -> Self {
+        Self {
+            choice_kind: match value.choice_kind {
+                ast::RoutableChoiceKind::OnlyConnector => RoutableChoiceKind::OnlyConnector,
+                ast::RoutableChoiceKind::FullStruct => RoutableChoiceKind::FullStruct,
+            },
+            connector: value.connector,
+            merchant_connector_id: value.merchant_connector_id,
+        }
+    }
+}
+
 impl PartialEq for RoutableConnectorChoice {
     fn eq(&self, other: &Self) -> bool {
         self.connector.eq(&other.connector)
@@ -246,7 +392,17 @@ impl From<RoutableConnectorChoice> for RoutableChoiceSerde {
     }
 }
 
-#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize, strum::Display, ToSchema)]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum RoutingAlgorithmKind {
@@ -254,6 +410,8 @@ pub enum RoutingAlgorithmKind {
     Priority,
     VolumeSplit,
     Advanced,
+    SuccessRate,
+    CostBased,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -275,8 +433,11 @@ pub enum RoutingAlgorithm {
     Single(Box<RoutableConnectorChoice>),
     Priority(Vec<RoutableConnectorChoice>),
     VolumeSplit(Vec<ConnectorVolumeSplit>),
+    /// The program of the algorithm, which is also accepted in the text syntax of the routing DSL
     #[schema(value_type=ProgramConnectorSelection)]
     Advanced(ast::Program<ConnectorSelection>),
+    SuccessRate(SuccessRateAlgorithm),
+    CostBased(CostBasedAlgorithm),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -285,7 +446,99 @@ pub enum RoutingAlgorithmSerde {
     Single(Box<RoutableConnectorChoice>),
     Priority(Vec<RoutableConnectorChoice>),
     VolumeSplit(Vec<ConnectorVolumeSplit>),
+    #[serde(deserialize_with = "deserialize_advanced_program")]
     Advanced(ast::Program<ConnectorSelection>),
+    SuccessRate(SuccessRateAlgorithm),
+    CostBased(CostBasedAlgorithm),
+}
+
+/// Accepts the program of an advanced routing algorithm either as JSON or as a string in the
+/// text syntax of the routing DSL
+fn deserialize_advanced_program<'de, D>(
+    deserializer: D,
+) -> Result<ast::Program<ConnectorSelection>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        #[cfg(feature = "ast_parser")]
+        serde_json::Value::String(text) => {
+            ast::parser::parse_program(&text).map_err(serde::de::Error::custom)
+        }
+        #[cfg(not(feature = "ast_parser"))]
+        serde_json::Value::String(_) => Err(serde::de::Error::custom(
+            "the text syntax of the routing DSL is not supported",
+        )),
+        value => serde_json::from_value(value).map_err(serde::de::Error::custom),
+    }
+}
+
+/// Routing algorithm which ranks the connectors by the fee estimated for the payment, as per the
+/// fee schedules of their connector accounts
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct CostBasedAlgorithm {
+    /// Connectors which are ranked on the basis of their estimated fee. Connectors with the same
+    /// fee keep the order in which they are listed, and connectors without a fee schedule are
+    /// ranked last
+    pub connectors: Vec<RoutableConnectorChoice>,
+}
+
+/// Routing algorithm which ranks the connectors by their recent success rate
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct SuccessRateAlgorithm {
+    /// Connectors which are ranked on the basis of their success rate
+    pub connectors: Vec<RoutableConnectorChoice>,
+    #[serde(default)]
+    pub config: SuccessRateConfig,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(default)]
+pub struct SuccessRateConfig {
+    /// Duration of the sliding window over which the success rate of a connector is computed
+    pub window_duration_in_secs: u32,
+    /// Number of buckets the sliding window is split into
+    pub window_bucket_count: u8,
+    /// Minimum number of attempts in the window for the success rate of a connector to be considered
+    pub min_aggregates_size: u32,
+    /// Success rate, in percentage, assumed for connectors without enough attempts in the window
+    pub default_success_rate: f64,
+    /// Percentage of payments routed to a random connector, so that the success rates of all the connectors stay up to date
+    pub exploration_percent: u8,
+    /// Dimensions by which the outcomes of the connectors are bucketed
+    pub dimensions: Vec<SuccessRateDimension>,
+}
+
+impl Default for SuccessRateConfig {
+    fn default() -> Self {
+        Self {
+            window_duration_in_secs: 3600,
+            window_bucket_count: 12,
+            min_aggregates_size: 10,
+            default_success_rate: 100.0,
+            exploration_percent: 10,
+            dimensions: Vec::new(),
+        }
+    }
+}
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SuccessRateDimension {
+    PaymentMethodType,
+    CardNetwork,
+    Currency,
 }
 
 impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
@@ -303,6 +556,51 @@ impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
                     "Connectors list can't be empty for Volume split Algorithm",
                 ))?
             }
+            RoutingAlgorithmSerde::VolumeSplit(i) if !has_uniform_stickiness_key(i) => {
+                Err(ParsingError::StructParseFailure(
+                    "All the connectors of a Volume split must have the same stickiness key",
+                ))?
+            }
+            RoutingAlgorithmSerde::Advanced(program)
+                if !std::iter::once(&program.default_selection)
+                    .chain(program.rules.iter().map(|rule| &rule.connector_selection))
+                    .all(ConnectorSelection::has_uniform_stickiness_key) =>
+            {
+                Err(ParsingError::StructParseFailure(
+                    "All the connectors of a Volume split must have the same stickiness key",
+                ))?
+            }
+            RoutingAlgorithmSerde::SuccessRate(i) if i.connectors.is_empty() => {
+                Err(ParsingError::StructParseFailure(
+                    "Connectors list can't be empty for Success rate Algorithm",
+                ))?
+            }
+            RoutingAlgorithmSerde::SuccessRate(i) if i.config.exploration_percent > 100 => {
+                Err(ParsingError::StructParseFailure(
+                    "Exploration percent can't be more than 100 for Success rate Algorithm",
+                ))?
+            }
+            RoutingAlgorithmSerde::SuccessRate(i)
+                if !(0.0..=100.0).contains(&i.config.default_success_rate) =>
+            {
+                Err(ParsingError::StructParseFailure(
+                    "Default success rate must be between 0 and 100 for Success rate Algorithm",
+                ))?
+            }
+            RoutingAlgorithmSerde::SuccessRate(i)
+                if i.config.window_bucket_count == 0
+                    || i.config.window_duration_in_secs
+                        < u32::from(i.config.window_bucket_count) =>
+            {
+                Err(ParsingError::StructParseFailure(
+                    "Window should have at least one bucket of a second for Success rate Algorithm",
+                ))?
+            }
+            RoutingAlgorithmSerde::CostBased(i) if i.connectors.is_empty() => {
+                Err(ParsingError::StructParseFailure(
+                    "Connectors list can't be empty for Cost based Algorithm",
+                ))?
+            }
             _ => {}
         };
         Ok(match value {
@@ -310,6 +608,8 @@ impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
             RoutingAlgorithmSerde::Priority(i) => Self::Priority(i),
             RoutingAlgorithmSerde::VolumeSplit(i) => Self::VolumeSplit(i),
             RoutingAlgorithmSerde::Advanced(i) => Self::Advanced(i),
+            RoutingAlgorithmSerde::SuccessRate(i) => Self::SuccessRate(i),
+            RoutingAlgorithmSerde::CostBased(i) => Self::CostBased(i),
         })
     }
 }
@@ -368,6 +668,11 @@ impl TryFrom<StraightThroughAlgorithmSerde> for StraightThroughAlgorithm {
                     "Connectors list can't be empty for Volume split Algorithm",
                 ))?
             }
+            StraightThroughAlgorithmInner::VolumeSplit(i) if !has_uniform_stickiness_key(i) => {
+                Err(ParsingError::StructParseFailure(
+                    "All the connectors of a Volume split must have the same stickiness key",
+                ))?
+            }
             _ => {}
         };
 
@@ -412,6 +717,17 @@ impl RoutingAlgorithm {
             Self::Priority(_) => RoutingAlgorithmKind::Priority,
             Self::VolumeSplit(_) => RoutingAlgorithmKind::VolumeSplit,
             Self::Advanced(_) => RoutingAlgorithmKind::Advanced,
+            Self::SuccessRate(_) => RoutingAlgorithmKind::SuccessRate,
+            Self::CostBased(_) => RoutingAlgorithmKind::CostBased,
+        }
+    }
+
+    /// Returns the program of an advanced routing algorithm in the text syntax of the routing
+    /// DSL, or an error if the program cannot be written in it
+    pub fn get_program_text(&self) -> Result<Option<String>, ast::printer::PrintError> {
+        match self {
+            Self::Advanced(program) => ast::printer::print_program(program).map(Some),
+            _ => Ok(None),
         }
     }
 }
@@ -461,6 +777,9 @@ pub struct RoutingDictionaryRecord {
     pub created_at: i64,
     pub modified_at: i64,
     pub algorithm_for: Option<TransactionType>,
+    /// Rules of the algorithm which can never be selected, returned when the algorithm is created
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<AnalysisWarning>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -488,3 +807,290 @@ pub struct RoutingLinkWrapper {
     pub profile_id: String,
     pub algorithm_id: RoutingAlgorithmId,
 }
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum RoutingSimulationAlgorithm {
+    /// A routing algorithm which has already been created for the profile
+    Stored(String),
+    /// A routing algorithm which has not been created yet
+    Inline(RoutingAlgorithm),
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingSimulationRequest {
+    /// The business profile whose payments are replayed
+    pub profile_id: String,
+    /// The routing algorithm to be simulated
+    pub algorithm: RoutingSimulationAlgorithm,
+    /// The time window in which the replayed payments were created
+    pub time_range: crate::payments::TimeRange,
+    /// The maximum number of payments to replay, most recent payments first
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    pub profile_id: String,
+    /// The number of payments which were replayed
+    pub total_payments: u64,
+    /// The number of payments for which the algorithm chose a different connector than the one
+    /// which actually processed the payment
+    pub rerouted_payments: u64,
+    /// The number of payments for which no eligible connector was found by the algorithm
+    pub unroutable_payments: u64,
+    /// The distribution of payments across connectors as chosen by the algorithm
+    pub simulated: Vec<ConnectorDistribution>,
+    /// The distribution of payments across connectors as actually processed
+    pub actual: Vec<ConnectorDistribution>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ConnectorDistribution {
+    /// The name of the connector
+    pub connector: String,
+    /// The number of payments routed to the connector
+    pub payment_count: u64,
+    /// The share of payments routed to the connector, in percentage
+    pub volume_percentage: f64,
+    /// The amounts routed to the connector, per currency
+    pub amounts: Vec<ConnectorAmountDistribution>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ConnectorAmountDistribution {
+    pub currency: crate::enums::Currency,
+    /// The total amount routed to the connector in the currency, in minor units
+    #[schema(value_type = i64)]
+    pub amount: common_utils::types::MinorUnit,
+    /// The share of the total amount in the currency routed to the connector, in percentage
+    pub amount_percentage: f64,
+}
+
+/// Explanation of how the connectors of a payment attempt were chosen
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingDecisionTrace {
+    /// How the candidate connectors were chosen
+    pub approach: RoutingApproach,
+    /// The routing algorithm which was evaluated
+    pub algorithm_id: Option<String>,
+    /// The kind of the routing algorithm which was evaluated
+    pub algorithm_kind: Option<RoutingAlgorithmKind>,
+    /// The version of the routing algorithm, which is the time at which it was activated
+    pub algorithm_version: Option<i64>,
+    /// The name of the rule of the advanced routing algorithm which matched the payment
+    pub matched_rule: Option<String>,
+    /// The connectors chosen by the routing algorithm, before the eligibility analysis
+    pub candidates: Vec<RoutableConnectorChoice>,
+    /// The candidates which remained eligible for the payment after the eligibility analysis
+    pub eligible_candidates: Vec<RoutableConnectorChoice>,
+    /// The candidates which were removed by the eligibility analysis
+    pub eliminated_candidates: Vec<EliminatedConnector>,
+    /// Whether the payment had to be routed through the default fallback connectors, as none of
+    /// the candidates were eligible
+    pub fallback_used: bool,
+    /// The connectors the payment could be routed through, in the order of preference
+    pub final_connectors: Vec<RoutableConnectorChoice>,
+    /// The fees estimated for the candidates by the cost based routing algorithm
+    #[serde(default)]
+    pub fee_estimates: Vec<ConnectorFeeEstimate>,
+    /// The fee estimated for the connector the payment is routed through
+    #[serde(default)]
+    pub estimated_fee: Option<ConnectorFeeEstimate>,
+    /// The dimension of the success rate window the payment was routed on, by the success rate
+    /// routing algorithm
+    #[serde(default)]
+    pub success_rate_dimension_key: Option<String>,
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingApproach {
+    /// The connectors were provided in the payment request
+    StraightThrough,
+    /// The connectors were chosen by the routing algorithm active on the profile
+    RoutingAlgorithm,
+    /// No routing algorithm is active on the profile, the default fallback connectors were used
+    #[default]
+    DefaultFallback,
+}
+
+/// Fee a connector is estimated to charge for a payment, in the currency of the payment
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorFeeEstimate {
+    pub connector: RoutableConnectorChoice,
+    #[schema(value_type = i64, example = 320)]
+    pub amount: common_utils::types::MinorUnit,
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: crate::enums::Currency,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct EliminatedConnector {
+    pub connector: RoutableConnectorChoice,
+    pub reason: ConnectorEliminationReason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorEliminationReason {
+    /// The connector is not configured to support the payment, as per the constraint graph
+    ConstraintGraph,
+    /// The connector is not part of the eligible connectors provided in the payment request
+    EligibleConnectorsFilter,
+    /// The circuit breaker of the connector is open or only letting probe traffic through, after
+    /// a high rate of technical failures
+    CircuitBreaker,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingActivationRecord {
+    pub activation_id: String,
+    pub profile_id: String,
+    pub transaction_type: TransactionType,
+    /// The routing algorithm which became active, empty if the profile was left without one
+    pub algorithm_id: Option<String>,
+    /// The routing algorithm which was active before
+    pub previous_algorithm_id: Option<String>,
+    pub activation_kind: common_enums::RoutingActivationKind,
+    /// The user or the API key which made the change, as `user:<user_id>` or `api_key:<key_id>`
+    pub activated_by: Option<String>,
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub activated_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingActivationHistoryQuery {
+    pub profile_id: String,
+    pub limit: Option<u16>,
+    pub offset: Option<u16>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRollbackRequest {
+    pub profile_id: String,
+    /// The activation whose routing algorithm has to be made active again. Defaults to
+    /// restoring the routing algorithm which was active before the latest activation.
+    pub activation_id: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingActivationScheduleRequest {
+    /// The time at which the routing algorithm has to be activated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub activate_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingActivationScheduleWrapper {
+    pub algorithm_id: String,
+    pub request: RoutingActivationScheduleRequest,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingScheduledActivation {
+    pub algorithm_id: String,
+    pub profile_id: String,
+    pub transaction_type: TransactionType,
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub activate_at: time::PrimitiveDateTime,
+    pub scheduled_by: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingDiffQuery {
+    pub base_algorithm_id: String,
+    pub target_algorithm_id: String,
+}
+
+/// The differences between two routing algorithms
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingAlgorithmDiff {
+    pub base_algorithm_id: String,
+    pub target_algorithm_id: String,
+    pub base_kind: RoutingAlgorithmKind,
+    pub target_kind: RoutingAlgorithmKind,
+    /// The change in the connectors chosen when no rule matches, or in the connectors chosen by
+    /// algorithms which do not have rules
+    pub default_selection: Option<ConnectorSelectionDiff>,
+    /// The rules which were added, removed or modified, matched by their names
+    pub rules: Vec<RoutingRuleDiff>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, ToSchema)]
+pub struct ConnectorSelectionDiff {
+    pub base: Option<ConnectorSelection>,
+    pub target: Option<ConnectorSelection>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, ToSchema)]
+pub struct RoutingRuleDiff {
+    pub rule_name: String,
+    pub change: RoutingRuleChange,
+    /// Whether the conditions of the rule differ
+    pub conditions_changed: bool,
+    /// The change in the connectors chosen by the rule, if any
+    pub connector_selection: Option<ConnectorSelectionDiff>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingRuleChange {
+    Added,
+    Removed,
+    Modified,
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod tests {
+    #![allow(clippy::expect_used, clippy::panic)]
+
+    use super::*;
+
+    #[test]
+    fn test_program_text_round_trip_with_stickiness_key() {
+        let program_text = r#"default: [60% stripe, 40% adyen("mca_adyen")] by customer_id
+
+card_rule: [50% checkout, 50% stripe] by metadata("merchant_ref") {
+    payment_method = card
+}
+"#;
+
+        let algorithm = RoutingAlgorithm::try_from(RoutingAlgorithmSerde::Advanced(
+            ast::parser::parse_program(program_text).expect("Program"),
+        ))
+        .expect("Routing algorithm");
+
+        let RoutingAlgorithm::Advanced(program) = &algorithm else {
+            panic!("Expected an advanced routing algorithm");
+        };
+        let ConnectorSelection::VolumeSplit(splits) = &program.default_selection else {
+            panic!("Expected a volume split");
+        };
+        assert!(splits
+            .iter()
+            .all(|split| split.stickiness_key == Some(VolumeSplitStickinessKey::CustomerId)));
+        let ConnectorSelection::VolumeSplit(splits) =
+            &program.rules.first().expect("Rule").connector_selection
+        else {
+            panic!("Expected a volume split");
+        };
+        assert!(splits.iter().all(|split| split.stickiness_key
+            == Some(VolumeSplitStickinessKey::Metadata(
+                "merchant_ref".to_string()
+            ))));
+
+        assert_eq!(
+            algorithm.get_program_text().expect("Printed program"),
+            Some(program_text.to_string())
+        );
+    }
+}
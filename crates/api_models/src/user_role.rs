@@ -3,7 +3,9 @@ use common_utils::pii;
 
 pub mod role;
 
-#[derive(Debug, serde::Serialize)]
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, utoipa::ToSchema,
+)]
 pub enum Permission {
     PaymentRead,
     PaymentWrite,
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use common_enums::EventType;
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use masking::Secret;
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The request body for registering a webhook endpoint on a business profile.
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointCreateRequest {
+    /// The URL to which outgoing webhooks are delivered.
+    #[schema(value_type = String, example = "https://example.com/webhooks/payments")]
+    pub url: url::Url,
+
+    /// The key used to sign the webhooks delivered to the endpoint. A random key is generated if
+    /// not specified.
+    #[schema(value_type = Option<String>)]
+    pub secret: Option<Secret<String>>,
+
+    /// The event types delivered to the endpoint. All event types are delivered if not specified
+    /// or empty.
+    pub enabled_events: Option<Vec<EventType>>,
+
+    /// Whether webhooks are delivered to the endpoint. Defaults to `true`.
+    pub enabled: Option<bool>,
+
+    /// Custom HTTP headers sent along with the webhooks delivered to the endpoint.
+    #[schema(value_type = Option<Object>, example = r#"{ "x-team": "finance" }"#)]
+    pub custom_http_headers: Option<HashMap<String, Secret<String>>>,
+
+    /// A description of the endpoint.
+    #[schema(max_length = 255, example = "Payment events for the finance system")]
+    pub description: Option<String>,
+}
+
+/// The request body for updating a webhook endpoint.
+#[derive(Clone, Debug, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointUpdateRequest {
+    /// The URL to which outgoing webhooks are delivered.
+    #[schema(value_type = Option<String>, example = "https://example.com/webhooks/payments")]
+    pub url: Option<url::Url>,
+
+    /// The key used to sign the webhooks delivered to the endpoint.
+    #[schema(value_type = Option<String>)]
+    pub secret: Option<Secret<String>>,
+
+    /// The event types delivered to the endpoint. All event types are delivered if empty.
+    pub enabled_events: Option<Vec<EventType>>,
+
+    /// Whether webhooks are delivered to the endpoint.
+    pub enabled: Option<bool>,
+
+    /// Custom HTTP headers sent along with the webhooks delivered to the endpoint.
+    #[schema(value_type = Option<Object>, example = r#"{ "x-team": "finance" }"#)]
+    pub custom_http_headers: Option<HashMap<String, Secret<String>>>,
+
+    /// A description of the endpoint.
+    #[schema(max_length = 255, example = "Payment events for the finance system")]
+    pub description: Option<String>,
+}
+
+/// The response body for webhook endpoint operations.
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whe_5jfoaQmTeIXAWnLkNJRT")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: String,
+
+    /// The URL to which outgoing webhooks are delivered.
+    #[schema(example = "https://example.com/webhooks/payments")]
+    pub url: String,
+
+    /// The key used to sign the webhooks delivered to the endpoint. This is only returned when
+    /// the endpoint is created.
+    #[schema(value_type = Option<String>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<Secret<String>>,
+
+    /// The event types delivered to the endpoint. All event types are delivered if empty.
+    pub enabled_events: Vec<EventType>,
+
+    /// Whether webhooks are delivered to the endpoint.
+    pub enabled: bool,
+
+    /// Custom HTTP headers sent along with the webhooks delivered to the endpoint.
+    #[schema(value_type = Option<Object>, example = r#"{ "x-team": "finance" }"#)]
+    pub custom_http_headers: Option<HashMap<String, Secret<String>>>,
+
+    /// A description of the endpoint.
+    #[schema(max_length = 255, example = "Payment events for the finance system")]
+    pub description: Option<String>,
+
+    /// The delivery health of the endpoint.
+    pub health: WebhookEndpointHealth,
+
+    /// Time at which the endpoint was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time at which the endpoint was last modified.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// The delivery health of a webhook endpoint.
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct WebhookEndpointHealth {
+    /// The number of deliveries to the endpoint which have failed since the last successful one.
+    #[schema(example = 0)]
+    pub consecutive_failures: u32,
+
+    /// The percentage of the most recent deliveries to the endpoint which were successful. Not
+    /// present if no deliveries have been made since the endpoint was created or re-enabled.
+    #[schema(example = 98)]
+    pub success_rate: Option<u8>,
+
+    /// Time at which deliveries to the endpoint were suspended due to repeated failures. Webhooks
+    /// for a suspended endpoint are queued until the endpoint is re-enabled. Not present if
+    /// deliveries to the endpoint are not suspended.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub suspended_at: Option<PrimitiveDateTime>,
+}
+
+/// The response body for re-enabling a suspended webhook endpoint.
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct WebhookEndpointReenableResponse {
+    #[serde(flatten)]
+    pub endpoint: WebhookEndpointResponse,
+
+    /// The number of webhooks queued while the endpoint was suspended, which have been scheduled
+    /// for delivery.
+    #[schema(example = 42)]
+    pub queued_webhooks_scheduled: u32,
+}
+
+/// The response body for deleting a webhook endpoint.
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct WebhookEndpointDeleteResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whe_5jfoaQmTeIXAWnLkNJRT")]
+    pub endpoint_id: String,
+
+    /// Indicates whether the webhook endpoint was deleted.
+    pub deleted: bool,
+}
+
+impl ApiEventMetric for WebhookEndpointResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointReenableResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.endpoint.get_api_event_type()
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointCreateRequest {}
+impl ApiEventMetric for WebhookEndpointUpdateRequest {}
+impl ApiEventMetric for WebhookEndpointDeleteResponse {}
@@ -1,4 +1,7 @@
-use common_enums::{EventClass, EventType, WebhookDeliveryAttempt};
+use common_enums::{
+    EventClass, EventType, WebhookDeliveryAttempt, WebhookDeliveryStatus, WebhookReplayEventStatus,
+    WebhookReplayJobStatus,
+};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -75,6 +78,11 @@ pub struct EventListItemResponse {
     #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
     pub initial_attempt_id: String,
 
+    /// The identifier for the webhook endpoint the event was delivered to. This will be `null`
+    /// for events delivered to the webhook URL configured in the business profile.
+    #[schema(max_length = 64, example = "whe_5jfoaQmTeIXAWnLkNJRT")]
+    pub webhook_endpoint_id: Option<String>,
+
     /// Time at which the event was created.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601")]
@@ -186,3 +194,194 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+/// The request body for replaying the outgoing webhooks of a business profile in bulk.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookReplayRequest {
+    /// Replay events of the specified business profile.
+    #[schema(max_length = 64, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: String,
+
+    /// The filters to select the events to be replayed with.
+    pub filters: WebhookReplayFilters,
+
+    /// The maximum number of events to be replayed per minute. Defaults to 100.
+    #[schema(minimum = 1, maximum = 1000, example = 100)]
+    pub max_events_per_minute: Option<u16>,
+}
+
+/// The filters to select the events to be replayed with. Only the initial delivery attempts of
+/// events are considered for replay.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookReplayFilters {
+    /// Replay events created at or after the specified time.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: PrimitiveDateTime,
+
+    /// Replay events created at or before the specified time.
+    #[schema(example = "2022-09-10T12:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_before: PrimitiveDateTime,
+
+    /// Replay only events of the specified types.
+    pub event_types: Option<Vec<EventType>>,
+
+    /// Replay only events with the specified delivery status, considering all of their delivery
+    /// attempts. The delivery status is checked again when the event is replayed, and events
+    /// whose delivery status no longer matches are skipped.
+    pub delivery_status: Option<WebhookDeliveryStatus>,
+
+    /// Replay only events associated with the specified object identifiers (Payment Intent ID,
+    /// Refund ID, etc.)
+    pub object_ids: Option<Vec<String>>,
+}
+
+/// The response body for a bulk replay of outgoing webhooks.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookReplayJobResponse {
+    /// The identifier for the replay job.
+    #[schema(max_length = 64, example = "wrj_5jfoaQmTeIXAWnLkNJRT")]
+    pub job_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: String,
+
+    /// The status of the replay job.
+    pub status: WebhookReplayJobStatus,
+
+    /// The filters the events to be replayed were selected with.
+    pub filters: WebhookReplayFilters,
+
+    /// The maximum number of events replayed per minute.
+    #[schema(example = 100)]
+    pub max_events_per_minute: u16,
+
+    /// The number of events selected for replay.
+    #[schema(example = 1200)]
+    pub total_events: u32,
+
+    /// The number of events which are yet to be replayed.
+    #[schema(example = 200)]
+    pub pending_events: u32,
+
+    /// The number of events which were replayed and delivered successfully.
+    #[schema(example = 950)]
+    pub delivered_events: u32,
+
+    /// The number of events which were replayed, but could not be delivered.
+    #[schema(example = 40)]
+    pub failed_events: u32,
+
+    /// The number of events which were skipped, since their delivery status no longer matched
+    /// the filters.
+    #[schema(example = 10)]
+    pub skipped_events: u32,
+
+    /// Time at which the replay job was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time at which the replay job was last updated.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookReplayJobResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+/// The constraints to apply when listing the events of a bulk replay of outgoing webhooks.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookReplayJobEventListConstraints {
+    /// Include only events with the specified replay outcome.
+    pub status: Option<WebhookReplayEventStatus>,
+
+    /// Include at most the specified number of events.
+    pub limit: Option<u16>,
+
+    /// Include events after the specified offset.
+    pub offset: Option<u16>,
+}
+
+/// The response body for retrieving the status of a bulk replay of outgoing webhooks.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookReplayJobStatusResponse {
+    #[serde(flatten)]
+    pub job: WebhookReplayJobResponse,
+
+    /// The outcome of replaying each of the events of the replay job.
+    pub events: Vec<WebhookReplayEventResponse>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookReplayJobStatusResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        self.job.get_api_event_type()
+    }
+}
+
+/// The outcome of replaying an event as part of a bulk replay of outgoing webhooks.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookReplayEventResponse {
+    /// The identifier for the initial delivery attempt of the event being replayed.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: String,
+
+    /// The outcome of replaying the event.
+    pub status: WebhookReplayEventStatus,
+
+    /// The identifier for the delivery attempt created by replaying the event.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab960")]
+    pub replayed_event_id: Option<String>,
+
+    /// The reason the event could not be delivered, if it could not be delivered.
+    #[schema(example = "Received status code 503")]
+    pub error_message: Option<String>,
+
+    /// Time at which the outcome of replaying the event was last updated.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookReplayRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub request: WebhookReplayRequest,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookReplayRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookReplayJobRetrieveRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub job_id: String,
+    pub constraints: WebhookReplayJobEventListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookReplayJobRetrieveRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
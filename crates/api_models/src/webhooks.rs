@@ -7,8 +7,9 @@ use utoipa::ToSchema;
 use crate::payouts;
 use crate::{disputes, enums as api_enums, mandates, payments, refunds};
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy, strum::Display)]
 #[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
 pub enum IncomingWebhookEvent {
     /// Authorization + Capture success
     PaymentIntentFailure,
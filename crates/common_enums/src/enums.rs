@@ -2549,6 +2549,209 @@ impl AuthenticationConnectors {
     }
 }
 
+/// How the code and the message of a GSM rule are matched against the error received from the
+/// connector.
+///
+/// A connector error is matched against the rules of the connector for the flow and the sub flow
+/// in the following order, and the first match is used:
+/// 1. the `exact` rule for the code and the message
+/// 2. the `wildcard` rules for the code, the pattern with the most literal characters first
+/// 3. the `regex` rules for the code, in the lexicographic order of the pattern
+/// 4. the `catch_all` rule
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GsmMatchType {
+    /// The code and the message are matched exactly
+    #[default]
+    Exact,
+    /// The code is matched exactly, and the message is a pattern where `*` matches any sequence
+    /// of characters and `?` matches a single character
+    Wildcard,
+    /// The code is matched exactly, and the message is a regular expression
+    Regex,
+    /// Matches every error of the connector, the code and the message are set to `*`
+    CatchAll,
+}
+
+/// The connectors a failed payment is retried on
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RetryConnectorOrder {
+    /// Retry on the connector which the failed attempt was made on
+    SameConnector,
+    /// Retry on the next eligible connector chosen by routing
+    #[default]
+    CrossConnector,
+}
+
+/// The scheme used to sign the outgoing webhooks of a business profile
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookSignatureScheme {
+    /// HMAC-SHA512 of the payload, sent as a hex string in the `X-Webhook-Signature-512` header
+    #[default]
+    Legacy,
+    /// HMAC-SHA512 of the timestamp and the payload joined by a `.`, sent in the
+    /// `X-Webhook-Signature` header as `t=<timestamp>,v1=<signature>`. During a rotation of the
+    /// signing key, a `v1` signature is sent for both the current and the previous key.
+    V1,
+}
+
+/// The delivery status of an outgoing webhook, considering all of its delivery attempts
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// At least one delivery attempt of the webhook was successful
+    Delivered,
+    /// None of the delivery attempts of the webhook were successful
+    Failed,
+}
+
+/// The status of a bulk replay of outgoing webhooks
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookReplayJobStatus {
+    /// The replay has been scheduled and no events have been replayed yet
+    #[default]
+    Pending,
+    /// Events are being replayed
+    Processing,
+    /// All the events of the replay have been processed
+    Completed,
+}
+
+/// The outcome of replaying an outgoing webhook as part of a bulk replay
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookReplayEventStatus {
+    /// The event is yet to be replayed
+    #[default]
+    Pending,
+    /// The event was replayed and delivered successfully
+    Delivered,
+    /// The event was replayed, but could not be delivered
+    Failed,
+    /// The event was not replayed, since its delivery status no longer matches the replay filter
+    Skipped,
+}
+
+/// The outcome of processing an archived incoming webhook
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ArchivedWebhookStatus {
+    /// The webhook has been received and is being processed
+    #[default]
+    Received,
+    /// The webhook was processed successfully
+    Processed,
+    /// Processing the webhook failed
+    Failed,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -2679,6 +2882,93 @@ pub enum TransactionType {
     Payout,
 }
 
+/// The change which made a routing algorithm active or inactive for a profile
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingActivationKind {
+    /// The algorithm was activated through the activate endpoint
+    Link,
+    /// The active algorithm was deactivated
+    Unlink,
+    /// A previously active algorithm was restored
+    Rollback,
+    /// The algorithm was activated at its scheduled time
+    Scheduled,
+}
+
+/// The kind of configuration entity recorded in an audit log entry
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEntityType {
+    RoutingAlgorithm,
+    DefaultFallbackRouting,
+    MerchantConnectorAccount,
+    ApiKey,
+    BusinessProfile,
+    Blocklist,
+    GsmRule,
+    SurchargeDecisionConfig,
+    ThreeDsDecisionConfig,
+    UserRole,
+    WebhookEndpoint,
+}
+
+/// The change made to the configuration entity recorded in an audit log entry
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    /// The entity was made active, such as a routing algorithm being linked to a profile
+    Activate,
+    /// The entity was made inactive, such as a routing algorithm being unlinked from a profile
+    Deactivate,
+    /// The entity was replaced by a newly created entity, such as an API key being rotated
+    Rotate,
+}
+
 #[derive(
     Clone,
     Copy,
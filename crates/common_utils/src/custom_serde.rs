@@ -218,6 +218,24 @@ pub mod json_string {
     }
 }
 
+/// Deserialize an optional field such that a field explicitly set to `null` can be distinguished
+/// from an absent field, as required for update requests which allow removing a value.
+///
+/// Has to be used along with `#[serde(default)]`: an absent field is deserialized as `None`, a
+/// field set to `null` as `Some(None)` and a field set to a value as `Some(Some(value))`.
+pub mod nullable {
+    use serde::{Deserialize, Deserializer};
+
+    /// Deserialize an optional field into a double option
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Option::<T>::deserialize(deserializer).map(Some)
+    }
+}
+
 /// Use a custom ISO 8601 format when serializing and deserializing
 /// [`PrimitiveDateTime`][PrimitiveDateTime].
 ///
@@ -283,4 +301,24 @@ mod tests {
 
         assert!(deser.is_ok())
     }
+
+    #[test]
+    fn test_nullable_distinguishes_null_from_absent() {
+        #[derive(Deserialize)]
+        struct Update {
+            #[serde(
+                default,
+                deserialize_with = "crate::custom_serde::nullable::deserialize"
+            )]
+            f: Option<Option<u8>>,
+        }
+
+        let absent = serde_json::from_value::<Update>(json!({})).map(|update| update.f);
+        let null = serde_json::from_value::<Update>(json!({"f": null})).map(|update| update.f);
+        let value = serde_json::from_value::<Update>(json!({"f": 1})).map(|update| update.f);
+
+        assert!(matches!(absent, Ok(None)));
+        assert!(matches!(null, Ok(Some(None))));
+        assert!(matches!(value, Ok(Some(Some(1)))));
+    }
 }
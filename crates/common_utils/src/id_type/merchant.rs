@@ -107,6 +107,11 @@ impl MerchantId {
         format!("max_auto_retries_enabled_{}", self.get_string_repr())
     }
 
+    /// get_requeue_delay_key
+    pub fn get_requeue_delay_key(&self) -> String {
+        format!("requeue_delay_in_secs_{}", self.get_string_repr())
+    }
+
     /// get_requires_cvv_key
     pub fn get_requires_cvv_key(&self) -> String {
         format!("{}_requires_cvv", self.get_string_repr())
@@ -203,6 +208,11 @@ impl MerchantId {
         format!("should_call_gsm_{}", self.get_string_repr())
     }
 
+    /// Get the key of the config overriding the rate limits of the merchant
+    pub fn get_rate_limit_config_key(&self) -> String {
+        format!("rate_limit_{}", self.get_string_repr())
+    }
+
     /// get_max_auto_single_connector_payout_retries_enabled_
     pub fn get_max_auto_single_connector_payout_retries_enabled(
         &self,
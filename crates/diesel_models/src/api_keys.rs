@@ -18,6 +18,12 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
+    pub permissions: Option<Vec<String>>,
+    #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
+    pub ip_allowlist: Option<Vec<String>>,
+    pub predecessor_key_id: Option<String>,
+    pub successor_key_id: Option<String>,
 }
 
 #[derive(Debug, Insertable)]
@@ -32,6 +38,10 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub permissions: Option<Vec<String>>,
+    pub ip_allowlist: Option<Vec<String>>,
+    pub predecessor_key_id: Option<String>,
+    pub successor_key_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -41,10 +51,16 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        permissions: Option<Option<Vec<String>>>,
+        ip_allowlist: Option<Option<Vec<String>>>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
     },
+    RotationUpdate {
+        expires_at: PrimitiveDateTime,
+        successor_key_id: String,
+    },
 }
 
 #[derive(Debug, AsChangeset)]
@@ -54,6 +70,9 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub permissions: Option<Option<Vec<String>>>,
+    pub ip_allowlist: Option<Option<Vec<String>>>,
+    pub successor_key_id: Option<String>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -64,17 +83,37 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                permissions,
+                ip_allowlist,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                permissions,
+                ip_allowlist,
+                successor_key_id: None,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                permissions: None,
+                ip_allowlist: None,
+                successor_key_id: None,
+            },
+            ApiKeyUpdate::RotationUpdate {
+                expires_at,
+                successor_key_id,
+            } => Self {
+                expires_at: Some(Some(expires_at)),
+                successor_key_id: Some(successor_key_id),
+                name: None,
+                description: None,
+                last_used: None,
+                permissions: None,
+                ip_allowlist: None,
             },
         }
     }
@@ -149,3 +188,12 @@ pub struct ApiKeyExpiryTrackingData {
     // Days on which email reminder about api_key expiry has to be sent, prior to it's expiry.
     pub expiry_reminder_days: Vec<u8>,
 }
+
+// Tracking data by process_tracker, for retiring an API key once the grace period of its rotation
+// is over
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ApiKeyRetirementTrackingData {
+    pub key_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub successor_key_id: String,
+}
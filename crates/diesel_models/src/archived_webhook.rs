@@ -0,0 +1,102 @@
+use common_utils::encryption::Encryption;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::archived_webhook};
+
+/// An incoming webhook, archived so that the webhook can be replayed through the incoming webhook
+/// flow
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = archived_webhook, primary_key(webhook_id), check_for_backend(diesel::pg::Pg))]
+pub struct ArchivedWebhook {
+    pub webhook_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: String,
+    pub merchant_connector_id: String,
+    pub connector_name: String,
+    /// The event ID assigned to the webhook by the connector, or a hash of the webhook body for
+    /// connectors which do not assign event IDs
+    pub deduplication_key: String,
+    pub event_type: String,
+    pub status: storage_enums::ArchivedWebhookStatus,
+    /// The encrypted headers, query parameters and body of the webhook, as received
+    pub payload: Encryption,
+    pub replay_count: i32,
+    pub last_replayed_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    /// Whether the source of the webhook was verified when it was received, which the webhook is
+    /// processed with when replayed
+    pub source_verified: bool,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = archived_webhook)]
+pub struct ArchivedWebhookNew {
+    pub webhook_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: String,
+    pub merchant_connector_id: String,
+    pub connector_name: String,
+    pub deduplication_key: String,
+    pub event_type: String,
+    pub status: storage_enums::ArchivedWebhookStatus,
+    pub payload: Encryption,
+    pub replay_count: i32,
+    pub last_replayed_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub source_verified: bool,
+}
+
+#[derive(Debug)]
+pub enum ArchivedWebhookUpdate {
+    StatusUpdate {
+        status: storage_enums::ArchivedWebhookStatus,
+    },
+    ReplayUpdate {
+        status: storage_enums::ArchivedWebhookStatus,
+        replay_count: i32,
+        last_replayed_at: PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = archived_webhook)]
+pub struct ArchivedWebhookUpdateInternal {
+    pub status: Option<storage_enums::ArchivedWebhookStatus>,
+    pub replay_count: Option<i32>,
+    pub last_replayed_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<ArchivedWebhookUpdate> for ArchivedWebhookUpdateInternal {
+    fn from(archived_webhook_update: ArchivedWebhookUpdate) -> Self {
+        match archived_webhook_update {
+            ArchivedWebhookUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                replay_count: None,
+                last_replayed_at: None,
+                modified_at: common_utils::date_time::now(),
+            },
+            ArchivedWebhookUpdate::ReplayUpdate {
+                status,
+                replay_count,
+                last_replayed_at,
+            } => Self {
+                status: Some(status),
+                replay_count: Some(replay_count),
+                last_replayed_at: Some(last_replayed_at),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+// Tracking data by process_tracker, for purging the archived webhooks of a merchant once they
+// exceed the retention period
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ArchivedWebhookPurgeTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
@@ -0,0 +1,33 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums, schema::audit_log};
+
+/// A change made to the configuration of a merchant, recorded for auditing purposes
+#[derive(Clone, Debug, Identifiable, Insertable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = audit_log, primary_key(audit_id), check_for_backend(diesel::pg::Pg))]
+pub struct AuditLog {
+    pub audit_id: String,
+    /// `None` for entities which are not owned by a merchant, such as GSM rules
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+    /// The user or the API key which made the change
+    pub actor: Option<String>,
+    pub entity_type: enums::AuditEntityType,
+    pub entity_id: String,
+    pub action: enums::AuditAction,
+    /// The fields which changed, with their values before and after the change
+    pub diff: serde_json::Value,
+    pub source_ip: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct AuditLogListConstraints {
+    pub entity_type: Option<enums::AuditEntityType>,
+    pub entity_id: Option<String>,
+    pub actor: Option<String>,
+    pub created_after: Option<time::PrimitiveDateTime>,
+    pub created_before: Option<time::PrimitiveDateTime>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
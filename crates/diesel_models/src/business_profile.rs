@@ -1,7 +1,9 @@
 use std::collections::{HashMap, HashSet};
 
-use common_enums::AuthenticationConnectors;
-use common_utils::{encryption::Encryption, pii};
+use common_enums::{
+    AuthenticationConnectors, PaymentMethod, RetryConnectorOrder, WebhookSignatureScheme,
+};
+use common_utils::{encryption::Encryption, pii, types::MinorUnit};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use masking::Secret;
 
@@ -54,6 +56,10 @@ pub struct BusinessProfile {
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
     pub collect_billing_details_from_wallet_connector: Option<bool>,
     pub outgoing_webhook_custom_http_headers: Option<Encryption>,
+    pub retry_policy: Option<RetryPolicy>,
+    pub webhook_signature_scheme: Option<WebhookSignatureScheme>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(all(
@@ -92,6 +98,10 @@ pub struct BusinessProfileNew {
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
     pub collect_billing_details_from_wallet_connector: Option<bool>,
     pub outgoing_webhook_custom_http_headers: Option<Encryption>,
+    pub retry_policy: Option<RetryPolicy>,
+    pub webhook_signature_scheme: Option<WebhookSignatureScheme>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(all(
@@ -127,6 +137,10 @@ pub struct BusinessProfileUpdateInternal {
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
     pub collect_billing_details_from_wallet_connector: Option<bool>,
     pub outgoing_webhook_custom_http_headers: Option<Encryption>,
+    pub retry_policy: Option<Option<RetryPolicy>>,
+    pub webhook_signature_scheme: Option<WebhookSignatureScheme>,
+    pub previous_payment_response_hash_key: Option<Option<String>>,
+    pub previous_payment_response_hash_key_expires_at: Option<Option<time::PrimitiveDateTime>>,
 }
 
 #[cfg(all(
@@ -161,6 +175,10 @@ impl BusinessProfileUpdateInternal {
             collect_shipping_details_from_wallet_connector,
             collect_billing_details_from_wallet_connector,
             outgoing_webhook_custom_http_headers,
+            retry_policy,
+            webhook_signature_scheme,
+            previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at,
         } = self;
         BusinessProfile {
             profile_id: source.profile_id,
@@ -205,6 +223,13 @@ impl BusinessProfileUpdateInternal {
                     .or(source.collect_billing_details_from_wallet_connector),
             outgoing_webhook_custom_http_headers: outgoing_webhook_custom_http_headers
                 .or(source.outgoing_webhook_custom_http_headers),
+            retry_policy: retry_policy.unwrap_or(source.retry_policy),
+            webhook_signature_scheme: webhook_signature_scheme.or(source.webhook_signature_scheme),
+            previous_payment_response_hash_key: previous_payment_response_hash_key
+                .unwrap_or(source.previous_payment_response_hash_key),
+            previous_payment_response_hash_key_expires_at:
+                previous_payment_response_hash_key_expires_at
+                    .unwrap_or(source.previous_payment_response_hash_key_expires_at),
         }
     }
 }
@@ -249,6 +274,10 @@ pub struct BusinessProfile {
     pub frm_routing_algorithm_id: Option<String>,
     pub payout_routing_algorithm_id: Option<String>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
+    pub retry_policy: Option<RetryPolicy>,
+    pub webhook_signature_scheme: Option<WebhookSignatureScheme>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(all(feature = "v2", feature = "business_profile_v2"))]
@@ -286,6 +315,10 @@ pub struct BusinessProfileNew {
     pub frm_routing_algorithm_id: Option<String>,
     pub payout_routing_algorithm_id: Option<String>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
+    pub retry_policy: Option<RetryPolicy>,
+    pub webhook_signature_scheme: Option<WebhookSignatureScheme>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(all(feature = "v2", feature = "business_profile_v2"))]
@@ -320,6 +353,10 @@ pub struct BusinessProfileUpdateInternal {
     pub frm_routing_algorithm_id: Option<String>,
     pub payout_routing_algorithm_id: Option<String>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
+    pub retry_policy: Option<Option<RetryPolicy>>,
+    pub webhook_signature_scheme: Option<WebhookSignatureScheme>,
+    pub previous_payment_response_hash_key: Option<Option<String>>,
+    pub previous_payment_response_hash_key_expires_at: Option<Option<time::PrimitiveDateTime>>,
 }
 
 #[cfg(all(feature = "v2", feature = "business_profile_v2"))]
@@ -353,6 +390,10 @@ impl BusinessProfileUpdateInternal {
             frm_routing_algorithm_id,
             payout_routing_algorithm_id,
             default_fallback_routing,
+            retry_policy,
+            webhook_signature_scheme,
+            previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at,
         } = self;
         BusinessProfile {
             profile_id: source.profile_id,
@@ -401,6 +442,13 @@ impl BusinessProfileUpdateInternal {
             payout_routing_algorithm_id: payout_routing_algorithm_id
                 .or(source.payout_routing_algorithm_id),
             default_fallback_routing: default_fallback_routing.or(source.default_fallback_routing),
+            retry_policy: retry_policy.unwrap_or(source.retry_policy),
+            webhook_signature_scheme: webhook_signature_scheme.or(source.webhook_signature_scheme),
+            previous_payment_response_hash_key: previous_payment_response_hash_key
+                .unwrap_or(source.previous_payment_response_hash_key),
+            previous_payment_response_hash_key_expires_at:
+                previous_payment_response_hash_key_expires_at
+                    .unwrap_or(source.previous_payment_response_hash_key_expires_at),
         }
     }
 }
@@ -444,6 +492,11 @@ impl From<BusinessProfileNew> for BusinessProfile {
             frm_routing_algorithm_id: new.frm_routing_algorithm_id,
             payout_routing_algorithm_id: new.payout_routing_algorithm_id,
             default_fallback_routing: new.default_fallback_routing,
+            retry_policy: new.retry_policy,
+            webhook_signature_scheme: new.webhook_signature_scheme,
+            previous_payment_response_hash_key: new.previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: new
+                .previous_payment_response_hash_key_expires_at,
         }
     }
 }
@@ -457,6 +510,24 @@ pub struct AuthenticationConnectorDetails {
 
 common_utils::impl_to_sql_from_sql_json!(AuthenticationConnectorDetails);
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct RetryPolicy {
+    pub max_attempts: u8,
+    pub connector_order: RetryConnectorOrder,
+    pub payment_methods: Option<Vec<PaymentMethod>>,
+    pub amount_ranges: Option<Vec<RetryAmountRange>>,
+    pub latency_budget_in_ms: Option<u32>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RetryAmountRange {
+    pub min_amount: Option<MinorUnit>,
+    pub max_amount: Option<MinorUnit>,
+}
+
+common_utils::impl_to_sql_from_sql_json!(RetryPolicy);
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Json)]
 pub struct WebhookDetails {
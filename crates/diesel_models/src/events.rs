@@ -30,6 +30,7 @@ pub struct EventNew {
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -61,6 +62,8 @@ pub struct Event {
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    // `None` for deliveries to the webhook URL configured in the business profile
+    pub webhook_endpoint_id: Option<String>,
 }
 
 pub struct EventWithEncryption {
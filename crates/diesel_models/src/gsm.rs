@@ -1,5 +1,6 @@
 //! Gateway status mapping
 
+use common_enums::GsmMatchType;
 use common_utils::{
     custom_serde,
     events::{ApiEventMetric, ApiEventsType},
@@ -39,6 +40,7 @@ pub struct GatewayStatusMap {
     pub step_up_possible: bool,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub match_type: GsmMatchType,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable)]
@@ -55,6 +57,7 @@ pub struct GatewayStatusMappingNew {
     pub step_up_possible: bool,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub match_type: GsmMatchType,
 }
 
 #[derive(
@@ -80,6 +83,7 @@ pub struct GatewayStatusMapperUpdateInternal {
     pub step_up_possible: Option<bool>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub match_type: Option<GsmMatchType>,
 }
 
 #[derive(Debug)]
@@ -90,6 +94,7 @@ pub struct GatewayStatusMappingUpdate {
     pub step_up_possible: Option<bool>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub match_type: Option<GsmMatchType>,
 }
 
 impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
@@ -101,6 +106,7 @@ impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
             step_up_possible,
             unified_code,
             unified_message,
+            match_type,
         } = value;
         Self {
             status,
@@ -109,6 +115,7 @@ impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
             step_up_possible,
             unified_code,
             unified_message,
+            match_type,
             ..Default::default()
         }
     }
@@ -56,6 +56,7 @@ pub struct MerchantConnectorAccount {
     pub additional_merchant_data: Option<Encryption>,
     pub connector_wallets_details: Option<Encryption>,
     pub version: common_enums::ApiVersion,
+    pub fee_schedule: Option<serde_json::Value>,
 }
 
 #[cfg(all(
@@ -104,6 +105,7 @@ pub struct MerchantConnectorAccount {
     pub connector_wallets_details: Option<Encryption>,
     pub id: String,
     pub version: common_enums::ApiVersion,
+    pub fee_schedule: Option<serde_json::Value>,
 }
 
 #[cfg(all(feature = "v2", feature = "merchant_connector_account_v2"))]
@@ -147,6 +149,7 @@ pub struct MerchantConnectorAccountNew {
     pub additional_merchant_data: Option<Encryption>,
     pub connector_wallets_details: Option<Encryption>,
     pub version: common_enums::ApiVersion,
+    pub fee_schedule: Option<serde_json::Value>,
 }
 
 #[cfg(all(feature = "v2", feature = "merchant_connector_account_v2"))]
@@ -175,6 +178,7 @@ pub struct MerchantConnectorAccountNew {
     pub connector_wallets_details: Option<Encryption>,
     pub id: String,
     pub version: common_enums::ApiVersion,
+    pub fee_schedule: Option<serde_json::Value>,
 }
 
 #[cfg(all(
@@ -203,6 +207,7 @@ pub struct MerchantConnectorAccountUpdateInternal {
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
     pub status: Option<storage_enums::ConnectorStatus>,
     pub connector_wallets_details: Option<Encryption>,
+    pub fee_schedule: Option<Option<serde_json::Value>>,
 }
 
 #[cfg(all(feature = "v2", feature = "merchant_connector_account_v2"))]
@@ -224,6 +229,7 @@ pub struct MerchantConnectorAccountUpdateInternal {
     pub pm_auth_config: Option<pii::SecretSerdeValue>,
     pub status: Option<storage_enums::ConnectorStatus>,
     pub connector_wallets_details: Option<Encryption>,
+    pub fee_schedule: Option<Option<serde_json::Value>>,
 }
 
 #[cfg(all(
@@ -251,6 +257,7 @@ impl MerchantConnectorAccountUpdateInternal {
             modified_at: self.modified_at.unwrap_or(source.modified_at),
             pm_auth_config: self.pm_auth_config,
             status: self.status.unwrap_or(source.status),
+            fee_schedule: self.fee_schedule.unwrap_or(source.fee_schedule),
 
             ..source
         }
@@ -274,6 +281,7 @@ impl MerchantConnectorAccountUpdateInternal {
             modified_at: self.modified_at.unwrap_or(source.modified_at),
             pm_auth_config: self.pm_auth_config,
             status: self.status.unwrap_or(source.status),
+            fee_schedule: self.fee_schedule.unwrap_or(source.fee_schedule),
 
             ..source
         }
@@ -78,6 +78,8 @@ pub struct PaymentAttempt {
     pub client_source: Option<String>,
     pub client_version: Option<String>,
     pub customer_acceptance: Option<pii::SecretSerdeValue>,
+    pub routing_decision_trace: Option<serde_json::Value>,
+    pub retry_policy: Option<serde_json::Value>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "payment_v2")))]
@@ -149,6 +151,8 @@ pub struct PaymentAttempt {
     pub client_source: Option<String>,
     pub client_version: Option<String>,
     pub customer_acceptance: Option<pii::SecretSerdeValue>,
+    pub routing_decision_trace: Option<serde_json::Value>,
+    pub retry_policy: Option<serde_json::Value>,
 }
 
 impl PaymentAttempt {
@@ -231,6 +235,8 @@ pub struct PaymentAttemptNew {
     pub client_source: Option<String>,
     pub client_version: Option<String>,
     pub customer_acceptance: Option<pii::SecretSerdeValue>,
+    pub routing_decision_trace: Option<serde_json::Value>,
+    pub retry_policy: Option<serde_json::Value>,
 }
 
 impl PaymentAttemptNew {
@@ -317,6 +323,8 @@ pub enum PaymentAttemptUpdate {
         client_source: Option<String>,
         client_version: Option<String>,
         customer_acceptance: Option<pii::SecretSerdeValue>,
+        routing_decision_trace: Option<serde_json::Value>,
+        retry_policy: Option<serde_json::Value>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -488,6 +496,8 @@ pub struct PaymentAttemptUpdateInternal {
     client_source: Option<String>,
     client_version: Option<String>,
     customer_acceptance: Option<pii::SecretSerdeValue>,
+    routing_decision_trace: Option<serde_json::Value>,
+    retry_policy: Option<Option<serde_json::Value>>,
 }
 
 impl PaymentAttemptUpdateInternal {
@@ -557,6 +567,8 @@ impl PaymentAttemptUpdate {
             client_source,
             client_version,
             customer_acceptance,
+            routing_decision_trace,
+            retry_policy,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -609,6 +621,8 @@ impl PaymentAttemptUpdate {
             client_source: client_source.or(source.client_source),
             client_version: client_version.or(source.client_version),
             customer_acceptance: customer_acceptance.or(source.customer_acceptance),
+            routing_decision_trace: routing_decision_trace.or(source.routing_decision_trace),
+            retry_policy: retry_policy.unwrap_or(source.retry_policy),
             ..source
         }
     }
@@ -684,6 +698,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -735,6 +751,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -767,6 +785,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source,
                 client_version,
                 customer_acceptance,
+                routing_decision_trace,
+                retry_policy,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -799,6 +819,10 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source,
                 client_version,
                 customer_acceptance,
+                routing_decision_trace,
+                // The policy recorded by an earlier confirmation is removed if the business profile
+                // no longer has a retry policy
+                retry_policy: Some(retry_policy),
                 net_amount: None,
                 connector_transaction_id: None,
                 amount_to_capture: None,
@@ -866,6 +890,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -919,6 +945,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
@@ -972,6 +1000,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -1023,6 +1053,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -1092,6 +1124,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::ErrorUpdate {
                 connector,
@@ -1153,6 +1187,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
                 status: Some(status),
@@ -1201,6 +1237,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -1258,6 +1296,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -1316,6 +1356,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::PreprocessingUpdate {
                 status,
@@ -1372,6 +1414,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::CaptureUpdate {
                 multiple_capture_count,
@@ -1424,6 +1468,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -1476,6 +1522,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -1531,6 +1579,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
                 amount,
@@ -1582,6 +1632,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -1636,6 +1688,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -1692,6 +1746,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 client_source: None,
                 client_version: None,
                 customer_acceptance: None,
+                routing_decision_trace: None,
+                retry_policy: None,
             },
         }
     }
@@ -210,6 +210,11 @@ pub enum ProcessTrackerRunner {
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
+    PaymentsRequeueWorkflow,
+    PayoutsRequeueWorkflow,
+    RoutingActivationWorkflow,
+    WebhookReplayWorkflow,
+    ArchivedWebhookPurgeWorkflow,
 }
 
 #[cfg(test)]
@@ -263,6 +268,12 @@ pub mod business_status {
     /// A task that reaches this status should not be retried (rescheduled for execution) later.
     pub const RESOURCE_STATUS_MISMATCH: &str = "RESOURCE_STATUS_MISMATCH";
 
+    /// The outgoing webhook could not be delivered as deliveries to the webhook endpoint were
+    /// suspended, and was queued to be retried once the endpoint is re-enabled.
+    /// A task that reaches this status is rescheduled for execution when the endpoint is
+    /// re-enabled.
+    pub const WEBHOOK_ENDPOINT_SUSPENDED: &str = "WEBHOOK_ENDPOINT_SUSPENDED";
+
     /// Business status set for newly created tasks.
     pub const PENDING: &str = "Pending";
 }
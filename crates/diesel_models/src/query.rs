@@ -1,5 +1,7 @@
 pub mod address;
 pub mod api_keys;
+pub mod archived_webhook;
+pub mod audit_log;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
@@ -41,3 +43,5 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
+pub mod webhook_replay_job;
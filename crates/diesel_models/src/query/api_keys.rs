@@ -1,4 +1,5 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -54,6 +55,33 @@ impl ApiKey {
         }
     }
 
+    /// Marks the API key as rotated to its successor, unless the API key has already been rotated
+    pub async fn rotate_by_merchant_id_key_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key_id: &str,
+        expires_at: PrimitiveDateTime,
+        successor_key_id: String,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::key_id.eq(key_id.to_owned()))
+                .and(dsl::successor_key_id.is_null()),
+            ApiKeyUpdateInternal::from(ApiKeyUpdate::RotationUpdate {
+                expires_at,
+                successor_key_id,
+            }),
+        )
+        .await
+    }
+
     pub async fn revoke_by_merchant_id_key_id(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
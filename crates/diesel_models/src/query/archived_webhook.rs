@@ -0,0 +1,139 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    archived_webhook::{
+        ArchivedWebhook, ArchivedWebhookNew, ArchivedWebhookUpdate, ArchivedWebhookUpdateInternal,
+    },
+    enums as storage_enums,
+    schema::archived_webhook::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ArchivedWebhookNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ArchivedWebhook> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ArchivedWebhook {
+    pub async fn find_by_merchant_id_webhook_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::webhook_id.eq(webhook_id.to_owned())),
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: Option<String>,
+        status: Option<storage_enums::ArchivedWebhookStatus>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(merchant_connector_id) = merchant_connector_id {
+            query = query.filter(dsl::merchant_connector_id.eq(merchant_connector_id));
+        }
+
+        if let Some(status) = status {
+            query = query.filter(dsl::status.eq(status));
+        }
+
+        if let Some(created_after) = created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = created_before {
+            query = query.filter(dsl::created_at.le(created_before));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering archived webhooks by constraints")
+    }
+
+    pub async fn update_by_merchant_id_webhook_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        archived_webhook_update: ArchivedWebhookUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::webhook_id.eq(webhook_id.to_owned())),
+            ArchivedWebhookUpdateInternal::from(archived_webhook_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_created_before(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+    ) -> StorageResult<usize> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let query = diesel::delete(
+            Self::table().filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::created_at.lt(created_before)),
+            ),
+        );
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.execute_async(conn), DatabaseOperation::Delete)
+            .await
+            .change_context(DatabaseError::Others) // Query deletes no rows when none are found
+            .attach_printable("Error deleting archived webhooks created before the given time")
+    }
+}
@@ -4,8 +4,10 @@ use diesel::{
 
 use super::generics;
 use crate::{
+    enums as storage_enums,
     events::{Event, EventNew, EventUpdateInternal},
     schema::events::dsl,
+    webhook_replay_job::WebhookReplayJobFilters,
     PgPooledConn, StorageResult,
 };
 
@@ -202,6 +204,59 @@ impl Event {
         .await
     }
 
+    /// Lists the identifiers of the initial attempts of the events of a business profile which
+    /// match the filters of a webhook replay job, in the order they were created in
+    pub async fn list_initial_attempt_ids_by_profile_id_replay_filters(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        filters: &WebhookReplayJobFilters,
+        limit: i64,
+    ) -> StorageResult<Vec<String>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .select(dsl::event_id)
+            .filter(
+                dsl::event_id
+                    .nullable()
+                    .eq(dsl::initial_attempt_id) // Filter initial attempts only
+                    .and(dsl::business_profile_id.eq(profile_id.to_owned()))
+                    .and(dsl::created_at.ge(filters.created_after))
+                    .and(dsl::created_at.le(filters.created_before)),
+            )
+            .order(dsl::created_at.asc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(event_types) = filters.event_types.clone() {
+            query = query.filter(dsl::event_type.eq_any(event_types));
+        }
+
+        if let Some(object_ids) = filters.object_ids.clone() {
+            query = query.filter(dsl::primary_object_id.eq_any(object_ids));
+        }
+
+        // An event whose initial attempt was delivered can never be considered as failed. The
+        // delivery status of the other events depends on their retries, and is checked when they
+        // are replayed.
+        if filters.delivery_status == Some(storage_enums::WebhookDeliveryStatus::Failed) {
+            query = query.filter(dsl::is_webhook_notified.eq(false));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering events by webhook replay filters")
+    }
+
     pub async fn update_by_merchant_id_event_id(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -1,3 +1,4 @@
+use common_enums::GsmMatchType;
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 use error_stack::report;
 
@@ -32,6 +33,62 @@ impl GatewayStatusMap {
         .await
     }
 
+    /// Lists the wildcard, regex and catch-all rules configured for the connector, flow and sub
+    /// flow. The caller decides which of them matches, as the patterns cannot be evaluated by the
+    /// database.
+    pub async fn find_pattern_rules(
+        conn: &PgPooledConn,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::connector
+                .eq(connector)
+                .and(dsl::flow.eq(flow))
+                .and(dsl::sub_flow.eq(sub_flow))
+                .and(dsl::match_type.ne(GsmMatchType::Exact)),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn list_by_connector(
+        conn: &PgPooledConn,
+        connector: Option<String>,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+
+        let mut query = Self::table()
+            .order((
+                dsl::connector.asc(),
+                dsl::flow.asc(),
+                dsl::sub_flow.asc(),
+                dsl::code.asc(),
+                dsl::message.asc(),
+            ))
+            .into_boxed();
+
+        if let Some(connector) = connector {
+            query = query.filter(dsl::connector.eq(connector));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error listing gsm rules")
+    }
+
     pub async fn retrieve_decision(
         conn: &PgPooledConn,
         connector: String,
@@ -7,8 +7,8 @@ use crate::{
     enums,
     errors::DatabaseError,
     query::generics,
-    routing_algorithm::{RoutingAlgorithm, RoutingProfileMetadata},
-    schema::routing_algorithm::dsl,
+    routing_algorithm::{RoutingAlgorithm, RoutingAlgorithmActivation, RoutingProfileMetadata},
+    schema::{routing_algorithm::dsl, routing_algorithm_activation::dsl as activation_dsl},
     PgPooledConn, StorageResult,
 };
 
@@ -288,3 +288,42 @@ impl RoutingAlgorithm {
             .collect())
     }
 }
+
+impl RoutingAlgorithmActivation {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Self> {
+        generics::generic_insert(conn, self).await
+    }
+
+    pub async fn find_by_activation_id_profile_id(
+        conn: &PgPooledConn,
+        activation_id: &str,
+        profile_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            activation_dsl::activation_id
+                .eq(activation_id.to_owned())
+                .and(activation_dsl::profile_id.eq(profile_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_profile_id_transaction_type(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        transaction_type: &enums::TransactionType,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            activation_dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(activation_dsl::transaction_type.eq(transaction_type.to_owned())),
+            Some(limit),
+            Some(offset),
+            Some(activation_dsl::created_at.desc()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,199 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    errors,
+    schema::{webhook_endpoint::dsl, webhook_endpoint_queued_event::dsl as queued_event_dsl},
+    webhook_endpoint::{
+        WebhookEndpoint, WebhookEndpointNew, WebhookEndpointQueuedEvent,
+        WebhookEndpointQueuedEventNew, WebhookEndpointUpdate, WebhookEndpointUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl WebhookEndpointNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WebhookEndpoint> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WebhookEndpoint {
+    pub async fn find_by_merchant_id_endpoint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::endpoint_id.eq(endpoint_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_profile_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::profile_id.eq(profile_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_endpoint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        webhook_endpoint_update: WebhookEndpointUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::endpoint_id.eq(endpoint_id.to_owned())),
+            WebhookEndpointUpdateInternal::from(webhook_endpoint_update),
+        )
+        .await
+    }
+
+    /// Records the outcome of a delivery to the endpoint in a single statement, so that the
+    /// outcomes of concurrent deliveries are not lost. The consecutive failures are reset by a
+    /// successful delivery, and only the latest `window` outcomes are retained.
+    pub async fn record_delivery_outcome_by_merchant_id_endpoint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        is_delivered: bool,
+        window: i32,
+    ) -> StorageResult<Self> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{
+            debug_query, dsl::sql, pg::Pg, result::Error as DieselError, sql_types, QueryDsl,
+        };
+        use error_stack::{report, ResultExt};
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+
+        let consecutive_failures = sql::<sql_types::Integer>("CASE WHEN ")
+            .bind::<sql_types::Bool, _>(is_delivered)
+            .sql(" THEN 0 ELSE consecutive_failures + 1 END");
+        // The array is sliced from the first of the outcomes within the window
+        let recent_delivery_outcomes =
+            sql::<sql_types::Array<sql_types::Nullable<sql_types::Bool>>>(
+                "(array_append(recent_delivery_outcomes, ",
+            )
+            .bind::<sql_types::Bool, _>(is_delivered)
+            .sql("))[greatest(cardinality(recent_delivery_outcomes) + 2 - ")
+            .bind::<sql_types::Integer, _>(window)
+            .sql(", 1):]");
+
+        let query = diesel::update(
+            <Self as HasTable>::table().filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::endpoint_id.eq(endpoint_id.to_owned())),
+            ),
+        )
+        .set((
+            dsl::consecutive_failures.eq(consecutive_failures),
+            dsl::recent_delivery_outcomes.eq(recent_delivery_outcomes),
+            dsl::modified_at.eq(common_utils::date_time::now()),
+        ));
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_result_async(conn), DatabaseOperation::Update)
+            .await
+            .map_err(|error| match error {
+                DieselError::NotFound => {
+                    report!(error).change_context(errors::DatabaseError::NotFound)
+                }
+                _ => report!(error).change_context(errors::DatabaseError::Others),
+            })
+            .attach_printable("Error while recording delivery outcome of webhook endpoint")
+    }
+
+    /// Suspends deliveries to the endpoint, returning `None` if deliveries to the endpoint were
+    /// already suspended.
+    pub async fn suspend_by_merchant_id_endpoint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        suspended_at: time::PrimitiveDateTime,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::endpoint_id.eq(endpoint_id.to_owned()))
+                .and(dsl::suspended_at.is_null()),
+            WebhookEndpointUpdateInternal::from(WebhookEndpointUpdate::Suspend { suspended_at }),
+        )
+        .await
+        .map(|mut webhook_endpoints| webhook_endpoints.pop())
+    }
+
+    pub async fn delete_by_merchant_id_endpoint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::endpoint_id.eq(endpoint_id.to_owned())),
+        )
+        .await
+    }
+}
+
+impl WebhookEndpointQueuedEventNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WebhookEndpointQueuedEvent> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WebhookEndpointQueuedEvent {
+    pub async fn list_by_endpoint_id(
+        conn: &PgPooledConn,
+        endpoint_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            queued_event_dsl::endpoint_id.eq(endpoint_id.to_owned()),
+            None,
+            None,
+            Some(queued_event_dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_endpoint_id_event_id(
+        conn: &PgPooledConn,
+        endpoint_id: &str,
+        event_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            queued_event_dsl::endpoint_id
+                .eq(endpoint_id.to_owned())
+                .and(queued_event_dsl::event_id.eq(event_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -0,0 +1,132 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    schema::{webhook_replay_job::dsl, webhook_replay_job_event::dsl as event_dsl},
+    webhook_replay_job::{
+        WebhookReplayJob, WebhookReplayJobEvent, WebhookReplayJobEventNew,
+        WebhookReplayJobEventUpdate, WebhookReplayJobEventUpdateInternal, WebhookReplayJobNew,
+        WebhookReplayJobUpdate, WebhookReplayJobUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl WebhookReplayJobNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WebhookReplayJob> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WebhookReplayJob {
+    pub async fn find_by_merchant_id_job_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::job_id.eq(job_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_job_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        webhook_replay_job_update: WebhookReplayJobUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::job_id.eq(job_id.to_owned())),
+            WebhookReplayJobUpdateInternal::from(webhook_replay_job_update),
+        )
+        .await
+    }
+}
+
+impl WebhookReplayJobEventNew {
+    pub async fn batch_insert(
+        webhook_replay_job_events: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<()> {
+        generics::generic_insert::<_, _, WebhookReplayJobEvent>(conn, webhook_replay_job_events)
+            .await?;
+        Ok(())
+    }
+}
+
+impl WebhookReplayJobEvent {
+    pub async fn list_by_job_id_status(
+        conn: &PgPooledConn,
+        job_id: &str,
+        status: Option<storage_enums::WebhookReplayEventStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(event_dsl::job_id.eq(job_id.to_owned()))
+            // Event IDs are time ordered, so that events are replayed in the order they were
+            // created in
+            .order(event_dsl::event_id.asc())
+            .into_boxed();
+
+        if let Some(status) = status {
+            query = query.filter(event_dsl::status.eq(status));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering webhook replay job events by constraints")
+    }
+
+    pub async fn update_by_job_id_event_id(
+        conn: &PgPooledConn,
+        job_id: &str,
+        event_id: &str,
+        webhook_replay_job_event_update: WebhookReplayJobEventUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            event_dsl::job_id
+                .eq(job_id.to_owned())
+                .and(event_dsl::event_id.eq(event_id.to_owned())),
+            WebhookReplayJobEventUpdateInternal::from(webhook_replay_job_event_update),
+        )
+        .await
+    }
+}
@@ -1,7 +1,10 @@
 use diesel::{Identifiable, Insertable, Queryable, Selectable};
 use serde::{Deserialize, Serialize};
 
-use crate::{enums, schema::routing_algorithm};
+use crate::{
+    enums,
+    schema::{routing_algorithm, routing_algorithm_activation},
+};
 
 #[derive(Clone, Debug, Identifiable, Insertable, Queryable, Selectable, Serialize, Deserialize)]
 #[diesel(table_name = routing_algorithm, primary_key(algorithm_id), check_for_backend(diesel::pg::Pg))]
@@ -38,3 +41,29 @@ pub struct RoutingProfileMetadata {
     pub modified_at: time::PrimitiveDateTime,
     pub algorithm_for: enums::TransactionType,
 }
+
+/// A change of the active routing algorithm of a profile
+#[derive(Clone, Debug, Identifiable, Insertable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = routing_algorithm_activation, primary_key(activation_id), check_for_backend(diesel::pg::Pg))]
+pub struct RoutingAlgorithmActivation {
+    pub activation_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: String,
+    pub transaction_type: enums::TransactionType,
+    /// The algorithm which became active, `None` if the profile was left without one
+    pub algorithm_id: Option<String>,
+    pub previous_algorithm_id: Option<String>,
+    pub activation_kind: enums::RoutingActivationKind,
+    pub activated_by: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RoutingActivationTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: String,
+    pub algorithm_id: String,
+    pub transaction_type: enums::TransactionType,
+    pub scheduled_by: Option<String>,
+}
@@ -55,6 +55,66 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        permissions -> Nullable<Array<Nullable<Text>>>,
+        ip_allowlist -> Nullable<Array<Nullable<Text>>>,
+        #[max_length = 64]
+        predecessor_key_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        successor_key_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    archived_webhook (webhook_id) {
+        #[max_length = 64]
+        webhook_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 128]
+        merchant_connector_id -> Varchar,
+        #[max_length = 64]
+        connector_name -> Varchar,
+        #[max_length = 255]
+        deduplication_key -> Varchar,
+        #[max_length = 64]
+        event_type -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        payload -> Bytea,
+        replay_count -> Int4,
+        last_replayed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        source_verified -> Bool,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    audit_log (audit_id) {
+        #[max_length = 64]
+        audit_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        actor -> Nullable<Varchar>,
+        #[max_length = 64]
+        entity_type -> Varchar,
+        #[max_length = 255]
+        entity_id -> Varchar,
+        #[max_length = 32]
+        action -> Varchar,
+        diff -> Jsonb,
+        #[max_length = 64]
+        source_ip -> Nullable<Varchar>,
+        created_at -> Timestamp,
     }
 }
 
@@ -210,6 +270,12 @@ diesel::table! {
         #[max_length = 64]
         payout_routing_algorithm_id -> Nullable<Varchar>,
         default_fallback_routing -> Nullable<Jsonb>,
+        retry_policy -> Nullable<Jsonb>,
+        #[max_length = 32]
+        webhook_signature_scheme -> Nullable<Varchar>,
+        #[max_length = 255]
+        previous_payment_response_hash_key -> Nullable<Varchar>,
+        previous_payment_response_hash_key_expires_at -> Nullable<Timestamp>,
     }
 }
 
@@ -402,7 +468,7 @@ diesel::table! {
         #[max_length = 64]
         business_profile_id -> Nullable<Varchar>,
         primary_object_created_at -> Nullable<Timestamp>,
-        #[max_length = 64]
+        #[max_length = 128]
         idempotent_event_id -> Nullable<Varchar>,
         #[max_length = 64]
         initial_attempt_id -> Nullable<Varchar>,
@@ -410,6 +476,8 @@ diesel::table! {
         response -> Nullable<Bytea>,
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
+        #[max_length = 64]
+        webhook_endpoint_id -> Nullable<Varchar>,
     }
 }
 
@@ -503,6 +571,8 @@ diesel::table! {
         unified_code -> Nullable<Varchar>,
         #[max_length = 1024]
         unified_message -> Nullable<Varchar>,
+        #[max_length = 16]
+        match_type -> Varchar,
     }
 }
 
@@ -688,6 +758,7 @@ diesel::table! {
         #[max_length = 64]
         id -> Varchar,
         version -> ApiVersion,
+        fee_schedule -> Nullable<Jsonb>,
     }
 }
 
@@ -805,6 +876,8 @@ diesel::table! {
         #[max_length = 64]
         client_version -> Nullable<Varchar>,
         customer_acceptance -> Nullable<Jsonb>,
+        routing_decision_trace -> Nullable<Jsonb>,
+        retry_policy -> Nullable<Jsonb>,
     }
 }
 
@@ -1197,6 +1270,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    routing_algorithm_activation (activation_id) {
+        #[max_length = 64]
+        activation_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        transaction_type -> TransactionType,
+        #[max_length = 64]
+        algorithm_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        previous_algorithm_id -> Nullable<Varchar>,
+        activation_kind -> RoutingActivationKind,
+        #[max_length = 255]
+        activated_by -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1307,9 +1403,95 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_endpoint (endpoint_id) {
+        #[max_length = 64]
+        endpoint_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        url -> Text,
+        secret -> Bytea,
+        enabled_events -> Array<Nullable<EventType>>,
+        enabled -> Bool,
+        custom_http_headers -> Nullable<Bytea>,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        consecutive_failures -> Int4,
+        recent_delivery_outcomes -> Array<Nullable<Bool>>,
+        suspended_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_endpoint_queued_event (endpoint_id, event_id) {
+        #[max_length = 64]
+        endpoint_id -> Varchar,
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_replay_job (job_id) {
+        #[max_length = 64]
+        job_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        filters -> Jsonb,
+        max_events_per_minute -> Int4,
+        total_events -> Int4,
+        delivered_events -> Int4,
+        failed_events -> Int4,
+        skipped_events -> Int4,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_replay_job_event (job_id, event_id) {
+        #[max_length = 64]
+        job_id -> Varchar,
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        replayed_event_id -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    archived_webhook,
+    audit_log,
     authentication,
     blocklist,
     blocklist_fingerprint,
@@ -1344,9 +1526,14 @@ diesel::allow_tables_to_appear_in_same_query!(
     reverse_lookup,
     roles,
     routing_algorithm,
+    routing_algorithm_activation,
     unified_translations,
     user_authentication_methods,
     user_key_store,
     user_roles,
     users,
+    webhook_endpoint,
+    webhook_endpoint_queued_event,
+    webhook_replay_job,
+    webhook_replay_job_event,
 );
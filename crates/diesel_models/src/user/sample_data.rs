@@ -144,6 +144,8 @@ impl PaymentAttemptBatchNew {
             client_source: self.client_source,
             client_version: self.client_version,
             customer_acceptance: self.customer_acceptance,
+            routing_decision_trace: None,
+            retry_policy: None,
         }
     }
 }
@@ -0,0 +1,156 @@
+use common_utils::encryption::Encryption;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{webhook_endpoint, webhook_endpoint_queued_event},
+};
+
+/// An endpoint registered on a business profile, to which outgoing webhooks are delivered in
+/// addition to the webhook URL configured in the business profile
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = webhook_endpoint, primary_key(endpoint_id), check_for_backend(diesel::pg::Pg))]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: String,
+    pub url: String,
+    /// The key used to sign the webhooks delivered to the endpoint
+    pub secret: Encryption,
+    /// The event types delivered to the endpoint, all event types are delivered if empty
+    #[diesel(deserialize_as = super::DieselArray<storage_enums::EventType>)]
+    pub enabled_events: Vec<storage_enums::EventType>,
+    pub enabled: bool,
+    pub custom_http_headers: Option<Encryption>,
+    pub description: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    /// The number of deliveries to the endpoint which have failed since the last successful one
+    pub consecutive_failures: i32,
+    /// The outcomes of the most recent deliveries to the endpoint, oldest first, `true`
+    /// indicating a successful delivery
+    #[diesel(deserialize_as = super::DieselArray<bool>)]
+    pub recent_delivery_outcomes: Vec<bool>,
+    /// The time at which deliveries to the endpoint were suspended due to repeated failures
+    pub suspended_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointNew {
+    pub endpoint_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: String,
+    pub url: String,
+    pub secret: Encryption,
+    pub enabled_events: Vec<storage_enums::EventType>,
+    pub enabled: bool,
+    pub custom_http_headers: Option<Encryption>,
+    pub description: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum WebhookEndpointUpdate {
+    Update {
+        url: Option<String>,
+        secret: Option<Encryption>,
+        enabled_events: Option<Vec<storage_enums::EventType>>,
+        enabled: Option<bool>,
+        custom_http_headers: Option<Encryption>,
+        description: Option<String>,
+    },
+    Suspend {
+        suspended_at: PrimitiveDateTime,
+    },
+    Reenable,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointUpdateInternal {
+    pub url: Option<String>,
+    pub secret: Option<Encryption>,
+    pub enabled_events: Option<Vec<storage_enums::EventType>>,
+    pub enabled: Option<bool>,
+    pub custom_http_headers: Option<Encryption>,
+    pub description: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+    pub consecutive_failures: Option<i32>,
+    pub recent_delivery_outcomes: Option<Vec<bool>>,
+    pub suspended_at: Option<Option<PrimitiveDateTime>>,
+}
+
+impl Default for WebhookEndpointUpdateInternal {
+    fn default() -> Self {
+        Self {
+            url: None,
+            secret: None,
+            enabled_events: None,
+            enabled: None,
+            custom_http_headers: None,
+            description: None,
+            modified_at: common_utils::date_time::now(),
+            consecutive_failures: None,
+            recent_delivery_outcomes: None,
+            suspended_at: None,
+        }
+    }
+}
+
+impl From<WebhookEndpointUpdate> for WebhookEndpointUpdateInternal {
+    fn from(webhook_endpoint_update: WebhookEndpointUpdate) -> Self {
+        match webhook_endpoint_update {
+            WebhookEndpointUpdate::Update {
+                url,
+                secret,
+                enabled_events,
+                enabled,
+                custom_http_headers,
+                description,
+            } => Self {
+                url,
+                secret,
+                enabled_events,
+                enabled,
+                custom_http_headers,
+                description,
+                ..Default::default()
+            },
+            WebhookEndpointUpdate::Suspend { suspended_at } => Self {
+                suspended_at: Some(Some(suspended_at)),
+                ..Default::default()
+            },
+            // The delivery health of the endpoint is tracked afresh once it is re-enabled
+            WebhookEndpointUpdate::Reenable => Self {
+                consecutive_failures: Some(0),
+                recent_delivery_outcomes: Some(Vec::new()),
+                suspended_at: Some(None),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// A webhook which could not be delivered to an endpoint as deliveries to the endpoint were
+/// suspended, and which is delivered once the endpoint is re-enabled
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = webhook_endpoint_queued_event, primary_key(endpoint_id, event_id), check_for_backend(diesel::pg::Pg))]
+pub struct WebhookEndpointQueuedEvent {
+    pub endpoint_id: String,
+    /// The initial delivery attempt of the event
+    pub event_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint_queued_event)]
+pub struct WebhookEndpointQueuedEventNew {
+    pub endpoint_id: String,
+    pub event_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub created_at: PrimitiveDateTime,
+}
@@ -0,0 +1,175 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{webhook_replay_job, webhook_replay_job_event},
+};
+
+/// A bulk replay of the outgoing webhooks of a business profile, processed in batches by the
+/// scheduler
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = webhook_replay_job, primary_key(job_id), check_for_backend(diesel::pg::Pg))]
+pub struct WebhookReplayJob {
+    pub job_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: String,
+    pub status: storage_enums::WebhookReplayJobStatus,
+    /// The filters the events to be replayed were selected with
+    pub filters: WebhookReplayJobFilters,
+    pub max_events_per_minute: i32,
+    pub total_events: i32,
+    pub delivered_events: i32,
+    pub failed_events: i32,
+    pub skipped_events: i32,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_replay_job)]
+pub struct WebhookReplayJobNew {
+    pub job_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: String,
+    pub status: storage_enums::WebhookReplayJobStatus,
+    pub filters: WebhookReplayJobFilters,
+    pub max_events_per_minute: i32,
+    pub total_events: i32,
+    pub delivered_events: i32,
+    pub failed_events: i32,
+    pub skipped_events: i32,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum WebhookReplayJobUpdate {
+    StatusUpdate {
+        status: storage_enums::WebhookReplayJobStatus,
+    },
+    ProgressUpdate {
+        status: storage_enums::WebhookReplayJobStatus,
+        delivered_events: i32,
+        failed_events: i32,
+        skipped_events: i32,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_replay_job)]
+pub struct WebhookReplayJobUpdateInternal {
+    pub status: Option<storage_enums::WebhookReplayJobStatus>,
+    pub delivered_events: Option<i32>,
+    pub failed_events: Option<i32>,
+    pub skipped_events: Option<i32>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<WebhookReplayJobUpdate> for WebhookReplayJobUpdateInternal {
+    fn from(webhook_replay_job_update: WebhookReplayJobUpdate) -> Self {
+        match webhook_replay_job_update {
+            WebhookReplayJobUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                delivered_events: None,
+                failed_events: None,
+                skipped_events: None,
+                modified_at: common_utils::date_time::now(),
+            },
+            WebhookReplayJobUpdate::ProgressUpdate {
+                status,
+                delivered_events,
+                failed_events,
+                skipped_events,
+            } => Self {
+                status: Some(status),
+                delivered_events: Some(delivered_events),
+                failed_events: Some(failed_events),
+                skipped_events: Some(skipped_events),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct WebhookReplayJobFilters {
+    pub created_after: PrimitiveDateTime,
+    pub created_before: PrimitiveDateTime,
+    pub event_types: Option<Vec<storage_enums::EventType>>,
+    pub delivery_status: Option<storage_enums::WebhookDeliveryStatus>,
+    pub object_ids: Option<Vec<String>>,
+}
+
+common_utils::impl_to_sql_from_sql_json!(WebhookReplayJobFilters);
+
+/// An event selected for replay as part of a bulk replay, along with the outcome of replaying it
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = webhook_replay_job_event, primary_key(job_id, event_id), check_for_backend(diesel::pg::Pg))]
+pub struct WebhookReplayJobEvent {
+    pub job_id: String,
+    /// The initial delivery attempt of the event being replayed
+    pub event_id: String,
+    pub status: storage_enums::WebhookReplayEventStatus,
+    /// The delivery attempt created by replaying the event
+    pub replayed_event_id: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_replay_job_event)]
+pub struct WebhookReplayJobEventNew {
+    pub job_id: String,
+    pub event_id: String,
+    pub status: storage_enums::WebhookReplayEventStatus,
+    pub replayed_event_id: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum WebhookReplayJobEventUpdate {
+    OutcomeUpdate {
+        status: storage_enums::WebhookReplayEventStatus,
+        replayed_event_id: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_replay_job_event)]
+pub struct WebhookReplayJobEventUpdateInternal {
+    pub status: Option<storage_enums::WebhookReplayEventStatus>,
+    pub replayed_event_id: Option<String>,
+    pub error_message: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<WebhookReplayJobEventUpdate> for WebhookReplayJobEventUpdateInternal {
+    fn from(webhook_replay_job_event_update: WebhookReplayJobEventUpdate) -> Self {
+        match webhook_replay_job_event_update {
+            WebhookReplayJobEventUpdate::OutcomeUpdate {
+                status,
+                replayed_event_id,
+                error_message,
+            } => Self {
+                status: Some(status),
+                replayed_event_id,
+                error_message,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+// Tracking data by process_tracker, for replaying the pending events of a replay job in batches
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct WebhookReplayTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub job_id: String,
+}
@@ -1,8 +1,12 @@
+#[cfg(feature = "valued_jit")]
+pub mod compiled;
 pub mod inputs;
 pub mod interpreter;
 #[cfg(feature = "valued_jit")]
 pub mod vir_interpreter;
 
+#[cfg(feature = "valued_jit")]
+pub use compiled::CompiledBackend;
 pub use inputs::BackendInput;
 pub use interpreter::InterpreterBackend;
 #[cfg(feature = "valued_jit")]
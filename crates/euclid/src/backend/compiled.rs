@@ -0,0 +1,488 @@
+//! Compiled Backend
+//!
+//! Compiles a program into a decision tree over the enum valued keys of the input. Every branch
+//! of the tree looks up the value of a single key, and leads to the rules which can still match
+//! for that value. The rules left at a leaf are evaluated in order by the valued interpreter, so
+//! the outcome is always the same as that of the [`VirInterpreterBackend`].
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    backend::{
+        self, inputs,
+        vir_interpreter::{types as vir_types, VirInterpreterBackend},
+        EuclidBackend,
+    },
+    frontend::{
+        ast,
+        dir::{self, EuclidDirFilter},
+        vir,
+    },
+    types::{DataType, EuclidKey, EuclidValue},
+};
+
+/// Depth beyond which the rules of a node are evaluated without splitting them further
+const MAX_DECISION_TREE_DEPTH: usize = 4;
+
+/// Upper bound on the number of branches in a tree, which keeps programs with many unconstrained
+/// rules from being copied into every branch
+const MAX_DECISION_TREE_BRANCHES: usize = 1024;
+
+/// Nodes with fewer rules than this are not split any further
+const MIN_RULES_TO_SPLIT: usize = 2;
+
+#[derive(Debug, Clone)]
+enum DecisionNode {
+    Branch {
+        key: EuclidKey,
+        branches: FxHashMap<EuclidValue, DecisionNode>,
+        /// Followed when the key is absent, or its value is not required by any rule
+        otherwise: Box<DecisionNode>,
+    },
+    Leaf {
+        /// Indices of the rules which can still match, in program order
+        rules: Vec<usize>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct CompiledBackend<O> {
+    program: vir::ValuedProgram<O>,
+    tree: DecisionNode,
+}
+
+/// Returns the values of the key, one of which has to be present in the input for the statement
+/// to match, or `None` if the statement does not require the key.
+fn get_statement_required_values(
+    statement: &vir::ValuedIfStatement,
+    key: &EuclidKey,
+) -> Option<FxHashSet<EuclidValue>> {
+    statement
+        .condition
+        .iter()
+        .filter(|comparison| {
+            matches!(
+                comparison.logic,
+                vir::ValuedComparisonLogic::PositiveDisjunction
+            ) && comparison
+                .values
+                .iter()
+                .all(|value| value.get_key() == *key)
+        })
+        .map(|comparison| comparison.values.iter().cloned().collect::<FxHashSet<_>>())
+        .reduce(|required, values| required.intersection(&values).cloned().collect())
+}
+
+/// Returns the values of the key, one of which has to be present in the input for the rule to
+/// match, or `None` if the rule can match without the key.
+fn get_rule_required_values<O>(
+    rule: &vir::ValuedRule<O>,
+    key: &EuclidKey,
+) -> Option<FxHashSet<EuclidValue>> {
+    rule.statements
+        .iter()
+        .try_fold(FxHashSet::default(), |mut required, statement| {
+            required.extend(get_statement_required_values(statement, key)?);
+            Some(required)
+        })
+}
+
+/// Collects the enum valued keys which the program requires to be present, in the order in
+/// which they first appear in it.
+fn get_decision_keys<O>(program: &vir::ValuedProgram<O>) -> Vec<EuclidKey> {
+    let mut keys = Vec::new();
+
+    let comparisons = program
+        .rules
+        .iter()
+        .flat_map(|rule| &rule.statements)
+        .flat_map(|statement| &statement.condition)
+        .filter(|comparison| {
+            matches!(
+                comparison.logic,
+                vir::ValuedComparisonLogic::PositiveDisjunction
+            )
+        });
+
+    for comparison in comparisons {
+        let key = comparison.values.first().map(EuclidValue::get_key);
+
+        if let Some(key) = key.filter(|key| {
+            // Only enum valued keys have at most a single value in the input
+            matches!(key.key_type(), DataType::EnumVariant) && !keys.contains(key)
+        }) {
+            keys.push(key);
+        }
+    }
+
+    keys
+}
+
+struct TreeBuilder<'a, O> {
+    rules: &'a [vir::ValuedRule<O>],
+    remaining_branches: usize,
+}
+
+impl<O> TreeBuilder<'_, O> {
+    fn build(&mut self, rules: Vec<usize>, keys: &[EuclidKey], depth: usize) -> DecisionNode {
+        if depth >= MAX_DECISION_TREE_DEPTH || rules.len() < MIN_RULES_TO_SPLIT {
+            return DecisionNode::Leaf { rules };
+        }
+
+        // Split on the key which the most rules require
+        let split = keys
+            .iter()
+            .map(|key| {
+                let required_values = rules
+                    .iter()
+                    .map(|rule_index| {
+                        let required = self
+                            .rules
+                            .get(*rule_index)
+                            .and_then(|rule| get_rule_required_values(rule, key));
+                        (*rule_index, required)
+                    })
+                    .collect::<Vec<_>>();
+
+                (key, required_values)
+            })
+            .max_by_key(|(_, required_values)| {
+                required_values
+                    .iter()
+                    .filter(|(_, required)| required.is_some())
+                    .count()
+            });
+
+        let Some((key, required_values)) = split.filter(|(_, required_values)| {
+            required_values
+                .iter()
+                .filter(|(_, required)| required.is_some())
+                .count()
+                >= MIN_RULES_TO_SPLIT
+        }) else {
+            return DecisionNode::Leaf { rules };
+        };
+
+        let values = required_values
+            .iter()
+            .filter_map(|(_, required)| required.as_ref())
+            .flatten()
+            .collect::<FxHashSet<_>>();
+
+        if values.len() + 1 > self.remaining_branches {
+            return DecisionNode::Leaf { rules };
+        }
+        self.remaining_branches -= values.len() + 1;
+
+        let remaining_keys = keys
+            .iter()
+            .filter(|remaining_key| *remaining_key != key)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let branches = values
+            .into_iter()
+            .map(|value| {
+                let branch_rules = required_values
+                    .iter()
+                    .filter(|(_, required)| {
+                        required
+                            .as_ref()
+                            .map_or(true, |required| required.contains(value))
+                    })
+                    .map(|(rule_index, _)| *rule_index)
+                    .collect();
+
+                (
+                    value.clone(),
+                    self.build(branch_rules, &remaining_keys, depth + 1),
+                )
+            })
+            .collect();
+
+        let otherwise_rules = required_values
+            .iter()
+            .filter(|(_, required)| required.is_none())
+            .map(|(rule_index, _)| *rule_index)
+            .collect();
+
+        DecisionNode::Branch {
+            key: key.clone(),
+            branches,
+            otherwise: Box::new(self.build(otherwise_rules, &remaining_keys, depth + 1)),
+        }
+    }
+}
+
+impl<O> CompiledBackend<O>
+where
+    O: Clone,
+{
+    pub fn with_valued_program(program: vir::ValuedProgram<O>) -> Self {
+        let keys = get_decision_keys(&program);
+        let tree = TreeBuilder {
+            rules: &program.rules,
+            remaining_branches: MAX_DECISION_TREE_BRANCHES,
+        }
+        .build((0..program.rules.len()).collect(), &keys, 0);
+
+        Self { program, tree }
+    }
+
+    pub fn get_program(&self) -> &vir::ValuedProgram<O> {
+        &self.program
+    }
+
+    fn get_candidate_rules(&self, ctx: &vir_types::Context) -> &[usize] {
+        let mut node = &self.tree;
+
+        loop {
+            match node {
+                DecisionNode::Branch {
+                    key,
+                    branches,
+                    otherwise,
+                } => {
+                    node = ctx
+                        .get_enum_value(key)
+                        .and_then(|value| branches.get(value))
+                        .unwrap_or(otherwise);
+                }
+                DecisionNode::Leaf { rules } => return rules,
+            }
+        }
+    }
+}
+
+impl<O> EuclidBackend<O> for CompiledBackend<O>
+where
+    O: Clone + EuclidDirFilter,
+{
+    type Error = vir_types::VirInterpreterError;
+
+    fn with_program(program: ast::Program<O>) -> Result<Self, Self::Error> {
+        let dir_program = ast::lowering::lower_program(program)
+            .map_err(vir_types::VirInterpreterError::LoweringError)?;
+
+        let vir_program = dir::lowering::lower_program(dir_program)
+            .map_err(vir_types::VirInterpreterError::LoweringError)?;
+
+        Ok(Self::with_valued_program(vir_program))
+    }
+
+    fn execute(
+        &self,
+        input: inputs::BackendInput,
+    ) -> Result<backend::BackendOutput<O>, Self::Error> {
+        let ctx = vir_types::Context::from_input(input);
+
+        Ok(self
+            .get_candidate_rules(&ctx)
+            .iter()
+            .filter_map(|rule_index| self.program.rules.get(*rule_index))
+            .find(|rule| VirInterpreterBackend::eval_rule(rule, &ctx))
+            .map_or_else(
+                || backend::BackendOutput {
+                    connector_selection: self.program.default_selection.clone(),
+                    rule_name: None,
+                },
+                |rule| backend::BackendOutput {
+                    connector_selection: rule.connector_selection.clone(),
+                    rule_name: Some(rule.name.clone()),
+                },
+            ))
+    }
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+    use crate::{enums, types::DummyOutput};
+
+    fn make_input(
+        payment_method: Option<(enums::PaymentMethod, enums::PaymentMethodType)>,
+        currency: enums::Currency,
+        capture_method: Option<enums::CaptureMethod>,
+        amount: i64,
+    ) -> inputs::BackendInput {
+        inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(amount),
+                currency,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                card_bin: None,
+                capture_method,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+                timestamp: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: payment_method.map(|(payment_method, _)| payment_method),
+                payment_method_type: payment_method
+                    .map(|(_, payment_method_type)| payment_method_type),
+                card_network: None,
+                card_bin_info: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_equivalence_with_interpreter() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            card_usd: ["stripe"]
+            {
+                payment_method = card & currency = USD
+            }
+
+            klarna_rule: ["adyen"]
+            {
+                pay_later = klarna
+            }
+
+            wallet_rule: ["checkout"]
+            {
+                payment_method = wallet {
+                    currency = EUR & amount > 500
+                    currency /= (EUR, INR)
+                }
+            }
+
+            manual_capture: ["adyen"]
+            {
+                capture_method = manual & amount >= 1000
+            }
+
+            affirm_or_apple_pay: ["stripe"]
+            {
+                pay_later = affirm
+                wallet = apple_pay
+            }
+
+            inr_rule: ["adyen"]
+            {
+                currency = INR & payment_method /= card
+            }
+
+            card_rule: ["checkout"]
+            {
+                payment_method = card
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let interpreter =
+            VirInterpreterBackend::<DummyOutput>::with_program(program.clone()).expect("Program");
+        let compiled = CompiledBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        assert!(
+            matches!(compiled.tree, DecisionNode::Branch { .. }),
+            "Program should be split on at least one key"
+        );
+
+        let payment_methods = [
+            None,
+            Some((enums::PaymentMethod::Card, enums::PaymentMethodType::Credit)),
+            Some((
+                enums::PaymentMethod::PayLater,
+                enums::PaymentMethodType::Klarna,
+            )),
+            Some((
+                enums::PaymentMethod::PayLater,
+                enums::PaymentMethodType::Affirm,
+            )),
+            Some((
+                enums::PaymentMethod::Wallet,
+                enums::PaymentMethodType::ApplePay,
+            )),
+            Some((
+                enums::PaymentMethod::Wallet,
+                enums::PaymentMethodType::GooglePay,
+            )),
+        ];
+        let currencies = [
+            enums::Currency::USD,
+            enums::Currency::EUR,
+            enums::Currency::INR,
+        ];
+        let capture_methods = [
+            None,
+            Some(enums::CaptureMethod::Automatic),
+            Some(enums::CaptureMethod::Manual),
+        ];
+        let amounts = [100, 501, 1000, 5000];
+
+        for payment_method in payment_methods {
+            for currency in currencies {
+                for capture_method in capture_methods {
+                    for amount in amounts {
+                        let input = make_input(payment_method, currency, capture_method, amount);
+
+                        let expected = interpreter.execute(input.clone()).expect("Execution");
+                        let result = compiled.execute(input).expect("Execution");
+
+                        assert_eq!(
+                            result.rule_name, expected.rule_name,
+                            "Mismatch for {payment_method:?}, {currency:?}, {capture_method:?}, {amount}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_unconstrained_rules_are_kept_in_order() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            klarna_rule: ["adyen"]
+            {
+                pay_later = klarna
+            }
+
+            large_amount: ["checkout"]
+            {
+                amount > 1000
+            }
+
+            affirm_rule: ["stripe"]
+            {
+                pay_later = affirm
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let compiled = CompiledBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        let affirm = Some((
+            enums::PaymentMethod::PayLater,
+            enums::PaymentMethodType::Affirm,
+        ));
+        let result = compiled
+            .execute(make_input(affirm, enums::Currency::USD, None, 5000))
+            .expect("Execution");
+        assert_eq!(result.rule_name.as_deref(), Some("large_amount"));
+
+        let result = compiled
+            .execute(make_input(affirm, enums::Currency::USD, None, 100))
+            .expect("Execution");
+        assert_eq!(result.rule_name.as_deref(), Some("affirm_rule"));
+
+        let result = compiled
+            .execute(make_input(None, enums::Currency::USD, None, 100))
+            .expect("Execution");
+        assert_eq!(result.rule_name, None);
+    }
+}
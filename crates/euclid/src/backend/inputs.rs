@@ -15,6 +15,17 @@ pub struct PaymentMethodInput {
     pub payment_method: Option<enums::PaymentMethod>,
     pub payment_method_type: Option<enums::PaymentMethodType>,
     pub card_network: Option<enums::CardNetwork>,
+    /// Attributes of the card looked up from its BIN
+    pub card_bin_info: Option<CardBinInfo>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CardBinInfo {
+    pub issuer_country: Option<enums::Country>,
+    pub issuer: Option<String>,
+    pub product_type: Option<String>,
+    pub is_prepaid: Option<bool>,
+    pub is_commercial: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +39,26 @@ pub struct PaymentInput {
     pub billing_country: Option<enums::Country>,
     pub business_label: Option<String>,
     pub setup_future_usage: Option<enums::SetupFutureUsage>,
+    /// UNIX timestamp, in seconds, at which the payment is being routed
+    pub timestamp: Option<i64>,
+}
+
+impl PaymentInput {
+    fn get_date_time(&self) -> Option<time::OffsetDateTime> {
+        self.timestamp
+            .and_then(|timestamp| time::OffsetDateTime::from_unix_timestamp(timestamp).ok())
+    }
+
+    /// Hour of the day, in UTC, at which the payment is being routed
+    pub fn get_hour_of_day(&self) -> Option<u8> {
+        self.get_date_time().map(|date_time| date_time.hour())
+    }
+
+    /// Day of the week, in UTC, on which the payment is being routed
+    pub fn get_day_of_week(&self) -> Option<enums::DayOfWeek> {
+        self.get_date_time()
+            .map(|date_time| enums::DayOfWeek::from(date_time.weekday()))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -5,6 +5,7 @@ use common_utils::types::MinorUnit;
 use crate::{
     backend::{self, inputs, EuclidBackend},
     frontend::ast,
+    types::{DateRangeValue, EuclidKey, Metadata},
 };
 
 pub struct InterpreterBackend<O> {
@@ -38,6 +39,22 @@ where
         Ok(false)
     }
 
+    fn eval_date_range(
+        timestamp: MinorUnit,
+        date_range: &str,
+        metadata: &Metadata,
+    ) -> Result<bool, types::InterpreterError> {
+        let date_range =
+            date_range
+                .parse::<DateRangeValue>()
+                .map_err(|message| types::InterpreterError {
+                    error_type: types::InterpreterErrorType::InvalidDateRange(message),
+                    metadata: metadata.clone(),
+                })?;
+
+        Ok(date_range.contains(timestamp.get_amount_as_i64()))
+    }
+
     fn eval_comparison(
         comparison: &ast::Comparison,
         ctx: &types::Context,
@@ -70,6 +87,17 @@ where
                 (Number(n), Equal, NumberComparisonArray(ncvec)) => {
                     Self::eval_number_comparison_array(*n, ncvec)
                 }
+                // The context holds the timestamp of the payment against the date range key
+                (Number(n), Equal, StrValue(s))
+                    if comparison.lhs == EuclidKey::DateRange.to_string() =>
+                {
+                    Self::eval_date_range(*n, s, &comparison.metadata)
+                }
+                (Number(n), NotEqual, StrValue(s))
+                    if comparison.lhs == EuclidKey::DateRange.to_string() =>
+                {
+                    Self::eval_date_range(*n, s, &comparison.metadata).map(|res| !res)
+                }
                 _ => Err(types::InterpreterError {
                     error_type: types::InterpreterErrorType::InvalidComparison,
                     metadata: comparison.metadata.clone(),
@@ -1,8 +1,9 @@
 use std::{collections::HashMap, fmt, ops::Deref, string::ToString};
 
+use common_utils::types::MinorUnit;
 use serde::Serialize;
 
-use crate::{backend::inputs, frontend::ast::ValueType, types::EuclidKey};
+use crate::{backend::inputs, enums, frontend::ast::ValueType, types::EuclidKey};
 
 #[derive(Debug, Clone, Serialize, thiserror::Error)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
@@ -11,6 +12,8 @@ pub enum InterpreterErrorType {
     InvalidKey(String),
     #[error("Invalid Comparison")]
     InvalidComparison,
+    #[error("Invalid date range received: {0}")]
+    InvalidDateRange(String),
 }
 
 #[derive(Debug, Clone, Serialize, thiserror::Error)]
@@ -37,6 +40,12 @@ impl Deref for Context {
 
 impl From<inputs::BackendInput> for Context {
     fn from(input: inputs::BackendInput) -> Self {
+        let card_bin_info = input
+            .payment_method
+            .card_bin_info
+            .clone()
+            .unwrap_or_default();
+
         let ctx = HashMap::<String, Option<ValueType>>::from_iter([
             (
                 EuclidKey::PaymentMethod.to_string(),
@@ -74,6 +83,53 @@ impl From<inputs::BackendInput> for Context {
                 EuclidKey::PaymentCurrency.to_string(),
                 Some(ValueType::EnumVariant(input.payment.currency.to_string())),
             ),
+            (
+                EuclidKey::HourOfDay.to_string(),
+                input
+                    .payment
+                    .get_hour_of_day()
+                    .map(|hour| ValueType::Number(MinorUnit::new(hour.into()))),
+            ),
+            (
+                EuclidKey::DayOfWeek.to_string(),
+                input
+                    .payment
+                    .get_day_of_week()
+                    .map(|day| ValueType::EnumVariant(day.to_string())),
+            ),
+            (
+                EuclidKey::DateRange.to_string(),
+                input
+                    .payment
+                    .timestamp
+                    .map(|timestamp| ValueType::Number(MinorUnit::new(timestamp))),
+            ),
+            (
+                EuclidKey::CardIssuerCountry.to_string(),
+                card_bin_info
+                    .issuer_country
+                    .map(|country| ValueType::EnumVariant(country.to_string())),
+            ),
+            (
+                EuclidKey::CardIssuer.to_string(),
+                card_bin_info.issuer.map(ValueType::StrValue),
+            ),
+            (
+                EuclidKey::CardProductType.to_string(),
+                card_bin_info.product_type.map(ValueType::StrValue),
+            ),
+            (
+                EuclidKey::PrepaidCard.to_string(),
+                card_bin_info.is_prepaid.map(|is_prepaid| {
+                    ValueType::EnumVariant(enums::BooleanFlag::from(is_prepaid).to_string())
+                }),
+            ),
+            (
+                EuclidKey::CommercialCard.to_string(),
+                card_bin_info.is_commercial.map(|is_commercial| {
+                    ValueType::EnumVariant(enums::BooleanFlag::from(is_commercial).to_string())
+                }),
+            ),
         ]);
 
         Self(ctx)
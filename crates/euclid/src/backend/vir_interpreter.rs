@@ -49,7 +49,11 @@ where
             .unwrap_or(false)
     }
 
-    fn eval_rule(rule: &vir::ValuedRule<O>, ctx: &types::Context) -> bool {
+    pub fn get_program(&self) -> &vir::ValuedProgram<O> {
+        &self.program
+    }
+
+    pub(crate) fn eval_rule(rule: &vir::ValuedRule<O>, ctx: &types::Context) -> bool {
         rule.statements
             .iter()
             .any(|stmt| Self::eval_statement(stmt, ctx))
@@ -109,7 +113,10 @@ mod test {
     use rustc_hash::FxHashMap;
 
     use super::*;
-    use crate::{enums, types::DummyOutput};
+    use crate::{
+        enums,
+        types::{DummyOutput, EuclidKey},
+    };
 
     #[test]
     fn test_execution() {
@@ -140,11 +147,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                timestamp: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_bin_info: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -180,11 +189,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                timestamp: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_bin_info: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -221,11 +232,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                timestamp: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_bin_info: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -262,11 +275,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                timestamp: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_bin_info: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: Some(enums::MandateAcceptanceType::Online),
@@ -303,11 +318,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                timestamp: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_bin_info: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -344,11 +361,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                timestamp: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_bin_info: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -385,11 +404,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                timestamp: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_bin_info: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -426,11 +447,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: Some(enums::SetupFutureUsage::OffSession),
+                timestamp: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_bin_info: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -469,11 +492,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                timestamp: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_bin_info: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -510,11 +535,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                timestamp: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_bin_info: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -560,11 +587,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                timestamp: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_bin_info: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -586,4 +615,202 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_time_based_rules() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+           hour_of_day >= 22
+        }
+
+        rule_2: ["adyen"]
+        {
+           day_of_week = (saturday, sunday)
+        }
+
+        rule_3: ["paypal"]
+        {
+           date_range = "2024-12-24T00:00:00+05:30/2024-12-26T00:00:00+05:30"
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_in_date_range = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+                // Wednesday, 2024-12-25T10:00:00Z
+                timestamp: Some(1735120800),
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+                card_bin_info: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        };
+        let mut inp_late_saturday = inp_in_date_range.clone();
+        // Saturday, 2024-12-28T23:00:00Z
+        inp_late_saturday.payment.timestamp = Some(1735426800);
+        let mut inp_saturday = inp_in_date_range.clone();
+        // Saturday, 2024-12-28T10:00:00Z
+        inp_saturday.payment.timestamp = Some(1735380000);
+        let mut inp_without_timestamp = inp_in_date_range.clone();
+        inp_without_timestamp.payment.timestamp = None;
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_in_date_range = backend.execute(inp_in_date_range).expect("Execution");
+        let result_late_saturday = backend.execute(inp_late_saturday).expect("Execution");
+        let result_saturday = backend.execute(inp_saturday).expect("Execution");
+        let result_without_timestamp = backend.execute(inp_without_timestamp).expect("Execution");
+        assert_eq!(
+            result_in_date_range.rule_name.expect("Rule Name").as_str(),
+            "rule_3"
+        );
+        assert_eq!(
+            result_late_saturday.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert_eq!(
+            result_saturday.rule_name.expect("Rule Name").as_str(),
+            "rule_2"
+        );
+        assert!(result_without_timestamp.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_card_bin_info_rules() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+           prepaid_card = true
+        }
+
+        rule_2: ["adyen"]
+        {
+           card_issuer_country = India & card_issuer = "HDFC BANK"
+        }
+
+        rule_3: ["paypal"]
+        {
+           commercial_card = true & card_product_type = "corporate"
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_domestic = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(120),
+                card_bin: Some("411111".to_string()),
+                currency: enums::Currency::INR,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::India),
+                billing_country: Some(enums::Country::India),
+                business_label: None,
+                setup_future_usage: None,
+                timestamp: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                card_network: None,
+                card_bin_info: Some(inputs::CardBinInfo {
+                    issuer_country: Some(enums::Country::India),
+                    issuer: Some("HDFC BANK".to_string()),
+                    product_type: Some("classic".to_string()),
+                    is_prepaid: Some(false),
+                    is_commercial: Some(false),
+                }),
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        };
+        let mut inp_prepaid = inp_domestic.clone();
+        inp_prepaid.payment_method.card_bin_info = Some(inputs::CardBinInfo {
+            is_prepaid: Some(true),
+            ..Default::default()
+        });
+        let mut inp_commercial = inp_domestic.clone();
+        inp_commercial.payment_method.card_bin_info = Some(inputs::CardBinInfo {
+            issuer_country: Some(enums::Country::UnitedStatesOfAmerica),
+            product_type: Some("corporate".to_string()),
+            is_commercial: Some(true),
+            ..Default::default()
+        });
+        let mut inp_without_bin_info = inp_domestic.clone();
+        inp_without_bin_info.payment_method.card_bin_info = None;
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_domestic = backend.execute(inp_domestic).expect("Execution");
+        let result_prepaid = backend.execute(inp_prepaid).expect("Execution");
+        let result_commercial = backend.execute(inp_commercial).expect("Execution");
+        let result_without_bin_info = backend.execute(inp_without_bin_info).expect("Execution");
+        assert_eq!(
+            result_domestic.rule_name.expect("Rule Name").as_str(),
+            "rule_2"
+        );
+        assert_eq!(
+            result_prepaid.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert_eq!(
+            result_commercial.rule_name.expect("Rule Name").as_str(),
+            "rule_3"
+        );
+        assert!(result_without_bin_info.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_card_bin_derived_keys() {
+        let program_with_nested_key = r#"
+        default: ["stripe"]
+
+        rule_1: ["adyen"]
+        {
+           payment_method = card {
+               card_issuer = "HDFC BANK"
+           }
+        }
+        "#;
+        let program_without_key = r#"
+        default: ["stripe"]
+
+        rule_1: ["adyen"]
+        {
+           payment_method = card & card_bin = "411111"
+        }
+        "#;
+
+        let has_card_bin_derived_key = |program_str| {
+            let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+            VirInterpreterBackend::<DummyOutput>::with_program(program)
+                .expect("Program")
+                .get_program()
+                .has_key(EuclidKey::is_card_bin_derived)
+        };
+
+        assert!(has_card_bin_derived_key(program_with_nested_key));
+        assert!(!has_card_bin_derived_key(program_without_key));
+    }
 }
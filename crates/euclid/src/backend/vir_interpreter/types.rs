@@ -1,3 +1,4 @@
+use common_utils::types::MinorUnit;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
@@ -15,10 +16,18 @@ pub enum VirInterpreterError {
 pub struct Context {
     atomic_values: FxHashSet<EuclidValue>,
     numeric_values: FxHashMap<EuclidKey, EuclidValue>,
+    timestamp: Option<i64>,
 }
 
 impl Context {
     pub fn check_presence(&self, value: &EuclidValue) -> bool {
+        // A date range is matched against the timestamp of the payment rather than by equality
+        if let EuclidValue::DateRange(date_range) = value {
+            return self
+                .timestamp
+                .map_or(false, |timestamp| date_range.contains(timestamp));
+        }
+
         let key = value.get_key();
 
         match key.key_type() {
@@ -51,11 +60,20 @@ impl Context {
         }
     }
 
+    /// Returns the value of an enum valued key, of which the input holds at most one
+    pub fn get_enum_value(&self, key: &EuclidKey) -> Option<&EuclidValue> {
+        self.atomic_values
+            .iter()
+            .find(|value| value.get_key() == *key)
+    }
+
     pub fn from_input(input: BackendInput) -> Self {
         let payment = input.payment;
         let payment_method = input.payment_method;
         let meta_data = input.metadata;
         let payment_mandate = input.mandate;
+        let hour_of_day = payment.get_hour_of_day();
+        let day_of_week = payment.get_day_of_week();
 
         let mut enum_values: FxHashSet<EuclidValue> =
             FxHashSet::from_iter([EuclidValue::PaymentCurrency(payment.currency)]);
@@ -78,6 +96,26 @@ impl Context {
             enum_values.insert(EuclidValue::CardNetwork(card_network));
         }
 
+        if let Some(card_bin_info) = payment_method.card_bin_info {
+            if let Some(issuer_country) = card_bin_info.issuer_country {
+                enum_values.insert(EuclidValue::CardIssuerCountry(issuer_country));
+            }
+            if let Some(issuer) = card_bin_info.issuer {
+                enum_values.insert(EuclidValue::CardIssuer(StrValue { value: issuer }));
+            }
+            if let Some(product_type) = card_bin_info.product_type {
+                enum_values.insert(EuclidValue::CardProductType(StrValue {
+                    value: product_type,
+                }));
+            }
+            if let Some(is_prepaid) = card_bin_info.is_prepaid {
+                enum_values.insert(EuclidValue::PrepaidCard(is_prepaid.into()));
+            }
+            if let Some(is_commercial) = card_bin_info.is_commercial {
+                enum_values.insert(EuclidValue::CommercialCard(is_commercial.into()));
+            }
+        }
+
         if let Some(at) = payment.authentication_type {
             enum_values.insert(EuclidValue::AuthenticationType(at));
         }
@@ -113,18 +151,31 @@ impl Context {
         if let Some(mandate_acceptance_type) = payment_mandate.mandate_acceptance_type {
             enum_values.insert(EuclidValue::MandateAcceptanceType(mandate_acceptance_type));
         }
+        if let Some(day_of_week) = day_of_week {
+            enum_values.insert(EuclidValue::DayOfWeek(day_of_week));
+        }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
                 refinement: None,
             }),
         )]);
+        if let Some(hour_of_day) = hour_of_day {
+            numeric_values.insert(
+                EuclidKey::HourOfDay,
+                EuclidValue::HourOfDay(types::NumValue {
+                    number: MinorUnit::new(hour_of_day.into()),
+                    refinement: None,
+                }),
+            );
+        }
 
         Self {
             atomic_values: enum_values,
             numeric_values,
+            timestamp: payment.timestamp,
         }
     }
 }
@@ -10,7 +10,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use crate::{
     dssa::{
         graph::CgraphExt,
-        state_machine, truth,
+        reachability, state_machine, truth,
         types::{self, EuclidAnalysable},
     },
     frontend::{
@@ -238,6 +238,18 @@ pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
     dir::lowering::lower_program(dir_program)
 }
 
+/// Lowers the program and reports the rules which can never be selected, along with the nested
+/// statements which are unreachable or redundant.
+///
+/// Unlike [`analyze`], the findings do not make the program invalid, and are returned as
+/// warnings instead of errors.
+pub fn analyze_rule_reachability<O: EuclidDirFilter>(
+    program: ast::Program<O>,
+) -> Result<Vec<types::AnalysisWarning>, types::AnalysisError> {
+    let dir_program = ast::lowering::lower_program(program)?;
+    Ok(reachability::analyze_rule_reachability(&dir_program))
+}
+
 #[cfg(all(test, feature = "ast_parser"))]
 mod tests {
     #![allow(clippy::panic, clippy::expect_used)]
@@ -454,4 +466,124 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn test_shadowed_rule_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            card_rule: ["stripe"]
+            {
+                payment_method = card
+            }
+
+            large_wallet_rule: ["adyen"]
+            {
+                payment_method = wallet & amount >= 1000
+            }
+
+            small_wallet_rule: ["checkout"]
+            {
+                payment_method = wallet & amount < 1000
+            }
+
+            card_or_wallet_rule: ["adyen"]
+            {
+                payment_method = (card, wallet) & capture_method = automatic
+            }
+
+            pay_later_rule: ["stripe"]
+            {
+                payment_method = pay_later
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let warnings = analyze_rule_reachability(program).expect("Analysis");
+
+        assert_eq!(
+            warnings,
+            vec![types::AnalysisWarning::ShadowedRule {
+                rule_name: "card_or_wallet_rule".to_string(),
+                shadowed_by: vec![
+                    "card_rule".to_string(),
+                    "large_wallet_rule".to_string(),
+                    "small_wallet_rule".to_string(),
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_shadowing_by_negation_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            automatic_rule: ["stripe"]
+            {
+                capture_method = automatic
+            }
+
+            non_automatic_rule: ["adyen"]
+            {
+                capture_method /= automatic
+            }
+
+            manual_rule: ["checkout"]
+            {
+                capture_method = manual
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let warnings = analyze_rule_reachability(program).expect("Analysis");
+
+        assert_eq!(
+            warnings,
+            vec![types::AnalysisWarning::ShadowedRule {
+                rule_name: "manual_rule".to_string(),
+                shadowed_by: vec!["non_automatic_rule".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_unreachable_and_redundant_statement_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            wallet_rule: ["adyen"]
+            {
+                payment_method = wallet {
+                    payment_method /= card
+                    payment_method = card
+                }
+            }
+
+            amount_rule: ["stripe"]
+            {
+                amount > 100 & amount < 50
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let warnings = analyze_rule_reachability(program).expect("Analysis");
+
+        assert_eq!(
+            warnings,
+            vec![
+                types::AnalysisWarning::RedundantNestedStatement {
+                    rule_name: "wallet_rule".to_string(),
+                    statement_path: vec![0, 0],
+                },
+                types::AnalysisWarning::UnreachableStatement {
+                    rule_name: "wallet_rule".to_string(),
+                    statement_path: vec![0, 1],
+                },
+                types::AnalysisWarning::UnreachableRule {
+                    rule_name: "amount_rule".to_string(),
+                },
+            ]
+        );
+    }
 }
@@ -70,6 +70,14 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::CardRedirectType(crt) => crt.to_string(),
             Self::RealTimePaymentType(rtpt) => rtpt.to_string(),
             Self::OpenBankingType(ob) => ob.to_string(),
+            Self::HourOfDay(hod) => hod.number.to_string(),
+            Self::DayOfWeek(dow) => dow.to_string(),
+            Self::DateRange(dr) => dr.to_string(),
+            Self::CardIssuerCountry(cic) => cic.to_string(),
+            Self::CardIssuer(ci) => ci.value.clone(),
+            Self::CardProductType(cpt) => cpt.value.clone(),
+            Self::PrepaidCard(pc) => pc.to_string(),
+            Self::CommercialCard(cc) => cc.to_string(),
         }
     }
 }
@@ -0,0 +1,505 @@
+//! Rule Reachability Analysis
+//!
+//! Finds the rules of a program which can never be selected, either because their conditions
+//! can never hold or because every input satisfying them is already claimed by the rules before
+//! them. Nested statements which can never be reached, or which do not narrow down the statement
+//! they are nested in, are reported as well.
+//!
+//! Every key is treated as independent of the others, so the analysis only reports findings that
+//! hold for all inputs, and may miss some that depend on keys being related to each other.
+use std::collections::BTreeSet;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    dssa::types::AnalysisWarning,
+    frontend::dir,
+    types::{NumValue, NumValueRefinement},
+};
+
+/// Upper bound on the number of cases explored while checking whether a rule is covered by the
+/// rules before it. Rules which exceed it are assumed to be reachable.
+const COVERAGE_CHECK_BUDGET: usize = 4096;
+
+/// The values a single key of the input can take, with `None` standing for an absent key.
+#[derive(Debug, Clone)]
+enum ValueSet {
+    /// The listed values, or every value apart from the listed ones when `negated` is set.
+    Discrete {
+        values: FxHashSet<Option<dir::DirValue>>,
+        negated: bool,
+    },
+    /// Sorted, disjoint, inclusive ranges over the numeric value of the key.
+    Numeric {
+        ranges: Vec<(i64, i64)>,
+        absent: bool,
+    },
+}
+
+impl ValueSet {
+    fn universe(kind: &dir::DirKeyKind) -> Self {
+        match kind {
+            dir::DirKeyKind::PaymentAmount
+            | dir::DirKeyKind::HourOfDay
+            | dir::DirKeyKind::DateRange => Self::Numeric {
+                ranges: vec![(i64::MIN, i64::MAX)],
+                absent: true,
+            },
+            _ => Self::Discrete {
+                values: FxHashSet::default(),
+                negated: true,
+            },
+        }
+    }
+
+    fn from_value(value: &dir::DirValue) -> Self {
+        match value {
+            dir::DirValue::PaymentAmount(num) | dir::DirValue::HourOfDay(num) => Self::Numeric {
+                ranges: get_num_value_ranges(num),
+                absent: false,
+            },
+            // A date range matches the timestamps in `[start, end)`
+            dir::DirValue::DateRange(date_range) => Self::Numeric {
+                ranges: date_range
+                    .end_timestamp
+                    .checked_sub(1)
+                    .filter(|last| *last >= date_range.start_timestamp)
+                    .map(|last| (date_range.start_timestamp, last))
+                    .into_iter()
+                    .collect(),
+                absent: false,
+            },
+            _ => Self::Discrete {
+                values: std::iter::once(Some(value.clone())).collect(),
+                negated: false,
+            },
+        }
+    }
+
+    fn from_comparison(comparison: &dir::DirComparison) -> Option<Self> {
+        let values = comparison
+            .values
+            .iter()
+            .map(Self::from_value)
+            .reduce(|acc, set| acc.union(&set))?;
+
+        Some(match comparison.logic {
+            dir::DirComparisonLogic::PositiveDisjunction => values,
+            dir::DirComparisonLogic::NegativeConjunction => values.complement(),
+        })
+    }
+
+    fn complement(&self) -> Self {
+        match self {
+            Self::Discrete { values, negated } => Self::Discrete {
+                values: values.clone(),
+                negated: !negated,
+            },
+            Self::Numeric { ranges, absent } => Self::Numeric {
+                ranges: complement_ranges(ranges),
+                absent: !absent,
+            },
+        }
+    }
+
+    fn intersect(&self, other: &Self) -> Self {
+        match (self, other) {
+            (
+                Self::Discrete {
+                    values: this,
+                    negated: false,
+                },
+                Self::Discrete {
+                    values: that,
+                    negated: false,
+                },
+            ) => Self::Discrete {
+                values: this.intersection(that).cloned().collect(),
+                negated: false,
+            },
+            (
+                Self::Discrete {
+                    values: included,
+                    negated: false,
+                },
+                Self::Discrete {
+                    values: excluded,
+                    negated: true,
+                },
+            )
+            | (
+                Self::Discrete {
+                    values: excluded,
+                    negated: true,
+                },
+                Self::Discrete {
+                    values: included,
+                    negated: false,
+                },
+            ) => Self::Discrete {
+                values: included.difference(excluded).cloned().collect(),
+                negated: false,
+            },
+            (
+                Self::Discrete {
+                    values: this,
+                    negated: true,
+                },
+                Self::Discrete {
+                    values: that,
+                    negated: true,
+                },
+            ) => Self::Discrete {
+                values: this.union(that).cloned().collect(),
+                negated: true,
+            },
+            (
+                Self::Numeric {
+                    ranges: this,
+                    absent: this_absent,
+                },
+                Self::Numeric {
+                    ranges: that,
+                    absent: that_absent,
+                },
+            ) => Self::Numeric {
+                ranges: intersect_ranges(this, that),
+                absent: *this_absent && *that_absent,
+            },
+            // A key is never compared against both discrete and numeric values. Keeping the
+            // larger set errs on the side of reporting nothing.
+            _ => self.clone(),
+        }
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        self.complement()
+            .intersect(&other.complement())
+            .complement()
+    }
+
+    fn is_empty(&self, kind: &dir::DirKeyKind) -> bool {
+        // The amount and the currency are part of every input
+        let is_always_present = matches!(
+            kind,
+            dir::DirKeyKind::PaymentAmount | dir::DirKeyKind::PaymentCurrency
+        );
+
+        match self {
+            Self::Discrete {
+                values,
+                negated: false,
+            } => values
+                .iter()
+                .all(|value| value.is_none() && is_always_present),
+            // Only keys with a known set of variants can be exhaustively negated
+            Self::Discrete {
+                values,
+                negated: true,
+            } => {
+                (is_always_present || values.contains(&None))
+                    && kind.get_value_set().map_or(false, |variants| {
+                        variants
+                            .into_iter()
+                            .all(|variant| values.contains(&Some(variant)))
+                    })
+            }
+            Self::Numeric { ranges, absent } => ranges.is_empty() && (!absent || is_always_present),
+        }
+    }
+
+    fn is_subset(&self, other: &Self, kind: &dir::DirKeyKind) -> bool {
+        self.intersect(&other.complement()).is_empty(kind)
+    }
+}
+
+fn get_num_value_ranges(num: &NumValue) -> Vec<(i64, i64)> {
+    let number = num.number.get_amount_as_i64();
+
+    match num.refinement {
+        None => vec![(number, number)],
+        Some(NumValueRefinement::NotEqual) => [
+            number.checked_sub(1).map(|last| (i64::MIN, last)),
+            number.checked_add(1).map(|first| (first, i64::MAX)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect(),
+        Some(NumValueRefinement::GreaterThan) => number
+            .checked_add(1)
+            .map(|first| (first, i64::MAX))
+            .into_iter()
+            .collect(),
+        Some(NumValueRefinement::GreaterThanEqual) => vec![(number, i64::MAX)],
+        Some(NumValueRefinement::LessThan) => number
+            .checked_sub(1)
+            .map(|last| (i64::MIN, last))
+            .into_iter()
+            .collect(),
+        Some(NumValueRefinement::LessThanEqual) => vec![(i64::MIN, number)],
+    }
+}
+
+fn intersect_ranges(this: &[(i64, i64)], that: &[(i64, i64)]) -> Vec<(i64, i64)> {
+    let mut ranges = this
+        .iter()
+        .flat_map(|(this_first, this_last)| {
+            that.iter().filter_map(move |(that_first, that_last)| {
+                let first = *this_first.max(that_first);
+                let last = *this_last.min(that_last);
+                (first <= last).then_some((first, last))
+            })
+        })
+        .collect::<Vec<_>>();
+    ranges.sort_unstable();
+
+    // Merge the ranges which overlap or touch each other
+    let mut merged: Vec<(i64, i64)> = Vec::with_capacity(ranges.len());
+    for (first, last) in ranges {
+        match merged.last_mut() {
+            Some((_, merged_last)) if first <= merged_last.saturating_add(1) => {
+                *merged_last = (*merged_last).max(last);
+            }
+            _ => merged.push((first, last)),
+        }
+    }
+
+    merged
+}
+
+fn complement_ranges(ranges: &[(i64, i64)]) -> Vec<(i64, i64)> {
+    let mut complement = Vec::with_capacity(ranges.len() + 1);
+    let mut next = Some(i64::MIN);
+
+    for (first, last) in ranges {
+        if let Some(gap_first) = next.filter(|gap_first| gap_first < first) {
+            complement.push((gap_first, first - 1));
+        }
+        next = last.checked_add(1);
+    }
+
+    if let Some(gap_first) = next {
+        complement.push((gap_first, i64::MAX));
+    }
+
+    complement
+}
+
+/// A conjunction of constraints over the keys of the input. Keys without a constraint can take
+/// any value.
+#[derive(Debug, Clone, Default)]
+struct Path {
+    constraints: FxHashMap<dir::DirKey, ValueSet>,
+}
+
+impl Path {
+    fn from_condition(condition: &dir::DirIfCondition) -> Option<Self> {
+        let mut path = Self::default();
+
+        for comparison in condition {
+            let Some(key) = comparison.values.first().map(dir::DirValue::get_key) else {
+                match comparison.logic {
+                    dir::DirComparisonLogic::PositiveDisjunction => return None,
+                    dir::DirComparisonLogic::NegativeConjunction => continue,
+                }
+            };
+
+            let set = ValueSet::from_comparison(comparison)?;
+            if !path.restrict(key, set) {
+                return None;
+            }
+        }
+
+        Some(path)
+    }
+
+    fn get(&self, key: &dir::DirKey) -> ValueSet {
+        self.constraints
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| ValueSet::universe(&key.kind))
+    }
+
+    /// Narrows down the values of the key, returning `false` if no value is left.
+    fn restrict(&mut self, key: dir::DirKey, set: ValueSet) -> bool {
+        let restricted = match self.constraints.get(&key) {
+            Some(existing) => existing.intersect(&set),
+            None => set,
+        };
+        let is_empty = restricted.is_empty(&key.kind);
+        self.constraints.insert(key, restricted);
+
+        !is_empty
+    }
+
+    fn intersect(&self, other: &Self) -> Option<Self> {
+        let mut path = self.clone();
+
+        other
+            .constraints
+            .iter()
+            .all(|(key, set)| path.restrict(key.clone(), set.clone()))
+            .then_some(path)
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        other
+            .constraints
+            .iter()
+            .all(|(key, set)| self.get(key).is_subset(set, &key.kind))
+    }
+}
+
+/// Checks whether every input in `cell` satisfies at least one of the candidate paths, recording
+/// the rules whose paths were needed to cover it.
+///
+/// Whenever no single candidate contains the cell, the cell is split on a key constrained by an
+/// overlapping candidate into the part inside the candidate and the part outside of it, and both
+/// parts are checked on their own.
+fn is_covered(
+    cell: &Path,
+    candidates: &[(usize, &Path)],
+    budget: &mut usize,
+    covering_rules: &mut BTreeSet<usize>,
+) -> bool {
+    if *budget == 0 {
+        return false;
+    }
+    *budget -= 1;
+
+    let overlapping = candidates
+        .iter()
+        .filter(|(_, candidate)| cell.intersect(candidate).is_some())
+        .copied()
+        .collect::<Vec<_>>();
+
+    if let Some((rule_index, _)) = overlapping
+        .iter()
+        .find(|(_, candidate)| cell.is_subset(candidate))
+    {
+        covering_rules.insert(*rule_index);
+        return true;
+    }
+
+    let Some((key, set)) = overlapping.first().and_then(|(_, candidate)| {
+        candidate
+            .constraints
+            .iter()
+            .find(|(key, set)| !cell.get(key).is_subset(set, &key.kind))
+    }) else {
+        return false;
+    };
+
+    let cell_set = cell.get(key);
+    [
+        cell_set.intersect(set),
+        cell_set.intersect(&set.complement()),
+    ]
+    .into_iter()
+    .all(|part| {
+        let mut sub_cell = cell.clone();
+        !sub_cell.restrict(key.clone(), part)
+            || is_covered(&sub_cell, &overlapping, budget, covering_rules)
+    })
+}
+
+struct RuleAnalysis<'a> {
+    rule_name: &'a str,
+    paths: Vec<Path>,
+    warnings: Vec<AnalysisWarning>,
+}
+
+impl RuleAnalysis<'_> {
+    fn collect_paths(
+        &mut self,
+        statements: &[dir::DirIfStatement],
+        parent: &Path,
+        statement_path: &mut Vec<usize>,
+    ) {
+        for (index, statement) in statements.iter().enumerate() {
+            statement_path.push(index);
+
+            match Path::from_condition(&statement.condition) {
+                Some(condition) => match parent.intersect(&condition) {
+                    Some(reached) => {
+                        if statement_path.len() > 1 && parent.is_subset(&condition) {
+                            self.warnings
+                                .push(AnalysisWarning::RedundantNestedStatement {
+                                    rule_name: self.rule_name.to_string(),
+                                    statement_path: statement_path.clone(),
+                                });
+                        }
+
+                        match &statement.nested {
+                            Some(nested) => self.collect_paths(nested, &reached, statement_path),
+                            None => self.paths.push(reached),
+                        }
+                    }
+                    None => self.push_unreachable_statement(statement_path),
+                },
+                None => self.push_unreachable_statement(statement_path),
+            }
+
+            statement_path.pop();
+        }
+    }
+
+    fn push_unreachable_statement(&mut self, statement_path: &[usize]) {
+        self.warnings.push(AnalysisWarning::UnreachableStatement {
+            rule_name: self.rule_name.to_string(),
+            statement_path: statement_path.to_vec(),
+        });
+    }
+}
+
+/// Reports the rules of the program which can never be selected, along with the nested
+/// statements which are unreachable or redundant.
+pub fn analyze_rule_reachability<O>(program: &dir::DirProgram<O>) -> Vec<AnalysisWarning> {
+    let mut warnings = Vec::new();
+    let mut earlier_rules: Vec<(&str, Vec<Path>)> = Vec::with_capacity(program.rules.len());
+
+    for rule in &program.rules {
+        let mut analysis = RuleAnalysis {
+            rule_name: &rule.name,
+            paths: Vec::new(),
+            warnings: Vec::new(),
+        };
+        analysis.collect_paths(&rule.statements, &Path::default(), &mut Vec::new());
+
+        if analysis.paths.is_empty() {
+            warnings.push(AnalysisWarning::UnreachableRule {
+                rule_name: rule.name.clone(),
+            });
+            continue;
+        }
+        warnings.extend(analysis.warnings);
+
+        let candidates = earlier_rules
+            .iter()
+            .enumerate()
+            .flat_map(|(rule_index, (_, paths))| paths.iter().map(move |path| (rule_index, path)))
+            .collect::<Vec<_>>();
+        let mut budget = COVERAGE_CHECK_BUDGET;
+        let mut covering_rules = BTreeSet::new();
+
+        let is_shadowed = analysis
+            .paths
+            .iter()
+            .all(|path| is_covered(path, &candidates, &mut budget, &mut covering_rules));
+
+        if is_shadowed {
+            warnings.push(AnalysisWarning::ShadowedRule {
+                rule_name: rule.name.clone(),
+                shadowed_by: covering_rules
+                    .into_iter()
+                    .filter_map(|rule_index| earlier_rules.get(rule_index))
+                    .map(|(rule_name, _)| rule_name.to_string())
+                    .collect(),
+            });
+        }
+
+        earlier_rules.push((rule.name.as_str(), analysis.paths));
+    }
+
+    warnings
+}
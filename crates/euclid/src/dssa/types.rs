@@ -1,6 +1,7 @@
 use std::fmt;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::{
     dssa::{self, graph},
@@ -154,6 +155,63 @@ pub enum AnalysisErrorType {
     NotSupported,
 }
 
+/// Findings of the analyses which do not make a program invalid, but point at parts of it which
+/// have no effect on the outcome.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum AnalysisWarning {
+    /// The conditions of the rule can never be satisfied
+    UnreachableRule { rule_name: String },
+    /// Every input satisfying the rule is already claimed by the listed earlier rules
+    ShadowedRule {
+        rule_name: String,
+        shadowed_by: Vec<String>,
+    },
+    /// The statement can never be satisfied within the statements it is nested in. The path
+    /// holds the index of the statement at every level of nesting.
+    UnreachableStatement {
+        rule_name: String,
+        statement_path: Vec<usize>,
+    },
+    /// The condition of the nested statement always holds within the statement it is nested in
+    RedundantNestedStatement {
+        rule_name: String,
+        statement_path: Vec<usize>,
+    },
+}
+
+impl fmt::Display for AnalysisWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnreachableRule { rule_name } => {
+                write!(f, "Rule '{rule_name}' can never be satisfied")
+            }
+            Self::ShadowedRule {
+                rule_name,
+                shadowed_by,
+            } => write!(
+                f,
+                "Rule '{rule_name}' is shadowed by the rules {}",
+                shadowed_by.join(", ")
+            ),
+            Self::UnreachableStatement {
+                rule_name,
+                statement_path,
+            } => write!(
+                f,
+                "Statement {statement_path:?} of rule '{rule_name}' can never be satisfied"
+            ),
+            Self::RedundantNestedStatement {
+                rule_name,
+                statement_path,
+            } => write!(
+                f,
+                "Statement {statement_path:?} of rule '{rule_name}' always holds within its parent"
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ValueType {
     EnumVariants(Vec<EuclidValue>),
@@ -297,6 +297,55 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::PaymentAmount => lower_number!(PaymentAmount, value, comparison),
 
+        dir::DirKeyKind::DayOfWeek => lower_enum!(DayOfWeek, value),
+
+        dir::DirKeyKind::HourOfDay => {
+            let hour_values = lower_number!(HourOfDay, value, comparison)?;
+            hour_values
+                .iter()
+                .filter_map(dir::DirValue::get_num_value)
+                .try_for_each(|hour| {
+                    if (0..=23).contains(&hour.number.get_amount_as_i64()) {
+                        Ok(())
+                    } else {
+                        Err(AnalysisErrorType::InvalidValue {
+                            key: dir::DirKeyKind::HourOfDay,
+                            value: hour.number.to_string(),
+                            message: Some("Expected an hour between 0 and 23".to_string()),
+                        })
+                    }
+                })?;
+            Ok(hour_values)
+        }
+
+        dir::DirKeyKind::DateRange => match value {
+            ast::ValueType::StrValue(st) => {
+                let date_range = st.parse::<types::DateRangeValue>().map_err(|message| {
+                    AnalysisErrorType::InvalidValue {
+                        key: dir::DirKeyKind::DateRange,
+                        value: st.clone(),
+                        message: Some(message),
+                    }
+                })?;
+                Ok(vec![dir::DirValue::DateRange(date_range)])
+            }
+            _ => Err(AnalysisErrorType::InvalidType {
+                key: dir::DirKeyKind::DateRange.to_string(),
+                expected: DataType::StrValue,
+                got: value.get_type(),
+            }),
+        },
+
+        dir::DirKeyKind::CardIssuerCountry => lower_enum!(CardIssuerCountry, value),
+
+        dir::DirKeyKind::CardIssuer => lower_str!(CardIssuer, value),
+
+        dir::DirKeyKind::CardProductType => lower_str!(CardProductType, value),
+
+        dir::DirKeyKind::PrepaidCard => lower_enum!(PrepaidCard, value),
+
+        dir::DirKeyKind::CommercialCard => lower_enum!(CommercialCard, value),
+
         dir::DirKeyKind::Connector => Err(AnalysisErrorType::InvalidKey(
             dir::DirKeyKind::Connector.to_string(),
         )),
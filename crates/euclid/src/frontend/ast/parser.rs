@@ -1,9 +1,19 @@
+use std::str::FromStr;
+
 use common_utils::types::MinorUnit;
 use nom::{
-    branch, bytes::complete, character::complete as pchar, combinator, error, multi, sequence,
+    branch,
+    bytes::complete,
+    character::complete as pchar,
+    combinator,
+    error::{self, ParseError},
+    multi, sequence,
 };
 
-use crate::{frontend::ast, types::DummyOutput};
+use crate::{
+    frontend::ast,
+    types::{DummyOutput, Metadata},
+};
 pub type ParseResult<T, U> = nom::IResult<T, U, error::VerboseError<T>>;
 
 pub enum EuclidError {
@@ -17,6 +27,67 @@ pub trait EuclidParsable: Sized {
     fn parse_output(input: &str) -> ParseResult<&str, Self>;
 }
 
+impl EuclidParsable for ast::ConnectorSelection {
+    fn parse_output(input: &str) -> ParseResult<&str, Self> {
+        let volume_split = combinator::map(
+            multi::separated_list1(skip_ws(complete::tag(",")), skip_ws(connector_volume_split)),
+            Self::VolumeSplit,
+        );
+        let priority = combinator::map(
+            multi::separated_list0(skip_ws(complete::tag(",")), skip_ws(connector_choice)),
+            Self::Priority,
+        );
+
+        error::context(
+            "connector_selection",
+            sequence::delimited(
+                skip_ws(complete::tag("[")),
+                branch::alt((volume_split, priority)),
+                skip_ws(complete::tag("]")),
+            ),
+        )(input)
+    }
+}
+
+pub fn connector_choice(input: &str) -> ParseResult<&str, ast::RoutableConnectorChoice> {
+    let connector = combinator::map_res(identifier, |name: String| {
+        common_enums::RoutableConnectors::from_str(&name)
+            .map_err(|_| EuclidError::InvalidConnector(name))
+    });
+    let merchant_connector_id = sequence::delimited(
+        skip_ws(complete::tag("(")),
+        skip_ws(string_str),
+        skip_ws(complete::tag(")")),
+    );
+
+    error::context(
+        "connector_choice",
+        combinator::map(
+            sequence::pair(connector, combinator::opt(merchant_connector_id)),
+            |tup: (common_enums::RoutableConnectors, Option<String>)| {
+                ast::RoutableConnectorChoice {
+                    choice_kind: ast::RoutableChoiceKind::FullStruct,
+                    connector: tup.0,
+                    merchant_connector_id: tup.1,
+                }
+            },
+        ),
+    )(input)
+}
+
+pub fn connector_volume_split(input: &str) -> ParseResult<&str, ast::ConnectorVolumeSplit> {
+    error::context(
+        "connector_volume_split",
+        combinator::map(
+            sequence::pair(percentage, skip_ws(connector_choice)),
+            |tup: (u8, ast::RoutableConnectorChoice)| ast::ConnectorVolumeSplit {
+                connector: tup.1,
+                split: tup.0,
+            },
+        ),
+    )(input)
+}
+
 impl EuclidParsable for DummyOutput {
     fn parse_output(input: &str) -> ParseResult<&str, Self> {
         let string_w = sequence::delimited(
@@ -97,12 +168,77 @@ pub fn identifier(input: &str) -> ParseResult<&str, String> {
         ),
     )(input)
 }
+/// Parses the metadata of a program or a comparison, written as a JSON object following an `@`,
+/// as in `@{"label": "Card payments"}`
+pub fn metadata(input: &str) -> ParseResult<&str, Metadata> {
+    error::context(
+        "metadata",
+        sequence::preceded(complete::tag("@"), json_object),
+    )(input)
+}
+
+fn json_object(input: &str) -> ParseResult<&str, Metadata> {
+    let mut objects = serde_json::Deserializer::from_str(input).into_iter::<Metadata>();
+    match objects.next() {
+        Some(Ok(object)) => input
+            .get(objects.byte_offset()..)
+            .map(|rest| (rest, object))
+            .ok_or_else(|| {
+                nom::Err::Error(error::VerboseError::from_error_kind(
+                    input,
+                    error::ErrorKind::Eof,
+                ))
+            }),
+        _ => Err(nom::Err::Error(error::VerboseError::from_error_kind(
+            input,
+            error::ErrorKind::Verify,
+        ))),
+    }
+}
+
+/// Parses a value of an enum which is serialized with the `type` and `data` tags, written as the
+/// name of its variant followed by its data in parentheses if it has any, as in `customer_id` or
+/// `metadata("tier")`
+pub fn tagged_value<T: serde::de::DeserializeOwned>(input: &str) -> ParseResult<&str, T> {
+    let data = sequence::delimited(
+        skip_ws(complete::tag("(")),
+        skip_ws(string_str),
+        skip_ws(complete::tag(")")),
+    );
+
+    error::context(
+        "tagged_value",
+        combinator::map_opt(
+            sequence::pair(identifier, combinator::opt(data)),
+            |tup: (String, Option<String>)| {
+                let value = match tup.1 {
+                    Some(data) => serde_json::json!({ "type": tup.0, "data": data }),
+                    None => serde_json::json!({ "type": tup.0 }),
+                };
+                serde_json::from_value(value).ok()
+            },
+        ),
+    )(input)
+}
+
+/// Parses the attribute a volume split is made sticky on, which follows the split as in
+/// `[50% stripe, 50% adyen] by customer_id`
+pub fn stickiness_key<T: serde::de::DeserializeOwned>(input: &str) -> ParseResult<&str, Option<T>> {
+    error::context(
+        "stickiness_key",
+        combinator::opt(sequence::preceded(
+            sequence::pair(skip_ws(complete::tag("by")), pchar::multispace1),
+            combinator::cut(tagged_value),
+        )),
+    )(input)
+}
+
 pub fn percentage(input: &str) -> ParseResult<&str, u8> {
     error::context(
         "volume_split_percentage",
         combinator::map_res(
             sequence::terminated(
-                complete::take_while_m_n(1, 2, |c: char| c.is_ascii_digit()),
+                complete::take_while_m_n(1, 3, |c: char| c.is_ascii_digit()),
                 complete::tag("%"),
             ),
             |o: &str| {
@@ -299,12 +435,13 @@ pub fn comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
                 })),
                 skip_ws(comparison_type),
                 skip_ws(value_type),
+                combinator::opt(skip_ws(metadata)),
             )),
-            |tup: (&str, ast::ComparisonType, ast::ValueType)| ast::Comparison {
+            |tup: (&str, ast::ComparisonType, ast::ValueType, Option<Metadata>)| ast::Comparison {
                 lhs: tup.0.to_string(),
                 comparison: tup.1,
                 value: tup.2,
-                metadata: std::collections::HashMap::new(),
+                metadata: tup.3.unwrap_or_default(),
             },
         ),
     )(input)
@@ -318,15 +455,16 @@ pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
                 skip_ws(string_str),
                 skip_ws(comparison_type),
                 skip_ws(string_str),
+                combinator::opt(skip_ws(metadata)),
             )),
-            |tup: (String, ast::ComparisonType, String)| ast::Comparison {
+            |tup: (String, ast::ComparisonType, String, Option<Metadata>)| ast::Comparison {
                 lhs: "metadata".to_string(),
                 comparison: tup.1,
                 value: ast::ValueType::MetadataVariant(ast::MetadataValue {
                     key: tup.0,
                     value: tup.2,
                 }),
-                metadata: std::collections::HashMap::new(),
+                metadata: tup.3.unwrap_or_default(),
             },
         ),
     )(input)
@@ -335,7 +473,10 @@ pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
 pub fn comparison_array(input: &str) -> ParseResult<&str, Vec<ast::Comparison>> {
     let many_with_ampersand = error::context(
         "many_with_amp",
-        multi::many0(sequence::preceded(skip_ws(complete::tag("&")), comparison)),
+        multi::many0(sequence::preceded(
+            skip_ws(complete::tag("&")),
+            skip_ws(branch::alt((comparison, arbitrary_comparison))),
+        )),
     );
 
     let full_sequence = sequence::pair(
@@ -387,16 +528,7 @@ pub fn rule_conditions_array(input: &str) -> ParseResult<&str, Vec<ast::IfStatem
 }
 
 pub fn rule<O: EuclidParsable>(input: &str) -> ParseResult<&str, ast::Rule<O>> {
-    let rule_name = error::context(
-        "rule_name",
-        combinator::map(
-            skip_ws(sequence::pair(
-                complete::take_while1(|c: char| c.is_ascii_alphabetic() || c == '_'),
-                complete::take_while(|c: char| c.is_ascii_alphanumeric() || c == '_'),
-            )),
-            |out: (&str, &str)| out.0.to_string() + out.1,
-        ),
-    );
+    let rule_name = error::context("rule_name", skip_ws(branch::alt((identifier, string_str))));
 
     let connector_selection = error::context(
         "parse_output",
@@ -434,12 +566,27 @@ pub fn program<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&str, as
     error::context(
         "program",
         combinator::map(
-            sequence::pair(default_output, multi::many1(skip_ws(rule::<O>))),
-            |tup: (O, Vec<ast::Rule<O>>)| ast::Program {
-                default_selection: tup.0,
-                rules: tup.1,
-                metadata: std::collections::HashMap::new(),
+            sequence::tuple((
+                combinator::opt(skip_ws(metadata)),
+                default_output,
+                multi::many0(skip_ws(rule::<O>)),
+            )),
+            |tup: (Option<Metadata>, O, Vec<ast::Rule<O>>)| ast::Program {
+                default_selection: tup.1,
+                rules: tup.2,
+                metadata: tup.0.unwrap_or_default(),
             },
         ),
     )(input)
 }
+
+/// Parses the complete text of a program, failing with a readable description of the
+/// position and the expected syntax if any of the input is left unparsed.
+pub fn parse_program<O: EuclidParsable + 'static>(input: &str) -> Result<ast::Program<O>, String> {
+    combinator::all_consuming(sequence::terminated(program::<O>, pchar::multispace0))(input)
+        .map(|(_, program)| program)
+        .map_err(|err| match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => error::convert_error(input, err),
+            nom::Err::Incomplete(_) => "Incomplete program".to_string(),
+        })
+}
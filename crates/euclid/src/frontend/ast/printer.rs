@@ -0,0 +1,446 @@
+//! Prints a program in the text syntax understood by the parser.
+//!
+//! The output is canonical: parsing the printed text gives back the same program, and printing
+//! a parsed program gives back the same text if it was already in the canonical layout.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    frontend::ast,
+    types::{DummyOutput, Metadata},
+};
+
+const INDENT: &str = "    ";
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum PrintError {
+    #[error("The rule '{0}' has no statements")]
+    EmptyRule(String),
+    #[error("The name '{0}' cannot be written in the text syntax")]
+    InvalidName(String),
+    #[error("The key '{0}' cannot be written in the text syntax")]
+    InvalidKey(String),
+    #[error("The value '{0}' cannot be written in the text syntax")]
+    InvalidValue(String),
+    #[error("The comparison '{0:?}' cannot be used within a comparison array")]
+    InvalidArrayComparison(ast::ComparisonType),
+}
+
+pub trait EuclidPrintable {
+    fn print_output(&self) -> Result<String, PrintError>;
+}
+
+impl EuclidPrintable for DummyOutput {
+    fn print_output(&self) -> Result<String, PrintError> {
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| print_string(output))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(format!("[{}]", outputs.join(", ")))
+    }
+}
+
+impl EuclidPrintable for ast::ConnectorSelection {
+    fn print_output(&self) -> Result<String, PrintError> {
+        let choices = match self {
+            Self::Priority(choices) => choices
+                .iter()
+                .map(print_connector_choice)
+                .collect::<Result<Vec<_>, _>>()?,
+            Self::VolumeSplit(splits) => splits
+                .iter()
+                .map(|split| {
+                    Ok(format!(
+                        "{}% {}",
+                        split.split,
+                        print_connector_choice(&split.connector)?
+                    ))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+
+        Ok(format!("[{}]", choices.join(", ")))
+    }
+}
+
+fn print_connector_choice(choice: &ast::RoutableConnectorChoice) -> Result<String, PrintError> {
+    Ok(match &choice.merchant_connector_id {
+        Some(merchant_connector_id) => format!(
+            "{}({})",
+            choice.connector,
+            print_string(merchant_connector_id)?
+        ),
+        None => choice.connector.to_string(),
+    })
+}
+
+/// Prints a value of an enum which is serialized with the `type` and `data` tags, as the name of
+/// its variant followed by its data in parentheses if it has any
+pub fn print_tagged_value<T: serde::Serialize>(value: &T) -> Result<String, PrintError> {
+    let value =
+        serde_json::to_value(value).map_err(|error| PrintError::InvalidValue(error.to_string()))?;
+    let variant = value
+        .get("type")
+        .and_then(serde_json::Value::as_str)
+        .ok_or_else(|| PrintError::InvalidValue(value.to_string()))?;
+    let variant = print_enum_variant(variant)?;
+
+    match value.get("data") {
+        None => Ok(variant),
+        Some(serde_json::Value::String(data)) => Ok(format!("{variant}({})", print_string(data)?)),
+        Some(data) => Err(PrintError::InvalidValue(data.to_string())),
+    }
+}
+
+/// Prints the metadata as a JSON object following an `@`, with its keys sorted so that the
+/// output does not depend on the order of the map
+fn print_metadata(metadata: &Metadata) -> Result<String, PrintError> {
+    let sorted_metadata = metadata.iter().collect::<BTreeMap<_, _>>();
+    serde_json::to_string(&sorted_metadata)
+        .map(|object| format!("@{object}"))
+        .map_err(|error| PrintError::InvalidValue(error.to_string()))
+}
+
+fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn print_string(value: &str) -> Result<String, PrintError> {
+    if value.is_empty() || value.contains('"') {
+        return Err(PrintError::InvalidValue(value.to_string()));
+    }
+
+    Ok(format!("\"{value}\""))
+}
+
+fn print_enum_variant(value: &str) -> Result<String, PrintError> {
+    if !is_identifier(value) {
+        return Err(PrintError::InvalidValue(value.to_string()));
+    }
+
+    Ok(value.to_string())
+}
+
+fn print_number(number: common_utils::types::MinorUnit) -> Result<String, PrintError> {
+    let number = number.get_amount_as_i64();
+    if number < 0 {
+        return Err(PrintError::InvalidValue(number.to_string()));
+    }
+
+    Ok(number.to_string())
+}
+
+fn print_comparison_type(comparison: &ast::ComparisonType) -> &'static str {
+    match comparison {
+        ast::ComparisonType::Equal => "=",
+        ast::ComparisonType::NotEqual => "/=",
+        ast::ComparisonType::LessThan => "<",
+        ast::ComparisonType::LessThanEqual => "<=",
+        ast::ComparisonType::GreaterThan => ">",
+        ast::ComparisonType::GreaterThanEqual => ">=",
+    }
+}
+
+fn print_value(value: &ast::ValueType) -> Result<String, PrintError> {
+    Ok(match value {
+        ast::ValueType::Number(number) => print_number(*number)?,
+        ast::ValueType::EnumVariant(variant) => print_enum_variant(variant)?,
+        ast::ValueType::StrValue(value) => print_string(value)?,
+        // Metadata values are written along with their key, as `"key" = "value"`
+        ast::ValueType::MetadataVariant(metadata) => {
+            return Err(PrintError::InvalidValue(metadata.value.clone()))
+        }
+        ast::ValueType::NumberArray(numbers) => format!(
+            "({})",
+            numbers
+                .iter()
+                .map(|number| print_number(*number))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ")
+        ),
+        ast::ValueType::EnumVariantArray(variants) => format!(
+            "({})",
+            variants
+                .iter()
+                .map(|variant| print_enum_variant(variant))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ")
+        ),
+        ast::ValueType::NumberComparisonArray(comparisons) => format!(
+            "({})",
+            comparisons
+                .iter()
+                .map(|comparison| match comparison.comparison_type {
+                    ast::ComparisonType::Equal | ast::ComparisonType::NotEqual => Err(
+                        PrintError::InvalidArrayComparison(comparison.comparison_type.clone()),
+                    ),
+                    _ => Ok(format!(
+                        "{}{}",
+                        print_comparison_type(&comparison.comparison_type),
+                        print_number(comparison.number)?
+                    )),
+                })
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ")
+        ),
+    })
+}
+
+fn print_comparison(comparison: &ast::Comparison) -> Result<String, PrintError> {
+    let text = print_condition(comparison)?;
+
+    if comparison.metadata.is_empty() {
+        Ok(text)
+    } else {
+        Ok(format!("{text} {}", print_metadata(&comparison.metadata)?))
+    }
+}
+
+fn print_condition(comparison: &ast::Comparison) -> Result<String, PrintError> {
+    let operator = print_comparison_type(&comparison.comparison);
+
+    if let ast::ValueType::MetadataVariant(metadata) = &comparison.value {
+        if comparison.lhs != "metadata" {
+            return Err(PrintError::InvalidKey(comparison.lhs.clone()));
+        }
+
+        return Ok(format!(
+            "{} {operator} {}",
+            print_string(&metadata.key)?,
+            print_string(&metadata.value)?
+        ));
+    }
+
+    let is_valid_key = !comparison.lhs.is_empty()
+        && comparison
+            .lhs
+            .chars()
+            .all(|c| c.is_ascii_alphabetic() || c == '.' || c == '_');
+    if !is_valid_key {
+        return Err(PrintError::InvalidKey(comparison.lhs.clone()));
+    }
+
+    Ok(format!(
+        "{} {operator} {}",
+        comparison.lhs,
+        print_value(&comparison.value)?
+    ))
+}
+
+fn print_if_statement(
+    statement: &ast::IfStatement,
+    depth: usize,
+    text: &mut String,
+) -> Result<(), PrintError> {
+    let condition = statement
+        .condition
+        .iter()
+        .map(print_comparison)
+        .collect::<Result<Vec<_>, _>>()?
+        .join(" & ");
+
+    text.push_str(&INDENT.repeat(depth));
+    text.push_str(&condition);
+
+    match &statement.nested {
+        Some(nested) if nested.is_empty() => text.push_str(" {}\n"),
+        Some(nested) => {
+            text.push_str(" {\n");
+            for nested_statement in nested {
+                print_if_statement(nested_statement, depth + 1, text)?;
+            }
+            text.push_str(&INDENT.repeat(depth));
+            text.push_str("}\n");
+        }
+        None => text.push('\n'),
+    }
+
+    Ok(())
+}
+
+fn print_rule<O: EuclidPrintable>(
+    rule: &ast::Rule<O>,
+    text: &mut String,
+) -> Result<(), PrintError> {
+    if rule.statements.is_empty() {
+        return Err(PrintError::EmptyRule(rule.name.clone()));
+    }
+
+    let name = if is_identifier(&rule.name) {
+        rule.name.clone()
+    } else {
+        print_string(&rule.name).map_err(|_| PrintError::InvalidName(rule.name.clone()))?
+    };
+
+    text.push_str(&format!(
+        "{name}: {} {{\n",
+        rule.connector_selection.print_output()?
+    ));
+    for statement in &rule.statements {
+        print_if_statement(statement, 1, text)?;
+    }
+    text.push_str("}\n");
+
+    Ok(())
+}
+
+/// Prints the program in the canonical layout of the text syntax.
+///
+/// ```text
+/// @{"version":2}
+/// default: [stripe, adyen("mca_adyen")]
+///
+/// card_rule: [70% stripe, 30% adyen] {
+///     payment_method = card & amount > 500 @{"label":"Large card payments"} {
+///         card_network = (visa, mastercard)
+///     }
+/// }
+/// ```
+pub fn print_program<O: EuclidPrintable>(program: &ast::Program<O>) -> Result<String, PrintError> {
+    let mut text = String::new();
+    if !program.metadata.is_empty() {
+        text.push_str(&print_metadata(&program.metadata)?);
+        text.push('\n');
+    }
+    text.push_str(&format!(
+        "default: {}\n",
+        program.default_selection.print_output()?
+    ));
+
+    for rule in &program.rules {
+        text.push('\n');
+        print_rule(rule, &mut text)?;
+    }
+
+    Ok(text)
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+    use crate::frontend::ast::parser;
+
+    #[test]
+    fn test_canonical_text_round_trip() {
+        let program_text = r#"default: [stripe, adyen("mca_adyen")]
+
+card_rule: [70% stripe, 30% adyen] {
+    payment_method = card & amount > 500 {
+        card_network = (Visa, Mastercard)
+        amount = (>500, <=1000) & currency /= USD
+    }
+    payment_method = wallet & "merchant_tier" = "gold"
+}
+
+"high value": [checkout] {
+    amount >= 100000 & business_label = "online"
+}
+"#;
+
+        let program =
+            parser::parse_program::<ast::ConnectorSelection>(program_text).expect("Program");
+
+        assert_eq!(program.rules.len(), 2);
+        assert_eq!(
+            print_program(&program).expect("Printed program"),
+            program_text
+        );
+    }
+
+    #[test]
+    fn test_program_round_trip() {
+        let program_text = r#"
+            default: [stripe]
+
+            rule_1: [ 50% adyen("mca_1"),50% stripe ]
+            {
+                payment_method = (card, wallet) { amount = (1, 2, 3) {} }
+            }
+        "#;
+
+        let program =
+            parser::parse_program::<ast::ConnectorSelection>(program_text).expect("Program");
+        let printed = print_program(&program).expect("Printed program");
+        let reparsed =
+            parser::parse_program::<ast::ConnectorSelection>(&printed).expect("Reparsed program");
+
+        assert_eq!(
+            serde_json::to_value(&program).expect("Program json"),
+            serde_json::to_value(&reparsed).expect("Reparsed program json")
+        );
+        assert_eq!(
+            print_program(&reparsed).expect("Reprinted program"),
+            printed
+        );
+    }
+
+    #[test]
+    fn test_metadata_round_trip() {
+        let program_text = r#"@{"created_by":"dashboard","version":2}
+default: [stripe]
+
+rule_1: [adyen] {
+    payment_method = card @{"label":"Cards"} & "tier" = "gold" @{"label":"Gold tier"}
+}
+"#;
+
+        let program =
+            parser::parse_program::<ast::ConnectorSelection>(program_text).expect("Program");
+
+        assert_eq!(program.metadata.get("version"), Some(&serde_json::json!(2)));
+        let condition = &program
+            .rules
+            .first()
+            .and_then(|rule| rule.statements.first())
+            .expect("Statement")
+            .condition;
+        assert_eq!(
+            condition
+                .iter()
+                .map(|comparison| comparison.metadata.get("label").cloned())
+                .collect::<Vec<_>>(),
+            vec![
+                Some(serde_json::json!("Cards")),
+                Some(serde_json::json!("Gold tier"))
+            ]
+        );
+        assert_eq!(
+            print_program(&program).expect("Printed program"),
+            program_text
+        );
+    }
+
+    #[test]
+    fn test_unprintable_program() {
+        let program = ast::Program {
+            default_selection: ast::ConnectorSelection::Priority(vec![]),
+            rules: vec![ast::Rule {
+                name: "rule_1".to_string(),
+                connector_selection: ast::ConnectorSelection::Priority(vec![]),
+                statements: vec![ast::IfStatement {
+                    condition: vec![ast::Comparison {
+                        lhs: "payment_method".to_string(),
+                        comparison: ast::ComparisonType::Equal,
+                        value: ast::ValueType::EnumVariant("bank debit".to_string()),
+                        metadata: Default::default(),
+                    }],
+                    nested: None,
+                }],
+            }],
+            metadata: Default::default(),
+        };
+
+        assert!(matches!(
+            print_program(&program),
+            Err(PrintError::InvalidValue(_))
+        ));
+    }
+}
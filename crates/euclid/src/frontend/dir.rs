@@ -278,6 +278,62 @@ pub enum DirKeyKind {
         props(Category = "Payment Method Types")
     )]
     OpenBankingType,
+    #[strum(
+        serialize = "hour_of_day",
+        detailed_message = "Hour of the day, in UTC, at which the payment is made (0 - 23)",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "hour_of_day")]
+    HourOfDay,
+    #[strum(
+        serialize = "day_of_week",
+        detailed_message = "Day of the week, in UTC, on which the payment is made",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "day_of_week")]
+    DayOfWeek,
+    #[strum(
+        serialize = "date_range",
+        detailed_message = "Range of time within which the payment is made, specified as two RFC 3339 date-times separated by a '/'",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "date_range")]
+    DateRange,
+    #[strum(
+        serialize = "card_issuer_country",
+        detailed_message = "Country of the bank which issued the payment card, derived from its BIN",
+        props(Category = "Card BIN")
+    )]
+    #[serde(rename = "card_issuer_country")]
+    CardIssuerCountry,
+    #[strum(
+        serialize = "card_issuer",
+        detailed_message = "Name of the bank which issued the payment card, derived from its BIN",
+        props(Category = "Card BIN")
+    )]
+    #[serde(rename = "card_issuer")]
+    CardIssuer,
+    #[strum(
+        serialize = "card_product_type",
+        detailed_message = "Product type of the payment card - eg. classic, platinum, business, derived from its BIN",
+        props(Category = "Card BIN")
+    )]
+    #[serde(rename = "card_product_type")]
+    CardProductType,
+    #[strum(
+        serialize = "prepaid_card",
+        detailed_message = "Whether the payment card is a prepaid card, derived from its BIN",
+        props(Category = "Card BIN")
+    )]
+    #[serde(rename = "prepaid_card")]
+    PrepaidCard,
+    #[strum(
+        serialize = "commercial_card",
+        detailed_message = "Whether the payment card is a commercial or corporate card, derived from its BIN",
+        props(Category = "Card BIN")
+    )]
+    #[serde(rename = "commercial_card")]
+    CommercialCard,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -327,6 +383,14 @@ impl DirKeyKind {
             Self::CardRedirectType => types::DataType::EnumVariant,
             Self::RealTimePaymentType => types::DataType::EnumVariant,
             Self::OpenBankingType => types::DataType::EnumVariant,
+            Self::HourOfDay => types::DataType::Number,
+            Self::DayOfWeek => types::DataType::EnumVariant,
+            Self::DateRange => types::DataType::StrValue,
+            Self::CardIssuerCountry => types::DataType::EnumVariant,
+            Self::CardIssuer => types::DataType::StrValue,
+            Self::CardProductType => types::DataType::StrValue,
+            Self::PrepaidCard => types::DataType::EnumVariant,
+            Self::CommercialCard => types::DataType::EnumVariant,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -459,6 +523,26 @@ impl DirKeyKind {
                     .map(DirValue::OpenBankingType)
                     .collect(),
             ),
+            Self::HourOfDay => None,
+            Self::DayOfWeek => Some(enums::DayOfWeek::iter().map(DirValue::DayOfWeek).collect()),
+            Self::DateRange => None,
+            Self::CardIssuerCountry => Some(
+                enums::Country::iter()
+                    .map(DirValue::CardIssuerCountry)
+                    .collect(),
+            ),
+            Self::CardIssuer => None,
+            Self::CardProductType => None,
+            Self::PrepaidCard => Some(
+                enums::BooleanFlag::iter()
+                    .map(DirValue::PrepaidCard)
+                    .collect(),
+            ),
+            Self::CommercialCard => Some(
+                enums::BooleanFlag::iter()
+                    .map(DirValue::CommercialCard)
+                    .collect(),
+            ),
         }
     }
 }
@@ -528,6 +612,22 @@ pub enum DirValue {
     RealTimePaymentType(enums::RealTimePaymentType),
     #[serde(rename = "open_banking")]
     OpenBankingType(enums::OpenBankingType),
+    #[serde(rename = "hour_of_day")]
+    HourOfDay(types::NumValue),
+    #[serde(rename = "day_of_week")]
+    DayOfWeek(enums::DayOfWeek),
+    #[serde(rename = "date_range")]
+    DateRange(types::DateRangeValue),
+    #[serde(rename = "card_issuer_country")]
+    CardIssuerCountry(enums::Country),
+    #[serde(rename = "card_issuer")]
+    CardIssuer(types::StrValue),
+    #[serde(rename = "card_product_type")]
+    CardProductType(types::StrValue),
+    #[serde(rename = "prepaid_card")]
+    PrepaidCard(enums::BooleanFlag),
+    #[serde(rename = "commercial_card")]
+    CommercialCard(enums::BooleanFlag),
 }
 
 impl DirValue {
@@ -563,6 +663,14 @@ impl DirValue {
             Self::GiftCardType(_) => (DirKeyKind::GiftCardType, None),
             Self::RealTimePaymentType(_) => (DirKeyKind::RealTimePaymentType, None),
             Self::OpenBankingType(_) => (DirKeyKind::OpenBankingType, None),
+            Self::HourOfDay(_) => (DirKeyKind::HourOfDay, None),
+            Self::DayOfWeek(_) => (DirKeyKind::DayOfWeek, None),
+            Self::DateRange(_) => (DirKeyKind::DateRange, None),
+            Self::CardIssuerCountry(_) => (DirKeyKind::CardIssuerCountry, None),
+            Self::CardIssuer(_) => (DirKeyKind::CardIssuer, None),
+            Self::CardProductType(_) => (DirKeyKind::CardProductType, None),
+            Self::PrepaidCard(_) => (DirKeyKind::PrepaidCard, None),
+            Self::CommercialCard(_) => (DirKeyKind::CommercialCard, None),
         };
 
         DirKey::new(kind, data)
@@ -599,19 +707,29 @@ impl DirValue {
             Self::CardRedirectType(_) => None,
             Self::RealTimePaymentType(_) => None,
             Self::OpenBankingType(_) => None,
+            Self::HourOfDay(_) => None,
+            Self::DayOfWeek(_) => None,
+            Self::DateRange(_) => None,
+            Self::CardIssuerCountry(_) => None,
+            Self::CardIssuer(_) => None,
+            Self::CardProductType(_) => None,
+            Self::PrepaidCard(_) => None,
+            Self::CommercialCard(_) => None,
         }
     }
 
     pub fn get_str_val(&self) -> Option<types::StrValue> {
         match self {
-            Self::CardBin(val) => Some(val.clone()),
+            Self::CardBin(val) | Self::CardIssuer(val) | Self::CardProductType(val) => {
+                Some(val.clone())
+            }
             _ => None,
         }
     }
 
     pub fn get_num_value(&self) -> Option<types::NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val) | Self::HourOfDay(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -645,6 +763,13 @@ impl DirValue {
             (Self::UpiType(ut1), Self::UpiType(ut2)) => ut1 == ut2,
             (Self::VoucherType(vt1), Self::VoucherType(vt2)) => vt1 == vt2,
             (Self::CardRedirectType(crt1), Self::CardRedirectType(crt2)) => crt1 == crt2,
+            (Self::DayOfWeek(dow1), Self::DayOfWeek(dow2)) => dow1 == dow2,
+            (Self::DateRange(dr1), Self::DateRange(dr2)) => dr1 == dr2,
+            (Self::CardIssuerCountry(c1), Self::CardIssuerCountry(c2)) => c1 == c2,
+            (Self::CardIssuer(ci1), Self::CardIssuer(ci2)) => ci1 == ci2,
+            (Self::CardProductType(cpt1), Self::CardProductType(cpt2)) => cpt1 == cpt2,
+            (Self::PrepaidCard(pc1), Self::PrepaidCard(pc2)) => pc1 == pc2,
+            (Self::CommercialCard(cc1), Self::CommercialCard(cc2)) => cc1 == cc2,
             _ => false,
         }
     }
@@ -829,6 +954,11 @@ mod test {
             dirval!(CaptureMethod = Manual),
             dirval!(BillingCountry = UnitedStatesOfAmerica),
             dirval!(BusinessCountry = France),
+            dirval!(HourOfDay = 22),
+            dirval!(DayOfWeek = Saturday),
+            dirval!(CardIssuerCountry = India),
+            dirval!(CardIssuer s= "HDFC BANK"),
+            dirval!(PrepaidCard = True),
         ];
 
         for val in values {
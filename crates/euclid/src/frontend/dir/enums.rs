@@ -2,9 +2,11 @@ use strum::VariantNames;
 
 use crate::enums::collect_variants;
 pub use crate::enums::{
-    AuthenticationType, CaptureMethod, CardNetwork, Country, Country as BusinessCountry,
-    Country as BillingCountry, CountryAlpha2, Currency as PaymentCurrency, MandateAcceptanceType,
-    MandateType, PaymentMethod, PaymentType, RoutableConnectors, SetupFutureUsage,
+    AuthenticationType, BooleanFlag, BooleanFlag as PrepaidCard, BooleanFlag as CommercialCard,
+    CaptureMethod, CardNetwork, Country, Country as BusinessCountry, Country as BillingCountry,
+    Country as CardIssuerCountry, CountryAlpha2, Currency as PaymentCurrency, DayOfWeek,
+    MandateAcceptanceType, MandateType, PaymentMethod, PaymentType, RoutableConnectors,
+    SetupFutureUsage,
 };
 #[cfg(feature = "payouts")]
 pub use crate::enums::{PayoutBankTransferType, PayoutType, PayoutWalletType};
@@ -247,6 +247,14 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::BusinessLabel(bl) => EuclidValue::BusinessLabel(bl),
         dir::DirValue::SetupFutureUsage(sfu) => EuclidValue::SetupFutureUsage(sfu),
         dir::DirValue::OpenBankingType(ob) => EuclidValue::PaymentMethodType(ob.into()),
+        dir::DirValue::HourOfDay(hod) => EuclidValue::HourOfDay(hod),
+        dir::DirValue::DayOfWeek(dow) => EuclidValue::DayOfWeek(dow),
+        dir::DirValue::DateRange(dr) => EuclidValue::DateRange(dr),
+        dir::DirValue::CardIssuerCountry(cic) => EuclidValue::CardIssuerCountry(cic),
+        dir::DirValue::CardIssuer(ci) => EuclidValue::CardIssuer(ci),
+        dir::DirValue::CardProductType(cpt) => EuclidValue::CardProductType(cpt),
+        dir::DirValue::PrepaidCard(pc) => EuclidValue::PrepaidCard(pc),
+        dir::DirValue::CommercialCard(cc) => EuclidValue::CommercialCard(cc),
     })
 }
 
@@ -1,7 +1,7 @@
 //! Valued Intermediate Representation
 use serde::{Deserialize, Serialize};
 
-use crate::types::{EuclidValue, Metadata};
+use crate::types::{EuclidKey, EuclidValue, Metadata};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ValuedComparisonLogic {
@@ -24,6 +24,21 @@ pub struct ValuedIfStatement {
     pub nested: Option<Vec<ValuedIfStatement>>,
 }
 
+impl ValuedIfStatement {
+    fn has_key(&self, predicate: &impl Fn(&EuclidKey) -> bool) -> bool {
+        self.condition.iter().any(|comparison| {
+            comparison
+                .values
+                .iter()
+                .any(|value| predicate(&value.get_key()))
+        }) || self
+            .nested
+            .iter()
+            .flatten()
+            .any(|statement| statement.has_key(predicate))
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ValuedRule<O> {
     pub name: String,
@@ -37,3 +52,14 @@ pub struct ValuedProgram<O> {
     pub rules: Vec<ValuedRule<O>>,
     pub metadata: Metadata,
 }
+
+impl<O> ValuedProgram<O> {
+    /// Returns whether any comparison of the program, including those of nested statements, is
+    /// on a key for which the predicate holds
+    pub fn has_key(&self, predicate: impl Fn(&EuclidKey) -> bool) -> bool {
+        self.rules
+            .iter()
+            .flat_map(|rule| &rule.statements)
+            .any(|statement| statement.has_key(&predicate))
+    }
+}
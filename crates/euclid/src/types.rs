@@ -60,6 +60,22 @@ pub enum EuclidKey {
     BusinessLabel,
     #[strum(serialize = "setup_future_usage")]
     SetupFutureUsage,
+    #[strum(serialize = "hour_of_day")]
+    HourOfDay,
+    #[strum(serialize = "day_of_week")]
+    DayOfWeek,
+    #[strum(serialize = "date_range")]
+    DateRange,
+    #[strum(serialize = "card_issuer_country")]
+    CardIssuerCountry,
+    #[strum(serialize = "card_issuer")]
+    CardIssuer,
+    #[strum(serialize = "card_product_type")]
+    CardProductType,
+    #[strum(serialize = "prepaid_card")]
+    PrepaidCard,
+    #[strum(serialize = "commercial_card")]
+    CommercialCard,
 }
 impl EuclidDirFilter for DummyOutput {
     const ALLOWED: &'static [DirKeyKind] = &[
@@ -77,6 +93,14 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::MandateType,
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
+        DirKeyKind::HourOfDay,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::DateRange,
+        DirKeyKind::CardIssuerCountry,
+        DirKeyKind::CardIssuer,
+        DirKeyKind::CardProductType,
+        DirKeyKind::PrepaidCard,
+        DirKeyKind::CommercialCard,
     ];
 }
 impl EuclidAnalysable for DummyOutput {
@@ -138,8 +162,29 @@ impl EuclidKey {
             Self::PaymentType => DataType::EnumVariant,
             Self::BusinessLabel => DataType::StrValue,
             Self::SetupFutureUsage => DataType::EnumVariant,
+            Self::HourOfDay => DataType::Number,
+            Self::DayOfWeek => DataType::EnumVariant,
+            Self::DateRange => DataType::StrValue,
+            Self::CardIssuerCountry => DataType::EnumVariant,
+            Self::CardIssuer => DataType::StrValue,
+            Self::CardProductType => DataType::StrValue,
+            Self::PrepaidCard => DataType::EnumVariant,
+            Self::CommercialCard => DataType::EnumVariant,
         }
     }
+
+    /// Whether the value of the key is looked up from the BIN of the card, rather than being
+    /// part of the payment
+    pub fn is_card_bin_derived(&self) -> bool {
+        matches!(
+            self,
+            Self::CardIssuerCountry
+                | Self::CardIssuer
+                | Self::CardProductType
+                | Self::PrepaidCard
+                | Self::CommercialCard
+        )
+    }
 }
 
 enums::collect_variants!(EuclidKey);
@@ -235,6 +280,60 @@ impl NumValue {
     }
 }
 
+/// A half open interval of time, `[start, end)`, represented as UNIX timestamps in seconds.
+///
+/// In the DSL a date range is written as two RFC 3339 date-times separated by a `/`, for
+/// instance `"2024-12-24T00:00:00+05:30/2024-12-26T00:00:00+05:30"`. The offsets of the bounds
+/// decide the timezone in which the range is specified.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct DateRangeValue {
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+}
+
+impl DateRangeValue {
+    pub fn contains(&self, timestamp: i64) -> bool {
+        self.start_timestamp <= timestamp && timestamp < self.end_timestamp
+    }
+}
+
+impl std::str::FromStr for DateRangeValue {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once('/')
+            .ok_or_else(|| "Date range must be of the form '<start>/<end>'".to_string())?;
+
+        let parse_bound = |bound: &str| {
+            time::OffsetDateTime::parse(
+                bound.trim(),
+                &time::format_description::well_known::Rfc3339,
+            )
+            .map(|date_time| date_time.unix_timestamp())
+            .map_err(|_| format!("'{bound}' is not a valid RFC 3339 date-time"))
+        };
+
+        let start_timestamp = parse_bound(start)?;
+        let end_timestamp = parse_bound(end)?;
+
+        if start_timestamp >= end_timestamp {
+            return Err("Start of the date range must be before its end".to_string());
+        }
+
+        Ok(Self {
+            start_timestamp,
+            end_timestamp,
+        })
+    }
+}
+
+impl std::fmt::Display for DateRangeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.start_timestamp, self.end_timestamp)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EuclidValue {
     PaymentMethod(enums::PaymentMethod),
@@ -253,12 +352,20 @@ pub enum EuclidValue {
     BillingCountry(enums::Country),
     BusinessLabel(StrValue),
     SetupFutureUsage(enums::SetupFutureUsage),
+    HourOfDay(NumValue),
+    DayOfWeek(enums::DayOfWeek),
+    DateRange(DateRangeValue),
+    CardIssuerCountry(enums::Country),
+    CardIssuer(StrValue),
+    CardProductType(StrValue),
+    PrepaidCard(enums::BooleanFlag),
+    CommercialCard(enums::BooleanFlag),
 }
 
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val) | Self::HourOfDay(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -281,6 +388,14 @@ impl EuclidValue {
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
             Self::BusinessLabel(_) => EuclidKey::BusinessLabel,
             Self::SetupFutureUsage(_) => EuclidKey::SetupFutureUsage,
+            Self::HourOfDay(_) => EuclidKey::HourOfDay,
+            Self::DayOfWeek(_) => EuclidKey::DayOfWeek,
+            Self::DateRange(_) => EuclidKey::DateRange,
+            Self::CardIssuerCountry(_) => EuclidKey::CardIssuerCountry,
+            Self::CardIssuer(_) => EuclidKey::CardIssuer,
+            Self::CardProductType(_) => EuclidKey::CardProductType,
+            Self::PrepaidCard(_) => EuclidKey::PrepaidCard,
+            Self::CommercialCard(_) => EuclidKey::CommercialCard,
         }
     }
 }
@@ -316,4 +431,22 @@ mod global_type_tests {
 
         assert!(val1.fits(&val2));
     }
+
+    #[test]
+    fn test_date_range_value_parsing() {
+        let date_range: DateRangeValue = "2024-12-24T00:00:00+05:30/2024-12-26T00:00:00+05:30"
+            .parse()
+            .expect("Date range parsing");
+
+        // 2024-12-23T18:30:00Z
+        assert_eq!(date_range.start_timestamp, 1734978600);
+        assert!(date_range.contains(1734978600));
+        assert!(!date_range.contains(1734978599));
+        assert!(!date_range.contains(date_range.end_timestamp));
+
+        assert!("2024-12-26T00:00:00Z/2024-12-24T00:00:00Z"
+            .parse::<DateRangeValue>()
+            .is_err());
+        assert!("2024-12-24/2024-12-26".parse::<DateRangeValue>().is_err());
+    }
 }
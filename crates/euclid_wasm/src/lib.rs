@@ -176,8 +176,9 @@ pub fn get_valid_connectors_for_rule(rule: JsValue) -> JsResult {
 #[wasm_bindgen(js_name = analyzeProgram)]
 pub fn analyze_program(js_program: JsValue) -> JsResult {
     let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
-    analyzer::analyze(program, SEED_DATA.get().map(|sd| &sd.cgraph)).err_to_js()?;
-    Ok(JsValue::NULL)
+    analyzer::analyze(program.clone(), SEED_DATA.get().map(|sd| &sd.cgraph)).err_to_js()?;
+    let warnings = analyzer::analyze_rule_reachability(program).err_to_js()?;
+    Ok(serde_wasm_bindgen::to_value(&warnings)?)
 }
 
 #[wasm_bindgen(js_name = runProgram)]
@@ -193,6 +194,22 @@ pub fn run_program(program: JsValue, input: JsValue) -> JsResult {
     Ok(serde_wasm_bindgen::to_value(&res)?)
 }
 
+#[wasm_bindgen(js_name = printProgram)]
+pub fn print_program(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    let program_text = ast::printer::print_program(&program)
+        .map_err(|error| error.to_string())
+        .err_to_js()?;
+    Ok(serde_wasm_bindgen::to_value(&program_text)?)
+}
+
+#[wasm_bindgen(js_name = parseProgram)]
+pub fn parse_program(program_text: &str) -> JsResult {
+    let program: ast::Program<ConnectorSelection> =
+        ast::parser::parse_program(program_text).err_to_js()?;
+    Ok(serde_wasm_bindgen::to_value(&program)?)
+}
+
 #[wasm_bindgen(js_name = getAllConnectors)]
 pub fn get_all_connectors() -> JsResult {
     Ok(serde_wasm_bindgen::to_value(RoutableConnectors::VARIANTS)?)
@@ -262,12 +279,21 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::BankDebitType => dir_enums::BankDebitType::VARIANTS,
         dir::DirKeyKind::RealTimePaymentType => dir_enums::RealTimePaymentType::VARIANTS,
         dir::DirKeyKind::OpenBankingType => dir_enums::OpenBankingType::VARIANTS,
+        dir::DirKeyKind::DayOfWeek => dir_enums::DayOfWeek::VARIANTS,
+        dir::DirKeyKind::CardIssuerCountry => dir_enums::Country::VARIANTS,
+        dir::DirKeyKind::PrepaidCard | dir::DirKeyKind::CommercialCard => {
+            dir_enums::BooleanFlag::VARIANTS
+        }
 
         dir::DirKeyKind::PaymentAmount
         | dir::DirKeyKind::Connector
         | dir::DirKeyKind::CardBin
         | dir::DirKeyKind::BusinessLabel
-        | dir::DirKeyKind::MetaData => Err("Key does not have variants".to_string())?,
+        | dir::DirKeyKind::MetaData
+        | dir::DirKeyKind::HourOfDay
+        | dir::DirKeyKind::DateRange
+        | dir::DirKeyKind::CardIssuer
+        | dir::DirKeyKind::CardProductType => Err("Key does not have variants".to_string())?,
     };
 
     Ok(serde_wasm_bindgen::to_value(variants)?)
@@ -18,6 +18,7 @@ pub enum ApplicationResponse<R> {
     FileData((Vec<u8>, mime::Mime)),
     JsonWithHeaders((R, Vec<(String, masking::Maskable<String>)>)),
     GenericLinkForm(Box<GenericLinks>),
+    IdempotentReplay(Box<IdempotentResponse>),
 }
 
 impl<T: ApiEventMetric> ApiEventMetric for ApplicationResponse<T> {
@@ -32,6 +33,22 @@ impl<T: ApiEventMetric> ApiEventMetric for ApplicationResponse<T> {
 
 impl_api_event_type!(Miscellaneous, (PaymentLinkFormData, GenericLinkFormData));
 
+/// The response of a request carrying an idempotency key, stored so that it can be replayed for
+/// retries of the request
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct IdempotentResponse {
+    pub status_code: u16,
+    pub body: masking::Secret<String>,
+    pub headers: Vec<IdempotentResponseHeader>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct IdempotentResponseHeader {
+    pub name: String,
+    pub value: masking::Secret<String>,
+    pub is_sensitive: bool,
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct RedirectionFormData {
     pub redirect_form: crate::router_response_types::RedirectForm,
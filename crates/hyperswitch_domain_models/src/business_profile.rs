@@ -1,5 +1,6 @@
 // #[cfg(all(feature = "v2", feature = "business_profile_v2"))]
 // use common_enums::OrderFulfillmentTimeOrigin;
+use common_enums::WebhookSignatureScheme;
 use common_utils::{
     crypto::OptionalEncryptableValue,
     date_time,
@@ -10,7 +11,7 @@ use common_utils::{
 };
 use diesel_models::business_profile::{
     AuthenticationConnectorDetails, BusinessPaymentLinkConfig, BusinessPayoutLinkConfig,
-    BusinessProfileUpdateInternal, WebhookDetails,
+    BusinessProfileUpdateInternal, RetryPolicy, WebhookDetails,
 };
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
@@ -51,6 +52,10 @@ pub struct BusinessProfile {
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
     pub collect_billing_details_from_wallet_connector: Option<bool>,
     pub outgoing_webhook_custom_http_headers: OptionalEncryptableValue,
+    pub retry_policy: Option<RetryPolicy>,
+    pub webhook_signature_scheme: Option<WebhookSignatureScheme>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(all(
@@ -81,6 +86,8 @@ pub struct BusinessProfileGeneralUpdate {
     pub collect_billing_details_from_wallet_connector: Option<bool>,
     pub is_connector_agnostic_mit_enabled: Option<bool>,
     pub outgoing_webhook_custom_http_headers: OptionalEncryptableValue,
+    pub retry_policy: Option<Option<RetryPolicy>>,
+    pub webhook_signature_scheme: Option<WebhookSignatureScheme>,
 }
 
 #[cfg(all(
@@ -100,6 +107,11 @@ pub enum BusinessProfileUpdate {
     ConnectorAgnosticMitUpdate {
         is_connector_agnostic_mit_enabled: Option<bool>,
     },
+    WebhookSigningKeyRotation {
+        payment_response_hash_key: String,
+        previous_payment_response_hash_key: Option<String>,
+        previous_payment_response_hash_key_expires_at: time::PrimitiveDateTime,
+    },
 }
 
 #[cfg(all(
@@ -135,6 +147,8 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                     collect_billing_details_from_wallet_connector,
                     is_connector_agnostic_mit_enabled,
                     outgoing_webhook_custom_http_headers,
+                    retry_policy,
+                    webhook_signature_scheme,
                 } = *update;
 
                 Self {
@@ -164,6 +178,10 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                     collect_billing_details_from_wallet_connector,
                     outgoing_webhook_custom_http_headers: outgoing_webhook_custom_http_headers
                         .map(Encryption::from),
+                    retry_policy,
+                    webhook_signature_scheme,
+                    previous_payment_response_hash_key: None,
+                    previous_payment_response_hash_key_expires_at: None,
                 }
             }
             BusinessProfileUpdate::RoutingAlgorithmUpdate {
@@ -195,6 +213,10 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 collect_shipping_details_from_wallet_connector: None,
                 collect_billing_details_from_wallet_connector: None,
                 outgoing_webhook_custom_http_headers: None,
+                retry_policy: None,
+                webhook_signature_scheme: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             BusinessProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -224,6 +246,10 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 collect_shipping_details_from_wallet_connector: None,
                 collect_billing_details_from_wallet_connector: None,
                 outgoing_webhook_custom_http_headers: None,
+                retry_policy: None,
+                webhook_signature_scheme: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             BusinessProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -253,6 +279,51 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 collect_shipping_details_from_wallet_connector: None,
                 collect_billing_details_from_wallet_connector: None,
                 outgoing_webhook_custom_http_headers: None,
+                retry_policy: None,
+                webhook_signature_scheme: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
+            },
+            BusinessProfileUpdate::WebhookSigningKeyRotation {
+                payment_response_hash_key,
+                previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at,
+            } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: Some(payment_response_hash_key),
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                retry_policy: None,
+                webhook_signature_scheme: None,
+                // Both are written on every rotation, so that the key replaced by an earlier
+                // rotation is removed when the profile had no key to replace
+                previous_payment_response_hash_key_expires_at: Some(
+                    previous_payment_response_hash_key
+                        .as_ref()
+                        .map(|_| previous_payment_response_hash_key_expires_at),
+                ),
+                previous_payment_response_hash_key: Some(previous_payment_response_hash_key),
             },
         }
     }
@@ -301,6 +372,11 @@ impl super::behaviour::Conversion for BusinessProfile {
             outgoing_webhook_custom_http_headers: self
                 .outgoing_webhook_custom_http_headers
                 .map(Encryption::from),
+            retry_policy: self.retry_policy,
+            webhook_signature_scheme: self.webhook_signature_scheme,
+            previous_payment_response_hash_key: self.previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: self
+                .previous_payment_response_hash_key_expires_at,
         })
     }
 
@@ -344,6 +420,11 @@ impl super::behaviour::Conversion for BusinessProfile {
                     .collect_shipping_details_from_wallet_connector,
                 collect_billing_details_from_wallet_connector: item
                     .collect_billing_details_from_wallet_connector,
+                retry_policy: item.retry_policy,
+                webhook_signature_scheme: item.webhook_signature_scheme,
+                previous_payment_response_hash_key: item.previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at: item
+                    .previous_payment_response_hash_key_expires_at,
                 outgoing_webhook_custom_http_headers: item
                     .outgoing_webhook_custom_http_headers
                     .async_lift(|inner| async {
@@ -400,6 +481,11 @@ impl super::behaviour::Conversion for BusinessProfile {
             outgoing_webhook_custom_http_headers: self
                 .outgoing_webhook_custom_http_headers
                 .map(Encryption::from),
+            retry_policy: self.retry_policy,
+            webhook_signature_scheme: self.webhook_signature_scheme,
+            previous_payment_response_hash_key: self.previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: self
+                .previous_payment_response_hash_key_expires_at,
         })
     }
 }
@@ -431,6 +517,10 @@ pub struct BusinessProfile {
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
     pub collect_billing_details_from_wallet_connector: Option<bool>,
     pub outgoing_webhook_custom_http_headers: OptionalEncryptableValue,
+    pub retry_policy: Option<RetryPolicy>,
+    pub webhook_signature_scheme: Option<WebhookSignatureScheme>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
     pub routing_algorithm_id: Option<String>,
     pub order_fulfillment_time: Option<i64>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
@@ -475,6 +565,8 @@ pub struct BusinessProfileGeneralUpdate {
     pub collect_billing_details_from_wallet_connector: Option<bool>,
     pub is_connector_agnostic_mit_enabled: Option<bool>,
     pub outgoing_webhook_custom_http_headers: OptionalEncryptableValue,
+    pub retry_policy: Option<Option<RetryPolicy>>,
+    pub webhook_signature_scheme: Option<WebhookSignatureScheme>,
     pub order_fulfillment_time: Option<i64>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
 }
@@ -496,6 +588,11 @@ pub enum BusinessProfileUpdate {
     ConnectorAgnosticMitUpdate {
         is_connector_agnostic_mit_enabled: Option<bool>,
     },
+    WebhookSigningKeyRotation {
+        payment_response_hash_key: String,
+        previous_payment_response_hash_key: Option<String>,
+        previous_payment_response_hash_key_expires_at: time::PrimitiveDateTime,
+    },
 }
 
 #[cfg(all(feature = "v2", feature = "business_profile_v2"))]
@@ -524,6 +621,8 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                     collect_billing_details_from_wallet_connector,
                     is_connector_agnostic_mit_enabled,
                     outgoing_webhook_custom_http_headers,
+                    retry_policy,
+                    webhook_signature_scheme,
                     order_fulfillment_time,
                     order_fulfillment_time_origin,
                 } = *update;
@@ -550,6 +649,10 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                     collect_billing_details_from_wallet_connector,
                     outgoing_webhook_custom_http_headers: outgoing_webhook_custom_http_headers
                         .map(Encryption::from),
+                    retry_policy,
+                    webhook_signature_scheme,
+                    previous_payment_response_hash_key: None,
+                    previous_payment_response_hash_key_expires_at: None,
                     routing_algorithm_id: None,
                     order_fulfillment_time,
                     order_fulfillment_time_origin,
@@ -583,6 +686,10 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 collect_shipping_details_from_wallet_connector: None,
                 collect_billing_details_from_wallet_connector: None,
                 outgoing_webhook_custom_http_headers: None,
+                retry_policy: None,
+                webhook_signature_scheme: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
                 routing_algorithm_id,
                 order_fulfillment_time: None,
                 order_fulfillment_time_origin: None,
@@ -614,6 +721,10 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 collect_shipping_details_from_wallet_connector: None,
                 collect_billing_details_from_wallet_connector: None,
                 outgoing_webhook_custom_http_headers: None,
+                retry_policy: None,
+                webhook_signature_scheme: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
                 routing_algorithm_id: None,
                 payout_routing_algorithm_id: None,
                 order_fulfillment_time: None,
@@ -645,6 +756,53 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 collect_shipping_details_from_wallet_connector: None,
                 collect_billing_details_from_wallet_connector: None,
                 outgoing_webhook_custom_http_headers: None,
+                retry_policy: None,
+                webhook_signature_scheme: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
+                routing_algorithm_id: None,
+                payout_routing_algorithm_id: None,
+                order_fulfillment_time: None,
+                order_fulfillment_time_origin: None,
+                frm_routing_algorithm_id: None,
+                default_fallback_routing: None,
+            },
+            BusinessProfileUpdate::WebhookSigningKeyRotation {
+                payment_response_hash_key,
+                previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at,
+            } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: Some(payment_response_hash_key),
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                retry_policy: None,
+                webhook_signature_scheme: None,
+                // Both are written on every rotation, so that the key replaced by an earlier
+                // rotation is removed when the profile had no key to replace
+                previous_payment_response_hash_key_expires_at: Some(
+                    previous_payment_response_hash_key
+                        .as_ref()
+                        .map(|_| previous_payment_response_hash_key_expires_at),
+                ),
+                previous_payment_response_hash_key: Some(previous_payment_response_hash_key),
                 routing_algorithm_id: None,
                 payout_routing_algorithm_id: None,
                 order_fulfillment_time: None,
@@ -676,6 +834,10 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 collect_shipping_details_from_wallet_connector: None,
                 collect_billing_details_from_wallet_connector: None,
                 outgoing_webhook_custom_http_headers: None,
+                retry_policy: None,
+                webhook_signature_scheme: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
                 routing_algorithm_id: None,
                 payout_routing_algorithm_id: None,
                 order_fulfillment_time: None,
@@ -723,6 +885,11 @@ impl super::behaviour::Conversion for BusinessProfile {
             outgoing_webhook_custom_http_headers: self
                 .outgoing_webhook_custom_http_headers
                 .map(Encryption::from),
+            retry_policy: self.retry_policy,
+            webhook_signature_scheme: self.webhook_signature_scheme,
+            previous_payment_response_hash_key: self.previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: self
+                .previous_payment_response_hash_key_expires_at,
             routing_algorithm_id: self.routing_algorithm_id,
             payout_routing_algorithm_id: self.payout_routing_algorithm_id,
             order_fulfillment_time: self.order_fulfillment_time,
@@ -768,6 +935,11 @@ impl super::behaviour::Conversion for BusinessProfile {
                     .collect_shipping_details_from_wallet_connector,
                 collect_billing_details_from_wallet_connector: item
                     .collect_billing_details_from_wallet_connector,
+                retry_policy: item.retry_policy,
+                webhook_signature_scheme: item.webhook_signature_scheme,
+                previous_payment_response_hash_key: item.previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at: item
+                    .previous_payment_response_hash_key_expires_at,
                 outgoing_webhook_custom_http_headers: item
                     .outgoing_webhook_custom_http_headers
                     .async_lift(|inner| async {
@@ -826,6 +998,11 @@ impl super::behaviour::Conversion for BusinessProfile {
             outgoing_webhook_custom_http_headers: self
                 .outgoing_webhook_custom_http_headers
                 .map(Encryption::from),
+            retry_policy: self.retry_policy,
+            webhook_signature_scheme: self.webhook_signature_scheme,
+            previous_payment_response_hash_key: self.previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: self
+                .previous_payment_response_hash_key_expires_at,
             routing_algorithm_id: self.routing_algorithm_id,
             order_fulfillment_time: self.order_fulfillment_time,
             order_fulfillment_time_origin: self.order_fulfillment_time_origin,
@@ -17,6 +17,7 @@ pub enum ErrorType {
     ValidationError,
     ConnectorError,
     LockTimeout,
+    RateLimitError,
 }
 
 // CE	Connector Error	Errors originating from connector's end
@@ -158,6 +159,8 @@ pub enum ApiErrorResponse {
     InvalidTenant { tenant_id: String },
     #[error(error_type = ErrorType::ValidationError, code = "HE_06", message = "Failed to convert amount to {amount_type} type")]
     AmountConversionFailed { amount_type: &'static str },
+    #[error(error_type = ErrorType::RateLimitError, code = "HE_07", message = "Too many requests, retry after {retry_after_secs} seconds")]
+    RateLimitExceeded { retry_after_secs: u64 },
     #[error(error_type = ErrorType::ServerNotAvailable, code = "IR_00", message = "{message:?}")]
     NotImplemented { message: NotImplementedMessage },
     #[error(
@@ -272,6 +275,12 @@ pub enum ApiErrorResponse {
     LinkConfigurationError { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_41", message = "Payout validation failed")]
     PayoutFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_42", message = "Idempotency key '{idempotency_key}' has already been used with a different request body")]
+    IdempotencyKeyReused { idempotency_key: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_43", message = "The API key used is not permitted to perform this operation, it requires the '{permission}' permission")]
+    ApiKeyPermissionDenied { permission: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_44", message = "The API key used is not allowed to be used from this IP address")]
+    ApiKeyIpNotAllowed,
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
@@ -485,6 +494,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::AmountConversionFailed { amount_type }  => {
                 AER::InternalServerError(ApiError::new("HE", 6, format!("Failed to convert amount to {amount_type} type"), None))
             }
+            Self::RateLimitExceeded { retry_after_secs } => {
+                AER::TooManyRequests(ApiError::new("HE", 7, format!("Too many requests, retry after {retry_after_secs} seconds"), None), *retry_after_secs)
+            }
 
             Self::NotImplemented { message } => {
                 AER::NotImplemented(ApiError::new("IR", 0, format!("{message:?}"), None))
@@ -625,6 +637,15 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::PayoutFailed { data } => {
                 AER::BadRequest(ApiError::new("IR", 41, "Payout failed while processing with connector.", Some(Extra { data: data.clone(), ..Default::default()})))
             },
+            Self::IdempotencyKeyReused { idempotency_key } => {
+                AER::Conflict(ApiError::new("IR", 42, format!("Idempotency key '{idempotency_key}' has already been used with a different request body"), None))
+            },
+            Self::ApiKeyPermissionDenied { permission } => {
+                AER::ForbiddenCommonResource(ApiError::new("IR", 43, format!("The API key used is not permitted to perform this operation, it requires the '{permission}' permission"), None))
+            },
+            Self::ApiKeyIpNotAllowed => {
+                AER::ForbiddenCommonResource(ApiError::new("IR", 44, "The API key used is not allowed to be used from this IP address", None))
+            },
 
             Self::WebhookAuthenticationFailed => {
                 AER::Unauthorized(ApiError::new("WE", 1, "Webhook authentication failed", None))
@@ -43,6 +43,7 @@ pub struct MerchantConnectorAccount {
     pub connector_wallets_details: Option<Encryptable<pii::SecretSerdeValue>>,
     pub additional_merchant_data: Option<Encryptable<pii::SecretSerdeValue>>,
     pub version: common_enums::ApiVersion,
+    pub fee_schedule: Option<serde_json::Value>,
 }
 
 #[cfg(all(
@@ -78,6 +79,7 @@ pub struct MerchantConnectorAccount {
     pub connector_wallets_details: Option<Encryptable<pii::SecretSerdeValue>>,
     pub additional_merchant_data: Option<Encryptable<pii::SecretSerdeValue>>,
     pub version: common_enums::ApiVersion,
+    pub fee_schedule: Option<serde_json::Value>,
 }
 
 #[cfg(all(feature = "v2", feature = "merchant_connector_account_v2"))]
@@ -109,6 +111,7 @@ pub enum MerchantConnectorAccountUpdate {
         connector_label: Option<String>,
         status: Option<enums::ConnectorStatus>,
         connector_wallets_details: Option<Encryptable<pii::SecretSerdeValue>>,
+        fee_schedule: Option<Option<serde_json::Value>>,
     },
     ConnectorWalletDetailsUpdate {
         connector_wallets_details: Encryptable<pii::SecretSerdeValue>,
@@ -131,6 +134,7 @@ pub enum MerchantConnectorAccountUpdate {
         connector_label: Option<String>,
         status: Option<enums::ConnectorStatus>,
         connector_wallets_details: Option<Encryptable<pii::SecretSerdeValue>>,
+        fee_schedule: Option<Option<serde_json::Value>>,
     },
     ConnectorWalletDetailsUpdate {
         connector_wallets_details: Encryptable<pii::SecretSerdeValue>,
@@ -174,6 +178,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
                 additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
                 version: self.version,
+                fee_schedule: self.fee_schedule,
             },
         )
     }
@@ -259,6 +264,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 None
             },
             version: other.version,
+            fee_schedule: other.fee_schedule,
         })
     }
 
@@ -290,6 +296,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
             version: self.version,
+            fee_schedule: self.fee_schedule,
         })
     }
 }
@@ -323,6 +330,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
                 additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
                 version: self.version,
+                fee_schedule: self.fee_schedule,
             },
         )
     }
@@ -400,6 +408,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 None
             },
             version: other.version,
+            fee_schedule: other.fee_schedule,
         })
     }
 
@@ -426,6 +435,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
             version: self.version,
+            fee_schedule: self.fee_schedule,
         })
     }
 }
@@ -453,6 +463,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_label,
                 status,
                 connector_wallets_details,
+                fee_schedule,
             } => Self {
                 connector_type,
                 connector_name,
@@ -471,6 +482,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_label,
                 status,
                 connector_wallets_details: connector_wallets_details.map(Encryption::from),
+                fee_schedule,
             },
             MerchantConnectorAccountUpdate::ConnectorWalletDetailsUpdate {
                 connector_wallets_details,
@@ -492,6 +504,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 applepay_verified_domains: None,
                 pm_auth_config: None,
                 status: None,
+                fee_schedule: None,
             },
         }
     }
@@ -514,6 +527,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_label,
                 status,
                 connector_wallets_details,
+                fee_schedule,
             } => Self {
                 connector_type,
                 connector_account_details: connector_account_details.map(Encryption::from),
@@ -528,6 +542,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 connector_label,
                 status,
                 connector_wallets_details: connector_wallets_details.map(Encryption::from),
+                fee_schedule,
             },
             MerchantConnectorAccountUpdate::ConnectorWalletDetailsUpdate {
                 connector_wallets_details,
@@ -545,6 +560,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 applepay_verified_domains: None,
                 pm_auth_config: None,
                 status: None,
+                fee_schedule: None,
             },
         }
     }
@@ -181,6 +181,8 @@ pub struct PaymentAttempt {
     pub client_source: Option<String>,
     pub client_version: Option<String>,
     pub customer_acceptance: Option<pii::SecretSerdeValue>,
+    pub routing_decision_trace: Option<serde_json::Value>,
+    pub retry_policy: Option<serde_json::Value>,
 }
 
 impl PaymentAttempt {
@@ -271,6 +273,8 @@ pub struct PaymentAttemptNew {
     pub client_source: Option<String>,
     pub client_version: Option<String>,
     pub customer_acceptance: Option<pii::SecretSerdeValue>,
+    pub routing_decision_trace: Option<serde_json::Value>,
+    pub retry_policy: Option<serde_json::Value>,
 }
 
 impl PaymentAttemptNew {
@@ -354,6 +358,8 @@ pub enum PaymentAttemptUpdate {
         client_source: Option<String>,
         client_version: Option<String>,
         customer_acceptance: Option<pii::SecretSerdeValue>,
+        routing_decision_trace: Option<serde_json::Value>,
+        retry_policy: Option<serde_json::Value>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -193,6 +193,18 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         _request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api_models::webhooks::IncomingWebhookEvent, errors::ConnectorError>;
 
+    /// fn get_webhook_event_id
+    ///
+    /// The identifier the connector assigns to the notification, which remains the same when the
+    /// connector resends the notification. Incoming webhooks are deduplicated by a hash of the
+    /// request body for connectors that do not provide one.
+    fn get_webhook_event_id(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        Ok(None)
+    }
+
     /// fn get_webhook_resource_object
     fn get_webhook_resource_object(
         &self,
@@ -67,6 +67,7 @@ fn build_test_data(
         profile_id: "profile_id".to_string(),
         applepay_verified_domains: None,
         pm_auth_config: None,
+        fee_schedule: None,
         status: api_enums::ConnectorStatus::Inactive,
         additional_merchant_data: None,
     };
@@ -93,6 +94,7 @@ fn build_test_data(
         profile_id: "profile_id".to_string(),
         applepay_verified_domains: None,
         pm_auth_config: None,
+        fee_schedule: None,
         status: api_enums::ConnectorStatus::Inactive,
         additional_merchant_data: None,
     };
@@ -755,6 +755,7 @@ mod tests {
             profile_id: "profile_id".to_string(),
             applepay_verified_domains: None,
             pm_auth_config: None,
+            fee_schedule: None,
             status: api_enums::ConnectorStatus::Inactive,
             additional_merchant_data: None,
         };
@@ -816,6 +817,7 @@ mod tests {
             profile_id: "profile_id".to_string(),
             applepay_verified_domains: None,
             pm_auth_config: None,
+            fee_schedule: None,
             status: api_enums::ConnectorStatus::Inactive,
             additional_merchant_data: None,
         };
@@ -1,4 +1,5 @@
 use api_models::enums as api_enums;
+use common_utils::types::MinorUnit;
 use euclid::{
     backend::BackendInput,
     dirval,
@@ -24,6 +25,17 @@ impl IntoContext for BackendInput {
 
         ctx.push(dir::DirValue::PaymentCurrency(self.payment.currency));
 
+        if let Some(hour_of_day) = self.payment.get_hour_of_day() {
+            ctx.push(dir::DirValue::HourOfDay(NumValue {
+                number: MinorUnit::new(hour_of_day.into()),
+                refinement: None,
+            }));
+        }
+
+        if let Some(day_of_week) = self.payment.get_day_of_week() {
+            ctx.push(dir::DirValue::DayOfWeek(day_of_week));
+        }
+
         if let Some(auth_type) = self.payment.authentication_type {
             ctx.push(dir::DirValue::AuthenticationType(auth_type));
         }
@@ -58,6 +70,25 @@ impl IntoContext for BackendInput {
         if let Some(card_network) = self.payment_method.card_network {
             ctx.push(dir::DirValue::CardNetwork(card_network));
         }
+        if let Some(card_bin_info) = self.payment_method.card_bin_info {
+            if let Some(issuer_country) = card_bin_info.issuer_country {
+                ctx.push(dir::DirValue::CardIssuerCountry(issuer_country));
+            }
+            if let Some(issuer) = card_bin_info.issuer {
+                ctx.push(dir::DirValue::CardIssuer(StrValue { value: issuer }));
+            }
+            if let Some(product_type) = card_bin_info.product_type {
+                ctx.push(dir::DirValue::CardProductType(StrValue {
+                    value: product_type,
+                }));
+            }
+            if let Some(is_prepaid) = card_bin_info.is_prepaid {
+                ctx.push(dir::DirValue::PrepaidCard(is_prepaid.into()));
+            }
+            if let Some(is_commercial) = card_bin_info.is_commercial {
+                ctx.push(dir::DirValue::CommercialCard(is_commercial.into()));
+            }
+        }
         if let Some(setup_future_usage) = self.payment.setup_future_usage {
             ctx.push(dir::DirValue::SetupFutureUsage(setup_future_usage));
         }
@@ -66,6 +66,8 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "payment link", description = "Create payment link"),
         (name = "Routing", description = "Create and manage routing configurations"),
         (name = "Event", description = "Manage events"),
+        (name = "Audit Log", description = "View changes made to the configuration of merchant accounts"),
+        (name = "Archived Webhook", description = "View and replay incoming webhooks received from connectors"),
     ),
     // The paths will be displayed in the same order as they are registered here
     paths(
@@ -113,6 +115,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::gsm::get_gsm_rule,
         routes::gsm::update_gsm_rule,
         routes::gsm::delete_gsm_rule,
+        routes::gsm::import_gsm_rules,
+        routes::gsm::export_gsm_rules,
 
         // Routes for mandates
         routes::mandates::get_mandate,
@@ -142,6 +146,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::business_profile::business_profile_retrieve,
         routes::business_profile::business_profile_update,
         routes::business_profile::business_profile_delete,
+        routes::business_profile::business_profile_rotate_webhook_signing_key,
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
@@ -151,6 +156,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_create_config,
         routes::routing::routing_link_config,
         routes::routing::routing_retrieve_config,
+        routes::routing::routing_simulate_config,
         routes::routing::list_routing_configs,
         routes::routing::routing_unlink_config,
         routes::routing::routing_update_default_config,
@@ -181,11 +187,29 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::api_keys::api_key_retrieve,
         routes::api_keys::api_key_update,
         routes::api_keys::api_key_revoke,
+        routes::api_keys::api_key_rotate,
 
         // Routes for events
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::replay_webhook_events,
+        routes::webhook_events::retrieve_webhook_replay_job,
+
+        // Routes for archived webhooks
+        routes::archived_webhooks::list_archived_webhooks,
+        routes::archived_webhooks::replay_archived_webhook,
+
+        // Routes for webhook endpoints
+        routes::webhook_endpoints::webhook_endpoint_create,
+        routes::webhook_endpoints::webhook_endpoint_list,
+        routes::webhook_endpoints::webhook_endpoint_retrieve,
+        routes::webhook_endpoints::webhook_endpoint_update,
+        routes::webhook_endpoints::webhook_endpoint_delete,
+        routes::webhook_endpoints::webhook_endpoint_reenable,
+
+        // Routes for audit logs
+        routes::audit_log::list_audit_logs,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
@@ -208,6 +232,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorResponse,
         api_models::admin::MerchantConnectorListResponse,
         api_models::admin::AuthenticationConnectorDetails,
+        api_models::admin::RetryPolicy,
+        api_models::admin::RetryAmountRange,
         api_models::admin::ExtendedCardInfoConfig,
         api_models::admin::BusinessGenericLinkConfig,
         api_models::admin::BusinessCollectLinkConfig,
@@ -239,6 +265,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ConnectorType,
         api_models::enums::PayoutConnectors,
         api_models::enums::AuthenticationConnectors,
+        api_models::enums::RetryConnectorOrder,
+        api_models::enums::WebhookSignatureScheme,
+        api_models::enums::WebhookDeliveryStatus,
+        api_models::enums::WebhookReplayJobStatus,
+        api_models::enums::WebhookReplayEventStatus,
+        api_models::enums::ArchivedWebhookStatus,
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
         api_models::enums::CaptureMethod,
@@ -271,6 +303,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorUpdate,
         api_models::admin::PrimaryBusinessDetails,
         api_models::admin::FrmConfigs,
+        api_models::admin::ConnectorFeeSchedule,
+        api_models::admin::ConnectorFeeRule,
         api_models::admin::FrmPaymentMethod,
         api_models::admin::FrmPaymentMethodType,
         api_models::admin::PaymentMethodsEnabled,
@@ -279,6 +313,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorWebhookDetails,
         api_models::admin::BusinessProfileCreate,
         api_models::admin::BusinessProfileResponse,
+        api_models::admin::WebhookSigningKeyRotateRequest,
+        api_models::admin::WebhookSigningKeyRotateResponse,
         api_models::admin::BusinessPaymentLinkConfig,
         api_models::admin::PaymentLinkConfigRequest,
         api_models::admin::PaymentLinkConfig,
@@ -291,6 +327,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::gsm::GsmDeleteResponse,
         api_models::gsm::GsmResponse,
         api_models::gsm::GsmDecision,
+        api_models::gsm::GsmImportRequest,
+        api_models::gsm::GsmImportResponse,
+        api_models::gsm::GsmImportRuleResult,
+        api_models::gsm::GsmImportAction,
+        api_models::gsm::GsmExportFormat,
+        api_models::gsm::GsmExportResponse,
+        api_models::enums::GsmMatchType,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
         api_models::payments::AliPayQr,
@@ -521,6 +564,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
+        api_models::api_keys::RotateApiKeyRequest,
+        api_models::api_keys::RotateApiKeyResponse,
+        api_models::user_role::Permission,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
@@ -530,6 +576,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
+        api_models::routing::AnalysisWarning,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
         api_models::routing::LinkedRoutingConfigRetrieveResponse,
@@ -541,6 +588,30 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::RoutingAlgorithm,
         api_models::routing::StraightThroughAlgorithm,
         api_models::routing::ConnectorVolumeSplit,
+        api_models::routing::VolumeSplitStickinessKey,
+        api_models::routing::SuccessRateAlgorithm,
+        api_models::routing::CostBasedAlgorithm,
+        api_models::routing::ConnectorFeeEstimate,
+        api_models::routing::SuccessRateConfig,
+        api_models::routing::SuccessRateDimension,
+        api_models::routing::RoutingActivationRecord,
+        api_models::routing::RoutingRollbackRequest,
+        api_models::routing::RoutingActivationScheduleRequest,
+        api_models::routing::RoutingScheduledActivation,
+        api_models::routing::RoutingAlgorithmDiff,
+        api_models::routing::ConnectorSelectionDiff,
+        api_models::routing::RoutingRuleDiff,
+        api_models::routing::RoutingRuleChange,
+        api_models::enums::RoutingActivationKind,
+        api_models::routing::RoutingDecisionTrace,
+        api_models::routing::RoutingApproach,
+        api_models::routing::EliminatedConnector,
+        api_models::routing::ConnectorEliminationReason,
+        api_models::routing::RoutingSimulationAlgorithm,
+        api_models::routing::RoutingSimulationRequest,
+        api_models::routing::RoutingSimulationResponse,
+        api_models::routing::ConnectorDistribution,
+        api_models::routing::ConnectorAmountDistribution,
         api_models::routing::ConnectorSelection,
         api_models::routing::ast::RoutableChoiceKind,
         api_models::enums::RoutableConnectors,
@@ -560,9 +631,25 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::blocklist::ListBlocklistQuery,
         api_models::enums::BlocklistDataKind,
         api_models::webhook_events::EventListItemResponse,
+        api_models::webhook_endpoints::WebhookEndpointCreateRequest,
+        api_models::webhook_endpoints::WebhookEndpointUpdateRequest,
+        api_models::webhook_endpoints::WebhookEndpointResponse,
+        api_models::webhook_endpoints::WebhookEndpointDeleteResponse,
+        api_models::webhook_endpoints::WebhookEndpointHealth,
+        api_models::webhook_endpoints::WebhookEndpointReenableResponse,
+        api_models::audit_log::AuditLogResponse,
+        api_models::enums::AuditEntityType,
+        api_models::enums::AuditAction,
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
+        api_models::webhook_events::WebhookReplayRequest,
+        api_models::webhook_events::WebhookReplayFilters,
+        api_models::webhook_events::WebhookReplayJobResponse,
+        api_models::webhook_events::WebhookReplayJobStatusResponse,
+        api_models::webhook_events::WebhookReplayEventResponse,
+        api_models::archived_webhooks::ArchivedWebhookListConstraints,
+        api_models::archived_webhooks::ArchivedWebhookResponse,
         api_models::enums::WebhookDeliveryAttempt,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
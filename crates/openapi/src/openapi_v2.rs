@@ -108,6 +108,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorResponse,
         api_models::admin::MerchantConnectorListResponse,
         api_models::admin::AuthenticationConnectorDetails,
+        api_models::admin::RetryPolicy,
+        api_models::admin::RetryAmountRange,
         api_models::admin::ExtendedCardInfoConfig,
         api_models::admin::BusinessGenericLinkConfig,
         api_models::admin::BusinessCollectLinkConfig,
@@ -139,6 +141,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ConnectorType,
         api_models::enums::PayoutConnectors,
         api_models::enums::AuthenticationConnectors,
+        api_models::enums::RetryConnectorOrder,
+        api_models::enums::WebhookSignatureScheme,
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
         api_models::enums::CaptureMethod,
@@ -172,6 +176,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorUpdate,
         api_models::admin::PrimaryBusinessDetails,
         api_models::admin::FrmConfigs,
+        api_models::admin::ConnectorFeeSchedule,
+        api_models::admin::ConnectorFeeRule,
         api_models::admin::FrmPaymentMethod,
         api_models::admin::FrmPaymentMethodType,
         api_models::admin::PaymentMethodsEnabled,
@@ -192,6 +198,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::gsm::GsmDeleteResponse,
         api_models::gsm::GsmResponse,
         api_models::gsm::GsmDecision,
+        api_models::gsm::GsmImportRequest,
+        api_models::gsm::GsmImportResponse,
+        api_models::gsm::GsmImportRuleResult,
+        api_models::gsm::GsmImportAction,
+        api_models::gsm::GsmExportFormat,
+        api_models::gsm::GsmExportResponse,
+        api_models::enums::GsmMatchType,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
         api_models::payments::AliPayQr,
@@ -419,6 +432,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
+        api_models::api_keys::RotateApiKeyRequest,
+        api_models::api_keys::RotateApiKeyResponse,
+        api_models::user_role::Permission,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
@@ -428,6 +444,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
+        api_models::routing::AnalysisWarning,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
         api_models::routing::LinkedRoutingConfigRetrieveResponse,
@@ -439,6 +456,25 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::RoutingAlgorithm,
         api_models::routing::StraightThroughAlgorithm,
         api_models::routing::ConnectorVolumeSplit,
+        api_models::routing::VolumeSplitStickinessKey,
+        api_models::routing::SuccessRateAlgorithm,
+        api_models::routing::CostBasedAlgorithm,
+        api_models::routing::ConnectorFeeEstimate,
+        api_models::routing::SuccessRateConfig,
+        api_models::routing::SuccessRateDimension,
+        api_models::routing::RoutingActivationRecord,
+        api_models::routing::RoutingRollbackRequest,
+        api_models::routing::RoutingActivationScheduleRequest,
+        api_models::routing::RoutingScheduledActivation,
+        api_models::routing::RoutingAlgorithmDiff,
+        api_models::routing::ConnectorSelectionDiff,
+        api_models::routing::RoutingRuleDiff,
+        api_models::routing::RoutingRuleChange,
+        api_models::enums::RoutingActivationKind,
+        api_models::routing::RoutingDecisionTrace,
+        api_models::routing::RoutingApproach,
+        api_models::routing::EliminatedConnector,
+        api_models::routing::ConnectorEliminationReason,
         api_models::routing::ConnectorSelection,
         api_models::routing::ast::RoutableChoiceKind,
         api_models::enums::RoutableConnectors,
@@ -458,6 +494,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::blocklist::ListBlocklistQuery,
         api_models::enums::BlocklistDataKind,
         api_models::webhook_events::EventListItemResponse,
+        api_models::audit_log::AuditLogResponse,
+        api_models::enums::AuditEntityType,
+        api_models::enums::AuditAction,
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
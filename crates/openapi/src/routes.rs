@@ -1,6 +1,8 @@
 #![allow(unused)]
 
 pub mod api_keys;
+pub mod archived_webhooks;
+pub mod audit_log;
 pub mod blocklist;
 pub mod business_profile;
 pub mod customers;
@@ -17,6 +19,7 @@ pub mod payouts;
 pub mod poll;
 pub mod refunds;
 pub mod routing;
+pub mod webhook_endpoints;
 pub mod webhook_events;
 
 pub use self::{
@@ -0,0 +1,68 @@
+/// Archived Webhooks - List
+///
+/// List the incoming webhooks received for a Merchant Account, most recent first.
+#[utoipa::path(
+    get,
+    path = "/archived_webhooks/{merchant_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        (
+            "merchant_connector_id" = Option<String>,
+            Query,
+            description = "Only include webhooks received for the specified merchant connector account."
+        ),
+        (
+            "status" = Option<ArchivedWebhookStatus>,
+            Query,
+            description = "Only include webhooks with the specified processing status."
+        ),
+        (
+            "created_after" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Only include webhooks received after the specified time."
+        ),
+        (
+            "created_before" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Only include webhooks received before the specified time."
+        ),
+        (
+            "limit" = Option<u16>,
+            Query,
+            description = "The maximum number of webhooks to include in the response."
+        ),
+        (
+            "offset" = Option<u16>,
+            Query,
+            description = "The number of webhooks to skip when retrieving the list of webhooks."
+        ),
+    ),
+    responses(
+        (status = 200, description = "List of archived webhooks retrieved successfully", body = Vec<ArchivedWebhookResponse>),
+    ),
+    tag = "Archived Webhook",
+    operation_id = "List all archived incoming webhooks of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub fn list_archived_webhooks() {}
+
+/// Archived Webhooks - Replay
+///
+/// Process the specified archived webhook again, as if it were just received from the connector.
+/// The webhook is processed even if it was processed successfully earlier.
+#[utoipa::path(
+    post,
+    path = "/archived_webhooks/{merchant_id}/{webhook_id}/replay",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("webhook_id" = String, Path, description = "The unique identifier for the archived webhook"),
+    ),
+    responses(
+        (status = 200, description = "The webhook was replayed successfully", body = ArchivedWebhookResponse),
+        (status = 404, description = "Archived webhook does not exist in our records"),
+    ),
+    tag = "Archived Webhook",
+    operation_id = "Replay an archived incoming webhook",
+    security(("admin_api_key" = []))
+)]
+pub fn replay_archived_webhook() {}
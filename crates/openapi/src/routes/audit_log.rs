@@ -0,0 +1,52 @@
+/// Audit Logs - List
+///
+/// List the changes made to the configuration of a Merchant Account, such as routing algorithms,
+/// connector accounts, API keys and business profiles.
+#[utoipa::path(
+    get,
+    path = "/audit_logs",
+    params(
+        (
+            "entity_type" = Option<AuditEntityType>,
+            Query,
+            description = "Only include changes made to the specified kind of entity."
+        ),
+        (
+            "entity_id" = Option<String>,
+            Query,
+            description = "Only include changes made to the entity with the specified identifier."
+        ),
+        (
+            "actor" = Option<String>,
+            Query,
+            description = "Only include changes made by the specified actor, such as `user:<user_id>` or `api_key:<key_id>`."
+        ),
+        (
+            "created_after" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Only include changes made after the specified time."
+        ),
+        (
+            "created_before" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Only include changes made before the specified time."
+        ),
+        (
+            "limit" = Option<i64>,
+            Query,
+            description = "The maximum number of audit logs to include in the response."
+        ),
+        (
+            "offset" = Option<i64>,
+            Query,
+            description = "The number of audit logs to skip when retrieving the list of audit logs."
+        ),
+    ),
+    responses(
+        (status = 200, description = "List of audit logs retrieved successfully", body = Vec<AuditLogResponse>),
+    ),
+    tag = "Audit Log",
+    operation_id = "List all Audit Logs of a Merchant Account",
+    security(("api_key" = []))
+)]
+pub async fn list_audit_logs() {}
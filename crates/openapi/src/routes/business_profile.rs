@@ -205,3 +205,26 @@ pub async fn business_profile_retrieve() {}
     security(("admin_api_key" = []))
 )]
 pub async fn business_profile_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Business Profile - Rotate Webhook Signing Key
+///
+/// Rotate the key used to sign outgoing webhooks of the *business profile*. The previous key
+/// remains valid for the rotation window, during which webhooks carry signatures for both keys.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/rotate_webhook_signing_key",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    request_body = WebhookSigningKeyRotateRequest,
+    responses(
+        (status = 200, description = "Webhook Signing Key Rotated", body = WebhookSigningKeyRotateResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Business Profile",
+    operation_id = "Rotate the Webhook Signing Key of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn business_profile_rotate_webhook_signing_key() {}
@@ -73,3 +73,42 @@ pub async fn update_gsm_rule() {}
     security(("admin_api_key" = [])),
 )]
 pub async fn delete_gsm_rule() {}
+
+/// Gsm - Import
+///
+/// Creates or updates Gsm Rules in bulk. The rules are only written if all of them are valid,
+/// and are only validated when `dry_run` is set
+#[utoipa::path(
+    post,
+    path = "/gsm/import",
+    request_body(
+        content = GsmImportRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules validated, and imported if all of them are valid", body = GsmImportResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Import Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+pub async fn import_gsm_rules() {}
+
+/// Gsm - Export
+///
+/// Exports the Gsm Rules as JSON or CSV
+#[utoipa::path(
+    get,
+    path = "/gsm/export",
+    params(
+        ("format" = Option<GsmExportFormat>, Query, description = "The format of the export, json by default"),
+        ("connector" = Option<Connector>, Query, description = "Only export the rules of this connector"),
+    ),
+    responses(
+        (status = 200, description = "Gsm rules exported", body = GsmExportResponse),
+    ),
+    tag = "Gsm",
+    operation_id = "Export Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+pub async fn export_gsm_rules() {}
@@ -62,6 +62,28 @@ pub async fn routing_link_config() {}
 )]
 pub async fn routing_retrieve_config() {}
 
+/// Routing - Simulate
+///
+/// Replay a routing algorithm over the payments of a profile in a time window, and compare the
+/// resulting connector distribution with the one that actually happened
+#[utoipa::path(
+    post,
+    path = "/routing/simulate",
+    request_body = RoutingSimulationRequest,
+    responses(
+        (status = 200, description = "Routing simulation completed", body = RoutingSimulationResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 412, description = "Routing algorithm cannot be simulated for the profile"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Simulate a routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_simulate_config() {}
+
 /// Routing - List
 ///
 /// List all routing configs
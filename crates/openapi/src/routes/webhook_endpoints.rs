@@ -0,0 +1,127 @@
+/// Webhook Endpoints - Create
+///
+/// Register a webhook endpoint on a business profile. Outgoing webhooks for the enabled event types
+/// are delivered to the endpoint, in addition to the webhook URL configured in the business profile.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    request_body = WebhookEndpointCreateRequest,
+    responses(
+        (status = 200, description = "Webhook Endpoint Created", body = WebhookEndpointResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Webhook Endpoints",
+    operation_id = "Create a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_endpoint_create() {}
+
+/// Webhook Endpoints - List
+///
+/// List the webhook endpoints registered on a business profile.
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoints Retrieved", body = Vec<WebhookEndpointResponse>)
+    ),
+    tag = "Webhook Endpoints",
+    operation_id = "List Webhook Endpoints",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_endpoint_list() {}
+
+/// Webhook Endpoints - Retrieve
+///
+/// Retrieve a webhook endpoint registered on a business profile.
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint Retrieved", body = WebhookEndpointResponse),
+        (status = 404, description = "Webhook Endpoint not found")
+    ),
+    tag = "Webhook Endpoints",
+    operation_id = "Retrieve a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_endpoint_retrieve() {}
+
+/// Webhook Endpoints - Update
+///
+/// Update a webhook endpoint registered on a business profile.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    request_body = WebhookEndpointUpdateRequest,
+    responses(
+        (status = 200, description = "Webhook Endpoint Updated", body = WebhookEndpointResponse),
+        (status = 404, description = "Webhook Endpoint not found")
+    ),
+    tag = "Webhook Endpoints",
+    operation_id = "Update a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_endpoint_update() {}
+
+/// Webhook Endpoints - Delete
+///
+/// Delete a webhook endpoint registered on a business profile.
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint Deleted", body = WebhookEndpointDeleteResponse),
+        (status = 404, description = "Webhook Endpoint not found")
+    ),
+    tag = "Webhook Endpoints",
+    operation_id = "Delete a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_endpoint_delete() {}
+
+/// Webhook Endpoints - Re-enable
+///
+/// Resume deliveries to a webhook endpoint which was suspended due to repeated delivery failures.
+/// The webhooks queued while the endpoint was suspended are scheduled for delivery.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}/reenable",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint Re-enabled", body = WebhookEndpointReenableResponse),
+        (status = 404, description = "Webhook Endpoint not found"),
+        (status = 412, description = "Webhook Endpoint is not suspended")
+    ),
+    tag = "Webhook Endpoints",
+    operation_id = "Re-enable a Webhook Endpoint",
+    security(("admin_api_key" = []))
+)]
+pub async fn webhook_endpoint_reenable() {}
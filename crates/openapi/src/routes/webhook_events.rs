@@ -97,3 +97,60 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Replay
+///
+/// Replay the delivery of all Events of a Business Profile matching the specified filters. The
+/// matching Events are replayed in the background, at the specified maximum rate.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/replay",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+    ),
+    request_body = WebhookReplayRequest,
+    responses(
+        (status = 200, description = "Replay job created successfully", body = WebhookReplayJobResponse),
+        (status = 400, description = "Invalid filters or rate limit specified"),
+        (status = 404, description = "No Events match the specified filters"),
+    ),
+    tag = "Event",
+    operation_id = "Replay Events matching the specified filters",
+    security(("admin_api_key" = []))
+)]
+pub fn replay_webhook_events() {}
+
+/// Events - Replay Job Retrieve
+///
+/// Retrieve the progress of a replay job, along with the outcome of replaying each of its Events.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/replay/{job_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("job_id" = String, Path, description = "The unique identifier for the replay job"),
+        (
+            "status" = Option<WebhookReplayEventStatus>,
+            Query,
+            description = "Only include Events with the specified replay status."
+        ),
+        (
+            "limit" = Option<u16>,
+            Query,
+            description = "The maximum number of Events to include in the response."
+        ),
+        (
+            "offset" = Option<u16>,
+            Query,
+            description = "The number of Events to skip when retrieving the list of Events."
+        ),
+    ),
+    responses(
+        (status = 200, description = "Replay job retrieved successfully", body = WebhookReplayJobStatusResponse),
+        (status = 404, description = "Replay job does not exist in our records"),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve a replay job",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_webhook_replay_job() {}
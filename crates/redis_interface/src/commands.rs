@@ -15,7 +15,10 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, ListInterface, SetsInterface, StreamsInterface},
+    interfaces::{
+        HashesInterface, KeysInterface, ListInterface, LuaInterface, SetsInterface,
+        StreamsInterface,
+    },
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
@@ -450,6 +453,17 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetHashFieldFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_hash_fields<V>(&self, key: &str) -> CustomResult<V, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        self.pool
+            .hgetall(self.add_prefix(key))
+            .await
+            .change_context(errors::RedisError::GetHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_hash_field_and_deserialize<V>(
         &self,
@@ -783,6 +797,30 @@ impl super::RedisConnectionPool {
             .await
             .change_context(errors::RedisError::ConsumerGroupClaimFailed)
     }
+
+    /// Runs the lua script atomically, with the prefix added to every key passed to it.
+    #[instrument(level = "DEBUG", skip(self, lua_script))]
+    pub async fn evaluate_redis_script<V, T>(
+        &self,
+        lua_script: &'static str,
+        keys: Vec<String>,
+        values: V,
+    ) -> CustomResult<T, errors::RedisError>
+    where
+        V: TryInto<MultipleValues> + Debug + Send + Sync,
+        V::Error: Into<fred::error::RedisError> + Send + Sync,
+        T: FromRedis,
+    {
+        let keys = keys
+            .iter()
+            .map(|key| self.add_prefix(key))
+            .collect::<Vec<_>>();
+
+        self.pool
+            .eval(lua_script, keys, values)
+            .await
+            .change_context(errors::RedisError::ScriptExecutionFailed)
+    }
 }
 
 #[cfg(test)]
@@ -78,4 +78,6 @@ pub enum RedisError {
     PopListElementsFailed,
     #[error("Failed to increment hash field in Redis")]
     IncrementHashFieldFailed,
+    #[error("Failed to execute lua script in Redis")]
+    ScriptExecutionFailed,
 }
@@ -284,18 +284,7 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                     workflows::tokenized_data::DeleteTokenizeDataWorkflow,
                 )),
                 storage::ProcessTrackerRunner::ApiKeyExpiryWorkflow => {
-                    #[cfg(feature = "email")]
-                    {
-                        Ok(Box::new(workflows::api_key_expiry::ApiKeyExpiryWorkflow))
-                    }
-
-                    #[cfg(not(feature = "email"))]
-                    {
-                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
-                            .attach_printable(
-                                "Cannot run API key expiry workflow when email feature is disabled",
-                            )
-                    }
+                    Ok(Box::new(workflows::api_key_expiry::ApiKeyExpiryWorkflow))
                 }
                 storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow => Ok(Box::new(
                     workflows::outgoing_webhook_retry::OutgoingWebhookRetryWorkflow,
@@ -320,6 +309,59 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PaymentMethodStatusUpdateWorkflow => Ok(Box::new(
                     workflows::payment_method_status_update::PaymentMethodStatusUpdateWorkflow,
                 )),
+                storage::ProcessTrackerRunner::PaymentsRequeueWorkflow => Ok(Box::new(
+                    workflows::payment_requeue::PaymentsRequeueWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PayoutsRequeueWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(workflows::payout_requeue::PayoutsRequeueWorkflow))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run payouts requeue workflow when payouts feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::RoutingActivationWorkflow => {
+                    #[cfg(all(
+                        any(feature = "v1", feature = "v2"),
+                        not(any(feature = "routing_v2", feature = "business_profile_v2"))
+                    ))]
+                    {
+                        Ok(Box::new(
+                            workflows::routing_activation::RoutingActivationWorkflow,
+                        ))
+                    }
+                    #[cfg(not(all(
+                        any(feature = "v1", feature = "v2"),
+                        not(any(feature = "routing_v2", feature = "business_profile_v2"))
+                    )))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run routing activation workflow when routing_v2 feature is enabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::WebhookReplayWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(workflows::webhook_replay::WebhookReplayWorkflow))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run webhook replay workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::ArchivedWebhookPurgeWorkflow => Ok(Box::new(
+                    workflows::archived_webhook_purge::ArchivedWebhookPurgeWorkflow,
+                )),
             }
         };
 
@@ -102,6 +102,7 @@ pub async fn setup_intents_retrieve(
         merchant_connector_details: None,
         client_secret: query_payload.client_secret.clone(),
         expand_attempts: None,
+        expose_routing_decision_trace: false,
         expand_captures: None,
     };
 
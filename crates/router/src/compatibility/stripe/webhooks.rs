@@ -17,10 +17,13 @@ use super::{
 use crate::{
     core::{
         errors,
-        webhooks::types::{OutgoingWebhookPayloadWithSignature, OutgoingWebhookType},
+        webhooks::types::{
+            OutgoingWebhookPayloadWithSignature, OutgoingWebhookType, WebhookSigningKeys,
+        },
     },
     headers,
     services::request::Maskable,
+    types::storage::enums,
 };
 
 #[derive(Serialize, Debug)]
@@ -35,13 +38,17 @@ pub struct StripeOutgoingWebhook {
 }
 
 impl OutgoingWebhookType for StripeOutgoingWebhook {
+    // Stripe compatible webhooks are always signed using the Stripe signature scheme
     fn get_outgoing_webhooks_signature(
         &self,
-        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        signing_keys: &WebhookSigningKeys,
+        _signature_scheme: enums::WebhookSignatureScheme,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
         let timestamp = self.created;
 
-        let payment_response_hash_key = payment_response_hash_key
+        let payment_response_hash_key = signing_keys
+            .current
+            .as_ref()
             .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
             .attach_printable("For stripe compatibility payment_response_hash_key is mandatory")?;
 
@@ -51,18 +58,24 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
             .attach_printable("failed encoding outgoing webhook payload")?;
 
         let new_signature_payload = format!("{timestamp}.{webhook_signature_payload}");
-        let v1 = hex::encode(
+        let sign = |key: &str| {
             common_utils::crypto::HmacSha256::sign_message(
                 &common_utils::crypto::HmacSha256,
-                payment_response_hash_key.as_ref(),
+                key.as_bytes(),
                 new_signature_payload.as_bytes(),
             )
             .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?,
-        );
+            .attach_printable("Failed to sign the message")
+            .map(hex::encode)
+        };
+        let v1 = sign(payment_response_hash_key)?;
 
         let t = timestamp;
-        let signature = Some(format!("t={t},v1={v1}"));
+        // While a key is being rotated out, a signature is included for the previous key as well
+        let signature = match &signing_keys.previous {
+            Some(previous_key) => Some(format!("t={t},v1={v1},v1={}", sign(previous_key)?)),
+            None => Some(format!("t={t},v1={v1}")),
+        };
 
         Ok(OutgoingWebhookPayloadWithSignature {
             payload: webhook_signature_payload.into(),
@@ -70,7 +83,11 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
         })
     }
 
-    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
+    fn add_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+        _signature_scheme: enums::WebhookSignatureScheme,
+    ) {
         header.push((
             headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE.to_string(),
             signature.into(),
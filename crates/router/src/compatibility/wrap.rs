@@ -6,7 +6,7 @@ use router_env::{instrument, tracing, Tag};
 use serde::Serialize;
 
 use crate::{
-    core::{api_locking, errors},
+    core::{api_locking, errors, idempotency},
     events::api_logs::ApiEventMetric,
     routes::{
         app::{AppStateInfo, ReqState},
@@ -54,6 +54,7 @@ where
             func,
             api_authentication,
             lock_action,
+            idempotency::IdempotencyAction::NotApplicable,
         ),
         &flow,
     )
@@ -179,6 +180,9 @@ where
                 }
             }
         }
+        Ok(api::ApplicationResponse::IdempotentReplay(response)) => {
+            api::http_response_idempotent_replay(*response)
+        }
         Err(error) => api::log_and_return_error_response(error),
     };
 
@@ -12,6 +12,7 @@ impl Default for super::settings::Server {
             host: "localhost".into(),
             request_body_limit: 16 * 1024, // POST request body is limited to 16KiB
             shutdown_timeout: 30,
+            trusted_proxies_count: 0,
             #[cfg(feature = "tls")]
             tls: None,
         }
@@ -135,6 +136,42 @@ impl Default for super::settings::KvConfig {
     }
 }
 
+impl Default for super::settings::CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_duration_in_secs: 300,
+            window_bucket_count: 10,
+            min_request_count: 20,
+            failure_rate_threshold: 50.0,
+            open_duration_in_secs: 60,
+            half_open_traffic_percent: 10,
+            half_open_success_count: 5,
+        }
+    }
+}
+
+impl Default for super::settings::WebhookEndpointHealthSettings {
+    fn default() -> Self {
+        Self {
+            auto_suspend_enabled: true,
+            consecutive_failures_threshold: 50,
+            success_rate_window: 100,
+            min_success_rate_percent: 10,
+        }
+    }
+}
+
+impl Default for super::settings::IncomingWebhookArchiveSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            deduplication_window_in_secs: 86400,
+            retention_period_in_days: 30,
+        }
+    }
+}
+
 use super::settings::{
     Mandates, SupportedConnectorsForMandate, SupportedPaymentMethodTypesForMandate,
     SupportedPaymentMethodsForMandate,
@@ -9487,6 +9524,9 @@ impl Default for super::settings::ApiKeys {
             #[cfg(feature = "email")]
             expiry_reminder_days: vec![7, 3, 1],
 
+            // Specifies the number of seconds a rotated API key remains valid for
+            rotation_grace_period_in_secs: 86400,
+
             // Hex-encoded key used for calculating checksum for partial auth
             #[cfg(feature = "partial-auth")]
             checksum_auth_key: String::new().into(),
@@ -113,6 +113,7 @@ impl SecretsHandler for settings::ApiKeys {
 
         #[cfg(feature = "email")]
         let expiry_reminder_days = api_keys.expiry_reminder_days.clone();
+        let rotation_grace_period_in_secs = api_keys.rotation_grace_period_in_secs;
 
         #[cfg(feature = "partial-auth")]
         let checksum_auth_context = secret_management_client
@@ -127,6 +128,7 @@ impl SecretsHandler for settings::ApiKeys {
             hash_key,
             #[cfg(feature = "email")]
             expiry_reminder_days,
+            rotation_grace_period_in_secs,
 
             #[cfg(feature = "partial-auth")]
             checksum_auth_key,
@@ -449,5 +451,7 @@ pub(crate) async fn fetch_raw_secrets(
         user_auth_methods,
         decision: conf.decision,
         locker_based_open_banking_connectors: conf.locker_based_open_banking_connectors,
+        circuit_breaker: conf.circuit_breaker,
+        rate_limit: conf.rate_limit,
     }
 }
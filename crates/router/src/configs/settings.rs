@@ -120,6 +120,8 @@ pub struct Settings<S: SecretState> {
     pub user_auth_methods: SecretStateContainer<UserAuthMethodSettings, S>,
     pub decision: Option<DecisionConfig>,
     pub locker_based_open_banking_connectors: LockerBasedRecipientConnectorList,
+    pub circuit_breaker: CircuitBreakerConfig,
+    pub rate_limit: RateLimitConfig,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -208,6 +210,59 @@ pub struct KvConfig {
     pub soft_kill: Option<bool>,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CircuitBreakerConfig {
+    /// Whether connectors with an open circuit are dropped from routing
+    pub enabled: bool,
+    /// Duration of the sliding window over which the failure rate of a connector is computed
+    pub window_duration_in_secs: u32,
+    /// Number of buckets the sliding window is divided into
+    pub window_bucket_count: u32,
+    /// Minimum number of connector calls in the window before the circuit can be opened
+    pub min_request_count: u32,
+    /// Percentage of technical failures in the window at which the circuit is opened
+    pub failure_rate_threshold: f64,
+    /// Duration for which the connector receives no traffic once its circuit is opened
+    pub open_duration_in_secs: u32,
+    /// Percentage of the payments routed to the connector while its circuit is half open
+    pub half_open_traffic_percent: u8,
+    /// Number of successful calls required while half open to close the circuit
+    pub half_open_success_count: u32,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RateLimitConfig {
+    /// Whether the requests of merchants are throttled
+    pub enabled: bool,
+    pub algorithm: RateLimitAlgorithm,
+    /// Limit on all the requests of a merchant
+    pub merchant: Option<RateLimit>,
+    /// Limit on the requests made with a single API key
+    pub api_key: Option<RateLimit>,
+    /// Limit on the requests of a merchant authenticated for the same business profile
+    pub profile: Option<RateLimit>,
+    /// Limits on the requests of a merchant to a route group, keyed by the route group name
+    pub route_groups: HashMap<String, RateLimit>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitAlgorithm {
+    /// Allows bursts of up to `requests`, refilled evenly over the window
+    #[default]
+    TokenBucket,
+    /// Allows at most `requests` within any window ending at the current request
+    SlidingWindow,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub window_in_secs: u32,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct KeyManagerConfig {
     pub enabled: Option<bool>,
@@ -586,6 +641,9 @@ pub struct Server {
     pub host: String,
     pub request_body_limit: usize,
     pub shutdown_timeout: u64,
+    /// The number of proxies in front of the server which append to the `X-Forwarded-For`
+    /// header, used to find the address of the client in it
+    pub trusted_proxies_count: usize,
     #[cfg(feature = "tls")]
     pub tls: Option<ServerTls>,
 }
@@ -644,6 +702,34 @@ pub struct DrainerSettings {
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
+    pub endpoint_health: WebhookEndpointHealthSettings,
+    pub incoming_archive: IncomingWebhookArchiveSettings,
+}
+
+/// Thresholds for suspending deliveries to webhook endpoints which fail repeatedly
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookEndpointHealthSettings {
+    pub auto_suspend_enabled: bool,
+    /// Number of consecutive failed deliveries after which an endpoint is suspended
+    pub consecutive_failures_threshold: u32,
+    /// Number of most recent deliveries the success rate of an endpoint is calculated over
+    pub success_rate_window: u16,
+    /// Success rate (in percent) below which an endpoint is suspended, once deliveries spanning
+    /// the entire window have been made
+    pub min_success_rate_percent: u8,
+}
+
+/// Archival and deduplication of incoming webhooks
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct IncomingWebhookArchiveSettings {
+    pub enabled: bool,
+    /// Duration (in seconds) within which source-verified webhooks resent by the connector are
+    /// ignored, once an identical webhook has been received, unless it failed to be processed
+    pub deduplication_window_in_secs: u32,
+    /// Number of days archived webhooks are retained for, after which they are purged
+    pub retention_period_in_days: u16,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -664,6 +750,10 @@ pub struct ApiKeys {
     #[cfg(feature = "email")]
     pub expiry_reminder_days: Vec<u8>,
 
+    // Specifies the number of seconds a rotated API key remains valid for, when a grace period is
+    // not provided in the rotation request
+    pub rotation_grace_period_in_secs: u32,
+
     #[cfg(feature = "partial-auth")]
     pub checksum_auth_context: Secret<String>,
 
@@ -804,6 +894,10 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.circuit_breaker.validate()?;
+        self.webhooks.endpoint_health.validate()?;
+        self.webhooks.incoming_archive.validate()?;
+        self.rate_limit.validate()?;
         self.events.validate()?;
 
         #[cfg(feature = "olap")]
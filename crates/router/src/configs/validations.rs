@@ -149,6 +149,16 @@ impl super::settings::ApiKeys {
             ))
         })?;
 
+        when(
+            self.rotation_grace_period_in_secs
+                > crate::consts::API_KEY_MAX_ROTATION_GRACE_PERIOD_IN_SECS,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "API key rotation grace period must not exceed 30 days".into(),
+                ))
+            },
+        )?;
+
         Ok(())
     }
 }
@@ -181,6 +191,106 @@ impl super::settings::LockSettings {
     }
 }
 
+impl super::settings::CircuitBreakerConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.window_duration_in_secs == 0 || self.window_bucket_count == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "circuit breaker window duration and bucket count must not be 0".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.failure_rate_threshold <= 0.0 || self.failure_rate_threshold > 100.0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "circuit breaker failure rate threshold must be within (0, 100]".into(),
+                ))
+            },
+        )?;
+
+        when(self.half_open_traffic_percent > 100, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "circuit breaker half open traffic percent must not exceed 100".into(),
+            ))
+        })?;
+
+        when(self.half_open_success_count == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "circuit breaker half open success count must not be 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::WebhookEndpointHealthSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.consecutive_failures_threshold == 0 || self.success_rate_window == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "webhook endpoint consecutive failures threshold and success rate window \
+                     must not be 0"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(self.min_success_rate_percent > 100, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhook endpoint minimum success rate percent must not exceed 100".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::IncomingWebhookArchiveSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.deduplication_window_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "incoming webhook deduplication window must not be 0".into(),
+            ))
+        })?;
+
+        when(self.retention_period_in_days == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "incoming webhook archive retention period must not be 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RateLimitConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.merchant
+            .iter()
+            .chain(self.api_key.iter())
+            .chain(self.profile.iter())
+            .chain(self.route_groups.values())
+            .try_for_each(super::settings::RateLimit::validate)
+    }
+}
+
+impl super::settings::RateLimit {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.requests == 0 || self.window_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit requests and window must not be 0".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::GenericLinkEnvConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
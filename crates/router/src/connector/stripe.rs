@@ -2387,6 +2387,19 @@ impl api::IncomingWebhook for Stripe {
         })
     }
 
+    fn get_webhook_event_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let details: stripe::WebhookEventIdBody =
+            request
+                .body
+                .parse_struct("WebhookEventIdBody")
+                .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+        Ok(Some(details.id))
+    }
+
     fn get_webhook_resource_object(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
@@ -3511,6 +3511,11 @@ pub struct WebhookEvent {
     pub event_data: WebhookEventData,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct WebhookEventIdBody {
+    pub id: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct WebhookEventTypeBody {
     #[serde(rename = "type")]
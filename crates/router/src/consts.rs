@@ -52,6 +52,12 @@ pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
 
+/// Maximum number of seconds a rotated API key can remain valid for (30 days)
+pub(crate) const API_KEY_MAX_ROTATION_GRACE_PERIOD_IN_SECS: u32 = 30 * 24 * 60 * 60;
+
+/// Minimum number of seconds between two updates of the last used time of a rotated API key
+pub(crate) const API_KEY_LAST_USED_UPDATE_INTERVAL_IN_SECS: i64 = 60;
+
 // Apple Pay validation url
 pub(crate) const APPLEPAY_VALIDATION_URL: &str =
     "https://apple-pay-gateway-cert.apple.com/paymentservices/startSession";
@@ -67,6 +73,26 @@ pub(crate) const METRICS_HOST_TAG_NAME: &str = "host";
 pub const MAX_ROUTING_CONFIGS_PER_MERCHANT: usize = 100;
 pub const ROUTING_CONFIG_ID_LENGTH: usize = 10;
 
+// Prefix of the redis keys holding the sliding window buckets of the success rate based routing
+pub const SUCCESS_RATE_WINDOW_PREFIX: &str = "SR_WINDOW";
+
+// Prefixes of the redis keys holding the failure windows and the states of the connector circuit breakers
+pub const CIRCUIT_BREAKER_WINDOW_PREFIX: &str = "CB_WINDOW";
+pub const CIRCUIT_BREAKER_OPEN_PREFIX: &str = "CB_OPEN";
+pub const CIRCUIT_BREAKER_TRIPPED_PREFIX: &str = "CB_TRIPPED";
+
+// Number of payments fetched at a time while simulating a routing algorithm
+pub const ROUTING_SIMULATION_BATCH_SIZE: u32 = 500;
+
+// Number of routing activations returned when no limit is provided
+pub const DEFAULT_ROUTING_HISTORY_LIMIT: u16 = 10;
+
+// Maximum number of payments that can be replayed in a single routing simulation
+pub const ROUTING_SIMULATION_MAX_PAYMENTS: u32 = 10000;
+
+// Basis points in a hundred percent, which is the highest percentage fee of a connector fee rule
+pub const MAX_FEE_BASIS_POINTS: u32 = 10000;
+
 pub const LOCKER_REDIS_PREFIX: &str = "LOCKER_PM_TOKEN";
 pub const LOCKER_REDIS_EXPIRY_SECONDS: u32 = 60 * 15; // 15 minutes
 
@@ -124,6 +150,17 @@ pub const POLL_ID_TTL: i64 = 900;
 pub const DEFAULT_POLL_DELAY_IN_SECS: i8 = 2;
 pub const DEFAULT_POLL_FREQUENCY: i8 = 5;
 
+// Default delay before a payment or payout requeued by GSM is attempted again.
+// This has to stay below `LOCKER_REDIS_EXPIRY_SECONDS`, since requeued payments read the payment method data back from the temporary locker
+pub const DEFAULT_REQUEUE_DELAY_IN_SECS: i64 = 5 * 60;
+pub const MAX_REQUEUE_DELAY_IN_SECS: i64 = 10 * 60;
+pub const REQUEUE_FAILURE_ERROR_MESSAGE: &str = "Requeued attempt could not be processed";
+
+// Retries of a requeued payment which could not be processed. Together with `MAX_REQUEUE_DELAY_IN_SECS`,
+// these have to stay below `LOCKER_REDIS_EXPIRY_SECONDS` as well
+pub const MAX_REQUEUE_RETRY_COUNT: i32 = 3;
+pub const REQUEUE_RETRY_DELAY_IN_SECS: i64 = 60;
+
 // Number of seconds to subtract from access token expiry
 pub(crate) const REDUCE_ACCESS_TOKEN_EXPIRY_TIME: u8 = 15;
 pub const CONNECTOR_CREDS_TOKEN_TTL: i64 = 900;
@@ -134,3 +171,21 @@ pub const MAX_ALLOWED_AMOUNT: i64 = 999999999;
 //payment attempt default unified error code and unified error message
 pub const DEFAULT_UNIFIED_ERROR_CODE: &str = "UE_000";
 pub const DEFAULT_UNIFIED_ERROR_MESSAGE: &str = "Something went wrong";
+
+// Maximum length allowed for the `Idempotency-Key` header
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+// Duration for which the response of an idempotent request is stored for replay
+pub const IDEMPOTENCY_KEY_EXPIRY_IN_SECS: i64 = 24 * 60 * 60; // 24 hours
+
+// Duration for which an idempotency key is claimed by the request being processed. This is well
+// above `REQUEST_TIME_OUT`, as a request can call the connector more than once, such as on retries
+pub const IDEMPOTENCY_CLAIM_EXPIRY_IN_SECS: i64 = 10 * 60; // 10 minutes
+
+// Time for which outgoing webhooks are signed with both the previous and the current key following
+// a rotation of the webhook signing key of a business profile
+pub const DEFAULT_WEBHOOK_SIGNING_KEY_ROTATION_WINDOW_IN_SECS: u32 = 24 * 60 * 60; // 24 hours
+pub const MAX_WEBHOOK_SIGNING_KEY_ROTATION_WINDOW_IN_SECS: u32 = 7 * 24 * 60 * 60; // 7 days
+
+// Prefix of the redis keys holding the token buckets and sliding windows of the rate limits
+pub const RATE_LIMIT_PREFIX: &str = "RATE_LIMIT";
@@ -2,6 +2,7 @@ pub mod admin;
 pub mod api_keys;
 pub mod api_locking;
 pub mod apple_pay_certificates_migration;
+pub mod audit_log;
 pub mod authentication;
 pub mod blocklist;
 pub mod cache;
@@ -21,6 +22,7 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+pub mod idempotency;
 pub mod locker_migration;
 pub mod mandate;
 pub mod metrics;
@@ -33,6 +35,7 @@ pub mod payout_link;
 pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
+pub mod rate_limit;
 pub mod refunds;
 pub mod routing;
 pub mod surcharge_decision_config;
@@ -32,6 +32,7 @@ use crate::types::transformers::ForeignFrom;
 use crate::{
     consts::{self, BASE64_ENGINE},
     core::{
+        audit_log::{self, ConfigChange},
         encryption::transfer_encryption_key,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payment_methods::{cards, transformers},
@@ -41,7 +42,7 @@ use crate::{
         utils as core_utils,
     },
     db::StorageInterface,
-    routes::{metrics, SessionState},
+    routes::{app::ReqState, metrics, SessionState},
     services::{self, api as service_api, authentication, pm_auth as payment_initiation_service},
     types::{
         self,
@@ -2065,6 +2066,10 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
                 state, &key_store, &metadata,
             )
             .await?,
+            fee_schedule: self
+                .fee_schedule
+                .map(get_fee_schedule_as_value)
+                .transpose()?,
         })
     }
 }
@@ -2204,6 +2209,10 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
                 state, &key_store, &metadata,
             )
             .await?,
+            fee_schedule: self
+                .fee_schedule
+                .map(get_fee_schedule_as_value)
+                .transpose()?,
         })
     }
 }
@@ -2357,6 +2366,7 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
                 None
             },
             version: hyperswitch_domain_models::consts::API_VERSION,
+            fee_schedule: get_fee_schedule_as_value(self.fee_schedule)?,
         })
     }
 
@@ -2527,6 +2537,7 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
                 None
             },
             version: hyperswitch_domain_models::consts::API_VERSION,
+            fee_schedule: get_fee_schedule_as_value(self.fee_schedule)?,
         })
     }
 
@@ -2583,6 +2594,7 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
 
 pub async fn create_connector(
     state: SessionState,
+    req_state: ReqState,
     req: api::MerchantConnectorCreate,
     merchant_id: &id_type::MerchantId,
 ) -> RouterResponse<api_models::admin::MerchantConnectorResponse> {
@@ -2745,7 +2757,23 @@ pub async fn create_connector(
         ]),
     );
 
-    let mca_response = mca.foreign_try_into()?;
+    let merchant_connector_id = mca.get_id();
+    let mca_response: api_models::admin::MerchantConnectorResponse = mca.foreign_try_into()?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_id),
+            entity_type: storage::enums::AuditEntityType::MerchantConnectorAccount,
+            entity_id: merchant_connector_id,
+            action: storage::enums::AuditAction::Create,
+            before: None,
+            after: Some(&mca_response),
+        },
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::Json(mca_response))
 }
 
@@ -2933,6 +2961,7 @@ pub async fn list_payment_connectors(
 
 pub async fn update_connector(
     state: SessionState,
+    req_state: ReqState,
     merchant_id: &id_type::MerchantId,
     profile_id: Option<String>,
     merchant_connector_id: &str,
@@ -2981,6 +3010,8 @@ pub async fn update_connector(
     let profile_id = mca.profile_id.clone();
 
     let request_connector_label = req.connector_label;
+    let mca_before_update: api_models::admin::MerchantConnectorResponse =
+        mca.clone().foreign_try_into()?;
 
     let updated_mca = db
         .update_merchant_connector_account(
@@ -3000,7 +3031,21 @@ pub async fn update_connector(
             format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
         })?;
 
-    let response = updated_mca.foreign_try_into()?;
+    let response: api_models::admin::MerchantConnectorResponse = updated_mca.foreign_try_into()?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_id),
+            entity_type: storage::enums::AuditEntityType::MerchantConnectorAccount,
+            entity_id: merchant_connector_id.to_owned(),
+            action: storage::enums::AuditAction::Update,
+            before: Some(&mca_before_update),
+            after: Some(&response),
+        },
+    )
+    .await;
 
     Ok(service_api::ApplicationResponse::Json(response))
 }
@@ -3011,6 +3056,7 @@ pub async fn update_connector(
 ))]
 pub async fn delete_connector(
     state: SessionState,
+    req_state: ReqState,
     merchant_id: id_type::MerchantId,
     merchant_connector_id: String,
 ) -> RouterResponse<api::MerchantConnectorDeleteResponse> {
@@ -3030,7 +3076,7 @@ pub async fn delete_connector(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-    let _mca = db
+    let mca = db
         .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
             key_manager_state,
             &merchant_id,
@@ -3052,6 +3098,23 @@ pub async fn delete_connector(
             id: merchant_connector_id.clone(),
         })?;
 
+    if is_deleted {
+        let deleted_mca: api_models::admin::MerchantConnectorResponse = mca.foreign_try_into()?;
+        audit_log::record_config_change(
+            &state,
+            &req_state,
+            ConfigChange {
+                merchant_id: Some(&merchant_id),
+                entity_type: storage::enums::AuditEntityType::MerchantConnectorAccount,
+                entity_id: merchant_connector_id.clone(),
+                action: storage::enums::AuditAction::Delete,
+                before: Some(&deleted_mca),
+                after: None,
+            },
+        )
+        .await;
+    }
+
     let response = api::MerchantConnectorDeleteResponse {
         merchant_id,
         merchant_connector_id,
@@ -3063,6 +3126,7 @@ pub async fn delete_connector(
 #[cfg(all(feature = "v2", feature = "merchant_connector_account_v2"))]
 pub async fn delete_connector(
     state: SessionState,
+    req_state: ReqState,
     merchant_id: id_type::MerchantId,
     id: String,
 ) -> RouterResponse<api::MerchantConnectorDeleteResponse> {
@@ -3103,6 +3167,23 @@ pub async fn delete_connector(
             id: id.clone(),
         })?;
 
+    if is_deleted {
+        let deleted_mca: api_models::admin::MerchantConnectorResponse = mca.foreign_try_into()?;
+        audit_log::record_config_change(
+            &state,
+            &req_state,
+            ConfigChange {
+                merchant_id: Some(&merchant_id),
+                entity_type: storage::enums::AuditEntityType::MerchantConnectorAccount,
+                entity_id: id.clone(),
+                action: storage::enums::AuditAction::Delete,
+                before: Some(&deleted_mca),
+                after: None,
+            },
+        )
+        .await;
+    }
+
     let response = api::MerchantConnectorDeleteResponse {
         merchant_id,
         id,
@@ -3270,6 +3351,31 @@ pub fn get_frm_config_as_secret(
     }
 }
 
+/// Validates the fee schedule of a connector account and converts it into the form it is stored in
+pub fn get_fee_schedule_as_value(
+    fee_schedule: Option<api_models::admin::ConnectorFeeSchedule>,
+) -> RouterResult<Option<serde_json::Value>> {
+    fee_schedule
+        .map(|fee_schedule| {
+            let has_invalid_fee = fee_schedule.rules.iter().any(|rule| {
+                rule.fixed_fee.get_amount_as_i64() < 0
+                    || rule.percentage_fee_basis_points > consts::MAX_FEE_BASIS_POINTS
+            });
+            if has_invalid_fee {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "fixed_fee can't be negative and percentage_fee_basis_points can't be more than 10000 in fee_schedule".to_string(),
+                }
+                .into());
+            }
+
+            fee_schedule
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the fee schedule of the connector account")
+        })
+        .transpose()
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "business_profile_v2")
@@ -3344,6 +3450,10 @@ impl BusinessProfileCreateBridge for api::BusinessProfileCreate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(retry_policy) = &self.retry_policy {
+            helpers::validate_retry_policy(retry_policy)?;
+        }
+
         if let Some(intent_fulfillment_expiry) = self.intent_fulfillment_time {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
@@ -3451,6 +3561,10 @@ impl BusinessProfileCreateBridge for api::BusinessProfileCreate {
                 .or(Some(false)),
             outgoing_webhook_custom_http_headers: outgoing_webhook_custom_http_headers
                 .map(Into::into),
+            retry_policy: self.retry_policy.map(ForeignInto::foreign_into),
+            webhook_signature_scheme: self.webhook_signature_scheme,
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
         })
     }
 
@@ -3465,6 +3579,10 @@ impl BusinessProfileCreateBridge for api::BusinessProfileCreate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(retry_policy) = &self.retry_policy {
+            helpers::validate_retry_policy(retry_policy)?;
+        }
+
         // Generate a unique profile id
         // TODO: the profile_id should be generated from the profile_name
         let profile_id = common_utils::generate_id_with_default_len("pro");
@@ -3538,6 +3656,10 @@ impl BusinessProfileCreateBridge for api::BusinessProfileCreate {
                 .or(Some(false)),
             outgoing_webhook_custom_http_headers: outgoing_webhook_custom_http_headers
                 .map(Into::into),
+            retry_policy: self.retry_policy.map(ForeignInto::foreign_into),
+            webhook_signature_scheme: self.webhook_signature_scheme,
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
             routing_algorithm_id: None,
             frm_routing_algorithm_id: None,
             payout_routing_algorithm_id: None,
@@ -3554,6 +3676,7 @@ impl BusinessProfileCreateBridge for api::BusinessProfileCreate {
 #[cfg(feature = "olap")]
 pub async fn create_business_profile(
     state: SessionState,
+    req_state: ReqState,
     request: api::BusinessProfileCreate,
     merchant_id: &id_type::MerchantId,
 ) -> RouterResponse<api_models::admin::BusinessProfileResponse> {
@@ -3614,10 +3737,27 @@ pub async fn create_business_profile(
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
     }
 
-    Ok(service_api::ApplicationResponse::Json(
+    let business_profile_response =
         api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile)
             .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to parse business profile details")?,
+            .attach_printable("Failed to parse business profile details")?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_id),
+            entity_type: storage::enums::AuditEntityType::BusinessProfile,
+            entity_id: profile_id,
+            action: storage::enums::AuditAction::Create,
+            before: None,
+            after: Some(&business_profile_response),
+        },
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(
+        business_profile_response,
     ))
 }
 
@@ -3681,17 +3821,60 @@ pub async fn retrieve_business_profile(
 
 pub async fn delete_business_profile(
     state: SessionState,
+    req_state: ReqState,
     profile_id: String,
     merchant_id: &id_type::MerchantId,
 ) -> RouterResponse<bool> {
     let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &key_store,
+            merchant_id,
+            &profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.clone(),
+        })?;
+
     let delete_result = db
         .delete_business_profile_by_profile_id_merchant_id(&profile_id, merchant_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
-            id: profile_id,
+            id: profile_id.clone(),
         })?;
 
+    if delete_result {
+        let deleted_business_profile =
+            api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse business profile details")?;
+
+        audit_log::record_config_change(
+            &state,
+            &req_state,
+            ConfigChange {
+                merchant_id: Some(merchant_id),
+                entity_type: storage::enums::AuditEntityType::BusinessProfile,
+                entity_id: profile_id,
+                action: storage::enums::AuditAction::Delete,
+                before: Some(&deleted_business_profile),
+                after: None,
+            },
+        )
+        .await;
+    }
+
     Ok(service_api::ApplicationResponse::Json(delete_result))
 }
 
@@ -3721,6 +3904,10 @@ impl BusinessProfileUpdateBridge for api::BusinessProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(Some(retry_policy)) = &self.retry_policy {
+            helpers::validate_retry_policy(retry_policy)?;
+        }
+
         if let Some(intent_fulfillment_expiry) = self.intent_fulfillment_time {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
@@ -3809,6 +3996,10 @@ impl BusinessProfileUpdateBridge for api::BusinessProfileUpdate {
                 is_connector_agnostic_mit_enabled: self.is_connector_agnostic_mit_enabled,
                 outgoing_webhook_custom_http_headers: outgoing_webhook_custom_http_headers
                     .map(Into::into),
+                retry_policy: self
+                    .retry_policy
+                    .map(|retry_policy| retry_policy.map(ForeignInto::foreign_into)),
+                webhook_signature_scheme: self.webhook_signature_scheme,
             },
         )))
     }
@@ -3826,6 +4017,10 @@ impl BusinessProfileUpdateBridge for api::BusinessProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(Some(retry_policy)) = &self.retry_policy {
+            helpers::validate_retry_policy(retry_policy)?;
+        }
+
         let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
 
         let payment_link_config = self
@@ -3893,6 +4088,10 @@ impl BusinessProfileUpdateBridge for api::BusinessProfileUpdate {
                 is_connector_agnostic_mit_enabled: self.is_connector_agnostic_mit_enabled,
                 outgoing_webhook_custom_http_headers: outgoing_webhook_custom_http_headers
                     .map(Into::into),
+                retry_policy: self
+                    .retry_policy
+                    .map(|retry_policy| retry_policy.map(ForeignInto::foreign_into)),
+                webhook_signature_scheme: self.webhook_signature_scheme,
                 order_fulfillment_time: self
                     .order_fulfillment_time
                     .map(|order_fulfillment_time| order_fulfillment_time.into_inner()),
@@ -3905,6 +4104,7 @@ impl BusinessProfileUpdateBridge for api::BusinessProfileUpdate {
 #[cfg(feature = "olap")]
 pub async fn update_business_profile(
     state: SessionState,
+    req_state: ReqState,
     profile_id: &str,
     merchant_id: &id_type::MerchantId,
     request: api::BusinessProfileUpdate,
@@ -3938,6 +4138,11 @@ pub async fn update_business_profile(
         .get_update_business_profile_object(&state, &key_store)
         .await?;
 
+    let business_profile_before_update =
+        api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile.clone())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse business profile details")?;
+
     let updated_business_profile = db
         .update_business_profile_by_profile_id(
             key_manager_state,
@@ -3950,10 +4155,27 @@ pub async fn update_business_profile(
             id: profile_id.to_owned(),
         })?;
 
-    Ok(service_api::ApplicationResponse::Json(
+    let business_profile_response =
         api_models::admin::BusinessProfileResponse::foreign_try_from(updated_business_profile)
             .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to parse business profile details")?,
+            .attach_printable("Failed to parse business profile details")?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_id),
+            entity_type: storage::enums::AuditEntityType::BusinessProfile,
+            entity_id: profile_id.to_owned(),
+            action: storage::enums::AuditAction::Update,
+            before: Some(&business_profile_before_update),
+            after: Some(&business_profile_response),
+        },
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(
+        business_profile_response,
     ))
 }
 
@@ -4216,6 +4438,117 @@ pub async fn connector_agnostic_mit_toggle(
     ))
 }
 
+pub async fn rotate_webhook_signing_key(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &str,
+    request: admin_types::WebhookSigningKeyRotateRequest,
+) -> RouterResponse<admin_types::WebhookSigningKeyRotateResponse> {
+    let rotation_window_in_secs = request
+        .rotation_window_in_secs
+        .unwrap_or(consts::DEFAULT_WEBHOOK_SIGNING_KEY_ROTATION_WINDOW_IN_SECS);
+    if rotation_window_in_secs > consts::MAX_WEBHOOK_SIGNING_KEY_ROTATION_WINDOW_IN_SECS {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`rotation_window_in_secs` must be at most {}",
+                consts::MAX_WEBHOOK_SIGNING_KEY_ROTATION_WINDOW_IN_SECS
+            ),
+        }
+        .into());
+    }
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the key store by merchant_id")?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(key_manager_state, &key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+
+    if business_profile.merchant_id != *merchant_id {
+        Err(errors::ApiErrorResponse::AccessForbidden {
+            resource: profile_id.to_string(),
+        })?
+    }
+
+    let payment_response_hash_key = request
+        .payment_response_hash_key
+        .map(ExposeInterface::expose)
+        .unwrap_or_else(|| {
+            common_utils::crypto::generate_cryptographically_secure_random_string(64)
+        });
+
+    // The previous key remains valid only until the end of the rotation window, a window of zero
+    // seconds replaces the key immediately
+    let previous_payment_response_hash_key = business_profile.payment_response_hash_key.clone();
+    let previous_payment_response_hash_key_expires_at = date_time::now()
+        .saturating_add(time::Duration::seconds(i64::from(rotation_window_in_secs)));
+
+    let business_profile_before_update =
+        api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile.clone())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse business profile details")?;
+
+    let business_profile_update = domain::BusinessProfileUpdate::WebhookSigningKeyRotation {
+        payment_response_hash_key: payment_response_hash_key.clone(),
+        previous_payment_response_hash_key: previous_payment_response_hash_key.clone(),
+        previous_payment_response_hash_key_expires_at,
+    };
+
+    let updated_business_profile = db
+        .update_business_profile_by_profile_id(
+            key_manager_state,
+            &key_store,
+            business_profile,
+            business_profile_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    let business_profile_after_update =
+        api_models::admin::BusinessProfileResponse::foreign_try_from(updated_business_profile)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse business profile details")?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_id),
+            entity_type: storage::enums::AuditEntityType::BusinessProfile,
+            entity_id: profile_id.to_owned(),
+            action: storage::enums::AuditAction::Update,
+            before: Some(&business_profile_before_update),
+            after: Some(&business_profile_after_update),
+        },
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(
+        admin_types::WebhookSigningKeyRotateResponse {
+            profile_id: profile_id.to_owned(),
+            payment_response_hash_key: Secret::new(payment_response_hash_key),
+            previous_payment_response_hash_key_expires_at: previous_payment_response_hash_key
+                .map(|_| previous_payment_response_hash_key_expires_at),
+        },
+    ))
+}
+
 pub async fn transfer_key_store_to_key_manager(
     state: SessionState,
     req: admin_types::MerchantKeyTransferRequest,
@@ -1,25 +1,33 @@
+use std::net::IpAddr;
+
 use common_utils::date_time;
 #[cfg(feature = "email")]
 use diesel_models::{api_keys::ApiKey, enums as storage_enums};
 use error_stack::{report, ResultExt};
+use ipnet::IpNet;
 use masking::{PeekInterface, StrongSecret};
-use router_env::{instrument, metrics::add_attributes, tracing};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
 
 use crate::{
     configs::settings,
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
-    routes::{metrics, SessionState},
-    services::{authentication, ApplicationResponse},
-    types::{api, storage, transformers::ForeignInto},
+    core::{
+        audit_log::{self, ConfigChange},
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    routes::{app::ReqState, metrics, SessionState},
+    services::{authentication, authorization::permissions::Permission, ApplicationResponse},
+    types::{
+        api, storage,
+        transformers::{ForeignFrom, ForeignInto},
+    },
     utils,
 };
 
-#[cfg(feature = "email")]
 const API_KEY_EXPIRY_TAG: &str = "API_KEY";
 #[cfg(feature = "email")]
 const API_KEY_EXPIRY_NAME: &str = "API_KEY_EXPIRY";
-#[cfg(feature = "email")]
+pub const API_KEY_RETIREMENT_NAME: &str = "API_KEY_RETIREMENT";
 const API_KEY_EXPIRY_RUNNER: diesel_models::ProcessTrackerRunner =
     diesel_models::ProcessTrackerRunner::ApiKeyExpiryWorkflow;
 
@@ -111,6 +119,7 @@ impl PlaintextApiKey {
 #[instrument(skip_all)]
 pub async fn create_api_key(
     state: SessionState,
+    req_state: ReqState,
     api_key: api::CreateApiKeyRequest,
     merchant_id: common_utils::id_type::MerchantId,
 ) -> RouterResponse<api::CreateApiKeyResponse> {
@@ -129,6 +138,9 @@ pub async fn create_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
+    let permissions = get_permissions_to_store(api_key.permissions)?;
+    let ip_allowlist = get_ip_allowlist_to_store(api_key.ip_allowlist)?;
+
     let hash_key = api_key_config.get_hash_key()?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -141,6 +153,10 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        permissions,
+        ip_allowlist,
+        predecessor_key_id: None,
+        successor_key_id: None,
     };
 
     let api_key = store
@@ -169,6 +185,20 @@ pub async fn create_api_key(
         authentication::decision::ADD,
     );
 
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(&merchant_id),
+            entity_type: storage::enums::AuditEntityType::ApiKey,
+            entity_id: api_key.key_id.clone(),
+            action: storage::enums::AuditAction::Create,
+            before: None,
+            after: Some(&api::RetrieveApiKeyResponse::foreign_from(api_key.clone())),
+        },
+    )
+    .await;
+
     metrics::API_KEY_CREATED.add(
         &metrics::CONTEXT,
         1,
@@ -283,21 +313,48 @@ pub async fn retrieve_api_key(
 #[instrument(skip_all)]
 pub async fn update_api_key(
     state: SessionState,
+    req_state: ReqState,
     api_key: api::UpdateApiKeyRequest,
 ) -> RouterResponse<api::RetrieveApiKeyResponse> {
     let merchant_id = api_key.merchant_id.clone();
     let key_id = api_key.key_id.clone();
     let store = state.store.as_ref();
 
+    let existing_api_key = store
+        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?; // If retrieve returned `None`
+
+    let api_key_update = storage::ApiKeyUpdate::Update {
+        name: api_key.name,
+        description: api_key.description,
+        expires_at: api_key.expiration.map(Into::into),
+        last_used: None,
+        permissions: get_permissions_to_update(api_key.permissions)?,
+        ip_allowlist: get_ip_allowlist_to_update(api_key.ip_allowlist)?,
+    };
+
     let api_key = store
-        .update_api_key(
-            merchant_id.to_owned(),
-            key_id.to_owned(),
-            api_key.foreign_into(),
-        )
+        .update_api_key(merchant_id.to_owned(), key_id.to_owned(), api_key_update)
         .await
         .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(&merchant_id),
+            entity_type: storage::enums::AuditEntityType::ApiKey,
+            entity_id: key_id.clone(),
+            action: storage::enums::AuditAction::Update,
+            before: Some(&api::RetrieveApiKeyResponse::foreign_from(existing_api_key)),
+            after: Some(&api::RetrieveApiKeyResponse::foreign_from(api_key.clone())),
+        },
+    )
+    .await;
+
     let state_inner = state.clone();
     let hashed_api_key = api_key.hashed_api_key.clone();
     let key_id_inner = api_key.key_id.clone();
@@ -438,6 +495,7 @@ pub async fn update_api_key_expiry_task(
 #[instrument(skip_all)]
 pub async fn revoke_api_key(
     state: SessionState,
+    req_state: ReqState,
     merchant_id: &common_utils::id_type::MerchantId,
     key_id: &str,
 ) -> RouterResponse<api::RevokeApiKeyResponse> {
@@ -454,16 +512,35 @@ pub async fn revoke_api_key(
         .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
     if let Some(api_key) = api_key {
-        let hashed_api_key = api_key.hashed_api_key;
-        let state = state.clone();
+        let hashed_api_key = api_key.hashed_api_key.clone();
+        let state_inner = state.clone();
 
         authentication::decision::spawn_tracked_job(
             async move {
-                authentication::decision::revoke_api_key(&state, hashed_api_key.into_inner().into())
-                    .await
+                authentication::decision::revoke_api_key(
+                    &state_inner,
+                    hashed_api_key.into_inner().into(),
+                )
+                .await
             },
             authentication::decision::REVOKE,
         );
+
+        if revoked {
+            audit_log::record_config_change(
+                &state,
+                &req_state,
+                ConfigChange {
+                    merchant_id: Some(merchant_id),
+                    entity_type: storage::enums::AuditEntityType::ApiKey,
+                    entity_id: key_id.to_owned(),
+                    action: storage::enums::AuditAction::Delete,
+                    before: Some(&api::RetrieveApiKeyResponse::foreign_from(api_key)),
+                    after: None,
+                },
+            )
+            .await;
+        }
     }
 
     metrics::API_KEY_REVOKED.add(&metrics::CONTEXT, 1, &[]);
@@ -522,6 +599,297 @@ pub async fn revoke_api_key_expiry_task(
     Ok(())
 }
 
+#[instrument(skip_all)]
+pub async fn rotate_api_key(
+    state: SessionState,
+    req_state: ReqState,
+    rotate_request: api::RotateApiKeyRequest,
+) -> RouterResponse<api::RotateApiKeyResponse> {
+    let api_key_config = state.conf.api_keys.get_inner();
+    let store = state.store.as_ref();
+    let merchant_id = rotate_request.merchant_id;
+
+    let predecessor = store
+        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &rotate_request.key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?; // If retrieve returned `None`
+
+    if let Some(successor_key_id) = &predecessor.successor_key_id {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "API key has already been rotated, rotate its successor `{successor_key_id}` instead"
+            ),
+        }));
+    }
+
+    let current_time = date_time::now();
+    if predecessor
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= current_time)
+    {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Expired API keys cannot be rotated".to_string(),
+        }));
+    }
+
+    let grace_period_in_secs = rotate_request
+        .grace_period_in_secs
+        .unwrap_or(api_key_config.rotation_grace_period_in_secs);
+    utils::when(
+        grace_period_in_secs > consts::API_KEY_MAX_ROTATION_GRACE_PERIOD_IN_SECS,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`grace_period_in_secs` must not exceed {} seconds",
+                    consts::API_KEY_MAX_ROTATION_GRACE_PERIOD_IN_SECS
+                ),
+            }))
+        },
+    )?;
+
+    // The rotated API key never remains valid for longer than it originally would have
+    let grace_period_ends_at =
+        current_time.saturating_add(time::Duration::seconds(i64::from(grace_period_in_secs)));
+    let predecessor_expires_at = predecessor
+        .expires_at
+        .map_or(grace_period_ends_at, |expires_at| {
+            expires_at.min(grace_period_ends_at)
+        });
+
+    let hash_key = api_key_config.get_hash_key()?;
+    let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
+    let successor = storage::ApiKeyNew {
+        key_id: PlaintextApiKey::new_key_id(),
+        merchant_id: merchant_id.to_owned(),
+        name: predecessor.name.clone(),
+        description: predecessor.description.clone(),
+        hashed_api_key: plaintext_api_key.keyed_hash(hash_key.peek()).into(),
+        prefix: plaintext_api_key.prefix(),
+        created_at: current_time,
+        expires_at: rotate_request
+            .expiration
+            .map_or(predecessor.expires_at, Into::into),
+        last_used: None,
+        permissions: predecessor.permissions.clone(),
+        ip_allowlist: predecessor.ip_allowlist.clone(),
+        predecessor_key_id: Some(predecessor.key_id.clone()),
+        successor_key_id: None,
+    };
+
+    let predecessor_before_rotation =
+        api::RetrieveApiKeyResponse::foreign_from(predecessor.clone());
+    let (successor, predecessor) = store
+        .rotate_api_key(&predecessor.key_id, predecessor_expires_at, successor)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                // The API key has been rotated or revoked since it was retrieved
+                error.change_context(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "API key has already been rotated or has been revoked".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to rotate API key")
+            }
+        })?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(&merchant_id),
+            entity_type: storage::enums::AuditEntityType::ApiKey,
+            entity_id: successor.key_id.clone(),
+            action: storage::enums::AuditAction::Create,
+            before: None,
+            after: Some(&api::RetrieveApiKeyResponse::foreign_from(
+                successor.clone(),
+            )),
+        },
+    )
+    .await;
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(&merchant_id),
+            entity_type: storage::enums::AuditEntityType::ApiKey,
+            entity_id: predecessor.key_id.clone(),
+            action: storage::enums::AuditAction::Rotate,
+            before: Some(&predecessor_before_rotation),
+            after: Some(&api::RetrieveApiKeyResponse::foreign_from(
+                predecessor.clone(),
+            )),
+        },
+    )
+    .await;
+
+    // Both API keys need to be updated in the decision service, as the expiry of the rotated API
+    // key has changed
+    for api_key in [&successor, &predecessor] {
+        let state_inner = state.clone();
+        let hashed_api_key = api_key.hashed_api_key.clone();
+        let merchant_id_inner = merchant_id.clone();
+        let key_id = api_key.key_id.clone();
+        let expires_at = api_key.expires_at;
+
+        authentication::decision::spawn_tracked_job(
+            async move {
+                authentication::decision::add_api_key(
+                    &state_inner,
+                    hashed_api_key.into_inner().into(),
+                    merchant_id_inner,
+                    key_id,
+                    expires_at.map(authentication::decision::convert_expiry),
+                )
+                .await
+            },
+            authentication::decision::ADD,
+        );
+    }
+
+    add_api_key_retirement_task(store, &predecessor)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert API key retirement task to process tracker")?;
+
+    metrics::API_KEY_ROTATED.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("merchant", merchant_id.get_string_repr().to_owned())]),
+    );
+
+    // The expiry reminders of the rotated API key are no longer relevant, as it is going to be
+    // revoked once the grace period is over. Reminders are set up for the successor instead.
+    #[cfg(feature = "email")]
+    {
+        let task_id = generate_task_id_for_api_key_expiry_workflow(&predecessor.key_id);
+        let existing_process_tracker_task = store
+            .find_process_by_id(task_id.as_str())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+            .attach_printable(
+                "Failed to retrieve API key expiry reminder task from process tracker",
+            )?;
+
+        if existing_process_tracker_task.is_some() {
+            revoke_api_key_expiry_task(store, &predecessor.key_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to revoke API key expiry reminder task in process tracker",
+                )?;
+        }
+
+        if successor.expires_at.is_some() {
+            let expiry_reminder_days = api_key_config.expiry_reminder_days.clone();
+
+            add_api_key_expiry_task(store, &successor, expiry_reminder_days)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert API key expiry reminder to process tracker")?;
+        }
+    }
+
+    Ok(ApplicationResponse::Json(api::RotateApiKeyResponse {
+        predecessor_key_id: predecessor.key_id,
+        predecessor_expires_at,
+        successor: (successor, plaintext_api_key).foreign_into(),
+    }))
+}
+
+// Add api_key_retirement task to the process_tracker table, which is run by the api_key_expiry
+// workflow. The task revokes a rotated API key once its grace period is over.
+#[instrument(skip_all)]
+pub async fn add_api_key_retirement_task(
+    store: &dyn crate::db::StorageInterface,
+    api_key: &storage::ApiKey,
+) -> Result<(), errors::ProcessTrackerError> {
+    let (Some(schedule_time), Some(successor_key_id)) =
+        (api_key.expires_at, api_key.successor_key_id.clone())
+    else {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("API key to be retired is missing its expiry or successor")
+            .into());
+    };
+
+    let api_key_retirement_tracker = storage::ApiKeyRetirementTrackingData {
+        key_id: api_key.key_id.clone(),
+        merchant_id: api_key.merchant_id.clone(),
+        successor_key_id,
+    };
+
+    let process_tracker_id =
+        generate_task_id_for_api_key_retirement_workflow(api_key.key_id.as_str());
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        API_KEY_RETIREMENT_NAME,
+        API_KEY_EXPIRY_RUNNER,
+        [API_KEY_EXPIRY_TAG],
+        api_key_retirement_tracker,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct API key retirement process tracker task")?;
+
+    store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting API key retirement task to process_tracker: api_key_id: {}",
+                api_key.key_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "ApiKeyRetirement")]),
+    );
+
+    Ok(())
+}
+
+/// Records the usage of an API key which has been rotated, so that it is known whether the
+/// predecessor is still in use before its grace period is over. The last used time is updated at
+/// most once every [`consts::API_KEY_LAST_USED_UPDATE_INTERVAL_IN_SECS`] seconds.
+pub async fn track_rotated_api_key_usage(
+    store: &dyn crate::db::StorageInterface,
+    api_key: &storage::ApiKey,
+) {
+    metrics::ROTATED_API_KEY_USED.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("merchant", api_key.merchant_id.get_string_repr().to_owned())]),
+    );
+
+    let current_time = date_time::now();
+    let is_update_due = api_key.last_used.map_or(true, |last_used| {
+        current_time - last_used
+            >= time::Duration::seconds(consts::API_KEY_LAST_USED_UPDATE_INTERVAL_IN_SECS)
+    });
+
+    if is_update_due {
+        store
+            .update_api_key(
+                api_key.merchant_id.clone(),
+                api_key.key_id.clone(),
+                storage::ApiKeyUpdate::LastUsedUpdate {
+                    last_used: current_time,
+                },
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to update last used time of rotated API key")
+            })
+            .ok();
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn list_api_keys(
     state: SessionState,
@@ -543,11 +911,110 @@ pub async fn list_api_keys(
     Ok(ApplicationResponse::Json(api_keys))
 }
 
+fn get_permissions_to_store(
+    permissions: Option<Vec<api_models::user_role::Permission>>,
+) -> RouterResult<Option<Vec<String>>> {
+    permissions
+        .map(|permissions| {
+            common_utils::fp_utils::when(permissions.is_empty(), || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "`permissions` must contain at least one permission".to_string(),
+                }))
+            })?;
+
+            let mut permissions = permissions
+                .into_iter()
+                .map(|permission| Permission::from(permission).to_string())
+                .collect::<Vec<_>>();
+            permissions.sort();
+            permissions.dedup();
+
+            Ok(permissions)
+        })
+        .transpose()
+}
+
+fn get_ip_allowlist_to_store(
+    ip_allowlist: Option<Vec<String>>,
+) -> RouterResult<Option<Vec<String>>> {
+    ip_allowlist
+        .map(|ip_allowlist| {
+            common_utils::fp_utils::when(ip_allowlist.is_empty(), || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "`ip_allowlist` must contain at least one IP address or CIDR range"
+                        .to_string(),
+                }))
+            })?;
+
+            ip_allowlist
+                .iter()
+                .map(|entry| {
+                    parse_ip_allowlist_entry(entry)
+                        .map(|network| network.to_string())
+                        .ok_or(report!(errors::ApiErrorResponse::InvalidRequestData {
+                            message: format!(
+                                "`{entry}` is not a valid IP address or CIDR range in `ip_allowlist`"
+                            ),
+                        }))
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+/// Permissions are left unchanged if not provided, an empty list or `null` removes the permissions
+/// of the API key, granting it access to all the operations.
+fn get_permissions_to_update(
+    permissions: Option<Option<Vec<api_models::user_role::Permission>>>,
+) -> RouterResult<Option<Option<Vec<String>>>> {
+    permissions
+        .map(|permissions| {
+            get_permissions_to_store(permissions.filter(|permissions| !permissions.is_empty()))
+        })
+        .transpose()
+}
+
+/// The IP allowlist is left unchanged if not provided, an empty list or `null` removes the
+/// allowlist of the API key, allowing it to be used from any IP address.
+fn get_ip_allowlist_to_update(
+    ip_allowlist: Option<Option<Vec<String>>>,
+) -> RouterResult<Option<Option<Vec<String>>>> {
+    ip_allowlist
+        .map(|ip_allowlist| {
+            get_ip_allowlist_to_store(ip_allowlist.filter(|ip_allowlist| !ip_allowlist.is_empty()))
+        })
+        .transpose()
+}
+
+/// Parses an entry of an API key IP allowlist, which is either a CIDR range or a single IP
+/// address. Host bits set in a CIDR range are ignored.
+fn parse_ip_allowlist_entry(entry: &str) -> Option<IpNet> {
+    let entry = entry.trim();
+
+    entry
+        .parse::<IpNet>()
+        .map(|network| network.trunc())
+        .or_else(|_| entry.parse::<IpAddr>().map(IpNet::from))
+        .ok()
+}
+
+/// Checks whether the IP address is allowed by any of the entries of an API key IP allowlist.
+pub fn is_ip_address_allowed(ip_allowlist: &[String], ip_address: IpAddr) -> bool {
+    ip_allowlist
+        .iter()
+        .filter_map(|entry| parse_ip_allowlist_entry(entry))
+        .any(|network| network.contains(&ip_address))
+}
+
 #[cfg(feature = "email")]
 fn generate_task_id_for_api_key_expiry_workflow(key_id: &str) -> String {
     format!("{API_KEY_EXPIRY_RUNNER}_{API_KEY_EXPIRY_NAME}_{key_id}")
 }
 
+fn generate_task_id_for_api_key_retirement_workflow(key_id: &str) -> String {
+    format!("{API_KEY_EXPIRY_RUNNER}_{API_KEY_RETIREMENT_NAME}_{key_id}")
+}
+
 impl From<&str> for PlaintextApiKey {
     fn from(s: &str) -> Self {
         Self(s.to_owned().into())
@@ -593,4 +1060,81 @@ mod tests {
         let new_hashed_api_key = plaintext_api_key.keyed_hash(hash_key.peek());
         assert_eq!(hashed_api_key, new_hashed_api_key)
     }
+
+    #[test]
+    fn test_ip_allowlist_validation() {
+        let ip_allowlist = get_ip_allowlist_to_store(Some(vec![
+            "203.0.113.7/24".to_string(),
+            "198.51.100.10".to_string(),
+            "2001:db8::/32".to_string(),
+        ]))
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            ip_allowlist,
+            vec!["203.0.113.0/24", "198.51.100.10/32", "2001:db8::/32"]
+        );
+        assert!(get_ip_allowlist_to_store(Some(vec!["300.0.0.1/8".to_string()])).is_err());
+        assert!(get_ip_allowlist_to_store(Some(vec![])).is_err());
+        assert!(get_ip_allowlist_to_store(None).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_is_ip_address_allowed() {
+        let ip_allowlist = vec!["203.0.113.0/24".to_string(), "2001:db8::/32".to_string()];
+
+        assert!(is_ip_address_allowed(
+            &ip_allowlist,
+            "203.0.113.42".parse().unwrap()
+        ));
+        assert!(is_ip_address_allowed(
+            &ip_allowlist,
+            "2001:db8::1".parse().unwrap()
+        ));
+        assert!(!is_ip_address_allowed(
+            &ip_allowlist,
+            "198.51.100.1".parse().unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_permissions_validation() {
+        use api_models::user_role::Permission as ApiPermission;
+
+        assert_eq!(
+            get_permissions_to_store(Some(vec![
+                ApiPermission::RefundWrite,
+                ApiPermission::RefundRead,
+                ApiPermission::RefundWrite,
+            ]))
+            .unwrap(),
+            Some(vec!["RefundRead".to_string(), "RefundWrite".to_string()])
+        );
+        assert!(get_permissions_to_store(Some(vec![])).is_err());
+    }
+
+    #[test]
+    fn test_restrictions_are_removed_on_update() {
+        use api_models::user_role::Permission as ApiPermission;
+
+        assert_eq!(get_permissions_to_update(None).unwrap(), None);
+        assert_eq!(get_permissions_to_update(Some(None)).unwrap(), Some(None));
+        assert_eq!(
+            get_permissions_to_update(Some(Some(vec![]))).unwrap(),
+            Some(None)
+        );
+        assert_eq!(
+            get_permissions_to_update(Some(Some(vec![ApiPermission::RefundRead]))).unwrap(),
+            Some(Some(vec!["RefundRead".to_string()]))
+        );
+
+        assert_eq!(get_ip_allowlist_to_update(None).unwrap(), None);
+        assert_eq!(get_ip_allowlist_to_update(Some(None)).unwrap(), Some(None));
+        assert_eq!(
+            get_ip_allowlist_to_update(Some(Some(vec![]))).unwrap(),
+            Some(None)
+        );
+        assert!(get_ip_allowlist_to_update(Some(Some(vec!["300.0.0.1".to_string()]))).is_err());
+    }
 }
@@ -0,0 +1,326 @@
+use api_models::audit_log as audit_log_api;
+use common_utils::id_type;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse},
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, SessionState},
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+const AUDIT_LOGS_LIST_MAX_LIMIT: i64 = 100;
+
+/// Fields which hold secrets but are not wrapped in `Secret` in the API models, and hence are not
+/// masked when serialized
+const UNMASKED_SECRET_FIELDS: &[&str] = &["payment_response_hash_key"];
+
+const REDACTED_VALUE: &str = "*** redacted ***";
+
+/// A change made to a configuration entity, to be recorded in the audit log
+#[derive(Debug)]
+pub struct ConfigChange<'a, T: Serialize> {
+    pub merchant_id: Option<&'a id_type::MerchantId>,
+    pub entity_type: enums::AuditEntityType,
+    pub entity_id: String,
+    pub action: enums::AuditAction,
+    /// The entity before the change, `None` if the entity was created
+    pub before: Option<&'a T>,
+    /// The entity after the change, `None` if the entity was deleted
+    pub after: Option<&'a T>,
+}
+
+/// Records a change made to a configuration entity in the audit log, along with the actor which
+/// made the change and the IP address it was made from.
+///
+/// The change has already been applied by the time it is recorded, so failures to record it are
+/// logged and not propagated to the caller.
+#[instrument(skip_all, fields(entity_type = %change.entity_type, action = %change.action))]
+pub async fn record_config_change<T: Serialize>(
+    state: &SessionState,
+    req_state: &ReqState,
+    change: ConfigChange<'_, T>,
+) {
+    let diff = match get_masked_diff(change.before, change.after) {
+        Ok(diff) => diff,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to compute the diff of the configuration change"
+            );
+            return;
+        }
+    };
+
+    let audit_log = storage::AuditLog {
+        audit_id: common_utils::generate_id(consts::ID_LENGTH, "audit"),
+        merchant_id: change.merchant_id.cloned(),
+        actor: req_state.get_actor(),
+        entity_type: change.entity_type,
+        entity_id: change.entity_id,
+        action: change.action,
+        diff,
+        source_ip: req_state.source_ip.clone(),
+        created_at: common_utils::date_time::now(),
+    };
+
+    req_state
+        .event_context
+        .emit(AuditEvent::new(AuditEventType::ConfigurationChanged {
+            audit_id: audit_log.audit_id.clone(),
+            entity_type: audit_log.entity_type,
+            entity_id: audit_log.entity_id.clone(),
+            action: audit_log.action,
+            actor: audit_log.actor.clone(),
+        }));
+
+    if let Err(error) = state.store.insert_audit_log(audit_log).await {
+        logger::error!(?error, "Failed to insert audit log");
+    }
+}
+
+/// Serializes both versions of the entity with secrets masked, and returns the fields which
+/// differ between them.
+fn get_masked_diff<T: Serialize>(
+    before: Option<&T>,
+    after: Option<&T>,
+) -> Result<Value, serde_json::Error> {
+    let masked_serialize = |value: Option<&T>| {
+        value
+            .map(masking::masked_serialize)
+            .transpose()
+            .map(|value| value.unwrap_or(Value::Object(Map::new())))
+    };
+
+    let mut changes = Map::new();
+    collect_changes(
+        None,
+        &masked_serialize(before)?,
+        &masked_serialize(after)?,
+        &mut changes,
+    );
+
+    Ok(Value::Object(changes))
+}
+
+/// Walks both values field by field and collects every changed leaf under its dotted path.
+/// Arrays are compared as a whole.
+fn collect_changes(
+    path: Option<&str>,
+    before: &Value,
+    after: &Value,
+    changes: &mut Map<String, Value>,
+) {
+    match (before, after) {
+        (Value::Object(before_fields), Value::Object(after_fields)) => {
+            let keys = before_fields.keys().chain(
+                after_fields
+                    .keys()
+                    .filter(|key| !before_fields.contains_key(*key)),
+            );
+
+            for key in keys {
+                let field_path = path.map_or_else(|| key.clone(), |path| format!("{path}.{key}"));
+                let before_value = before_fields.get(key).unwrap_or(&Value::Null);
+                let after_value = after_fields.get(key).unwrap_or(&Value::Null);
+
+                if UNMASKED_SECRET_FIELDS.contains(&key.as_str()) {
+                    let redact = |value: &Value| match value {
+                        Value::Null => Value::Null,
+                        _ => Value::String(REDACTED_VALUE.to_string()),
+                    };
+                    if before_value != after_value {
+                        changes.insert(
+                            field_path,
+                            serde_json::json!({
+                                "before": redact(before_value),
+                                "after": redact(after_value),
+                            }),
+                        );
+                    }
+                } else {
+                    collect_changes(Some(&field_path), before_value, after_value, changes);
+                }
+            }
+        }
+        (before, after) if before != after => {
+            changes.insert(
+                path.unwrap_or_default().to_string(),
+                serde_json::json!({ "before": before, "after": after }),
+            );
+        }
+        _ => {}
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn list_audit_logs(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: audit_log_api::AuditLogListConstraints,
+) -> RouterResponse<Vec<audit_log_api::AuditLogResponse>> {
+    let limit = match constraints.limit.map(i64::from) {
+        Some(limit) if limit > AUDIT_LOGS_LIST_MAX_LIMIT => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("`limit` must be a number less than {AUDIT_LOGS_LIST_MAX_LIMIT}"),
+            })
+        }
+        Some(limit) => Ok(limit),
+        None => Ok(AUDIT_LOGS_LIST_MAX_LIMIT),
+    }?;
+
+    let audit_logs = state
+        .store
+        .list_audit_logs_by_merchant_id_constraints(
+            merchant_account.get_id(),
+            storage::AuditLogListConstraints {
+                entity_type: constraints.entity_type,
+                entity_id: constraints.entity_id,
+                actor: constraints.actor,
+                created_after: constraints.created_after,
+                created_before: constraints.created_before,
+                limit: Some(limit),
+                offset: constraints.offset.map(i64::from),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list audit logs with specified constraints")?;
+
+    Ok(ApplicationResponse::Json(
+        audit_logs
+            .into_iter()
+            .map(|audit_log| audit_log_api::AuditLogResponse {
+                audit_id: audit_log.audit_id,
+                merchant_id: audit_log.merchant_id,
+                actor: audit_log.actor,
+                entity_type: audit_log.entity_type,
+                entity_id: audit_log.entity_id,
+                action: audit_log.action,
+                diff: audit_log.diff,
+                source_ip: audit_log.source_ip,
+                created_at: audit_log.created_at,
+            })
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::Secret;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Connector {
+        label: String,
+        disabled: bool,
+        api_key: Secret<String>,
+        metadata: Option<Value>,
+    }
+
+    #[derive(Serialize)]
+    struct Profile {
+        profile_name: String,
+        payment_response_hash_key: Option<String>,
+    }
+
+    #[test]
+    fn test_diff_contains_only_changed_fields() {
+        let before = Connector {
+            label: "stripe_default".to_string(),
+            disabled: false,
+            api_key: Secret::new("sk_test_old".to_string()),
+            metadata: Some(json!({ "city": "NY", "unit": "245" })),
+        };
+        let after = Connector {
+            label: "stripe_default".to_string(),
+            disabled: true,
+            api_key: Secret::new("sk_test_old".to_string()),
+            metadata: Some(json!({ "city": "LA", "unit": "245" })),
+        };
+
+        let diff = get_masked_diff(Some(&before), Some(&after)).unwrap();
+
+        assert_eq!(
+            diff,
+            json!({
+                "disabled": { "before": false, "after": true },
+                "metadata.city": { "before": "NY", "after": "LA" },
+            })
+        );
+    }
+
+    #[test]
+    fn test_diff_masks_secrets() {
+        let after = Connector {
+            label: "stripe_default".to_string(),
+            disabled: false,
+            api_key: Secret::new("sk_test_new".to_string()),
+            metadata: None,
+        };
+
+        let diff = get_masked_diff(None, Some(&after)).unwrap();
+        let serialized_diff = diff.to_string();
+
+        assert!(!serialized_diff.contains("sk_test_new"));
+        assert_eq!(
+            diff.get("label"),
+            Some(&json!({ "before": null, "after": "stripe_default" }))
+        );
+        assert!(diff.get("api_key").is_some());
+        assert!(diff.get("metadata").is_none());
+    }
+
+    #[test]
+    fn test_diff_redacts_unmasked_secret_fields() {
+        let before = Profile {
+            profile_name: "default".to_string(),
+            payment_response_hash_key: Some("old_hash_key".to_string()),
+        };
+        let after = Profile {
+            profile_name: "default".to_string(),
+            payment_response_hash_key: Some("new_hash_key".to_string()),
+        };
+
+        let diff = get_masked_diff(Some(&before), Some(&after)).unwrap();
+
+        assert_eq!(
+            diff,
+            json!({
+                "payment_response_hash_key": {
+                    "before": REDACTED_VALUE,
+                    "after": REDACTED_VALUE,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_diff_of_deleted_entity() {
+        let before = Connector {
+            label: "stripe_default".to_string(),
+            disabled: false,
+            api_key: Secret::new("sk_test_old".to_string()),
+            metadata: None,
+        };
+
+        let diff = get_masked_diff(Some(&before), None::<&Connector>).unwrap();
+
+        assert_eq!(
+            diff.get("disabled"),
+            Some(&json!({ "before": false, "after": null }))
+        );
+        assert!(!diff.to_string().contains("sk_test_old"));
+    }
+}
@@ -4,30 +4,65 @@ pub mod utils;
 use api_models::blocklist as api_blocklist;
 
 use crate::{
-    core::errors::{self, RouterResponse},
-    routes::SessionState,
+    core::{
+        audit_log::{self, ConfigChange},
+        errors::{self, RouterResponse},
+    },
+    routes::{app::ReqState, SessionState},
     services,
-    types::domain,
+    types::{domain, storage::enums},
 };
 
 pub async fn add_entry_to_blocklist(
     state: SessionState,
+    req_state: ReqState,
     merchant_account: domain::MerchantAccount,
     body: api_blocklist::AddToBlocklistRequest,
 ) -> RouterResponse<api_blocklist::AddToBlocklistResponse> {
-    utils::insert_entry_into_blocklist(&state, merchant_account.get_id(), body)
-        .await
-        .map(services::ApplicationResponse::Json)
+    let blocklist_entry =
+        utils::insert_entry_into_blocklist(&state, merchant_account.get_id(), body).await?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_account.get_id()),
+            entity_type: enums::AuditEntityType::Blocklist,
+            entity_id: blocklist_entry.fingerprint_id.clone(),
+            action: enums::AuditAction::Create,
+            before: None,
+            after: Some(&blocklist_entry),
+        },
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(blocklist_entry))
 }
 
 pub async fn remove_entry_from_blocklist(
     state: SessionState,
+    req_state: ReqState,
     merchant_account: domain::MerchantAccount,
     body: api_blocklist::DeleteFromBlocklistRequest,
 ) -> RouterResponse<api_blocklist::DeleteFromBlocklistResponse> {
-    utils::delete_entry_from_blocklist(&state, merchant_account.get_id(), body)
-        .await
-        .map(services::ApplicationResponse::Json)
+    let blocklist_entry =
+        utils::delete_entry_from_blocklist(&state, merchant_account.get_id(), body).await?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_account.get_id()),
+            entity_type: enums::AuditEntityType::Blocklist,
+            entity_id: blocklist_entry.fingerprint_id.clone(),
+            action: enums::AuditAction::Delete,
+            before: Some(&blocklist_entry),
+            after: None,
+        },
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(blocklist_entry))
 }
 
 pub async fn list_blocklist_entries(
@@ -42,10 +77,33 @@ pub async fn list_blocklist_entries(
 
 pub async fn toggle_blocklist_guard(
     state: SessionState,
+    req_state: ReqState,
     merchant_account: domain::MerchantAccount,
     query: api_blocklist::ToggleBlocklistQuery,
 ) -> RouterResponse<api_blocklist::ToggleBlocklistResponse> {
-    utils::toggle_blocklist_guard_for_merchant(&state, merchant_account.get_id(), query)
-        .await
-        .map(services::ApplicationResponse::Json)
+    let action = if query.status {
+        enums::AuditAction::Activate
+    } else {
+        enums::AuditAction::Deactivate
+    };
+    let guard_key = merchant_account.get_id().get_blocklist_guard_key();
+    let guard_status =
+        utils::toggle_blocklist_guard_for_merchant(&state, merchant_account.get_id(), query)
+            .await?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_account.get_id()),
+            entity_type: enums::AuditEntityType::Blocklist,
+            entity_id: guard_key,
+            action,
+            before: None,
+            after: Some(&guard_status),
+        },
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(guard_status))
 }
@@ -5,15 +5,19 @@ use common_utils::ext_traits::StringExt;
 use error_stack::ResultExt;
 
 use crate::{
-    core::errors::{self, RouterResponse},
-    routes::SessionState,
+    core::{
+        audit_log::{self, ConfigChange},
+        errors::{self, RouterResponse},
+    },
+    routes::{app::ReqState, SessionState},
     services::api as service_api,
-    types::domain,
+    types::{domain, storage::enums},
 };
 
 #[cfg(all(feature = "v2", feature = "merchant_account_v2"))]
 pub async fn upsert_conditional_config(
     _state: SessionState,
+    _req_state: ReqState,
     _key_store: domain::MerchantKeyStore,
     _merchant_account: domain::MerchantAccount,
     _request: DecisionManager,
@@ -27,6 +31,7 @@ pub async fn upsert_conditional_config(
 ))]
 pub async fn upsert_conditional_config(
     state: SessionState,
+    req_state: ReqState,
     key_store: domain::MerchantKeyStore,
     merchant_account: domain::MerchantAccount,
     request: DecisionManager,
@@ -92,7 +97,7 @@ pub async fn upsert_conditional_config(
                 .attach_printable("The Payment Config Key Not Found")?;
 
             let new_algo = DecisionManagerRecord {
-                name: previous_record.name,
+                name: previous_record.name.clone(),
                 program: prog,
                 modified_at: timestamp,
                 created_at: previous_record.created_at,
@@ -113,12 +118,26 @@ pub async fn upsert_conditional_config(
                 .attach_printable("Error serializing the config")?;
 
             algo_id.update_conditional_config_id(key.clone());
-            let config_key = cache::CacheKind::DecisionManager(key.into());
+            let config_key = cache::CacheKind::DecisionManager(key.clone().into());
             update_merchant_active_algorithm_ref(&state, &key_store, config_key, algo_id)
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Failed to update routing algorithm ref")?;
 
+            audit_log::record_config_change(
+                &state,
+                &req_state,
+                ConfigChange {
+                    merchant_id: Some(merchant_account.get_id()),
+                    entity_type: enums::AuditEntityType::ThreeDsDecisionConfig,
+                    entity_id: key,
+                    action: enums::AuditAction::Update,
+                    before: Some(&previous_record),
+                    after: Some(&new_algo),
+                },
+            )
+            .await;
+
             Ok(service_api::ApplicationResponse::Json(new_algo))
         }
         Err(e) if e.current_context().is_db_not_found() => {
@@ -149,12 +168,26 @@ pub async fn upsert_conditional_config(
                 .attach_printable("Error fetching the config")?;
 
             algo_id.update_conditional_config_id(key.clone());
-            let config_key = cache::CacheKind::DecisionManager(key.into());
+            let config_key = cache::CacheKind::DecisionManager(key.clone().into());
             update_merchant_active_algorithm_ref(&state, &key_store, config_key, algo_id)
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Failed to update routing algorithm ref")?;
 
+            audit_log::record_config_change(
+                &state,
+                &req_state,
+                ConfigChange {
+                    merchant_id: Some(merchant_account.get_id()),
+                    entity_type: enums::AuditEntityType::ThreeDsDecisionConfig,
+                    entity_id: key,
+                    action: enums::AuditAction::Create,
+                    before: None,
+                    after: Some(&new_rec),
+                },
+            )
+            .await;
+
             Ok(service_api::ApplicationResponse::Json(new_rec))
         }
         Err(e) => Err(e)
@@ -166,6 +199,7 @@ pub async fn upsert_conditional_config(
 #[cfg(all(feature = "v2", feature = "merchant_account_v2"))]
 pub async fn delete_conditional_config(
     _state: SessionState,
+    _req_state: ReqState,
     _key_store: domain::MerchantKeyStore,
     _merchant_account: domain::MerchantAccount,
 ) -> RouterResponse<()> {
@@ -178,6 +212,7 @@ pub async fn delete_conditional_config(
 ))]
 pub async fn delete_conditional_config(
     state: SessionState,
+    req_state: ReqState,
     key_store: domain::MerchantKeyStore,
     merchant_account: domain::MerchantAccount,
 ) -> RouterResponse<()> {
@@ -188,6 +223,12 @@ pub async fn delete_conditional_config(
 
     let db = state.store.as_ref();
     let key = merchant_account.get_id().get_payment_config_routing_id();
+    let record_before_delete = db.find_config_by_key(&key).await.ok().and_then(|config| {
+        config
+            .config
+            .parse_struct::<DecisionManagerRecord>("DecisionManagerRecord")
+            .ok()
+    });
     let mut algo_id: api_models::routing::RoutingAlgorithmRef = merchant_account
         .routing_algorithm
         .clone()
@@ -207,6 +248,21 @@ pub async fn delete_conditional_config(
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to delete routing config from DB")?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_account.get_id()),
+            entity_type: enums::AuditEntityType::ThreeDsDecisionConfig,
+            entity_id: key,
+            action: enums::AuditAction::Delete,
+            before: record_before_delete.as_ref(),
+            after: None,
+        },
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::StatusOk)
 }
 
@@ -56,7 +56,7 @@ pub async fn sync_onboarding_status(
     state: SessionState,
     user_from_token: auth::UserFromToken,
     request: api::OnboardingSyncRequest,
-    _req_state: ReqState,
+    req_state: ReqState,
 ) -> RouterResponse<api::OnboardingStatus> {
     utils::check_if_connector_exists(&state, &request.connector_id, &user_from_token.merchant_id)
         .await?;
@@ -88,6 +88,7 @@ pub async fn sync_onboarding_status(
                 };
                 let update_mca_data = paypal::update_mca(
                     &state,
+                    req_state,
                     user_from_token.merchant_id,
                     request.connector_id.to_owned(),
                     auth_details,
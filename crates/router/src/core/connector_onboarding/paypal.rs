@@ -8,6 +8,7 @@ use crate::{
         admin,
         errors::{ApiErrorResponse, RouterResult},
     },
+    routes::app::ReqState,
     services::{send_request, ApplicationResponse, Request},
     types::{self as oss_types, api as oss_api_types, api::connector_onboarding as types},
     utils::connector_onboarding as utils,
@@ -140,6 +141,7 @@ async fn find_paypal_merchant_by_tracking_id(
 
 pub async fn update_mca(
     state: &SessionState,
+    req_state: ReqState,
     merchant_id: common_utils::id_type::MerchantId,
     connector_id: String,
     auth_details: oss_types::ConnectorAuthType,
@@ -163,6 +165,7 @@ pub async fn update_mca(
         frm_configs: None,
         connector_webhook_details: None,
         pm_auth_config: None,
+        fee_schedule: None,
         test_mode: None,
     };
     #[cfg(all(feature = "v2", feature = "merchant_connector_account_v2"))]
@@ -177,10 +180,18 @@ pub async fn update_mca(
         frm_configs: None,
         connector_webhook_details: None,
         pm_auth_config: None,
+        fee_schedule: None,
         merchant_id: merchant_id.clone(),
     };
-    let mca_response =
-        admin::update_connector(state.clone(), &merchant_id, None, &connector_id, request).await?;
+    let mca_response = admin::update_connector(
+        state.clone(),
+        req_state,
+        &merchant_id,
+        None,
+        &connector_id,
+        request,
+    )
+    .await?;
 
     match mca_response {
         ApplicationResponse::Json(mca_data) => Ok(mca_data),
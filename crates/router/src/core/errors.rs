@@ -186,6 +186,16 @@ pub enum WebhooksFlowError {
     OutgoingWebhookRetrySchedulingFailed,
     #[error("Outgoing webhook response encoding failed")]
     OutgoingWebhookResponseEncodingFailed,
+    #[error("Webhook endpoint not found")]
+    WebhookEndpointNotFound,
+    #[error("Webhook endpoint is disabled")]
+    WebhookEndpointDisabled,
+    #[error("Failed to fetch webhook endpoint")]
+    WebhookEndpointFetchFailed,
+    #[error("Deliveries to webhook endpoint are suspended")]
+    WebhookEndpointSuspended,
+    #[error("Failed to queue webhook for delivery to suspended webhook endpoint")]
+    WebhookQueueingFailed,
 }
 
 impl WebhooksFlowError {
@@ -194,7 +204,10 @@ impl WebhooksFlowError {
             Self::MerchantConfigNotFound
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
-            | Self::OutgoingWebhookResponseEncodingFailed => false,
+            | Self::OutgoingWebhookResponseEncodingFailed
+            | Self::WebhookEndpointNotFound
+            | Self::WebhookEndpointDisabled
+            | Self::WebhookEndpointSuspended => false,
 
             Self::WebhookEventUpdationFailed
             | Self::OutgoingWebhookSigningFailed
@@ -203,7 +216,9 @@ impl WebhooksFlowError {
             | Self::DisputeWebhookValidationFailed
             | Self::OutgoingWebhookEncodingFailed
             | Self::OutgoingWebhookProcessTrackerTaskUpdateFailed
-            | Self::OutgoingWebhookRetrySchedulingFailed => true,
+            | Self::OutgoingWebhookRetrySchedulingFailed
+            | Self::WebhookEndpointFetchFailed
+            | Self::WebhookQueueingFailed => true,
         }
     }
 }
@@ -308,6 +323,12 @@ pub enum RoutingError {
     VolumeSplitFailed,
     #[error("Unable to parse metadata")]
     MetadataParsingError,
+    #[error("Failed to update the success rate window of the connector")]
+    SuccessRateWindowUpdateFailed,
+    #[error("Failed to fetch the circuit breaker state of the connector")]
+    CircuitBreakerStateFetchFailed,
+    #[error("Failed to update the circuit breaker state of the connector")]
+    CircuitBreakerStateUpdateFailed,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -1,31 +1,321 @@
-use api_models::gsm as gsm_api_types;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use actix_multipart::form::{bytes::Bytes, MultipartForm};
+use api_models::{enums::GsmMatchType, gsm as gsm_api_types};
 use diesel_models::gsm as storage;
-use error_stack::ResultExt;
-use router_env::{instrument, tracing};
+use error_stack::{report, ResultExt};
+use rdkafka::message::ToBytes;
+use router_env::{instrument, logger, tracing};
+use storage_impl::redis::cache::{CacheKey, GSM_PATTERN_RULES_CACHE};
 
 use crate::{
     core::{
+        audit_log::{self, ConfigChange},
         errors,
-        errors::{RouterResponse, StorageErrorExt},
+        errors::{CustomResult, RouterResponse, StorageErrorExt},
     },
-    db::gsm::GsmInterface,
+    db::gsm::{self as gsm_db, GsmInterface},
+    routes::app::ReqState,
     services,
-    types::transformers::ForeignInto,
+    types::{storage::enums, transformers::ForeignInto},
     SessionState,
 };
 
+/// The code and the message of a `catch_all` rule.
+const CATCH_ALL_PATTERN: &str = "*";
+
+#[derive(Debug, MultipartForm)]
+pub struct GsmImportForm {
+    #[multipart(limit = "1MB")]
+    pub file: Bytes,
+}
+
+/// Matches the value against a pattern where `*` matches any sequence of characters and `?`
+/// matches a single character.
+pub fn is_wildcard_match(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let value = value.chars().collect::<Vec<_>>();
+
+    let (mut pattern_index, mut value_index) = (0, 0);
+    // The position of the last `*` in the pattern, and of the value when it was reached
+    let mut backtrack = None;
+
+    while value_index < value.len() {
+        match pattern.get(pattern_index) {
+            Some('*') => {
+                backtrack = Some((pattern_index, value_index));
+                pattern_index += 1;
+            }
+            Some(pattern_char)
+                if *pattern_char == '?' || Some(pattern_char) == value.get(value_index) =>
+            {
+                pattern_index += 1;
+                value_index += 1;
+            }
+            _ => match backtrack {
+                Some((star_index, star_value_index)) => {
+                    backtrack = Some((star_index, star_value_index + 1));
+                    pattern_index = star_index + 1;
+                    value_index = star_value_index + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern.get(pattern_index..).map_or(true, |rest| {
+        rest.iter().all(|pattern_char| *pattern_char == '*')
+    })
+}
+
+fn build_message_regex(pattern: &str) -> Result<regex::Regex, regex::Error> {
+    // The pattern has to match the whole message, same as the wildcard patterns
+    regex::Regex::new(&format!("^(?:{pattern})$"))
+}
+
+/// A rule along with its compiled message pattern, so that the regex of a rule is compiled once
+/// for all the lookups served from the cache.
+#[derive(Clone, Debug)]
+struct CompiledGsmRule {
+    rule: storage::GatewayStatusMap,
+    message_regex: Option<regex::Regex>,
+}
+
+impl CompiledGsmRule {
+    fn new(rule: storage::GatewayStatusMap) -> Self {
+        let message_regex = match rule.match_type {
+            GsmMatchType::Regex => build_message_regex(&rule.message)
+                .map_err(|error| {
+                    logger::warn!(?error, pattern = %rule.message, "Invalid regex in gsm rule");
+                })
+                .ok(),
+            GsmMatchType::Exact | GsmMatchType::Wildcard | GsmMatchType::CatchAll => None,
+        };
+
+        Self {
+            rule,
+            message_regex,
+        }
+    }
+
+    fn is_match(&self, code: &str, message: &str) -> bool {
+        let rule = &self.rule;
+        match rule.match_type {
+            GsmMatchType::Exact => rule.code == code && rule.message == message,
+            GsmMatchType::Wildcard => {
+                rule.code == code && is_wildcard_match(&rule.message, message)
+            }
+            GsmMatchType::Regex => {
+                rule.code == code
+                    && self
+                        .message_regex
+                        .as_ref()
+                        .is_some_and(|regex| regex.is_match(message))
+            }
+            GsmMatchType::CatchAll => true,
+        }
+    }
+}
+
+fn compare_rule_precedence(
+    a: &storage::GatewayStatusMap,
+    b: &storage::GatewayStatusMap,
+) -> Ordering {
+    let literal_count = |rule: &storage::GatewayStatusMap| match rule.match_type {
+        GsmMatchType::Wildcard => rule
+            .message
+            .chars()
+            .filter(|pattern_char| !matches!(pattern_char, '*' | '?'))
+            .count(),
+        _ => 0,
+    };
+
+    (a.match_type, Reverse(literal_count(a)), &a.message).cmp(&(
+        b.match_type,
+        Reverse(literal_count(b)),
+        &b.message,
+    ))
+}
+
+fn select_matching_compiled_rule<'a>(
+    rules: &'a [CompiledGsmRule],
+    code: &str,
+    message: &str,
+) -> Option<&'a storage::GatewayStatusMap> {
+    rules
+        .iter()
+        .filter(|compiled_rule| compiled_rule.is_match(code, message))
+        .map(|compiled_rule| &compiled_rule.rule)
+        .min_by(|a, b| compare_rule_precedence(a, b))
+}
+
+/// Picks the rule which applies to the code and the message, following the precedence
+/// documented on [`GsmMatchType`].
+pub fn select_matching_rule(
+    rules: Vec<storage::GatewayStatusMap>,
+    code: &str,
+    message: &str,
+) -> Option<storage::GatewayStatusMap> {
+    let compiled_rules = rules
+        .into_iter()
+        .map(CompiledGsmRule::new)
+        .collect::<Vec<_>>();
+
+    select_matching_compiled_rule(&compiled_rules, code, message).cloned()
+}
+
+/// Fetches the compiled pattern rules of the connector flow from the cache, loading them from
+/// the database when they are not cached. The cache entry is invalidated whenever a rule of the
+/// connector flow is written.
+async fn get_compiled_gsm_pattern_rules(
+    state: &SessionState,
+    connector: &str,
+    flow: &str,
+    sub_flow: &str,
+) -> CustomResult<Arc<Vec<CompiledGsmRule>>, errors::StorageError> {
+    let key = CacheKey {
+        key: gsm_db::get_gsm_pattern_rules_cache_key(connector, flow, sub_flow),
+        prefix: state.tenant.redis_key_prefix.clone(),
+    };
+
+    if let Some(compiled_rules) = GSM_PATTERN_RULES_CACHE
+        .get_val::<Arc<Vec<CompiledGsmRule>>>(key.clone())
+        .await
+    {
+        return Ok(compiled_rules);
+    }
+
+    let compiled_rules = Arc::new(
+        state
+            .store
+            .find_gsm_pattern_rules(
+                connector.to_string(),
+                flow.to_string(),
+                sub_flow.to_string(),
+            )
+            .await?
+            .into_iter()
+            .map(CompiledGsmRule::new)
+            .collect::<Vec<_>>(),
+    );
+    GSM_PATTERN_RULES_CACHE
+        .push(key, compiled_rules.clone())
+        .await;
+
+    Ok(compiled_rules)
+}
+
+/// Finds the rule for the connector error, trying the exact rule first and then the pattern
+/// based rules of the connector.
+#[instrument(skip_all)]
+pub async fn find_matching_gsm_rule(
+    state: &SessionState,
+    connector: String,
+    flow: String,
+    sub_flow: String,
+    code: String,
+    message: String,
+) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
+    match state
+        .store
+        .find_gsm_rule(
+            connector.clone(),
+            flow.clone(),
+            sub_flow.clone(),
+            code.clone(),
+            message.clone(),
+        )
+        .await
+    {
+        Ok(rule) if rule.match_type == GsmMatchType::Exact => return Ok(rule),
+        // A pattern rule whose pattern is the message itself, evaluated along with the others
+        Ok(_) => (),
+        Err(error) if error.current_context().is_db_not_found() => (),
+        Err(error) => return Err(error),
+    }
+
+    let pattern_rules = get_compiled_gsm_pattern_rules(state, &connector, &flow, &sub_flow).await?;
+
+    select_matching_compiled_rule(&pattern_rules, &code, &message)
+        .cloned()
+        .ok_or_else(|| {
+            report!(errors::StorageError::ValueNotFound(format!(
+                "No gsm rule matches connector: {connector}, flow: {flow}, sub_flow: {sub_flow}"
+            )))
+        })
+}
+
+fn validate_match_pattern(
+    match_type: GsmMatchType,
+    code: &str,
+    message: &str,
+) -> Result<(), String> {
+    match match_type {
+        GsmMatchType::Exact => Ok(()),
+        GsmMatchType::Wildcard => {
+            if message.contains(['*', '?']) {
+                Ok(())
+            } else {
+                Err("A wildcard rule must have `*` or `?` in its message".to_string())
+            }
+        }
+        GsmMatchType::Regex => build_message_regex(message)
+            .map(|_| ())
+            .map_err(|error| format!("The message is not a valid regex: {error}")),
+        GsmMatchType::CatchAll => {
+            if code == CATCH_ALL_PATTERN && message == CATCH_ALL_PATTERN {
+                Ok(())
+            } else {
+                Err(format!(
+                    "A catch_all rule must have `{CATCH_ALL_PATTERN}` as its code and message"
+                ))
+            }
+        }
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn create_gsm_rule(
     state: SessionState,
+    req_state: ReqState,
     gsm_rule: gsm_api_types::GsmCreateRequest,
 ) -> RouterResponse<gsm_api_types::GsmResponse> {
+    validate_match_pattern(gsm_rule.match_type, &gsm_rule.code, &gsm_rule.message)
+        .map_err(|message| errors::ApiErrorResponse::InvalidRequestData { message })?;
+
     let db = state.store.as_ref();
-    GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
+    let gsm: gsm_api_types::GsmResponse = GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
         .await
         .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
             message: "GSM with given key already exists in our records".to_string(),
-        })
-        .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+        })?
+        .foreign_into();
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: None,
+            entity_type: enums::AuditEntityType::GsmRule,
+            entity_id: get_gsm_rule_entity_id(
+                &gsm.connector,
+                &gsm.flow,
+                &gsm.sub_flow,
+                &gsm.code,
+                &gsm.message,
+            ),
+            action: enums::AuditAction::Create,
+            before: None,
+            after: Some(&gsm),
+        },
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(gsm))
 }
 
 #[instrument(skip_all)]
@@ -52,6 +342,7 @@ pub async fn retrieve_gsm_rule(
 #[instrument(skip_all)]
 pub async fn update_gsm_rule(
     state: SessionState,
+    req_state: ReqState,
     gsm_request: gsm_api_types::GsmUpdateRequest,
 ) -> RouterResponse<gsm_api_types::GsmResponse> {
     let db = state.store.as_ref();
@@ -67,8 +358,30 @@ pub async fn update_gsm_rule(
         step_up_possible,
         unified_code,
         unified_message,
+        match_type,
     } = gsm_request;
-    GsmInterface::update_gsm_rule(
+    if let Some(match_type) = match_type {
+        validate_match_pattern(match_type, &code, &message)
+            .map_err(|message| errors::ApiErrorResponse::InvalidRequestData { message })?;
+    }
+
+    let entity_id =
+        get_gsm_rule_entity_id(&connector.to_string(), &flow, &sub_flow, &code, &message);
+    let gsm_before_update: gsm_api_types::GsmResponse = GsmInterface::find_gsm_rule(
+        db,
+        connector.to_string(),
+        flow.clone(),
+        sub_flow.clone(),
+        code.clone(),
+        message.clone(),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "GSM with given key does not exist in our records".to_string(),
+    })?
+    .foreign_into();
+
+    let gsm: gsm_api_types::GsmResponse = GsmInterface::update_gsm_rule(
         db,
         connector.to_string(),
         flow,
@@ -82,19 +395,37 @@ pub async fn update_gsm_rule(
             step_up_possible,
             unified_code,
             unified_message,
+            match_type,
         },
     )
     .await
     .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
         message: "GSM with given key does not exist in our records".to_string(),
     })
-    .attach_printable("Failed while updating Gsm rule")
-    .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+    .attach_printable("Failed while updating Gsm rule")?
+    .foreign_into();
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: None,
+            entity_type: enums::AuditEntityType::GsmRule,
+            entity_id,
+            action: enums::AuditAction::Update,
+            before: Some(&gsm_before_update),
+            after: Some(&gsm),
+        },
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(gsm))
 }
 
 #[instrument(skip_all)]
 pub async fn delete_gsm_rule(
     state: SessionState,
+    req_state: ReqState,
     gsm_request: gsm_api_types::GsmDeleteRequest,
 ) -> RouterResponse<gsm_api_types::GsmDeleteResponse> {
     let db = state.store.as_ref();
@@ -105,6 +436,20 @@ pub async fn delete_gsm_rule(
         code,
         message,
     } = gsm_request;
+    let gsm_before_delete: gsm_api_types::GsmResponse = GsmInterface::find_gsm_rule(
+        db,
+        connector.to_string(),
+        flow.clone(),
+        sub_flow.clone(),
+        code.clone(),
+        message.clone(),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "GSM with given key does not exist in our records".to_string(),
+    })?
+    .foreign_into();
+
     match GsmInterface::delete_gsm_rule(
         db,
         connector.to_string(),
@@ -121,6 +466,26 @@ pub async fn delete_gsm_rule(
     {
         Ok(is_deleted) => {
             if is_deleted {
+                audit_log::record_config_change(
+                    &state,
+                    &req_state,
+                    ConfigChange {
+                        merchant_id: None,
+                        entity_type: enums::AuditEntityType::GsmRule,
+                        entity_id: get_gsm_rule_entity_id(
+                            &connector.to_string(),
+                            &flow,
+                            &sub_flow,
+                            &code,
+                            &message,
+                        ),
+                        action: enums::AuditAction::Delete,
+                        before: Some(&gsm_before_delete),
+                        after: None,
+                    },
+                )
+                .await;
+
                 Ok(services::ApplicationResponse::Json(
                     gsm_api_types::GsmDeleteResponse {
                         gsm_rule_delete: true,
@@ -138,3 +503,359 @@ pub async fn delete_gsm_rule(
         Err(err) => Err(err),
     }
 }
+
+/// GSM rules are global and have a composite key, which is joined to identify the rule in the
+/// audit log.
+fn get_gsm_rule_entity_id(
+    connector: &str,
+    flow: &str,
+    sub_flow: &str,
+    code: &str,
+    message: &str,
+) -> String {
+    format!("{connector}:{flow}:{sub_flow}:{code}:{message}")
+}
+
+/// Where the rules of an import were read from, which decides how the rules are numbered in the
+/// import result.
+#[derive(Clone, Copy, Debug)]
+pub enum GsmImportSource {
+    Json,
+    Csv,
+}
+
+impl GsmImportSource {
+    fn get_line_number(self, index: usize) -> usize {
+        match self {
+            // The position of the rule in the list
+            Self::Json => index + 1,
+            // The line of the rule in the file, which starts with the header
+            Self::Csv => index + 2,
+        }
+    }
+}
+
+pub fn get_gsm_import_request(
+    form: GsmImportForm,
+    dry_run: bool,
+) -> Result<gsm_api_types::GsmImportRequest, errors::ApiErrorResponse> {
+    csv::Reader::from_reader(form.file.data.to_bytes())
+        .deserialize()
+        .collect::<Result<Vec<gsm_api_types::GsmCreateRequest>, _>>()
+        .map(|rules| gsm_api_types::GsmImportRequest { rules, dry_run })
+        .map_err(|error| errors::ApiErrorResponse::PreconditionFailed {
+            message: error.to_string(),
+        })
+}
+
+/// Validates all the rules before writing any of them, and then writes them in a single
+/// transaction, so that an import either applies all of its rules or none of them.
+#[instrument(skip_all)]
+pub async fn import_gsm_rules(
+    state: SessionState,
+    req_state: ReqState,
+    import_request: gsm_api_types::GsmImportRequest,
+    import_source: GsmImportSource,
+) -> RouterResponse<gsm_api_types::GsmImportResponse> {
+    let db = state.store.as_ref();
+    let gsm_api_types::GsmImportRequest { rules, dry_run } = import_request;
+
+    let mut seen_keys = HashSet::new();
+    let mut results = Vec::with_capacity(rules.len());
+    // The rules which are updated by the import, keyed by their audit log entity id
+    let mut existing_rules: HashMap<String, gsm_api_types::GsmResponse> = HashMap::new();
+
+    for (index, rule) in rules.iter().enumerate() {
+        let connector = rule.connector.to_string();
+        let mut rule_errors = Vec::new();
+
+        if let Err(error) = validate_match_pattern(rule.match_type, &rule.code, &rule.message) {
+            rule_errors.push(error);
+        }
+
+        let key = (
+            connector.clone(),
+            rule.flow.clone(),
+            rule.sub_flow.clone(),
+            rule.code.clone(),
+            rule.message.clone(),
+        );
+        if !seen_keys.insert(key) {
+            rule_errors.push("The rule is repeated in the import".to_string());
+        }
+
+        let action = if rule_errors.is_empty() {
+            match db
+                .find_gsm_rule(
+                    connector.clone(),
+                    rule.flow.clone(),
+                    rule.sub_flow.clone(),
+                    rule.code.clone(),
+                    rule.message.clone(),
+                )
+                .await
+            {
+                Ok(existing_rule) => {
+                    existing_rules.insert(
+                        get_gsm_rule_entity_id(
+                            &existing_rule.connector,
+                            &existing_rule.flow,
+                            &existing_rule.sub_flow,
+                            &existing_rule.code,
+                            &existing_rule.message,
+                        ),
+                        existing_rule.foreign_into(),
+                    );
+                    gsm_api_types::GsmImportAction::Update
+                }
+                Err(error) if error.current_context().is_db_not_found() => {
+                    gsm_api_types::GsmImportAction::Create
+                }
+                Err(error) => {
+                    return Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                        .attach_printable("Failed while looking up the gsm rule to import")
+                }
+            }
+        } else {
+            gsm_api_types::GsmImportAction::Invalid
+        };
+
+        results.push(gsm_api_types::GsmImportRuleResult {
+            line_number: import_source.get_line_number(index),
+            connector,
+            flow: rule.flow.clone(),
+            sub_flow: rule.sub_flow.clone(),
+            code: rule.code.clone(),
+            message: rule.message.clone(),
+            action,
+            errors: rule_errors,
+        });
+    }
+
+    let count_action = |action| {
+        results
+            .iter()
+            .filter(|result| result.action == action)
+            .count()
+    };
+    let created = count_action(gsm_api_types::GsmImportAction::Create);
+    let updated = count_action(gsm_api_types::GsmImportAction::Update);
+    let invalid = count_action(gsm_api_types::GsmImportAction::Invalid);
+    let applied = !dry_run && invalid == 0;
+
+    if applied {
+        let (new_rules, updated_rules) = rules
+            .into_iter()
+            .zip(results.iter())
+            .partition::<Vec<_>, _>(|(_, result)| {
+                result.action == gsm_api_types::GsmImportAction::Create
+            });
+
+        let imported_rules = GsmInterface::import_gsm_rules(
+            db,
+            new_rules
+                .into_iter()
+                .map(|(rule, _)| rule.foreign_into())
+                .collect(),
+            updated_rules
+                .into_iter()
+                .map(|(rule, _)| rule.foreign_into())
+                .collect(),
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "GSM with given key already exists in our records".to_string(),
+        })
+        .attach_printable("Failed while importing Gsm rules")?;
+
+        for imported_rule in imported_rules {
+            let gsm: gsm_api_types::GsmResponse = imported_rule.foreign_into();
+            let entity_id = get_gsm_rule_entity_id(
+                &gsm.connector,
+                &gsm.flow,
+                &gsm.sub_flow,
+                &gsm.code,
+                &gsm.message,
+            );
+            let gsm_before_import = existing_rules.get(&entity_id);
+
+            audit_log::record_config_change(
+                &state,
+                &req_state,
+                ConfigChange {
+                    merchant_id: None,
+                    entity_type: enums::AuditEntityType::GsmRule,
+                    entity_id,
+                    action: if gsm_before_import.is_some() {
+                        enums::AuditAction::Update
+                    } else {
+                        enums::AuditAction::Create
+                    },
+                    before: gsm_before_import,
+                    after: Some(&gsm),
+                },
+            )
+            .await;
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        gsm_api_types::GsmImportResponse {
+            dry_run,
+            applied,
+            created,
+            updated,
+            invalid,
+            rules: results,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn export_gsm_rules(
+    state: SessionState,
+    export_request: gsm_api_types::GsmExportRequest,
+) -> RouterResponse<gsm_api_types::GsmExportResponse> {
+    let db = state.store.as_ref();
+    let rules = GsmInterface::list_gsm_rules(
+        db,
+        export_request
+            .connector
+            .map(|connector| connector.to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while listing Gsm rules")?
+    .into_iter()
+    .map(ForeignInto::foreign_into)
+    .collect::<Vec<gsm_api_types::GsmResponse>>();
+
+    match export_request.format {
+        gsm_api_types::GsmExportFormat::Json => Ok(services::ApplicationResponse::Json(
+            gsm_api_types::GsmExportResponse { rules },
+        )),
+        gsm_api_types::GsmExportFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            for rule in rules {
+                writer
+                    .serialize(rule)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed while writing Gsm rule to csv")?;
+            }
+            let file_data = writer
+                .into_inner()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while writing Gsm rules to csv")?;
+
+            Ok(services::ApplicationResponse::FileData((
+                file_data,
+                mime::TEXT_CSV,
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn make_rule(match_type: GsmMatchType, code: &str, message: &str) -> storage::GatewayStatusMap {
+        let now = common_utils::date_time::now();
+        storage::GatewayStatusMap {
+            connector: "stripe".to_string(),
+            flow: "Authorize".to_string(),
+            sub_flow: "sub_flow".to_string(),
+            code: code.to_string(),
+            message: message.to_string(),
+            status: "failure".to_string(),
+            router_error: None,
+            decision: "retry".to_string(),
+            created_at: now,
+            last_modified: now,
+            step_up_possible: false,
+            unified_code: None,
+            unified_message: None,
+            match_type,
+        }
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(is_wildcard_match(
+            "card declined*",
+            "card declined by issuer"
+        ));
+        assert!(is_wildcard_match("*declined*", "card declined by issuer"));
+        assert!(is_wildcard_match("do not honou?", "do not honour"));
+        assert!(is_wildcard_match("*", ""));
+        assert!(is_wildcard_match("a*b*c", "aXXbYYbc"));
+        assert!(!is_wildcard_match("card declined*", "insufficient funds"));
+        assert!(!is_wildcard_match("do not honou?", "do not honou"));
+        assert!(!is_wildcard_match("a*b", "aXXbc"));
+    }
+
+    #[test]
+    fn test_matching_rule_precedence() {
+        let rules = vec![
+            make_rule(GsmMatchType::CatchAll, "*", "*"),
+            make_rule(GsmMatchType::Regex, "card_declined", "card .+"),
+            make_rule(GsmMatchType::Wildcard, "card_declined", "card*"),
+            make_rule(GsmMatchType::Wildcard, "card_declined", "card declined*"),
+            make_rule(GsmMatchType::Exact, "card_declined", "card declined"),
+        ];
+
+        let matched = |message: &str| {
+            select_matching_rule(rules.clone(), "card_declined", message)
+                .map(|rule| (rule.match_type, rule.message))
+                .unwrap()
+        };
+
+        assert_eq!(
+            matched("card declined"),
+            (GsmMatchType::Exact, "card declined".to_string())
+        );
+        assert_eq!(
+            matched("card declined by issuer"),
+            (GsmMatchType::Wildcard, "card declined*".to_string())
+        );
+        assert_eq!(
+            matched("card expired"),
+            (GsmMatchType::Wildcard, "card*".to_string())
+        );
+        assert_eq!(
+            select_matching_rule(rules.clone(), "expired_card", "expired")
+                .map(|rule| rule.match_type),
+            Some(GsmMatchType::CatchAll)
+        );
+        let pattern_rules = rules.into_iter().skip(1).collect::<Vec<_>>();
+        assert!(select_matching_rule(pattern_rules, "expired_card", "expired").is_none());
+    }
+
+    #[test]
+    fn test_regex_rules_match_whole_message() {
+        let rules = vec![
+            make_rule(GsmMatchType::Regex, "05", "do not hono(u)?r"),
+            make_rule(GsmMatchType::Regex, "05", "(invalid"),
+        ];
+
+        assert!(select_matching_rule(rules.clone(), "05", "do not honor").is_some());
+        assert!(select_matching_rule(rules, "05", "do not honor, retry").is_none());
+    }
+
+    #[test]
+    fn test_import_line_numbers_account_for_csv_header() {
+        assert_eq!(GsmImportSource::Json.get_line_number(0), 1);
+        assert_eq!(GsmImportSource::Csv.get_line_number(0), 2);
+    }
+
+    #[test]
+    fn test_validate_match_pattern() {
+        assert!(validate_match_pattern(GsmMatchType::Exact, "05", "declined").is_ok());
+        assert!(validate_match_pattern(GsmMatchType::Wildcard, "05", "declined*").is_ok());
+        assert!(validate_match_pattern(GsmMatchType::Wildcard, "05", "declined").is_err());
+        assert!(validate_match_pattern(GsmMatchType::Regex, "05", "(declined").is_err());
+        assert!(validate_match_pattern(GsmMatchType::CatchAll, "*", "*").is_ok());
+        assert!(validate_match_pattern(GsmMatchType::CatchAll, "05", "*").is_err());
+    }
+}
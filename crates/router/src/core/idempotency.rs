@@ -0,0 +1,238 @@
+use common_utils::crypto::{self, GenerateDigest};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::api::IdempotentResponse;
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use super::errors::{self, RouterResult};
+use crate::{
+    consts, headers,
+    routes::{app::SessionStateInfo, lock_utils},
+    services::authentication as auth,
+};
+
+pub const IDEMPOTENCY_PREFIX: &str = "IDEMPOTENCY";
+
+#[derive(Clone, Debug)]
+pub enum IdempotencyAction {
+    // Replay the stored response for retries carrying the same idempotency key
+    Enforce { input: IdempotencyInput },
+    // Idempotency Not applicable
+    NotApplicable,
+}
+
+#[derive(Clone, Debug)]
+pub struct IdempotencyInput {
+    pub idempotency_key: String,
+    pub api_identifier: lock_utils::ApiIdentifier,
+    pub request_fingerprint: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdempotencyStatus {
+    // The first request with this key is still being processed
+    InProgress,
+    // The first request with this key has completed and its response is stored
+    Completed,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IdempotencyRecord {
+    pub request_fingerprint: String,
+    pub status: IdempotencyStatus,
+    pub response: Option<IdempotentResponse>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IdempotencyOutcome {
+    // No response has been stored for this key, the request has to be processed
+    Proceed,
+    // The response of the original request
+    Replay(IdempotentResponse),
+}
+
+impl IdempotencyInput {
+    fn get_redis_idempotency_key(&self, merchant_id: &common_utils::id_type::MerchantId) -> String {
+        format!(
+            "{}_{}_{}_{}",
+            IDEMPOTENCY_PREFIX,
+            merchant_id.get_string_repr(),
+            self.api_identifier,
+            self.idempotency_key
+        )
+    }
+}
+
+impl IdempotencyAction {
+    /// Builds the idempotency action from the `Idempotency-Key` header of the request.
+    ///
+    /// The fingerprint has to be computed from the payload as sent by the merchant, before any
+    /// identifiers are generated into it.
+    pub fn from_request<T, F>(
+        request_headers: &actix_web::http::header::HeaderMap,
+        payload: &T,
+        flow: F,
+    ) -> RouterResult<Self>
+    where
+        T: Serialize,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        let Some(idempotency_key) =
+            auth::get_header_value_by_key(headers::IDEMPOTENCY_KEY.into(), request_headers)?
+        else {
+            return Ok(Self::NotApplicable);
+        };
+
+        if idempotency_key.is_empty() || idempotency_key.len() > consts::MAX_IDEMPOTENCY_KEY_LENGTH
+        {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "{} header must be a non-empty string of at most {} characters",
+                    headers::IDEMPOTENCY_KEY,
+                    consts::MAX_IDEMPOTENCY_KEY_LENGTH
+                ),
+            }));
+        }
+
+        let serialized_payload = serde_json::to_vec(payload)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize request for idempotency fingerprint")?;
+        let request_fingerprint = crypto::Sha256
+            .generate_digest(&serialized_payload)
+            .map(hex::encode)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to generate idempotency fingerprint")?;
+
+        Ok(Self::Enforce {
+            input: IdempotencyInput {
+                idempotency_key: idempotency_key.to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                request_fingerprint,
+            },
+        })
+    }
+
+    /// Claims the idempotency key for the current request, or returns the stored response of the
+    /// request which used the same key earlier.
+    #[instrument(skip_all)]
+    pub async fn perform_idempotency_check<A>(
+        &self,
+        state: &A,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> RouterResult<IdempotencyOutcome>
+    where
+        A: SessionStateInfo,
+    {
+        match self {
+            Self::Enforce { input } => {
+                let redis_conn = state
+                    .store()
+                    .get_redis_conn()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+                let redis_idempotency_key = input.get_redis_idempotency_key(merchant_id);
+                let in_progress_record = IdempotencyRecord {
+                    request_fingerprint: input.request_fingerprint.clone(),
+                    status: IdempotencyStatus::InProgress,
+                    response: None,
+                };
+
+                // The in progress record expires so that a crashed request does not block the key
+                // forever, but only well after the request could still be running
+                let claim_result = redis_conn
+                    .serialize_and_set_key_if_not_exist(
+                        &redis_idempotency_key,
+                        in_progress_record,
+                        Some(consts::IDEMPOTENCY_CLAIM_EXPIRY_IN_SECS),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+                if claim_result == redis::SetnxReply::KeySet {
+                    logger::info!("Idempotency key claimed for input {:?}", input);
+                    return Ok(IdempotencyOutcome::Proceed);
+                }
+
+                let existing_record = redis_conn
+                    .get_and_deserialize_key::<IdempotencyRecord>(
+                        &redis_idempotency_key,
+                        "IdempotencyRecord",
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch idempotency record")?;
+
+                if existing_record.request_fingerprint != input.request_fingerprint {
+                    return Err(report!(errors::ApiErrorResponse::IdempotencyKeyReused {
+                        idempotency_key: input.idempotency_key.clone(),
+                    }));
+                }
+
+                match (existing_record.status, existing_record.response) {
+                    (IdempotencyStatus::Completed, Some(response)) => {
+                        logger::info!("Replaying stored response for input {:?}", input);
+                        Ok(IdempotencyOutcome::Replay(response))
+                    }
+                    (IdempotencyStatus::Completed, None) | (IdempotencyStatus::InProgress, _) => {
+                        Err(report!(errors::ApiErrorResponse::ResourceBusy))
+                    }
+                }
+            }
+            Self::NotApplicable => Ok(IdempotencyOutcome::Proceed),
+        }
+    }
+
+    /// Stores the response against the claimed idempotency key.
+    ///
+    /// When no response is provided the key is released, so that the request can be retried with
+    /// the same key.
+    #[instrument(skip_all)]
+    pub async fn store_idempotent_response<A>(
+        self,
+        state: &A,
+        merchant_id: &common_utils::id_type::MerchantId,
+        response: Option<IdempotentResponse>,
+    ) -> RouterResult<()>
+    where
+        A: SessionStateInfo,
+    {
+        match self {
+            Self::Enforce { input } => {
+                let redis_conn = state
+                    .store()
+                    .get_redis_conn()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+                let redis_idempotency_key = input.get_redis_idempotency_key(merchant_id);
+
+                match response {
+                    Some(response) => {
+                        let completed_record = IdempotencyRecord {
+                            request_fingerprint: input.request_fingerprint,
+                            status: IdempotencyStatus::Completed,
+                            response: Some(response),
+                        };
+                        redis_conn
+                            .serialize_and_set_key_with_expiry(
+                                &redis_idempotency_key,
+                                completed_record,
+                                consts::IDEMPOTENCY_KEY_EXPIRY_IN_SECS,
+                            )
+                            .await
+                            .change_context(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable("Failed to store idempotent response")
+                    }
+                    None => redis_conn
+                        .delete_key(&redis_idempotency_key)
+                        .await
+                        .map(|_| ())
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to release idempotency key"),
+                }
+            }
+            Self::NotApplicable => Ok(()),
+        }
+    }
+}
@@ -41,6 +41,7 @@ counter_metric!(
 
 counter_metric!(WEBHOOK_INCOMING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_INCOMING_FILTERED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_INCOMING_DUPLICATE_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_SOURCE_VERIFIED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
@@ -83,6 +84,14 @@ counter_metric!(
     ROUTING_RETRIEVE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE,
     GLOBAL_METER
 );
+counter_metric!(ROUTING_SIMULATE_REQUEST_RECEIVED, GLOBAL_METER);
+counter_metric!(ROUTING_SIMULATE_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_ROLLBACK_CONFIG, GLOBAL_METER);
+counter_metric!(ROUTING_ROLLBACK_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_SCHEDULE_ACTIVATION, GLOBAL_METER);
+counter_metric!(ROUTING_SCHEDULE_ACTIVATION_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(CIRCUIT_BREAKER_STATE_TRANSITION, GLOBAL_METER);
+counter_metric!(CIRCUIT_BREAKER_CONNECTOR_ELIMINATED, GLOBAL_METER);
 
 #[cfg(feature = "partial-auth")]
 counter_metric!(PARTIAL_AUTH_FAILURE, GLOBAL_METER);
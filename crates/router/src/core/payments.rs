@@ -420,9 +420,13 @@ where
                     #[cfg(feature = "retry")]
                     {
                         use crate::core::payments::retry::{self, GsmValidation};
-                        let config_bool =
-                            retry::config_should_call_gsm(&*state.store, merchant_account.get_id())
-                                .await;
+                        // The retry policy of the profile enables retries on its own
+                        let config_bool = business_profile.retry_policy.is_some()
+                            || retry::config_should_call_gsm(
+                                &*state.store,
+                                merchant_account.get_id(),
+                            )
+                            .await;
 
                         if config_bool && router_data.should_call_gsm() {
                             router_data = retry::do_gsm_actions(
@@ -1170,6 +1174,7 @@ impl PaymentRedirectFlow for PaymentRedirectSync {
             }),
             client_secret: None,
             expand_attempts: None,
+            expose_routing_decision_trace: false,
             expand_captures: None,
         };
         let response = Box::pin(payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
@@ -1361,6 +1366,7 @@ impl PaymentRedirectFlow for PaymentAuthenticateCompleteAuthorize {
                 }),
                 client_secret: None,
                 expand_attempts: None,
+                expose_routing_decision_trace: false,
                 expand_captures: None,
             };
             Box::pin(payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
@@ -2757,6 +2763,10 @@ where
     pub authentication: Option<storage::Authentication>,
     pub recurring_details: Option<RecurringDetails>,
     pub poll_config: Option<router_types::PollConfig>,
+    pub expose_routing_decision_trace: bool,
+    /// Attributes of the card looked up from its BIN, once per payment and only for the programs
+    /// which have rules on them
+    pub card_bin_info: Option<euclid::backend::inputs::CardBinInfo>,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -3220,6 +3230,63 @@ pub async fn add_process_sync_task(
     Ok(())
 }
 
+const PAYMENTS_REQUEUE_TASK: &str = "PAYMENTS_REQUEUE";
+
+/// The requeue task is keyed on the payment rather than on the attempt, so that it also records
+/// that the payment has been requeued once
+fn get_payment_requeue_process_tracker_id(
+    payment_id: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::PaymentsRequeueWorkflow,
+        PAYMENTS_REQUEUE_TASK,
+        payment_id,
+        merchant_id,
+    )
+}
+
+/// Checks whether a requeue task has ever been added for the payment, a payment is requeued only once
+pub async fn is_payment_requeued(
+    db: &dyn StorageInterface,
+    payment_id: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> CustomResult<bool, errors::StorageError> {
+    Ok(db
+        .find_process_by_id(&get_payment_requeue_process_tracker_id(
+            payment_id,
+            merchant_id,
+        ))
+        .await?
+        .is_some())
+}
+
+pub async fn add_process_requeue_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::payment_attempt::PaymentRequeueTrackingData,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PaymentsRequeueWorkflow;
+    let task = PAYMENTS_REQUEUE_TASK;
+    let tag = ["REQUEUE", "PAYMENT"];
+    let process_tracker_id = get_payment_requeue_process_tracker_id(
+        &tracking_data.payment_id,
+        &tracking_data.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
 pub async fn reset_process_sync_task(
     db: &dyn StorageInterface,
     payment_attempt: &storage::PaymentAttempt,
@@ -3550,9 +3617,14 @@ where
         let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
             &routing_algorithm,
             payment_data.creds_identifier.clone(),
+            Some(&routing::VolumeSplitStickinessInput::from(
+                &TransactionData::Payment(payment_data),
+            )),
         )
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed execution of straight through routing")?;
+        let mut routing_trace =
+            routing::make_straight_through_routing_trace(&routing_algorithm, &connectors);
 
         if check_eligibility {
             let profile_id = payment_data.payment_intent.profile_id.clone();
@@ -3564,11 +3636,13 @@ where
                 &TransactionData::Payment(payment_data),
                 eligible_connectors,
                 profile_id,
+                Some(&mut routing_trace),
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("failed eligibility analysis and fallback")?;
         }
+        routing::set_routing_decision_trace(payment_data, routing_trace);
 
         let connector_data = connectors
             .into_iter()
@@ -3599,9 +3673,14 @@ where
         let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
             routing_algorithm,
             payment_data.creds_identifier.clone(),
+            Some(&routing::VolumeSplitStickinessInput::from(
+                &TransactionData::Payment(payment_data),
+            )),
         )
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed execution of straight through routing")?;
+        let mut routing_trace =
+            routing::make_straight_through_routing_trace(routing_algorithm, &connectors);
 
         if check_eligibility {
             let profile_id = payment_data.payment_intent.profile_id.clone();
@@ -3613,11 +3692,13 @@ where
                 &TransactionData::Payment(payment_data),
                 eligible_connectors,
                 profile_id,
+                Some(&mut routing_trace),
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("failed eligibility analysis and fallback")?;
         }
+        routing::set_routing_decision_trace(payment_data, routing_trace);
 
         let connector_data = connectors
             .into_iter()
@@ -3983,7 +4064,7 @@ pub async fn route_connector_v1<F>(
     merchant_account: &domain::MerchantAccount,
     business_profile: &domain::BusinessProfile,
     key_store: &domain::MerchantKeyStore,
-    transaction_data: TransactionData<'_, F>,
+    mut transaction_data: TransactionData<'_, F>,
     routing_data: &mut storage::RoutingData,
     eligible_connectors: Option<Vec<enums::RoutableConnectors>>,
     mandate_type: Option<api::MandateTransactionType>,
@@ -3995,11 +4076,12 @@ where
         super::admin::BusinessProfileWrapper::new(business_profile.clone())
             .get_profile_id_and_routing_algorithm_id(&transaction_data);
 
-    let connectors = routing::perform_static_routing_v1(
+    let (connectors, mut routing_trace) = routing::perform_static_routing_v1(
         state,
+        key_store,
         merchant_account.get_id(),
         routing_algorithm_id,
-        &transaction_data,
+        &mut transaction_data,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
@@ -4011,6 +4093,7 @@ where
         &transaction_data,
         eligible_connectors,
         profile_id,
+        Some(&mut routing_trace),
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -4039,6 +4122,7 @@ where
 
     match transaction_data {
         TransactionData::Payment(payment_data) => {
+            routing::set_routing_decision_trace(payment_data, routing_trace);
             decide_multiplex_connector_for_normal_or_recurring_payment(
                 state,
                 payment_data,
@@ -4071,7 +4155,7 @@ pub async fn route_connector_v1<F>(
     merchant_account: &domain::MerchantAccount,
     business_profile: &domain::BusinessProfile,
     key_store: &domain::MerchantKeyStore,
-    transaction_data: TransactionData<'_, F>,
+    mut transaction_data: TransactionData<'_, F>,
     routing_data: &mut storage::RoutingData,
     eligible_connectors: Option<Vec<enums::RoutableConnectors>>,
     mandate_type: Option<api::MandateTransactionType>,
@@ -4079,7 +4163,7 @@ pub async fn route_connector_v1<F>(
 where
     F: Send + Clone,
 {
-    let (profile_id, routing_algorithm_id) = {
+    let (profile_id, algorithm_ref) = {
         let (profile_id, routing_algorithm) = match &transaction_data {
             TransactionData::Payment(payment_data) => (
                 payment_data.payment_intent.profile_id.clone(),
@@ -4098,17 +4182,23 @@ where
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Could not decode merchant routing algorithm ref")?
             .unwrap_or_default();
-        (profile_id, algorithm_ref.algorithm_id)
+        (profile_id, algorithm_ref)
     };
 
-    let connectors = routing::perform_static_routing_v1(
+    let (connectors, mut routing_trace) = routing::perform_static_routing_v1(
         state,
+        key_store,
         merchant_account.get_id(),
-        routing_algorithm_id,
-        &transaction_data,
+        algorithm_ref.algorithm_id,
+        &mut transaction_data,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    // The timestamp of the algorithm ref identifies the activation of the algorithm used
+    if routing_trace.algorithm_id.is_some() {
+        routing_trace.algorithm_version = Some(algorithm_ref.timestamp);
+    }
+
     let connectors = routing::perform_eligibility_analysis_with_fallback(
         &state.clone(),
         key_store,
@@ -4116,6 +4206,7 @@ where
         &transaction_data,
         eligible_connectors,
         profile_id,
+        Some(&mut routing_trace),
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -4144,6 +4235,7 @@ where
 
     match transaction_data {
         TransactionData::Payment(payment_data) => {
+            routing::set_routing_decision_trace(payment_data, routing_trace);
             decide_multiplex_connector_for_normal_or_recurring_payment(
                 state,
                 payment_data,
@@ -6,11 +6,14 @@ use api_models::{
 };
 use common_utils::ext_traits::StringExt;
 use error_stack::ResultExt;
-use euclid::backend::{self, inputs as dsl_inputs, EuclidBackend};
+use euclid::{
+    backend::{self, inputs as dsl_inputs, EuclidBackend},
+    types::EuclidKey,
+};
 use router_env::{instrument, tracing};
 use storage_impl::redis::cache::{self, DECISION_MANAGER_CACHE};
 
-use super::routing::make_dsl_input;
+use super::routing::{make_dsl_input, populate_card_bin_info};
 use crate::{
     core::{errors, errors::ConditionalConfigError as ConfigError, payments},
     routes,
@@ -56,6 +59,12 @@ pub async fn perform_decision_management<F: Clone>(
     .await
     .change_context(ConfigError::DslCachePoisoned)?;
 
+    if interpreter
+        .get_program()
+        .has_key(EuclidKey::is_card_bin_derived)
+    {
+        populate_card_bin_info(state, payment_data).await;
+    }
     let backend_input =
         make_dsl_input(payment_data).change_context(ConfigError::InputConstructionError)?;
 
@@ -52,6 +52,7 @@ use crate::{
     core::{
         authentication,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        gsm,
         mandate::helpers::MandateGenericData,
         payment_methods::{
             self,
@@ -3657,6 +3658,23 @@ pub fn get_attempt_type(
     }
 }
 
+/// A requeued attempt can be confirmed only while it is pending and has not reached the connector
+#[instrument(skip_all)]
+pub fn validate_requeued_payment_attempt(payment_attempt: &PaymentAttempt) -> RouterResult<()> {
+    utils::when(
+        payment_attempt.status != enums::AttemptStatus::Pending
+            || payment_attempt.connector_transaction_id.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "You cannot requeue this payment because its attempt has the status {}",
+                    payment_attempt.status
+                ),
+            }))
+        },
+    )
+}
+
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum AttemptType {
     New,
@@ -3752,6 +3770,8 @@ impl AttemptType {
             client_source: old_payment_attempt.client_source,
             client_version: old_payment_attempt.client_version,
             customer_acceptance: old_payment_attempt.customer_acceptance,
+            routing_decision_trace: None,
+            retry_policy: None,
         }
     }
 
@@ -4667,7 +4687,8 @@ pub async fn get_gsm_record(
     flow: String,
 ) -> Option<storage::gsm::GatewayStatusMap> {
     let get_gsm = || async {
-        state.store.find_gsm_rule(
+        gsm::find_matching_gsm_rule(
+                state,
                 connector_name.clone(),
                 flow.clone(),
                 "sub_flow".to_string(),
@@ -4770,6 +4791,36 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+pub fn validate_retry_policy(
+    retry_policy: &api_models::admin::RetryPolicy,
+) -> Result<(), errors::ApiErrorResponse> {
+    if retry_policy.max_attempts == 0 {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "retry_policy.max_attempts should be greater than 0".to_string(),
+        });
+    }
+
+    let has_invalid_amount_range =
+        retry_policy
+            .amount_ranges
+            .iter()
+            .flatten()
+            .any(
+                |amount_range| match (amount_range.min_amount, amount_range.max_amount) {
+                    (Some(min_amount), Some(max_amount)) => min_amount > max_amount,
+                    _ => false,
+                },
+            );
+    if has_invalid_amount_range {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "min_amount of a retry_policy.amount_ranges should not be greater than its max_amount"
+                .to_string(),
+        });
+    }
+
+    Ok(())
+}
+
 pub fn get_recipient_id_for_open_banking(
     merchant_data: &AdditionalMerchantData,
 ) -> Result<Option<String>, errors::ApiErrorResponse> {
@@ -182,6 +182,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCaptureRequest>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            expose_routing_decision_trace: false,
+            card_bin_info: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -339,6 +339,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             authentication: None,
             recurring_details,
             poll_config: None,
+            expose_routing_decision_trace: false,
+            card_bin_info: None,
         };
 
         let customer_details = Some(CustomerDetails {
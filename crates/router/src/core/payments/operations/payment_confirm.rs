@@ -104,6 +104,12 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         helpers::validate_customer_access(&payment_intent, auth_flow, request)?;
 
+        // Requeued payments are confirmed again by the requeue workflow while they are processing
+        let is_requeue = matches!(
+            request.retry_action,
+            Some(api_models::enums::RetryAction::Requeue)
+        ) && payment_intent.status == storage_enums::IntentStatus::Processing;
+
         if [
             Some(common_enums::PaymentSource::Webhook),
             Some(common_enums::PaymentSource::ExternalAuthenticator),
@@ -121,7 +127,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 ],
                 "confirm",
             )?;
-        } else {
+        } else if !is_requeue {
             helpers::validate_payment_status_against_not_allowed_statuses(
                 &payment_intent.status,
                 &[
@@ -295,6 +301,26 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                         business_profile,
                     )
                 }
+                api_models::enums::IntentStatus::Processing if is_requeue => {
+                    // Requeued payment, the pending attempt created while requeueing is confirmed as is
+                    let (payment_attempt, shipping_address, billing_address, business_profile, _) =
+                        tokio::try_join!(
+                            utils::flatten_join_error(payment_attempt_fut),
+                            utils::flatten_join_error(shipping_address_fut),
+                            utils::flatten_join_error(billing_address_fut),
+                            utils::flatten_join_error(business_profile_fut),
+                            utils::flatten_join_error(config_update_fut)
+                        )?;
+
+                    helpers::validate_requeued_payment_attempt(&payment_attempt)?;
+
+                    (
+                        payment_attempt,
+                        shipping_address,
+                        billing_address,
+                        business_profile,
+                    )
+                }
                 _ => {
                     // Retry payment
                     let (
@@ -643,6 +669,16 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .as_ref()
             .map(|payment_method_billing| payment_method_billing.address_id.clone());
 
+        // The retry policy applies to the payment from its first attempt, so it is recorded even
+        // when the payment is not retried
+        payment_attempt.retry_policy = business_profile
+            .retry_policy
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the retry policy")?;
+
         let address = PaymentAddress::new(
             shipping_address.as_ref().map(From::from),
             billing_address.as_ref().map(From::from),
@@ -724,6 +760,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             authentication: None,
             recurring_details,
             poll_config: None,
+            expose_routing_decision_trace: false,
+            card_bin_info: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -1259,6 +1297,8 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let m_error_code = error_code.clone();
         let m_error_message = error_message.clone();
         let m_fingerprint_id = payment_data.payment_attempt.fingerprint_id.clone();
+        let m_routing_decision_trace = payment_data.payment_attempt.routing_decision_trace.clone();
+        let m_retry_policy = payment_data.payment_attempt.retry_policy.clone();
         let m_db = state.clone().store;
         let surcharge_amount = payment_data
             .surcharge_details
@@ -1317,6 +1357,8 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         client_source,
                         client_version,
                         customer_acceptance: payment_data.payment_attempt.customer_acceptance,
+                        routing_decision_trace: m_routing_decision_trace,
+                        retry_policy: m_retry_policy,
                     },
                     storage_scheme,
                 )
@@ -1520,10 +1562,9 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentConfir
                 merchant_id: merchant_account.get_id().to_owned(),
                 payment_id: payment_id.and_then(|id| core_utils::validate_id(id, "payment_id"))?,
                 storage_scheme: merchant_account.storage_scheme,
-                requeue: matches!(
-                    request.retry_action,
-                    Some(api_models::enums::RetryAction::Requeue)
-                ),
+                // Requeued payments are confirmed on a new attempt, which does not have a sync
+                // task of its own yet, so the task is added rather than reset
+                requeue: false,
             },
         ))
     }
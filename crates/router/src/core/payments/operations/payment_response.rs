@@ -26,7 +26,7 @@ use crate::{
                 self as payments_helpers,
                 update_additional_payment_data_with_connector_response_pm_data,
             },
-            tokenization,
+            routing, tokenization,
             types::MultipleCaptureData,
             PaymentData,
         },
@@ -82,6 +82,7 @@ impl<F: Send + Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthor
             key_store,
             storage_scheme,
             locale,
+            true,
         ))
         .await?;
 
@@ -426,6 +427,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsSyncData> for
             key_store,
             storage_scheme,
             locale,
+            false,
         ))
         .await
     }
@@ -480,6 +482,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsSessionData>
             key_store,
             storage_scheme,
             locale,
+            false,
         ))
         .await?;
 
@@ -512,6 +515,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCaptureData>
             key_store,
             storage_scheme,
             locale,
+            false,
         ))
         .await?;
 
@@ -542,6 +546,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCancelData> f
             key_store,
             storage_scheme,
             locale,
+            false,
         ))
         .await?;
 
@@ -574,6 +579,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsApproveData>
             key_store,
             storage_scheme,
             locale,
+            false,
         ))
         .await?;
 
@@ -604,6 +610,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsRejectData> f
             key_store,
             storage_scheme,
             locale,
+            false,
         ))
         .await?;
 
@@ -645,6 +652,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::SetupMandateRequestDa
             key_store,
             storage_scheme,
             locale,
+            false,
         ))
         .await?;
 
@@ -740,6 +748,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
             key_store,
             storage_scheme,
             locale,
+            true,
         ))
         .await
     }
@@ -770,6 +779,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
 }
 
 #[instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
 async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     state: &SessionState,
     _payment_id: &api::PaymentIdType,
@@ -778,7 +788,11 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     key_store: &domain::MerchantKeyStore,
     storage_scheme: enums::MerchantStorageScheme,
     locale: &Option<String>,
+    is_authorization: bool,
 ) -> RouterResult<PaymentData<F>> {
+    let previous_attempt_status = payment_data.payment_attempt.status;
+    let mut is_technical_failure = false;
+
     // Update additional payment data with the payment method response that we received from connector
     let additional_payment_method_data =
         update_additional_payment_data_with_connector_response_pm_data(
@@ -799,6 +813,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     });
     let (capture_update, mut payment_attempt_update) = match router_data.response.clone() {
         Err(err) => {
+            is_technical_failure = routing::circuit_breaker::is_technical_failure(&err, None);
             let auth_update = if Some(router_data.auth_type)
                 != payment_data.payment_attempt.authentication_type
             {
@@ -842,6 +857,8 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                         flow_name.clone(),
                     )
                     .await;
+                    is_technical_failure =
+                        routing::circuit_breaker::is_technical_failure(&err, option_gsm.as_ref());
 
                     let gsm_unified_code =
                         option_gsm.as_ref().and_then(|gsm| gsm.unified_code.clone());
@@ -1403,6 +1420,30 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
 
     payment_data.payment_intent = payment_intent;
     payment_data.payment_attempt = payment_attempt;
+
+    // Failing to record the outcome should not fail the payment
+    routing::success_rate::record_payment_attempt_outcome(
+        state,
+        &payment_data,
+        previous_attempt_status,
+    )
+    .await
+    .map_err(|error| logger::error!(success_rate_window_update_error=?error))
+    .ok();
+
+    // Only authorizations reflect the health of the connector, syncs and captures of the attempt
+    // are not counted. Failing to record the outcome should not fail the payment
+    if is_authorization {
+        routing::circuit_breaker::record_connector_call_outcome(
+            state,
+            &payment_data.payment_attempt,
+            is_technical_failure,
+        )
+        .await
+        .map_err(|error| logger::error!(circuit_breaker_update_error=?error))
+        .ok();
+    }
+
     router_data.payment_method_status.and_then(|status| {
         payment_data
             .payment_method_info
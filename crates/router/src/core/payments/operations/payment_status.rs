@@ -466,6 +466,8 @@ async fn get_tracker_for_sync<
         authentication,
         recurring_details: None,
         poll_config: None,
+        expose_routing_decision_trace: request.expose_routing_decision_trace,
+        card_bin_info: None,
     };
 
     let get_trackers_response = operations::GetTrackerResponse {
@@ -1,6 +1,9 @@
-use std::{str::FromStr, vec::IntoIter};
+use std::{str::FromStr, time::Instant, vec::IntoIter};
 
-use common_utils::{ext_traits::Encode, types::MinorUnit};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    types::MinorUnit,
+};
 use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
 use router_env::{
@@ -16,6 +19,7 @@ use crate::{
             flows::{ConstructFlowSpecificData, Feature},
             operations,
         },
+        utils as core_utils,
     },
     db::StorageInterface,
     routes::{
@@ -56,6 +60,16 @@ where
     dyn api::Connector: services::api::ConnectorIntegration<F, FData, types::PaymentsResponseData>,
 {
     let mut retries = None;
+    let retry_policy = business_profile.retry_policy.as_ref();
+    let applied_retry_policy = retry_policy
+        .map(Encode::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the retry policy")?;
+
+    // The latency budget of the retry policy covers the first attempt as well as the retries
+    let initial_attempt_latency = router_data.external_latency.unwrap_or_default();
+    let retry_start_time = Instant::now();
 
     metrics::AUTO_RETRY_ELIGIBLE_REQUEST_COUNT.add(&metrics::CONTEXT, 1, &[]);
 
@@ -95,6 +109,7 @@ where
             validate_result,
             schedule_time,
             true,
+            None,
             frm_suggestion,
             business_profile,
         )
@@ -111,21 +126,91 @@ where
 
             match get_gsm_decision(gsm) {
                 api_models::gsm::GsmDecision::Retry => {
-                    retries = get_retries(state, retries, merchant_account.get_id()).await;
-
-                    if retries.is_none() || retries == Some(0) {
-                        metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(&metrics::CONTEXT, 1, &[]);
-                        logger::info!("retries exhausted for auto_retry payment");
-                        break;
-                    }
-
-                    if connectors.len() == 0 {
-                        logger::info!("connectors exhausted for auto_retry payment");
-                        metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(&metrics::CONTEXT, 1, &[]);
-                        break;
-                    }
-
-                    let connector = super::get_connector_data(&mut connectors)?;
+                    let connector = match retry_policy {
+                        Some(retry_policy) => {
+                            if !is_payment_eligible_for_retry(
+                                retry_policy,
+                                payment_data.payment_attempt.payment_method,
+                                payment_data.payment_attempt.amount,
+                            ) {
+                                metrics::AUTO_RETRY_POLICY_INELIGIBLE_COUNT.add(
+                                    &metrics::CONTEXT,
+                                    1,
+                                    &[],
+                                );
+                                logger::info!(
+                                    "payment is not eligible for retry as per the retry policy"
+                                );
+                                break;
+                            }
+
+                            if payment_data.payment_intent.attempt_count
+                                >= i16::from(retry_policy.max_attempts)
+                            {
+                                metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(&metrics::CONTEXT, 1, &[]);
+                                logger::info!("attempts exhausted for auto_retry payment");
+                                break;
+                            }
+
+                            let elapsed_latency =
+                                initial_attempt_latency + retry_start_time.elapsed().as_millis();
+                            if retry_policy
+                                .latency_budget_in_ms
+                                .is_some_and(|latency_budget| {
+                                    elapsed_latency >= u128::from(latency_budget)
+                                })
+                            {
+                                metrics::AUTO_RETRY_LATENCY_BUDGET_EXHAUSTED_COUNT.add(
+                                    &metrics::CONTEXT,
+                                    1,
+                                    &[],
+                                );
+                                logger::info!(
+                                    ?elapsed_latency,
+                                    "latency budget exhausted for auto_retry payment"
+                                );
+                                break;
+                            }
+
+                            match retry_policy.connector_order {
+                                storage_enums::RetryConnectorOrder::SameConnector => {
+                                    original_connector_data.clone()
+                                }
+                                storage_enums::RetryConnectorOrder::CrossConnector => {
+                                    if connectors.len() == 0 {
+                                        logger::info!(
+                                            "connectors exhausted for auto_retry payment"
+                                        );
+                                        metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(
+                                            &metrics::CONTEXT,
+                                            1,
+                                            &[],
+                                        );
+                                        break;
+                                    }
+
+                                    super::get_connector_data(&mut connectors)?
+                                }
+                            }
+                        }
+                        None => {
+                            retries = get_retries(state, retries, merchant_account.get_id()).await;
+
+                            if retries.is_none() || retries == Some(0) {
+                                metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(&metrics::CONTEXT, 1, &[]);
+                                logger::info!("retries exhausted for auto_retry payment");
+                                break;
+                            }
+
+                            if connectors.len() == 0 {
+                                logger::info!("connectors exhausted for auto_retry payment");
+                                metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(&metrics::CONTEXT, 1, &[]);
+                                break;
+                            }
+
+                            super::get_connector_data(&mut connectors)?
+                        }
+                    };
 
                     router_data = do_retry(
                         &state.clone(),
@@ -141,6 +226,7 @@ where
                         schedule_time,
                         //this is an auto retry payment, but not step-up
                         false,
+                        applied_retry_policy.clone(),
                         frm_suggestion,
                         business_profile,
                     )
@@ -149,11 +235,30 @@ where
                     retries = retries.map(|i| i - 1);
                 }
                 api_models::gsm::GsmDecision::Requeue => {
-                    Err(report!(errors::ApiErrorResponse::NotImplemented {
-                        message: errors::NotImplementedMessage::Reason(
-                            "Requeue not implemented".to_string(),
-                        ),
-                    }))?
+                    // A payment is requeued only once, the requeued attempt falls back to the default action
+                    let is_requeued = payments::is_payment_requeued(
+                        &*state.store,
+                        &payment_data.payment_attempt.payment_id,
+                        &payment_data.payment_attempt.merchant_id,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to check whether the payment was requeued")?;
+                    if is_requeued {
+                        logger::info!("requeue skipped for an already requeued payment");
+                        break;
+                    }
+
+                    router_data = do_requeue(
+                        state,
+                        payment_data,
+                        router_data,
+                        merchant_account,
+                        key_store,
+                        business_profile,
+                    )
+                    .await?;
+                    break;
                 }
                 api_models::gsm::GsmDecision::DoDefault => break,
             }
@@ -187,6 +292,39 @@ pub async fn is_step_up_enabled_for_merchant_connector(
         .unwrap_or(false)
 }
 
+/// Checks whether the payment method and the amount of the payment are eligible for retry as
+/// per the retry policy of the business profile
+pub fn is_payment_eligible_for_retry(
+    retry_policy: &diesel_models::business_profile::RetryPolicy,
+    payment_method: Option<storage_enums::PaymentMethod>,
+    amount: MinorUnit,
+) -> bool {
+    let is_payment_method_eligible =
+        retry_policy
+            .payment_methods
+            .as_ref()
+            .map_or(true, |payment_methods| {
+                payment_method
+                    .is_some_and(|payment_method| payment_methods.contains(&payment_method))
+            });
+
+    let is_amount_eligible = retry_policy
+        .amount_ranges
+        .as_ref()
+        .map_or(true, |amount_ranges| {
+            amount_ranges.iter().any(|amount_range| {
+                amount_range
+                    .min_amount
+                    .map_or(true, |min_amount| amount >= min_amount)
+                    && amount_range
+                        .max_amount
+                        .map_or(true, |max_amount| amount <= max_amount)
+            })
+        });
+
+    is_payment_method_eligible && is_amount_eligible
+}
+
 #[instrument(skip_all)]
 pub async fn get_retries(
     state: &app::SessionState,
@@ -282,6 +420,7 @@ pub async fn do_retry<F, ApiRequest, FData>(
     validate_result: &operations::ValidateResult,
     schedule_time: Option<time::PrimitiveDateTime>,
     is_step_up: bool,
+    retry_policy: Option<serde_json::Value>,
     frm_suggestion: Option<storage_enums::FrmSuggestion>,
     business_profile: &domain::BusinessProfile,
 ) -> RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
@@ -302,8 +441,9 @@ where
         payment_data,
         key_store,
         merchant_account.storage_scheme,
-        router_data,
+        &router_data,
         is_step_up,
+        retry_policy,
     )
     .await?;
 
@@ -329,6 +469,90 @@ where
     Ok(router_data)
 }
 
+/// Moves the failed attempt into a new pending attempt on the same connector, and schedules the
+/// payments requeue workflow to make the connector call for it after the configured delay
+#[instrument(skip_all)]
+pub async fn do_requeue<F, FData>(
+    state: &routes::SessionState,
+    payment_data: &mut payments::PaymentData<F>,
+    mut router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::BusinessProfile,
+) -> RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
+where
+    F: Clone + Send,
+    FData: Send,
+{
+    metrics::AUTO_RETRY_REQUEUE_COUNT.add(&metrics::CONTEXT, 1, &[]);
+
+    // Payment method data is not persisted with the attempt, so it is stored in the temporary
+    // locker for the requeue workflow to fetch it back using the payment token
+    if payment_data.payment_attempt.payment_token.is_none() {
+        if let (Some(payment_method_data), Some(payment_method)) = (
+            payment_data.payment_method_data.as_ref(),
+            payment_data.payment_attempt.payment_method,
+        ) {
+            let payment_token = payments::helpers::store_in_vault_and_generate_ppmt(
+                state,
+                payment_method_data,
+                &payment_data.payment_intent,
+                &payment_data.payment_attempt,
+                payment_method,
+                key_store,
+                Some(business_profile),
+            )
+            .await?;
+            payment_data.payment_attempt.payment_token = Some(payment_token);
+        }
+    }
+
+    modify_trackers(
+        state,
+        router_data.connector.clone(),
+        payment_data,
+        key_store,
+        merchant_account.storage_scheme,
+        &router_data,
+        false,
+        None,
+    )
+    .await?;
+
+    let schedule_time =
+        core_utils::get_requeue_schedule_time(&*state.store, merchant_account.get_id()).await;
+
+    payments::add_process_requeue_task(
+        &*state.store,
+        storage::payment_attempt::PaymentRequeueTrackingData::from(&payment_data.payment_attempt),
+        schedule_time,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while adding requeue task to process tracker")?;
+
+    logger::info!(
+        attempt_id = %payment_data.payment_attempt.attempt_id,
+        ?schedule_time,
+        "payment requeued"
+    );
+
+    // The new attempt stays pending, and the payment stays in processing, until the requeue workflow runs
+    router_data.status = storage_enums::AttemptStatus::Pending;
+    router_data.response = Ok(types::PaymentsResponseData::TransactionResponse {
+        resource_id: types::ResponseId::NoResponseId,
+        redirection_data: None,
+        mandate_reference: None,
+        connector_metadata: None,
+        network_txn_id: None,
+        connector_response_reference_id: None,
+        incremental_authorization_allowed: None,
+        charge_id: None,
+    });
+
+    Ok(router_data)
+}
+
 #[instrument(skip_all)]
 pub async fn modify_trackers<F, FData>(
     state: &routes::SessionState,
@@ -336,8 +560,9 @@ pub async fn modify_trackers<F, FData>(
     payment_data: &mut payments::PaymentData<F>,
     key_store: &domain::MerchantKeyStore,
     storage_scheme: storage_enums::MerchantStorageScheme,
-    router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
+    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
     is_step_up: bool,
+    retry_policy: Option<serde_json::Value>,
 ) -> RouterResult<()>
 where
     F: Clone + Send,
@@ -349,6 +574,7 @@ where
         payment_data.payment_attempt.clone(),
         new_attempt_count,
         is_step_up,
+        retry_policy,
     );
 
     let db = &*state.store;
@@ -361,7 +587,7 @@ where
                 .and_then(|connector_response| connector_response.additional_payment_method_data),
         )?;
 
-    match router_data.response {
+    match &router_data.response {
         Ok(types::PaymentsResponseData::TransactionResponse {
             resource_id,
             connector_metadata,
@@ -386,7 +612,7 @@ where
                     connector_transaction_id: match resource_id {
                         types::ResponseId::NoResponseId => None,
                         types::ResponseId::ConnectorTransactionId(id)
-                        | types::ResponseId::EncodedData(id) => Some(id),
+                        | types::ResponseId::EncodedData(id) => Some(id.to_owned()),
                     },
                     connector_response_reference_id: payment_data
                         .payment_attempt
@@ -398,7 +624,7 @@ where
                         .mandate_id
                         .clone()
                         .and_then(|mandate| mandate.mandate_id),
-                    connector_metadata,
+                    connector_metadata: connector_metadata.to_owned(),
                     payment_token: None,
                     error_code: None,
                     error_message: None,
@@ -414,7 +640,7 @@ where
                     unified_code: None,
                     unified_message: None,
                     payment_method_data: additional_payment_method_data,
-                    charge_id,
+                    charge_id: charge_id.to_owned(),
                 },
                 storage_scheme,
             )
@@ -425,8 +651,8 @@ where
             logger::error!("unexpected response: this response was not expected in Retry flow");
             return Ok(());
         }
-        Err(ref error_response) => {
-            let option_gsm = get_gsm(state, &router_data).await?;
+        Err(error_response) => {
+            let option_gsm = get_gsm(state, router_data).await?;
             let auth_update = if Some(router_data.auth_type)
                 != payment_data.payment_attempt.authentication_type
             {
@@ -492,8 +718,13 @@ pub fn make_new_payment_attempt(
     old_payment_attempt: storage::PaymentAttempt,
     new_attempt_count: i16,
     is_step_up: bool,
+    retry_policy: Option<serde_json::Value>,
 ) -> storage::PaymentAttemptNew {
     let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
+    let routing_decision_trace = get_routing_decision_trace_for_retry(
+        old_payment_attempt.routing_decision_trace,
+        &connector,
+    );
     storage::PaymentAttemptNew {
         connector: Some(connector),
         attempt_id: utils::get_payment_attempt_id(
@@ -528,6 +759,8 @@ pub fn make_new_payment_attempt(
         payment_token: old_payment_attempt.payment_token,
         client_source: old_payment_attempt.client_source,
         client_version: old_payment_attempt.client_version,
+        routing_decision_trace,
+        retry_policy,
         created_at,
         modified_at,
         last_synced,
@@ -535,6 +768,38 @@ pub fn make_new_payment_attempt(
     }
 }
 
+/// The connector of the new attempt was chosen by the routing decision of the old attempt, whose
+/// fee estimates cover all the candidates. The estimated fee of the trace is moved over to the
+/// connector of the new attempt.
+fn get_routing_decision_trace_for_retry(
+    routing_decision_trace: Option<serde_json::Value>,
+    connector: &str,
+) -> Option<serde_json::Value> {
+    let value = routing_decision_trace?;
+    let mut trace = match value
+        .clone()
+        .parse_value::<api_models::routing::RoutingDecisionTrace>("RoutingDecisionTrace")
+    {
+        Ok(trace) => trace,
+        Err(error) => {
+            logger::error!(routing_decision_trace_parsing_error=?error);
+            return Some(value);
+        }
+    };
+
+    trace.estimated_fee = trace
+        .fee_estimates
+        .iter()
+        .find(|estimate| estimate.connector.connector.to_string() == connector)
+        .cloned();
+
+    trace
+        .encode_to_value()
+        .map_err(|error| logger::error!(routing_decision_trace_encoding_error=?error))
+        .ok()
+        .or(Some(value))
+}
+
 pub async fn config_should_call_gsm(
     db: &dyn StorageInterface,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -598,3 +863,105 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payment_eligibility_for_retry() {
+        let retry_policy = diesel_models::business_profile::RetryPolicy {
+            max_attempts: 3,
+            connector_order: storage_enums::RetryConnectorOrder::CrossConnector,
+            payment_methods: Some(vec![storage_enums::PaymentMethod::Card]),
+            amount_ranges: Some(vec![
+                diesel_models::business_profile::RetryAmountRange {
+                    min_amount: Some(MinorUnit::new(100)),
+                    max_amount: Some(MinorUnit::new(1000)),
+                },
+                diesel_models::business_profile::RetryAmountRange {
+                    min_amount: Some(MinorUnit::new(5000)),
+                    max_amount: None,
+                },
+            ]),
+            latency_budget_in_ms: None,
+        };
+
+        let card = Some(storage_enums::PaymentMethod::Card);
+        assert!(is_payment_eligible_for_retry(
+            &retry_policy,
+            card,
+            MinorUnit::new(100)
+        ));
+        assert!(is_payment_eligible_for_retry(
+            &retry_policy,
+            card,
+            MinorUnit::new(10000)
+        ));
+        assert!(!is_payment_eligible_for_retry(
+            &retry_policy,
+            card,
+            MinorUnit::new(2000)
+        ));
+        assert!(!is_payment_eligible_for_retry(
+            &retry_policy,
+            Some(storage_enums::PaymentMethod::Wallet),
+            MinorUnit::new(500)
+        ));
+        assert!(!is_payment_eligible_for_retry(
+            &retry_policy,
+            None,
+            MinorUnit::new(500)
+        ));
+
+        let unrestricted_policy = diesel_models::business_profile::RetryPolicy {
+            payment_methods: None,
+            amount_ranges: None,
+            ..retry_policy
+        };
+        assert!(is_payment_eligible_for_retry(
+            &unrestricted_policy,
+            None,
+            MinorUnit::new(2000)
+        ));
+    }
+
+    #[tokio::test]
+    #[allow(clippy::unwrap_used)]
+    async fn test_payment_is_requeued_only_once() {
+        let db = storage_impl::MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let merchant_id = common_utils::id_type::MerchantId::default();
+        let payment_id = "pay_requeue_once";
+
+        // The payment fails with a requeue decision, first on its original attempt and then on
+        // the requeued attempt, which falls back to the default action
+        for attempt_id in ["pay_requeue_once_1", "pay_requeue_once_2"] {
+            let is_requeued = payments::is_payment_requeued(&db, payment_id, &merchant_id)
+                .await
+                .unwrap();
+            if !is_requeued {
+                let tracking_data = storage::payment_attempt::PaymentRequeueTrackingData {
+                    payment_id: payment_id.to_string(),
+                    attempt_id: attempt_id.to_string(),
+                    merchant_id: merchant_id.clone(),
+                };
+                payments::add_process_requeue_task(
+                    &db,
+                    tracking_data,
+                    common_utils::date_time::now(),
+                )
+                .await
+                .unwrap();
+            }
+        }
+
+        let processes = db.processes.lock().await;
+        assert_eq!(processes.len(), 1);
+        assert_eq!(
+            processes[0].tracking_data["attempt_id"],
+            "pay_requeue_once_1"
+        );
+    }
+}
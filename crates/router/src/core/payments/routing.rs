@@ -1,3 +1,8 @@
+pub mod circuit_breaker;
+pub mod cost_based;
+#[cfg(feature = "olap")]
+pub mod simulation;
+pub mod success_rate;
 mod transformers;
 
 use std::{
@@ -11,8 +16,9 @@ use api_models::{
     admin as admin_api,
     enums::{self as api_enums, CountryAlpha2},
     payments::Address,
-    routing::ConnectorSelection,
+    routing::{ConnectorSelection, VolumeSplitStickinessKey},
 };
+use common_utils::crypto::{self, GenerateDigest};
 use diesel_models::enums as storage_enums;
 use error_stack::ResultExt;
 use euclid::{
@@ -20,6 +26,7 @@ use euclid::{
     dssa::graph::{self as euclid_graph, CgraphExt},
     enums as euclid_enums,
     frontend::{ast, dir as euclid_dir},
+    types::EuclidKey,
 };
 use kgraph_utils::{
     mca as mca_graph,
@@ -32,12 +39,14 @@ use rand::{
     SeedableRng,
 };
 use rustc_hash::FxHashMap;
-use storage_impl::redis::cache::{CacheKey, CGRAPH_CACHE, ROUTING_CACHE};
+use storage_impl::redis::cache::{CacheKey, CGRAPH_CACHE, COMPILED_ROUTING_CACHE, ROUTING_CACHE};
+use strum::IntoEnumIterator;
 
 #[cfg(feature = "payouts")]
 use crate::core::payouts;
 use crate::{
     core::{
+        blocklist::utils as blocklist_utils,
         errors, errors as oss_errors, payments as payments_oss,
         routing::{self, helpers as routing_helpers},
     },
@@ -47,7 +56,7 @@ use crate::{
         domain, storage as oss_storage,
         transformers::{ForeignFrom, ForeignInto, ForeignTryFrom},
     },
-    utils::{OptionExt, ValueExt},
+    utils::{Encode, OptionExt, ValueExt},
     SessionState,
 };
 
@@ -55,7 +64,22 @@ pub enum CachedAlgorithm {
     Single(Box<routing_types::RoutableConnectorChoice>),
     Priority(Vec<routing_types::RoutableConnectorChoice>),
     VolumeSplit(Vec<routing_types::ConnectorVolumeSplit>),
-    Advanced(backend::VirInterpreterBackend<ConnectorSelection>),
+    Advanced(Arc<backend::CompiledBackend<ConnectorSelection>>),
+    SuccessRate(routing_types::SuccessRateAlgorithm),
+    CostBased(routing_types::CostBasedAlgorithm),
+}
+
+impl CachedAlgorithm {
+    fn get_kind(&self) -> routing_types::RoutingAlgorithmKind {
+        match self {
+            Self::Single(_) => routing_types::RoutingAlgorithmKind::Single,
+            Self::Priority(_) => routing_types::RoutingAlgorithmKind::Priority,
+            Self::VolumeSplit(_) => routing_types::RoutingAlgorithmKind::VolumeSplit,
+            Self::Advanced(_) => routing_types::RoutingAlgorithmKind::Advanced,
+            Self::SuccessRate(_) => routing_types::RoutingAlgorithmKind::SuccessRate,
+            Self::CostBased(_) => routing_types::RoutingAlgorithmKind::CostBased,
+        }
+    }
 }
 
 pub struct SessionFlowRoutingInput<'a> {
@@ -74,12 +98,83 @@ pub struct SessionRoutingPmTypeInput<'a> {
     attempt_id: &'a str,
     routing_algorithm: &'a MerchantAccountRoutingAlgorithm,
     backend_input: dsl_inputs::BackendInput,
+    stickiness_input: VolumeSplitStickinessInput,
     allowed_connectors: FxHashMap<String, api::GetToken>,
     profile_id: Option<String>,
 }
 
 type RoutingResult<O> = oss_errors::CustomResult<O, errors::RoutingError>;
 
+/// Attributes of the payment on which the connector of a volume split can be made sticky
+#[derive(Debug, Clone, Default)]
+pub struct VolumeSplitStickinessInput {
+    customer_id: Option<String>,
+    card_fingerprint: Option<String>,
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl VolumeSplitStickinessInput {
+    pub fn new(
+        payment_intent: &oss_storage::PaymentIntent,
+        payment_attempt: &oss_storage::PaymentAttempt,
+    ) -> Self {
+        // The fingerprint is generated by the locker with the fingerprint secret of the merchant,
+        // so the card number never has to be hashed here
+        let card_fingerprint = payment_attempt
+            .fingerprint_id
+            .clone()
+            .or_else(|| payment_attempt.payment_method_id.clone());
+
+        Self {
+            customer_id: payment_intent
+                .customer_id
+                .as_ref()
+                .map(|customer_id| customer_id.get_string_repr().to_owned()),
+            card_fingerprint,
+            metadata: payment_intent
+                .metadata
+                .clone()
+                .and_then(|val| val.parse_value("routing_parameters").ok()),
+        }
+    }
+
+    fn get_value(&self, stickiness_key: &VolumeSplitStickinessKey) -> Option<&str> {
+        match stickiness_key {
+            VolumeSplitStickinessKey::CustomerId => self.customer_id.as_deref(),
+            VolumeSplitStickinessKey::CardFingerprint => self.card_fingerprint.as_deref(),
+            VolumeSplitStickinessKey::Metadata(key) => self
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.get(key))
+                .map(String::as_str),
+        }
+    }
+}
+
+impl<F: Clone> From<&routing::TransactionData<'_, F>> for VolumeSplitStickinessInput {
+    fn from(transaction_data: &routing::TransactionData<'_, F>) -> Self {
+        match transaction_data {
+            routing::TransactionData::Payment(payment_data) => {
+                Self::new(&payment_data.payment_intent, &payment_data.payment_attempt)
+            }
+            #[cfg(feature = "payouts")]
+            routing::TransactionData::Payout(payout_data) => Self {
+                customer_id: payout_data
+                    .payouts
+                    .customer_id
+                    .as_ref()
+                    .map(|customer_id| customer_id.get_string_repr().to_owned()),
+                card_fingerprint: None,
+                metadata: payout_data
+                    .payouts
+                    .metadata
+                    .clone()
+                    .and_then(|val| val.parse_value("routing_parameters").ok()),
+            },
+        }
+    }
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(any(feature = "routing_v2", feature = "business_profile_v2"))
@@ -146,6 +241,7 @@ pub fn make_dsl_input_for_payouts(
             .map(api_enums::Country::from_alpha2),
         business_label: payout_data.payout_attempt.business_label.clone(),
         setup_future_usage: None,
+        timestamp: Some(common_utils::date_time::now_unix_timestamp()),
     };
     let payment_method = dsl_inputs::PaymentMethodInput {
         payment_method: payout_data
@@ -157,6 +253,7 @@ pub fn make_dsl_input_for_payouts(
             .clone()
             .map(api_enums::PaymentMethodType::foreign_from),
         card_network: None,
+        card_bin_info: None,
     };
     Ok(dsl_inputs::BackendInput {
         mandate,
@@ -166,6 +263,135 @@ pub fn make_dsl_input_for_payouts(
     })
 }
 
+/// Product types which the card networks use for the cards of businesses
+const COMMERCIAL_CARD_PRODUCT_TYPES: [&str; 5] =
+    ["business", "commercial", "corporate", "fleet", "purchasing"];
+
+fn get_issuer_country(card_info: &oss_storage::CardInfo) -> Option<api_enums::Country> {
+    let from_code = card_info
+        .country_code
+        .as_deref()
+        .and_then(|code| CountryAlpha2::from_str(&code.trim().to_uppercase()).ok())
+        .map(api_enums::Country::from_alpha2);
+
+    // The issuing country is a country name, written with any casing and spacing
+    let normalize = |name: &str| {
+        name.chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_lowercase()
+    };
+    from_code.or_else(|| {
+        let issuing_country = normalize(card_info.card_issuing_country.as_deref()?);
+        api_enums::Country::iter()
+            .find(|country| normalize(&country.to_string()) == issuing_country)
+    })
+}
+
+fn make_card_bin_info(card_info: &oss_storage::CardInfo) -> dsl_inputs::CardBinInfo {
+    let mentions_any = |value: Option<&String>, words: &[&str]| {
+        let value = value?.to_lowercase();
+        Some(words.iter().any(|word| value.contains(word)))
+    };
+
+    let is_prepaid = match (
+        mentions_any(card_info.card_type.as_ref(), &["prepaid"]),
+        mentions_any(card_info.card_subtype.as_ref(), &["prepaid"]),
+    ) {
+        (None, None) => None,
+        (card_type, card_subtype) => {
+            Some(card_type.unwrap_or_default() || card_subtype.unwrap_or_default())
+        }
+    };
+
+    dsl_inputs::CardBinInfo {
+        issuer_country: get_issuer_country(card_info),
+        issuer: card_info.card_issuer.clone(),
+        product_type: card_info.card_subtype.clone(),
+        is_prepaid,
+        is_commercial: mentions_any(
+            card_info.card_subtype.as_ref(),
+            &COMMERCIAL_CARD_PRODUCT_TYPES,
+        ),
+    }
+}
+
+fn get_card_bin<F: Clone>(payment_data: &payments_oss::PaymentData<F>) -> Option<String> {
+    payment_data
+        .payment_method_data
+        .as_ref()
+        .and_then(|pm_data| match pm_data {
+            domain::PaymentMethodData::Card(card) => Some(card.card_number.get_card_isin()),
+            _ => None,
+        })
+}
+
+/// Looks up the BIN of the card in the cards info table, for the rules on the issuer and the
+/// product of the card. The lookup is made at most once per payment, and the attributes are left
+/// unset if it fails.
+pub async fn populate_card_bin_info<F: Clone>(
+    state: &SessionState,
+    payment_data: &mut payments_oss::PaymentData<F>,
+) {
+    if payment_data.card_bin_info.is_some() {
+        return;
+    }
+    let Some(card_bin) = get_card_bin(payment_data) else {
+        return;
+    };
+
+    let card_info = state
+        .store
+        .get_card_info(&card_bin)
+        .await
+        .map_err(|error| logger::warn!(card_info_error=?error))
+        .ok()
+        .flatten();
+    payment_data.card_bin_info = Some(
+        card_info
+            .as_ref()
+            .map(make_card_bin_info)
+            .unwrap_or_default(),
+    );
+}
+
+/// Generates the locker fingerprint of the card for volume splits which are sticky on the card
+/// fingerprint. The fingerprint is otherwise only generated by the blocklist guard after routing,
+/// so cards used for the first time would not have one yet. The fingerprint is left unset if it
+/// could not be generated.
+pub async fn populate_card_fingerprint<F: Clone>(
+    state: &SessionState,
+    payment_data: &mut payments_oss::PaymentData<F>,
+) {
+    if payment_data.payment_attempt.fingerprint_id.is_some()
+        || !matches!(
+            payment_data.payment_method_data,
+            Some(domain::PaymentMethodData::Card(_))
+        )
+    {
+        return;
+    }
+
+    payment_data.payment_attempt.fingerprint_id = blocklist_utils::generate_payment_fingerprint(
+        state,
+        payment_data.payment_attempt.merchant_id.clone(),
+        payment_data.payment_method_data.clone(),
+    )
+    .await
+    .map_err(|error| logger::warn!(card_fingerprint_error=?error))
+    .ok()
+    .flatten();
+}
+
+/// Returns whether the connector of the volume split is sticky on the card fingerprint
+fn is_sticky_on_card_fingerprint(splits: &[routing_types::ConnectorVolumeSplit]) -> bool {
+    // All the splits share the same stickiness key, as validated while creating the algorithm
+    splits
+        .first()
+        .and_then(|split| split.stickiness_key.as_ref())
+        == Some(&VolumeSplitStickinessKey::CardFingerprint)
+}
+
 pub fn make_dsl_input<F>(
     payment_data: &payments_oss::PaymentData<F>,
 ) -> RoutingResult<dsl_inputs::BackendInput>
@@ -218,19 +444,12 @@ where
 
                 _ => None,
             }),
+        card_bin_info: payment_data.card_bin_info.clone(),
     };
 
     let payment_input = dsl_inputs::PaymentInput {
         amount: payment_data.payment_intent.amount,
-        card_bin: payment_data
-            .payment_method_data
-            .as_ref()
-            .and_then(|pm_data| match pm_data {
-                domain::PaymentMethodData::Card(card) => {
-                    Some(card.card_number.peek().chars().take(6).collect())
-                }
-                _ => None,
-            }),
+        card_bin: get_card_bin(payment_data),
         currency: payment_data.currency,
         authentication_type: payment_data.payment_attempt.authentication_type,
         capture_method: payment_data
@@ -249,6 +468,7 @@ where
             .map(api_enums::Country::from_alpha2),
         business_label: payment_data.payment_intent.business_label.clone(),
         setup_future_usage: payment_data.payment_intent.setup_future_usage,
+        timestamp: Some(common_utils::date_time::now_unix_timestamp()),
     };
 
     let metadata = payment_data
@@ -271,51 +491,120 @@ where
 
 pub async fn perform_static_routing_v1<F: Clone>(
     state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
     merchant_id: &common_utils::id_type::MerchantId,
     algorithm_id: Option<String>,
-    transaction_data: &routing::TransactionData<'_, F>,
-) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let profile_id = match transaction_data {
+    transaction_data: &mut routing::TransactionData<'_, F>,
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    routing_types::RoutingDecisionTrace,
+)> {
+    let profile_id = match &*transaction_data {
         routing::TransactionData::Payment(payment_data) => payment_data
             .payment_intent
             .profile_id
-            .as_ref()
+            .clone()
             .get_required_value("profile_id")
             .change_context(errors::RoutingError::ProfileIdMissing)?,
         #[cfg(feature = "payouts")]
-        routing::TransactionData::Payout(payout_data) => &payout_data.payout_attempt.profile_id,
+        routing::TransactionData::Payout(payout_data) => {
+            payout_data.payout_attempt.profile_id.clone()
+        }
     };
+    let transaction_type = api_enums::TransactionType::from(&*transaction_data);
     let algorithm_id = if let Some(id) = algorithm_id {
         id
     } else {
         let fallback_config = routing_helpers::get_merchant_default_config(
             &*state.clone().store,
-            profile_id,
-            &api_enums::TransactionType::from(transaction_data),
+            &profile_id,
+            &transaction_type,
         )
         .await
         .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
 
-        return Ok(fallback_config);
+        let routing_trace = routing_types::RoutingDecisionTrace {
+            approach: routing_types::RoutingApproach::DefaultFallback,
+            candidates: fallback_config.clone(),
+            ..Default::default()
+        };
+
+        return Ok((fallback_config, routing_trace));
     };
     let cached_algorithm = ensure_algorithm_cached_v1(
         state,
         merchant_id,
         &algorithm_id,
-        Some(profile_id).cloned(),
-        &api_enums::TransactionType::from(transaction_data),
+        Some(profile_id.clone()),
+        &transaction_type,
     )
     .await?;
 
-    Ok(match cached_algorithm.as_ref() {
+    let mut matched_rule = None;
+    let mut fee_estimates = Vec::new();
+    let mut success_rate_dimension_key = None;
+    let connectors = match cached_algorithm.as_ref() {
         CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
 
         CachedAlgorithm::Priority(plist) => plist.clone(),
 
-        CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec(), None)
-            .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+        CachedAlgorithm::VolumeSplit(splits) => {
+            if is_sticky_on_card_fingerprint(splits) {
+                match transaction_data {
+                    routing::TransactionData::Payment(payment_data) => {
+                        populate_card_fingerprint(state, payment_data).await;
+                    }
+                    #[cfg(feature = "payouts")]
+                    routing::TransactionData::Payout(_) => {}
+                }
+            }
 
-        CachedAlgorithm::Advanced(interpreter) => {
+            perform_volume_split(
+                splits.to_vec(),
+                None,
+                Some(&VolumeSplitStickinessInput::from(&*transaction_data)),
+            )
+            .change_context(errors::RoutingError::ConnectorSelectionFailed)?
+        }
+
+        CachedAlgorithm::Advanced(compiled_program) => {
+            let backend_input = match transaction_data {
+                routing::TransactionData::Payment(payment_data) => {
+                    let program = compiled_program.get_program();
+                    if program.has_key(EuclidKey::is_card_bin_derived) {
+                        populate_card_bin_info(state, payment_data).await;
+                    }
+                    let has_card_fingerprint_stickiness =
+                        std::iter::once(&program.default_selection)
+                            .chain(program.rules.iter().map(|rule| &rule.connector_selection))
+                            .any(|selection| {
+                                matches!(
+                                    selection,
+                                    ConnectorSelection::VolumeSplit(splits)
+                                        if is_sticky_on_card_fingerprint(splits)
+                                )
+                            });
+                    if has_card_fingerprint_stickiness {
+                        populate_card_fingerprint(state, payment_data).await;
+                    }
+                    make_dsl_input(payment_data)?
+                }
+                #[cfg(feature = "payouts")]
+                routing::TransactionData::Payout(payout_data) => {
+                    make_dsl_input_for_payouts(payout_data)?
+                }
+            };
+
+            let (connectors, rule_name) = execute_dsl_and_get_connector_v1(
+                backend_input,
+                compiled_program,
+                Some(&VolumeSplitStickinessInput::from(&*transaction_data)),
+            )?;
+            matched_rule = rule_name;
+            connectors
+        }
+
+        CachedAlgorithm::SuccessRate(algorithm) => {
             let backend_input = match transaction_data {
                 routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
                 #[cfg(feature = "payouts")]
@@ -324,9 +613,52 @@ pub async fn perform_static_routing_v1<F: Clone>(
                 }
             };
 
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?
+            let (connectors, dimension_key) = success_rate::perform_success_rate_routing(
+                state,
+                &profile_id,
+                algorithm,
+                &backend_input,
+            )
+            .await?;
+            success_rate_dimension_key = Some(dimension_key);
+            connectors
         }
-    })
+
+        CachedAlgorithm::CostBased(algorithm) => {
+            let backend_input = match transaction_data {
+                routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
+                #[cfg(feature = "payouts")]
+                routing::TransactionData::Payout(payout_data) => {
+                    make_dsl_input_for_payouts(payout_data)?
+                }
+            };
+
+            let (connectors, estimates) = cost_based::perform_cost_based_routing(
+                state,
+                key_store,
+                &profile_id,
+                algorithm,
+                &backend_input,
+                &transaction_type,
+            )
+            .await?;
+            fee_estimates = estimates;
+            connectors
+        }
+    };
+
+    let routing_trace = routing_types::RoutingDecisionTrace {
+        approach: routing_types::RoutingApproach::RoutingAlgorithm,
+        algorithm_id: Some(algorithm_id),
+        algorithm_kind: Some(cached_algorithm.get_kind()),
+        matched_rule,
+        candidates: connectors.clone(),
+        fee_estimates,
+        success_rate_dimension_key,
+        ..Default::default()
+    };
+
+    Ok((connectors, routing_trace))
 }
 
 async fn ensure_algorithm_cached_v1(
@@ -378,6 +710,7 @@ async fn ensure_algorithm_cached_v1(
 pub fn perform_straight_through_routing(
     algorithm: &routing_types::StraightThroughAlgorithm,
     creds_identifier: Option<String>,
+    stickiness_input: Option<&VolumeSplitStickinessInput>,
 ) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, bool)> {
     Ok(match algorithm {
         routing_types::StraightThroughAlgorithm::Single(conn) => {
@@ -387,7 +720,7 @@ pub fn perform_straight_through_routing(
         routing_types::StraightThroughAlgorithm::Priority(conns) => (conns.clone(), true),
 
         routing_types::StraightThroughAlgorithm::VolumeSplit(splits) => (
-            perform_volume_split(splits.to_vec(), None)
+            perform_volume_split(splits.to_vec(), None, stickiness_input)
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)
                 .attach_printable(
                     "Volume Split connector selection error in straight through routing",
@@ -399,22 +732,28 @@ pub fn perform_straight_through_routing(
 
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
-    interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
-) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::RoutingAlgorithm = interpreter
+    compiled_program: &backend::CompiledBackend<ConnectorSelection>,
+    stickiness_input: Option<&VolumeSplitStickinessInput>,
+) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, Option<String>)> {
+    let backend_output = compiled_program
         .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
         .change_context(errors::RoutingError::DslExecutionError)?;
+    let routing_output: routing_types::RoutingAlgorithm =
+        backend_output.connector_selection.foreign_into();
 
-    Ok(match routing_output {
+    let connectors = match routing_output {
         routing_types::RoutingAlgorithm::Priority(plist) => plist,
 
-        routing_types::RoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits, None)
-            .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?,
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
+            perform_volume_split(splits, None, stickiness_input)
+                .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?
+        }
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .attach_printable("Unsupported algorithm received as a result of static routing")?,
-    })
+    };
+
+    Ok((connectors, backend_output.rule_name))
 }
 
 pub async fn refresh_routing_cache_v1(
@@ -440,20 +779,11 @@ pub async fn refresh_routing_cache_v1(
     };
 
     let cached_algorithm = match algorithm {
-        routing_types::RoutingAlgorithm::Single(conn) => CachedAlgorithm::Single(conn),
-        routing_types::RoutingAlgorithm::Priority(plist) => CachedAlgorithm::Priority(plist),
-        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
-            CachedAlgorithm::VolumeSplit(splits)
-        }
-        routing_types::RoutingAlgorithm::Advanced(program) => {
-            let interpreter = backend::VirInterpreterBackend::with_program(program)
-                .change_context(errors::RoutingError::DslBackendInitError)
-                .attach_printable("Error initializing DSL interpreter backend")?;
-
-            CachedAlgorithm::Advanced(interpreter)
-        }
+        routing_types::RoutingAlgorithm::Advanced(program) => CachedAlgorithm::Advanced(
+            get_compiled_routing_program(state, algorithm_id, program).await?,
+        ),
+        algorithm => make_cached_algorithm(algorithm)?,
     };
-
     let arc_cached_algorithm = Arc::new(cached_algorithm);
 
     ROUTING_CACHE
@@ -469,25 +799,91 @@ pub async fn refresh_routing_cache_v1(
     Ok(arc_cached_algorithm)
 }
 
+fn make_cached_algorithm(
+    algorithm: routing_types::RoutingAlgorithm,
+) -> RoutingResult<CachedAlgorithm> {
+    Ok(match algorithm {
+        routing_types::RoutingAlgorithm::Single(conn) => CachedAlgorithm::Single(conn),
+        routing_types::RoutingAlgorithm::Priority(plist) => CachedAlgorithm::Priority(plist),
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
+            CachedAlgorithm::VolumeSplit(splits)
+        }
+        routing_types::RoutingAlgorithm::Advanced(program) => {
+            CachedAlgorithm::Advanced(Arc::new(compile_routing_program(program)?))
+        }
+        routing_types::RoutingAlgorithm::SuccessRate(algorithm) => {
+            CachedAlgorithm::SuccessRate(algorithm)
+        }
+        routing_types::RoutingAlgorithm::CostBased(algorithm) => {
+            CachedAlgorithm::CostBased(algorithm)
+        }
+    })
+}
+
+fn compile_routing_program(
+    program: ast::Program<ConnectorSelection>,
+) -> RoutingResult<backend::CompiledBackend<ConnectorSelection>> {
+    backend::CompiledBackend::with_program(program)
+        .change_context(errors::RoutingError::DslBackendInitError)
+        .attach_printable("Error compiling DSL program")
+}
+
+/// Fetches the compiled form of the program of a routing algorithm from the cache, compiling it
+/// when it is not cached. Profiles switching between their algorithms reuse the compiled programs.
+async fn get_compiled_routing_program(
+    state: &SessionState,
+    algorithm_id: &str,
+    program: ast::Program<ConnectorSelection>,
+) -> RoutingResult<Arc<backend::CompiledBackend<ConnectorSelection>>> {
+    let key = CacheKey {
+        key: algorithm_id.to_string(),
+        prefix: state.tenant.redis_key_prefix.clone(),
+    };
+
+    if let Some(compiled_program) = COMPILED_ROUTING_CACHE
+        .get_val::<Arc<backend::CompiledBackend<ConnectorSelection>>>(key.clone())
+        .await
+    {
+        return Ok(compiled_program);
+    }
+
+    let compiled_program = Arc::new(compile_routing_program(program)?);
+    COMPILED_ROUTING_CACHE
+        .push(key, compiled_program.clone())
+        .await;
+
+    Ok(compiled_program)
+}
+
 pub fn perform_volume_split(
     mut splits: Vec<routing_types::ConnectorVolumeSplit>,
     rng_seed: Option<&str>,
+    stickiness_input: Option<&VolumeSplitStickinessInput>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     let weights: Vec<u8> = splits.iter().map(|sp| sp.split).collect();
-    let weighted_index = distributions::WeightedIndex::new(weights)
-        .change_context(errors::RoutingError::VolumeSplitFailed)
-        .attach_printable("Error creating weighted distribution for volume split")?;
 
-    let idx = if let Some(seed) = rng_seed {
-        let mut hasher = hash_map::DefaultHasher::new();
-        seed.hash(&mut hasher);
-        let hash = hasher.finish();
+    // All the splits share the same stickiness key, as validated while creating the algorithm
+    let stickiness_value = splits
+        .first()
+        .and_then(|split| split.stickiness_key.as_ref())
+        .and_then(|stickiness_key| {
+            let value = stickiness_input.and_then(|input| input.get_value(stickiness_key));
+            if value.is_none() {
+                logger::debug!(
+                    ?stickiness_key,
+                    "stickiness value not found, picking the volume split at random"
+                );
+            }
+            value
+        });
 
-        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(hash);
-        weighted_index.sample(&mut rng)
+    let idx = if let Some(value) = stickiness_value {
+        get_sticky_volume_split_index(&weights, value)?
     } else {
-        let mut rng = rand::thread_rng();
-        weighted_index.sample(&mut rng)
+        let weighted_index = distributions::WeightedIndex::new(weights)
+            .change_context(errors::RoutingError::VolumeSplitFailed)
+            .attach_printable("Error creating weighted distribution for volume split")?;
+        get_random_volume_split_index(&weighted_index, rng_seed)
     };
 
     splits
@@ -503,6 +899,57 @@ pub fn perform_volume_split(
     Ok(splits.into_iter().map(|sp| sp.connector).collect())
 }
 
+fn get_random_volume_split_index(
+    weighted_index: &distributions::WeightedIndex<u8>,
+    rng_seed: Option<&str>,
+) -> usize {
+    if let Some(seed) = rng_seed {
+        let mut hasher = hash_map::DefaultHasher::new();
+        seed.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(hash);
+        weighted_index.sample(&mut rng)
+    } else {
+        let mut rng = rand::thread_rng();
+        weighted_index.sample(&mut rng)
+    }
+}
+
+/// Hashes the stickiness value into one of the buckets formed by the weights of the splits.
+///
+/// A value lands in the same bucket for as long as the weights are unchanged, and changing a
+/// weight only moves the values between the neighbouring buckets.
+fn get_sticky_volume_split_index(weights: &[u8], stickiness_value: &str) -> RoutingResult<usize> {
+    let total_weight: u64 = weights.iter().map(|weight| u64::from(*weight)).sum();
+    if total_weight == 0 {
+        return Err(errors::RoutingError::VolumeSplitFailed)
+            .attach_printable("Volume split weights must not all be zero");
+    }
+
+    let digest = crypto::Sha256
+        .generate_digest(stickiness_value.as_bytes())
+        .change_context(errors::RoutingError::VolumeSplitFailed)
+        .attach_printable("Failed to hash the stickiness value of the volume split")?;
+    let hash = digest
+        .get(..8)
+        .and_then(|bytes| <[u8; 8]>::try_from(bytes).ok())
+        .map(u64::from_be_bytes)
+        .ok_or(errors::RoutingError::VolumeSplitFailed)
+        .attach_printable("Stickiness value digest is too short")?;
+
+    let bucket = hash % total_weight;
+    let mut bucket_end = 0;
+    weights
+        .iter()
+        .position(|weight| {
+            bucket_end += u64::from(*weight);
+            bucket < bucket_end
+        })
+        .ok_or(errors::RoutingError::VolumeSplitFailed)
+        .attach_printable("Stickiness bucket lookup failed")
+}
+
 pub async fn get_merchant_cgraph<'a>(
     state: &SessionState,
     key_store: &domain::MerchantKeyStore,
@@ -650,7 +1097,10 @@ async fn perform_cgraph_filtering(
     eligible_connectors: Option<&Vec<api_enums::RoutableConnectors>>,
     profile_id: Option<String>,
     transaction_type: &api_enums::TransactionType,
-) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    Vec<routing_types::EliminatedConnector>,
+)> {
     let context = euclid_graph::AnalysisContext::from_dir_values(
         backend_input
             .into_context()
@@ -659,6 +1109,7 @@ async fn perform_cgraph_filtering(
     let cached_cgraph = get_merchant_cgraph(state, key_store, profile_id, transaction_type).await?;
 
     let mut final_selection = Vec::<routing_types::RoutableConnectorChoice>::new();
+    let mut eliminated_connectors = Vec::<routing_types::EliminatedConnector>::new();
     for choice in chosen {
         let routable_connector = choice.connector;
         let euclid_choice: ast::ConnectorChoice = choice.clone().foreign_into();
@@ -678,12 +1129,22 @@ async fn perform_cgraph_filtering(
         let filter_eligible =
             eligible_connectors.map_or(true, |list| list.contains(&routable_connector));
 
-        if cgraph_eligible && filter_eligible {
+        if !cgraph_eligible {
+            eliminated_connectors.push(routing_types::EliminatedConnector {
+                connector: choice,
+                reason: routing_types::ConnectorEliminationReason::ConstraintGraph,
+            });
+        } else if !filter_eligible {
+            eliminated_connectors.push(routing_types::EliminatedConnector {
+                connector: choice,
+                reason: routing_types::ConnectorEliminationReason::EligibleConnectorsFilter,
+            });
+        } else {
             final_selection.push(choice);
         }
     }
 
-    Ok(final_selection)
+    Ok((final_selection, eliminated_connectors))
 }
 
 pub async fn perform_eligibility_analysis<F: Clone>(
@@ -693,7 +1154,10 @@ pub async fn perform_eligibility_analysis<F: Clone>(
     transaction_data: &routing::TransactionData<'_, F>,
     eligible_connectors: Option<&Vec<api_enums::RoutableConnectors>>,
     profile_id: Option<String>,
-) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    Vec<routing_types::EliminatedConnector>,
+)> {
     let backend_input = match transaction_data {
         routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
         #[cfg(feature = "payouts")]
@@ -752,6 +1216,7 @@ pub async fn perform_fallback_routing<F: Clone>(
         &api_enums::TransactionType::from(transaction_data),
     )
     .await
+    .map(|(fallback_selection, _)| fallback_selection)
 }
 
 pub async fn perform_eligibility_analysis_with_fallback<F: Clone>(
@@ -761,8 +1226,9 @@ pub async fn perform_eligibility_analysis_with_fallback<F: Clone>(
     transaction_data: &routing::TransactionData<'_, F>,
     eligible_connectors: Option<Vec<api_enums::RoutableConnectors>>,
     profile_id: Option<String>,
+    routing_trace: Option<&mut routing_types::RoutingDecisionTrace>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let mut final_selection = perform_eligibility_analysis(
+    let (mut final_selection, mut eliminated_candidates) = perform_eligibility_analysis(
         state,
         key_store,
         chosen,
@@ -781,6 +1247,8 @@ pub async fn perform_eligibility_analysis_with_fallback<F: Clone>(
     )
     .await;
 
+    let eligible_candidates = final_selection.clone();
+
     final_selection.append(
         &mut fallback_selection
             .unwrap_or_default()
@@ -792,15 +1260,74 @@ pub async fn perform_eligibility_analysis_with_fallback<F: Clone>(
             .collect::<Vec<_>>(),
     );
 
+    let (final_selection, mut circuit_eliminated_candidates) =
+        circuit_breaker::filter_connectors_by_circuit_state(state, final_selection).await;
+    eliminated_candidates.append(&mut circuit_eliminated_candidates);
+
     let final_selected_connectors = final_selection
         .iter()
         .map(|item| item.connector)
         .collect::<Vec<_>>();
     logger::debug!(final_selected_connectors_for_routing=?final_selected_connectors, "List of final selected connectors for routing");
 
+    if let Some(routing_trace) = routing_trace {
+        routing_trace.fallback_used = eligible_candidates.is_empty() && !final_selection.is_empty();
+        routing_trace.eligible_candidates = eligible_candidates;
+        routing_trace.eliminated_candidates = eliminated_candidates;
+        routing_trace.estimated_fee = final_selection.first().and_then(|connector| {
+            routing_trace
+                .fee_estimates
+                .iter()
+                .find(|estimate| &estimate.connector == connector)
+                .cloned()
+        });
+        routing_trace.final_connectors = final_selection.clone();
+    }
+
     Ok(final_selection)
 }
 
+/// Builds the routing decision trace for the connectors provided in the payment request, which is
+/// completed by the eligibility analysis when it is performed.
+pub fn make_straight_through_routing_trace(
+    algorithm: &routing_types::StraightThroughAlgorithm,
+    connectors: &[routing_types::RoutableConnectorChoice],
+) -> routing_types::RoutingDecisionTrace {
+    let algorithm_kind = match algorithm {
+        routing_types::StraightThroughAlgorithm::Single(_) => {
+            routing_types::RoutingAlgorithmKind::Single
+        }
+        routing_types::StraightThroughAlgorithm::Priority(_) => {
+            routing_types::RoutingAlgorithmKind::Priority
+        }
+        routing_types::StraightThroughAlgorithm::VolumeSplit(_) => {
+            routing_types::RoutingAlgorithmKind::VolumeSplit
+        }
+    };
+
+    routing_types::RoutingDecisionTrace {
+        approach: routing_types::RoutingApproach::StraightThrough,
+        algorithm_kind: Some(algorithm_kind),
+        candidates: connectors.to_vec(),
+        eligible_candidates: connectors.to_vec(),
+        final_connectors: connectors.to_vec(),
+        ..Default::default()
+    }
+}
+
+/// Stores the routing decision trace on the payment attempt, to be persisted along with the
+/// connector chosen for the attempt.
+pub fn set_routing_decision_trace<F: Clone>(
+    payment_data: &mut payments_oss::PaymentData<F>,
+    routing_trace: routing_types::RoutingDecisionTrace,
+) {
+    // Failing to record the trace should not fail the payment
+    payment_data.payment_attempt.routing_decision_trace = routing_trace
+        .encode_to_value()
+        .map_err(|error| logger::error!(routing_decision_trace_encoding_error=?error))
+        .ok();
+}
+
 pub async fn perform_session_flow_routing(
     session_input: SessionFlowRoutingInput<'_>,
     transaction_type: &api_enums::TransactionType,
@@ -851,6 +1378,7 @@ pub async fn perform_session_flow_routing(
         payment_method: None,
         payment_method_type: None,
         card_network: None,
+        card_bin_info: None,
     };
 
     let payment_input = dsl_inputs::PaymentInput {
@@ -877,6 +1405,7 @@ pub async fn perform_session_flow_routing(
             .map(storage_enums::Country::from_alpha2),
         business_label: session_input.payment_intent.business_label.clone(),
         setup_future_usage: session_input.payment_intent.setup_future_usage,
+        timestamp: Some(common_utils::date_time::now_unix_timestamp()),
     };
 
     let metadata = session_input
@@ -900,6 +1429,11 @@ pub async fn perform_session_flow_routing(
         },
     };
 
+    let stickiness_input = VolumeSplitStickinessInput::new(
+        session_input.payment_intent,
+        session_input.payment_attempt,
+    );
+
     for connector_data in session_input.chosen.iter() {
         pm_type_map
             .entry(connector_data.payment_method_type)
@@ -928,6 +1462,7 @@ pub async fn perform_session_flow_routing(
             attempt_id: &session_input.payment_attempt.attempt_id,
             routing_algorithm: &routing_algorithm,
             backend_input: backend_input.clone(),
+            stickiness_input: stickiness_input.clone(),
             allowed_connectors,
 
             profile_id: session_input.payment_intent.profile_id.clone(),
@@ -998,14 +1533,22 @@ async fn perform_session_routing_for_pm_type(
         match cached_algorithm.as_ref() {
             CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
             CachedAlgorithm::Priority(plist) => plist.clone(),
-            CachedAlgorithm::VolumeSplit(splits) => {
-                perform_volume_split(splits.to_vec(), Some(session_pm_input.attempt_id))
-                    .change_context(errors::RoutingError::ConnectorSelectionFailed)?
+            CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(
+                splits.to_vec(),
+                Some(session_pm_input.attempt_id),
+                Some(&session_pm_input.stickiness_input),
+            )
+            .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+            CachedAlgorithm::Advanced(compiled_program) => {
+                execute_dsl_and_get_connector_v1(
+                    session_pm_input.backend_input.clone(),
+                    compiled_program,
+                    Some(&session_pm_input.stickiness_input),
+                )?
+                .0
             }
-            CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
-                session_pm_input.backend_input.clone(),
-                interpreter,
-            )?,
+            CachedAlgorithm::SuccessRate(algorithm) => algorithm.connectors.clone(),
+            CachedAlgorithm::CostBased(algorithm) => algorithm.connectors.clone(),
         }
     } else {
         routing_helpers::get_merchant_default_config(
@@ -1021,7 +1564,7 @@ async fn perform_session_routing_for_pm_type(
         .change_context(errors::RoutingError::FallbackConfigFetchFailed)?
     };
 
-    let mut final_selection = perform_cgraph_filtering(
+    let (mut final_selection, _) = perform_cgraph_filtering(
         &session_pm_input.state.clone(),
         session_pm_input.key_store,
         chosen_connectors,
@@ -1045,7 +1588,7 @@ async fn perform_session_routing_for_pm_type(
         .await
         .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
 
-        final_selection = perform_cgraph_filtering(
+        (final_selection, _) = perform_cgraph_filtering(
             &session_pm_input.state.clone(),
             session_pm_input.key_store,
             fallback,
@@ -1095,6 +1638,7 @@ pub fn make_dsl_input_for_surcharge(
             .map(api_enums::Country::from_alpha2),
         business_label: payment_intent.business_label.clone(),
         setup_future_usage: payment_intent.setup_future_usage,
+        timestamp: Some(common_utils::date_time::now_unix_timestamp()),
     };
     let metadata = payment_intent
         .metadata
@@ -1108,6 +1652,7 @@ pub fn make_dsl_input_for_surcharge(
         payment_method: None,
         payment_method_type: None,
         card_network: None,
+        card_bin_info: None,
     };
     let backend_input = dsl_inputs::BackendInput {
         metadata,
@@ -1117,3 +1662,185 @@ pub fn make_dsl_input_for_surcharge(
     };
     Ok(backend_input)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn make_split(
+        connector: api_enums::RoutableConnectors,
+        split: u8,
+        stickiness_key: Option<VolumeSplitStickinessKey>,
+    ) -> routing_types::ConnectorVolumeSplit {
+        routing_types::ConnectorVolumeSplit {
+            connector: routing_types::RoutableConnectorChoice {
+                choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+                connector,
+                merchant_connector_id: None,
+            },
+            split,
+            stickiness_key,
+        }
+    }
+
+    fn make_card_info(
+        card_issuing_country: Option<&str>,
+        country_code: Option<&str>,
+        card_type: Option<&str>,
+        card_subtype: Option<&str>,
+    ) -> oss_storage::CardInfo {
+        oss_storage::CardInfo {
+            card_iin: "411111".to_string(),
+            card_issuer: Some("HDFC BANK".to_string()),
+            card_network: None,
+            card_type: card_type.map(str::to_string),
+            card_subtype: card_subtype.map(str::to_string),
+            card_issuing_country: card_issuing_country.map(str::to_string),
+            bank_code_id: None,
+            bank_code: None,
+            country_code: country_code.map(str::to_string),
+            date_created: common_utils::date_time::now(),
+            last_updated: None,
+            last_updated_provider: None,
+        }
+    }
+
+    #[test]
+    fn test_card_bin_info_from_card_info() {
+        let card_bin_info = make_card_bin_info(&make_card_info(
+            Some("UNITED STATES OF AMERICA"),
+            None,
+            Some("CREDIT"),
+            Some("CORPORATE T&E"),
+        ));
+        assert_eq!(
+            card_bin_info.issuer_country,
+            Some(api_enums::Country::UnitedStatesOfAmerica)
+        );
+        assert_eq!(card_bin_info.issuer.as_deref(), Some("HDFC BANK"));
+        assert_eq!(card_bin_info.product_type.as_deref(), Some("CORPORATE T&E"));
+        assert_eq!(card_bin_info.is_prepaid, Some(false));
+        assert_eq!(card_bin_info.is_commercial, Some(true));
+
+        let card_bin_info = make_card_bin_info(&make_card_info(
+            Some("INDIA"),
+            Some("fr"),
+            Some("PREPAID"),
+            None,
+        ));
+        assert_eq!(
+            card_bin_info.issuer_country,
+            Some(api_enums::Country::France)
+        );
+        assert_eq!(card_bin_info.is_prepaid, Some(true));
+        assert_eq!(card_bin_info.is_commercial, None);
+
+        let card_bin_info = make_card_bin_info(&make_card_info(Some("ATLANTIS"), None, None, None));
+        assert_eq!(card_bin_info.issuer_country, None);
+        assert_eq!(card_bin_info.is_prepaid, None);
+    }
+
+    #[test]
+    fn test_sticky_volume_split_index() {
+        let weights = [30, 0, 70];
+
+        let mut counts = [0_u32; 3];
+        for customer in 0..1000 {
+            let value = format!("cus_{customer}");
+            let idx = get_sticky_volume_split_index(&weights, &value).unwrap();
+            assert_eq!(
+                idx,
+                get_sticky_volume_split_index(&weights, &value).unwrap()
+            );
+            *counts.get_mut(idx).unwrap() += 1;
+        }
+
+        assert_eq!(counts.get(1), Some(&0));
+        assert!((200..400).contains(counts.first().unwrap()));
+        assert!((600..800).contains(counts.get(2).unwrap()));
+        assert!(get_sticky_volume_split_index(&[0, 0], "cus_1").is_err());
+    }
+
+    #[test]
+    fn test_sticky_volume_split() {
+        let splits = vec![
+            make_split(
+                api_enums::RoutableConnectors::Stripe,
+                50,
+                Some(VolumeSplitStickinessKey::CustomerId),
+            ),
+            make_split(
+                api_enums::RoutableConnectors::Adyen,
+                50,
+                Some(VolumeSplitStickinessKey::CustomerId),
+            ),
+        ];
+        let stickiness_input = VolumeSplitStickinessInput {
+            customer_id: Some("cus_123".to_string()),
+            ..Default::default()
+        };
+
+        let first_choice = perform_volume_split(splits.clone(), None, Some(&stickiness_input))
+            .unwrap()
+            .first()
+            .unwrap()
+            .connector;
+        for _ in 0..20 {
+            let choice = perform_volume_split(splits.clone(), None, Some(&stickiness_input))
+                .unwrap()
+                .first()
+                .unwrap()
+                .connector;
+            assert_eq!(choice, first_choice);
+        }
+
+        // The split is picked at random when the payment has no value for the key
+        let connectors =
+            perform_volume_split(splits, None, Some(&VolumeSplitStickinessInput::default()))
+                .unwrap();
+        assert_eq!(connectors.len(), 2);
+    }
+
+    #[test]
+    fn test_card_fingerprint_stickiness_for_first_time_card() {
+        let splits = vec![
+            make_split(
+                api_enums::RoutableConnectors::Stripe,
+                50,
+                Some(VolumeSplitStickinessKey::CardFingerprint),
+            ),
+            make_split(
+                api_enums::RoutableConnectors::Adyen,
+                50,
+                Some(VolumeSplitStickinessKey::CardFingerprint),
+            ),
+        ];
+        // The fingerprint of a card used for the first time is generated before routing
+        assert!(is_sticky_on_card_fingerprint(&splits));
+        assert!(!is_sticky_on_card_fingerprint(&[make_split(
+            api_enums::RoutableConnectors::Stripe,
+            100,
+            Some(VolumeSplitStickinessKey::CustomerId),
+        )]));
+
+        // A first time card has no saved payment method, only the fingerprint generated for it
+        let stickiness_input = VolumeSplitStickinessInput {
+            card_fingerprint: Some("fingerprint_123".to_string()),
+            ..Default::default()
+        };
+        let first_choice = perform_volume_split(splits.clone(), None, Some(&stickiness_input))
+            .unwrap()
+            .first()
+            .unwrap()
+            .connector;
+        for _ in 0..20 {
+            let choice = perform_volume_split(splits.clone(), None, Some(&stickiness_input))
+                .unwrap()
+                .first()
+                .unwrap()
+                .connector;
+            assert_eq!(choice, first_choice);
+        }
+    }
+}
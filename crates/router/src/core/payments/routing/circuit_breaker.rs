@@ -0,0 +1,598 @@
+use std::{collections::HashMap, str::FromStr};
+
+use error_stack::ResultExt;
+use rand::Rng;
+use redis_interface::{DelReply, RedisConnectionPool, SetnxReply};
+use router_env::metrics::add_attributes;
+
+use super::RoutingResult;
+use crate::{
+    configs::settings::CircuitBreakerConfig,
+    consts,
+    core::{errors, metrics},
+    events::audit_events::{AuditEvent, AuditEventType},
+    logger,
+    types::{self, api::routing as routing_types, storage},
+    SessionState,
+};
+
+const TOTAL_FIELD: &str = "total";
+const FAILURE_FIELD: &str = "failure";
+const OPENED_AT_FIELD: &str = "opened_at";
+const HALF_OPENED_AT_FIELD: &str = "half_opened_at";
+const PROBE_SUCCESS_FIELD: &str = "probe_success";
+
+/// Reads the state of the circuit of a merchant connector account, moving the circuit to half
+/// open once its open duration has elapsed.
+///
+/// KEYS hold the open key and the tripped key of the account. ARGV holds the field recording the
+/// move to half open, the current time and the expiry of the tripped state in seconds. Returns 0
+/// when the circuit is closed, 1 when it is open, 2 when it is half open and 3 when it has just
+/// moved from open to half open.
+const READ_CIRCUIT_STATE_SCRIPT: &str = r#"
+if redis.call('EXISTS', KEYS[1]) == 1 then
+    return 1
+end
+if redis.call('EXISTS', KEYS[2]) == 0 then
+    return 0
+end
+local circuit_state = 2
+if redis.call('HSETNX', KEYS[2], ARGV[1], ARGV[2]) == 1 then
+    circuit_state = 3
+end
+redis.call('EXPIRE', KEYS[2], ARGV[3])
+return circuit_state
+"#;
+
+/// Records a call in the current bucket of the failure window, and sums the calls and the
+/// technical failures of the whole window.
+///
+/// KEYS are the buckets of the window, the current one last. ARGV holds the total field, the
+/// failure field, whether the call is a technical failure (`1` or `0`) and the expiry of the
+/// bucket in seconds. Returns the number of calls and of technical failures in the window.
+const UPDATE_FAILURE_WINDOW_SCRIPT: &str = r#"
+local current_bucket = KEYS[#KEYS]
+redis.call('HINCRBY', current_bucket, ARGV[1], 1)
+if ARGV[3] == '1' then
+    redis.call('HINCRBY', current_bucket, ARGV[2], 1)
+end
+redis.call('EXPIRE', current_bucket, ARGV[4])
+
+local total, failures = 0, 0
+for _, key in ipairs(KEYS) do
+    total = total + (tonumber(redis.call('HGET', key, ARGV[1])) or 0)
+    failures = failures + (tonumber(redis.call('HGET', key, ARGV[2])) or 0)
+end
+return {total, failures}
+"#;
+
+/// State of the circuit breaker of a merchant connector account.
+///
+/// The circuit is closed while the connector is healthy. It is opened once the technical failures
+/// of the connector cross the configured threshold, and the connector then receives no traffic
+/// for the open duration. After that the circuit is half open, and a limited share of the
+/// traffic probes the connector: a failed probe opens the circuit again, while enough
+/// successful probes close it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+/// Returns whether the error response of the connector indicates that the connector itself is
+/// failing, rather than the payment being declined.
+///
+/// Server errors, timeouts and errors for which the GSM decision is to requeue the payment are
+/// considered technical failures.
+pub fn is_technical_failure(
+    error: &types::ErrorResponse,
+    gsm: Option<&storage::gsm::GatewayStatusMap>,
+) -> bool {
+    (500..=599).contains(&error.status_code)
+        || error.code == consts::REQUEST_TIMEOUT_ERROR_CODE
+        || gsm.is_some_and(|gsm| {
+            api_models::gsm::GsmDecision::from_str(&gsm.decision)
+                .is_ok_and(|decision| decision == api_models::gsm::GsmDecision::Requeue)
+        })
+}
+
+/// Drops the connectors whose circuit is open from the selection, and lets through only the
+/// configured share of the traffic to the connectors whose circuit is half open.
+///
+/// Connectors chosen without a merchant connector account are not tracked, and are always kept.
+/// When every connector of the selection would be dropped, the selection is left untouched so
+/// that the payment is still attempted.
+pub async fn filter_connectors_by_circuit_state(
+    state: &SessionState,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> (
+    Vec<routing_types::RoutableConnectorChoice>,
+    Vec<routing_types::EliminatedConnector>,
+) {
+    let config = &state.conf.circuit_breaker;
+    if !config.enabled || connectors.is_empty() {
+        return (connectors, Vec::new());
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(circuit_breaker_redis_connection_error=?error);
+            return (connectors, Vec::new());
+        }
+    };
+
+    let tracked_accounts = connectors
+        .iter()
+        .filter_map(|choice| {
+            choice
+                .merchant_connector_id
+                .as_deref()
+                .map(|merchant_connector_id| (choice.connector.to_string(), merchant_connector_id))
+        })
+        .collect::<Vec<_>>();
+
+    // Routing should not fail because the states could not be read, the circuits are assumed to be closed
+    let circuit_states = get_circuit_states(state, &redis_conn, &tracked_accounts)
+        .await
+        .map_err(|error| logger::error!(circuit_breaker_state_fetch_error=?error))
+        .unwrap_or_default();
+
+    let mut selection = Vec::with_capacity(connectors.len());
+    let mut eliminated = Vec::new();
+    for choice in &connectors {
+        let circuit_state = choice
+            .merchant_connector_id
+            .as_ref()
+            .and_then(|merchant_connector_id| circuit_states.get(merchant_connector_id.as_str()))
+            .copied()
+            .unwrap_or(CircuitState::Closed);
+
+        let is_allowed = match circuit_state {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => {
+                rand::thread_rng().gen_range(0..100) < config.half_open_traffic_percent
+            }
+        };
+
+        if is_allowed {
+            selection.push(choice.clone());
+        } else {
+            eliminated.push(routing_types::EliminatedConnector {
+                connector: choice.clone(),
+                reason: routing_types::ConnectorEliminationReason::CircuitBreaker,
+            });
+        }
+    }
+
+    if selection.is_empty() {
+        logger::warn!(
+            "Circuits of all the selected connectors are open, ignoring the circuit breaker"
+        );
+        return (connectors, Vec::new());
+    }
+
+    for eliminated_connector in &eliminated {
+        metrics::CIRCUIT_BREAKER_CONNECTOR_ELIMINATED.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([(
+                "connector",
+                eliminated_connector.connector.connector.to_string(),
+            )]),
+        );
+    }
+
+    (selection, eliminated)
+}
+
+/// Feeds the outcome of the connector call made for the payment attempt into the circuit breaker
+/// of its merchant connector account.
+pub async fn record_connector_call_outcome(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+    is_technical_failure: bool,
+) -> RoutingResult<()> {
+    let config = &state.conf.circuit_breaker;
+    if !config.enabled {
+        return Ok(());
+    }
+
+    let (Some(connector), Some(merchant_connector_id)) = (
+        payment_attempt.connector.as_deref(),
+        payment_attempt.merchant_connector_id.as_deref(),
+    ) else {
+        return Ok(());
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::RoutingError::CircuitBreakerStateUpdateFailed)
+        .attach_printable("Failed to get redis connection")?;
+
+    match get_circuit_state(state, &redis_conn, connector, merchant_connector_id).await? {
+        // Calls can still reach the connector while the circuit is open, through straight through
+        // routing or when every connector is open, they are not counted
+        CircuitState::Open => Ok(()),
+        CircuitState::HalfOpen if is_technical_failure => {
+            open_circuit(
+                state,
+                &redis_conn,
+                connector,
+                merchant_connector_id,
+                CircuitState::HalfOpen,
+            )
+            .await
+        }
+        CircuitState::HalfOpen => {
+            record_successful_probe(state, &redis_conn, connector, merchant_connector_id).await
+        }
+        CircuitState::Closed => {
+            let (total, failures) = update_failure_window(
+                &redis_conn,
+                config,
+                merchant_connector_id,
+                is_technical_failure,
+            )
+            .await?;
+
+            if is_technical_failure && should_open_circuit(config, total, failures) {
+                open_circuit(
+                    state,
+                    &redis_conn,
+                    connector,
+                    merchant_connector_id,
+                    CircuitState::Closed,
+                )
+                .await?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Returns the states of the circuits of the merchant connector accounts, keyed by the merchant
+/// connector id. The keys of different accounts may be in different slots of a redis cluster, so
+/// the states are read concurrently with a script per account.
+async fn get_circuit_states<'a>(
+    state: &SessionState,
+    redis_conn: &RedisConnectionPool,
+    accounts: &[(String, &'a str)],
+) -> RoutingResult<HashMap<&'a str, CircuitState>> {
+    let circuit_states = futures::future::try_join_all(accounts.iter().map(
+        |(connector, merchant_connector_id)| async move {
+            get_circuit_state(state, redis_conn, connector, merchant_connector_id)
+                .await
+                .map(|circuit_state| (*merchant_connector_id, circuit_state))
+        },
+    ))
+    .await?;
+
+    Ok(circuit_states.into_iter().collect())
+}
+
+/// Returns the state of the circuit, moving it from open to half open once the open duration
+/// has elapsed.
+async fn get_circuit_state(
+    state: &SessionState,
+    redis_conn: &RedisConnectionPool,
+    connector: &str,
+    merchant_connector_id: &str,
+) -> RoutingResult<CircuitState> {
+    let keys = vec![
+        get_open_key(merchant_connector_id),
+        get_tripped_key(merchant_connector_id),
+    ];
+    let args = vec![
+        HALF_OPENED_AT_FIELD.to_string(),
+        common_utils::date_time::now_unix_timestamp().to_string(),
+        get_tripped_state_ttl(&state.conf.circuit_breaker).to_string(),
+    ];
+
+    let reply = redis_conn
+        .evaluate_redis_script::<_, i64>(READ_CIRCUIT_STATE_SCRIPT, keys, args)
+        .await
+        .change_context(errors::RoutingError::CircuitBreakerStateFetchFailed)?;
+
+    Ok(match reply {
+        1 => CircuitState::Open,
+        2 => CircuitState::HalfOpen,
+        // Only the first read after the open duration records the transition to half open
+        3 => {
+            emit_state_transition(
+                state,
+                connector,
+                merchant_connector_id,
+                CircuitState::Open,
+                CircuitState::HalfOpen,
+            );
+            CircuitState::HalfOpen
+        }
+        _ => CircuitState::Closed,
+    })
+}
+
+async fn open_circuit(
+    state: &SessionState,
+    redis_conn: &RedisConnectionPool,
+    connector: &str,
+    merchant_connector_id: &str,
+    previous_state: CircuitState,
+) -> RoutingResult<()> {
+    let config = &state.conf.circuit_breaker;
+
+    // Concurrent failures may all attempt to open the circuit, only the first one records it
+    let reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &get_open_key(merchant_connector_id),
+            common_utils::date_time::now_unix_timestamp(),
+            Some(i64::from(config.open_duration_in_secs)),
+        )
+        .await
+        .change_context(errors::RoutingError::CircuitBreakerStateUpdateFailed)?;
+    if reply == SetnxReply::KeyNotSet {
+        return Ok(());
+    }
+
+    // The probes of the previous half open state, if any, are discarded
+    let tripped_key = get_tripped_key(merchant_connector_id);
+    redis_conn
+        .delete_key(&tripped_key)
+        .await
+        .change_context(errors::RoutingError::CircuitBreakerStateUpdateFailed)?;
+    redis_conn
+        .set_hash_fields(
+            &tripped_key,
+            vec![(
+                OPENED_AT_FIELD,
+                common_utils::date_time::now_unix_timestamp().to_string(),
+            )],
+            Some(i64::from(get_tripped_state_ttl(config))),
+        )
+        .await
+        .change_context(errors::RoutingError::CircuitBreakerStateUpdateFailed)?;
+
+    emit_state_transition(
+        state,
+        connector,
+        merchant_connector_id,
+        previous_state,
+        CircuitState::Open,
+    );
+
+    Ok(())
+}
+
+async fn record_successful_probe(
+    state: &SessionState,
+    redis_conn: &RedisConnectionPool,
+    connector: &str,
+    merchant_connector_id: &str,
+) -> RoutingResult<()> {
+    let config = &state.conf.circuit_breaker;
+    let tripped_key = get_tripped_key(merchant_connector_id);
+
+    let probe_successes = redis_conn
+        .increment_field_in_hash(&tripped_key, PROBE_SUCCESS_FIELD, 1)
+        .await
+        .change_context(errors::RoutingError::CircuitBreakerStateUpdateFailed)?;
+
+    // The increment recreates the state if the circuit was closed concurrently, it must not outlive the ttl
+    redis_conn
+        .set_expiry(&tripped_key, i64::from(get_tripped_state_ttl(config)))
+        .await
+        .change_context(errors::RoutingError::CircuitBreakerStateUpdateFailed)?;
+
+    if u32::try_from(probe_successes).unwrap_or(u32::MAX) < config.half_open_success_count {
+        return Ok(());
+    }
+
+    // Concurrent probes may all attempt to close the circuit, only the first one records it
+    let reply = redis_conn
+        .delete_key(&tripped_key)
+        .await
+        .change_context(errors::RoutingError::CircuitBreakerStateUpdateFailed)?;
+    if matches!(reply, DelReply::KeyNotDeleted) {
+        return Ok(());
+    }
+
+    // The failures which opened the circuit should not open it again
+    let current_bucket = get_current_bucket(config);
+    for bucket in get_first_bucket(config, current_bucket)..=current_bucket {
+        redis_conn
+            .delete_key(&get_window_key(merchant_connector_id, bucket))
+            .await
+            .change_context(errors::RoutingError::CircuitBreakerStateUpdateFailed)?;
+    }
+
+    emit_state_transition(
+        state,
+        connector,
+        merchant_connector_id,
+        CircuitState::HalfOpen,
+        CircuitState::Closed,
+    );
+
+    Ok(())
+}
+
+/// Records the call in the current bucket of the failure window, and returns the number of calls
+/// and of technical failures in the whole window.
+async fn update_failure_window(
+    redis_conn: &RedisConnectionPool,
+    config: &CircuitBreakerConfig,
+    merchant_connector_id: &str,
+    is_technical_failure: bool,
+) -> RoutingResult<(i64, i64)> {
+    let current_bucket = get_current_bucket(config);
+    let bucket_keys = (get_first_bucket(config, current_bucket)..=current_bucket)
+        .map(|bucket| get_window_key(merchant_connector_id, bucket))
+        .collect::<Vec<_>>();
+    let args = vec![
+        TOTAL_FIELD.to_string(),
+        FAILURE_FIELD.to_string(),
+        if is_technical_failure { "1" } else { "0" }.to_string(),
+        // The bucket is only read as long as it is a part of the window
+        (i64::from(config.window_duration_in_secs) + get_bucket_duration_in_secs(config))
+            .to_string(),
+    ];
+
+    let window = redis_conn
+        .evaluate_redis_script::<_, Vec<i64>>(UPDATE_FAILURE_WINDOW_SCRIPT, bucket_keys, args)
+        .await
+        .change_context(errors::RoutingError::CircuitBreakerStateUpdateFailed)
+        .attach_printable("Failed to update circuit breaker window")?;
+
+    match window.as_slice() {
+        [total, failures] => Ok((*total, *failures)),
+        _ => Err(errors::RoutingError::CircuitBreakerStateUpdateFailed)
+            .attach_printable("Unexpected reply for the circuit breaker window"),
+    }
+}
+
+fn should_open_circuit(config: &CircuitBreakerConfig, total: i64, failures: i64) -> bool {
+    if total <= 0 || total < i64::from(config.min_request_count) {
+        return false;
+    }
+
+    #[allow(clippy::as_conversions)]
+    let failure_rate = (failures as f64 / total as f64) * 100.0;
+    failure_rate >= config.failure_rate_threshold
+}
+
+fn emit_state_transition(
+    state: &SessionState,
+    connector: &str,
+    merchant_connector_id: &str,
+    previous_state: CircuitState,
+    current_state: CircuitState,
+) {
+    logger::info!(
+        %connector,
+        %merchant_connector_id,
+        %previous_state,
+        %current_state,
+        "Connector circuit state changed"
+    );
+
+    metrics::CIRCUIT_BREAKER_STATE_TRANSITION.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([
+            ("connector", connector.to_string()),
+            ("previous_state", previous_state.to_string()),
+            ("current_state", current_state.to_string()),
+        ]),
+    );
+
+    state.get_req_state().event_context.emit(AuditEvent::new(
+        AuditEventType::ConnectorCircuitStateChanged {
+            connector: connector.to_string(),
+            merchant_connector_id: merchant_connector_id.to_string(),
+            previous_state,
+            current_state,
+        },
+    ));
+}
+
+/// A tripped circuit which receives no probes while half open is closed once its state expires
+fn get_tripped_state_ttl(config: &CircuitBreakerConfig) -> u32 {
+    config
+        .open_duration_in_secs
+        .saturating_add(config.window_duration_in_secs)
+}
+
+fn get_bucket_duration_in_secs(config: &CircuitBreakerConfig) -> i64 {
+    (i64::from(config.window_duration_in_secs) / i64::from(config.window_bucket_count.max(1)))
+        .max(1)
+}
+
+fn get_current_bucket(config: &CircuitBreakerConfig) -> i64 {
+    common_utils::date_time::now_unix_timestamp() / get_bucket_duration_in_secs(config)
+}
+
+fn get_first_bucket(config: &CircuitBreakerConfig, current_bucket: i64) -> i64 {
+    current_bucket - i64::from(config.window_bucket_count) + 1
+}
+
+/// All the keys of a merchant connector account share the same hash tag, so that they can be used
+/// together in a script on a redis cluster.
+fn get_window_key(merchant_connector_id: &str, bucket: i64) -> String {
+    format!(
+        "{}_{{{merchant_connector_id}}}_{bucket}",
+        consts::CIRCUIT_BREAKER_WINDOW_PREFIX
+    )
+}
+
+fn get_open_key(merchant_connector_id: &str) -> String {
+    format!(
+        "{}_{{{merchant_connector_id}}}",
+        consts::CIRCUIT_BREAKER_OPEN_PREFIX
+    )
+}
+
+fn get_tripped_key(merchant_connector_id: &str) -> String {
+    format!(
+        "{}_{{{merchant_connector_id}}}",
+        consts::CIRCUIT_BREAKER_TRIPPED_PREFIX
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_technical_failure_classification() {
+        let server_error = types::ErrorResponse {
+            status_code: 503,
+            ..Default::default()
+        };
+        let timeout = types::ErrorResponse {
+            code: consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
+            status_code: 504,
+            ..Default::default()
+        };
+        let decline = types::ErrorResponse {
+            code: "card_declined".to_string(),
+            status_code: 402,
+            ..Default::default()
+        };
+
+        assert!(is_technical_failure(&server_error, None));
+        assert!(is_technical_failure(&timeout, None));
+        assert!(!is_technical_failure(&decline, None));
+    }
+
+    #[test]
+    fn test_circuit_opens_at_failure_rate_threshold() {
+        let config = CircuitBreakerConfig {
+            min_request_count: 10,
+            failure_rate_threshold: 50.0,
+            ..Default::default()
+        };
+
+        assert!(!should_open_circuit(&config, 9, 9));
+        assert!(!should_open_circuit(&config, 10, 4));
+        assert!(should_open_circuit(&config, 10, 5));
+        assert!(should_open_circuit(&config, 20, 20));
+    }
+
+    #[test]
+    fn test_keys_of_an_account_share_a_hash_tag() {
+        let keys = [
+            get_window_key("mca_123", 42),
+            get_open_key("mca_123"),
+            get_tripped_key("mca_123"),
+        ];
+
+        assert!(keys.iter().all(|key| key.contains("{mca_123}")));
+    }
+}
@@ -0,0 +1,396 @@
+use api_models::{
+    admin::{ConnectorFeeRule, ConnectorFeeSchedule},
+    enums as api_enums,
+    routing::CostBasedAlgorithm,
+};
+use common_utils::{ext_traits::ValueExt, types::MinorUnit};
+use currency_conversion::types::ExchangeRates;
+use euclid::backend::inputs as dsl_inputs;
+use futures::future;
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+
+use super::RoutingResult;
+use crate::{
+    consts,
+    core::errors,
+    logger,
+    types::{api::routing as routing_types, domain},
+    utils::currency,
+    SessionState,
+};
+
+/// Ranks the connectors of the algorithm by the fee estimated for the payment, as per the fee
+/// schedules of their connector accounts in the profile.
+///
+/// Ties keep the order in which the connectors were configured, and connectors whose fee could not
+/// be estimated are ranked last. Fixed fees in another currency than the one of the payment are
+/// converted with the current exchange rates.
+pub async fn perform_cost_based_routing(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &str,
+    algorithm: &CostBasedAlgorithm,
+    backend_input: &dsl_inputs::BackendInput,
+    transaction_type: &api_enums::TransactionType,
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    Vec<routing_types::ConnectorFeeEstimate>,
+)> {
+    let connector_type = match transaction_type {
+        api_enums::TransactionType::Payment => common_enums::ConnectorType::PaymentProcessor,
+        #[cfg(feature = "payouts")]
+        api_enums::TransactionType::Payout => common_enums::ConnectorType::PayoutProcessor,
+    };
+
+    let fee_schedules = future::join_all(
+        algorithm
+            .connectors
+            .iter()
+            .map(|choice| get_fee_schedule(state, key_store, profile_id, connector_type, choice)),
+    )
+    .await;
+
+    let payment_currency = backend_input.payment.currency;
+    let exchange_rates = if fee_schedules
+        .iter()
+        .flatten()
+        .any(|fee_schedule| fee_schedule.currency != payment_currency)
+    {
+        // Routing should not fail because the rates could not be fetched, the fees in other
+        // currencies are left out of the estimation instead
+        currency::get_exchange_rates(state)
+            .await
+            .map_err(|error| logger::error!(cost_based_routing_exchange_rates_error=?error))
+            .ok()
+    } else {
+        None
+    };
+
+    let estimated_fees = algorithm
+        .connectors
+        .iter()
+        .zip(fee_schedules.iter())
+        .map(|(choice, fee_schedule)| {
+            let estimated_fee = fee_schedule.as_ref().and_then(|fee_schedule| {
+                estimate_fee(fee_schedule, backend_input, exchange_rates.as_deref())
+            });
+            (choice.clone(), estimated_fee)
+        })
+        .collect::<Vec<_>>();
+
+    logger::debug!(
+        cost_based_ranking=?estimated_fees
+            .iter()
+            .map(|(choice, estimated_fee)| (choice.connector, *estimated_fee))
+            .collect::<Vec<_>>(),
+        "Connectors ranked by estimated fee"
+    );
+
+    Ok(rank_connectors_by_fee(estimated_fees, payment_currency))
+}
+
+/// Finds the fee schedule of the connector account of the choice, which is the account of the
+/// connector in the profile when the choice does not specify one. The connector accounts are looked
+/// up individually, as these lookups are served from the accounts cache.
+async fn get_fee_schedule(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &str,
+    connector_type: common_enums::ConnectorType,
+    choice: &routing_types::RoutableConnectorChoice,
+) -> Option<ConnectorFeeSchedule> {
+    let key_manager_state = &state.into();
+    let merchant_connector_account = match choice.merchant_connector_id.as_ref() {
+        Some(merchant_connector_id) => {
+            state
+                .store
+                .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                    key_manager_state,
+                    &key_store.merchant_id,
+                    merchant_connector_id,
+                    key_store,
+                )
+                .await
+        }
+        None => {
+            state
+                .store
+                .find_merchant_connector_account_by_profile_id_connector_name(
+                    key_manager_state,
+                    profile_id,
+                    &choice.connector.to_string(),
+                    key_store,
+                )
+                .await
+        }
+    }
+    .map_err(|error| logger::error!(fee_schedule_fetch_error=?error))
+    .ok()?;
+
+    let is_usable = merchant_connector_account.profile_id == profile_id
+        && merchant_connector_account.connector_type == connector_type
+        && merchant_connector_account.disabled != Some(true);
+    if !is_usable {
+        return None;
+    }
+
+    merchant_connector_account
+        .fee_schedule?
+        .parse_value::<ConnectorFeeSchedule>("ConnectorFeeSchedule")
+        .map_err(|error| logger::error!(fee_schedule_parsing_error=?error))
+        .ok()
+}
+
+/// Sorts the connectors by their estimated fee, keeping the configured order for ties and
+/// ranking the connectors without an estimate last.
+fn rank_connectors_by_fee(
+    mut estimated_fees: Vec<(routing_types::RoutableConnectorChoice, Option<MinorUnit>)>,
+    currency: api_enums::Currency,
+) -> (
+    Vec<routing_types::RoutableConnectorChoice>,
+    Vec<routing_types::ConnectorFeeEstimate>,
+) {
+    estimated_fees.sort_by_key(|(_, estimated_fee)| {
+        (
+            estimated_fee.is_none(),
+            estimated_fee.map(|fee| fee.get_amount_as_i64()),
+        )
+    });
+
+    let fee_estimates = estimated_fees
+        .iter()
+        .filter_map(|(choice, estimated_fee)| {
+            estimated_fee.map(|amount| routing_types::ConnectorFeeEstimate {
+                connector: choice.clone(),
+                amount,
+                currency,
+            })
+        })
+        .collect();
+
+    let ranked_connectors = estimated_fees
+        .into_iter()
+        .map(|(choice, _)| choice)
+        .collect();
+
+    (ranked_connectors, fee_estimates)
+}
+
+/// Returns the most specific rule of the fee schedule which matches the payment, and the first of
+/// them when several rules are equally specific.
+fn get_matching_rule<'a>(
+    fee_schedule: &'a ConnectorFeeSchedule,
+    backend_input: &dsl_inputs::BackendInput,
+) -> Option<&'a ConnectorFeeRule> {
+    let payment_method_input = &backend_input.payment_method;
+    let billing_country = backend_input.payment.billing_country;
+
+    fee_schedule
+        .rules
+        .iter()
+        .filter(|rule| {
+            rule.payment_method
+                .map_or(true, |pm| payment_method_input.payment_method == Some(pm))
+                && rule.payment_method_type.map_or(true, |pmt| {
+                    payment_method_input.payment_method_type == Some(pmt)
+                })
+                && rule.card_network.as_ref().map_or(true, |card_network| {
+                    payment_method_input.card_network.as_ref() == Some(card_network)
+                })
+                && rule.countries.as_ref().map_or(true, |countries| {
+                    billing_country.map_or(false, |billing_country| {
+                        countries.iter().any(|country| {
+                            api_enums::Country::from_alpha2(*country) == billing_country
+                        })
+                    })
+                })
+        })
+        .fold(
+            None,
+            |most_specific: Option<&ConnectorFeeRule>, rule| match most_specific {
+                Some(current) if current.get_specificity() >= rule.get_specificity() => {
+                    Some(current)
+                }
+                _ => Some(rule),
+            },
+        )
+}
+
+/// Estimates the fee of the payment, in the minor unit of its currency. The fee can't be estimated
+/// when no rule matches the payment, or when the fixed fee is in another currency and the exchange
+/// rates are not available.
+fn estimate_fee(
+    fee_schedule: &ConnectorFeeSchedule,
+    backend_input: &dsl_inputs::BackendInput,
+    exchange_rates: Option<&ExchangeRates>,
+) -> Option<MinorUnit> {
+    let rule = get_matching_rule(fee_schedule, backend_input)?;
+    let payment_currency = backend_input.payment.currency;
+
+    let fixed_fee = if fee_schedule.currency == payment_currency {
+        Decimal::from(rule.fixed_fee.get_amount_as_i64())
+    } else {
+        let converted_fee = currency_conversion::conversion::convert(
+            exchange_rates?,
+            fee_schedule.currency,
+            payment_currency,
+            rule.fixed_fee.get_amount_as_i64(),
+        )
+        .map_err(|error| logger::error!(fee_conversion_error=?error))
+        .ok()?;
+
+        // The converted amount is in the major unit of the currency
+        converted_fee
+            * Decimal::from(10_i64.checked_pow(u32::from(
+                payment_currency.number_of_digits_after_decimal_point(),
+            ))?)
+    };
+
+    let percentage_fee = Decimal::from(backend_input.payment.amount.get_amount_as_i64())
+        * Decimal::from(rule.percentage_fee_basis_points)
+        / Decimal::from(consts::MAX_FEE_BASIS_POINTS);
+
+    (fixed_fee + percentage_fee)
+        .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+        .to_i64()
+        .map(MinorUnit::new)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn make_backend_input(
+        payment_method_type: api_enums::PaymentMethodType,
+        card_network: Option<api_enums::CardNetwork>,
+        billing_country: Option<api_enums::Country>,
+    ) -> dsl_inputs::BackendInput {
+        dsl_inputs::BackendInput {
+            metadata: None,
+            payment: dsl_inputs::PaymentInput {
+                amount: MinorUnit::new(10000),
+                currency: api_enums::Currency::USD,
+                authentication_type: None,
+                card_bin: None,
+                capture_method: None,
+                business_country: None,
+                billing_country,
+                business_label: None,
+                setup_future_usage: None,
+                timestamp: None,
+            },
+            payment_method: dsl_inputs::PaymentMethodInput {
+                payment_method: Some(api_enums::PaymentMethod::Card),
+                payment_method_type: Some(payment_method_type),
+                card_network,
+                card_bin_info: None,
+            },
+            mandate: dsl_inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_most_specific_rule_is_applied() {
+        let fee_schedule: ConnectorFeeSchedule = serde_json::from_value(serde_json::json!({
+            "currency": "USD",
+            "rules": [
+                { "fixed_fee": 30, "percentage_fee_basis_points": 290 },
+                {
+                    "payment_method_type": "debit",
+                    "fixed_fee": 30,
+                    "percentage_fee_basis_points": 150
+                },
+                {
+                    "payment_method_type": "debit",
+                    "card_network": "Visa",
+                    "countries": ["US"],
+                    "fixed_fee": 10,
+                    "percentage_fee_basis_points": 100
+                }
+            ]
+        }))
+        .unwrap();
+
+        let domestic_visa_debit = make_backend_input(
+            api_enums::PaymentMethodType::Debit,
+            Some(api_enums::CardNetwork::Visa),
+            Some(api_enums::Country::UnitedStatesOfAmerica),
+        );
+        assert_eq!(
+            estimate_fee(&fee_schedule, &domestic_visa_debit, None),
+            Some(MinorUnit::new(110))
+        );
+
+        let foreign_visa_debit = make_backend_input(
+            api_enums::PaymentMethodType::Debit,
+            Some(api_enums::CardNetwork::Visa),
+            Some(api_enums::Country::Germany),
+        );
+        assert_eq!(
+            estimate_fee(&fee_schedule, &foreign_visa_debit, None),
+            Some(MinorUnit::new(180))
+        );
+
+        let credit = make_backend_input(api_enums::PaymentMethodType::Credit, None, None);
+        assert_eq!(
+            estimate_fee(&fee_schedule, &credit, None),
+            Some(MinorUnit::new(320))
+        );
+    }
+
+    #[test]
+    fn test_fee_in_other_currency_requires_exchange_rates() {
+        let fee_schedule: ConnectorFeeSchedule = serde_json::from_value(serde_json::json!({
+            "currency": "EUR",
+            "rules": [{ "fixed_fee": 25, "percentage_fee_basis_points": 140 }]
+        }))
+        .unwrap();
+        let input = make_backend_input(api_enums::PaymentMethodType::Credit, None, None);
+
+        assert_eq!(estimate_fee(&fee_schedule, &input, None), None);
+    }
+
+    #[test]
+    fn test_connectors_are_ranked_by_fee_with_ties_in_configured_order() {
+        let choices: Vec<routing_types::RoutableConnectorChoice> =
+            serde_json::from_value(serde_json::json!([
+                "stripe",
+                "checkout",
+                "adyen",
+                "cybersource"
+            ]))
+            .unwrap();
+        let estimated_fees = [
+            Some(MinorUnit::new(320)),
+            None,
+            Some(MinorUnit::new(250)),
+            Some(MinorUnit::new(320)),
+        ];
+
+        let (ranked_connectors, fee_estimates) = rank_connectors_by_fee(
+            choices.into_iter().zip(estimated_fees).collect(),
+            api_enums::Currency::USD,
+        );
+
+        let ranked_connectors = ranked_connectors
+            .iter()
+            .map(|choice| choice.connector)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranked_connectors,
+            vec![
+                api_enums::RoutableConnectors::Adyen,
+                api_enums::RoutableConnectors::Stripe,
+                api_enums::RoutableConnectors::Cybersource,
+                api_enums::RoutableConnectors::Checkout,
+            ]
+        );
+        assert_eq!(fee_estimates.len(), 3);
+        assert_eq!(fee_estimates.first().unwrap().amount, MinorUnit::new(250));
+    }
+}
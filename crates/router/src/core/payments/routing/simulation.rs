@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+
+use api_models::{enums as api_enums, routing::RoutingSimulationResponse};
+use common_utils::types::MinorUnit;
+use error_stack::ResultExt;
+use euclid::backend::inputs as dsl_inputs;
+
+use super::{
+    cost_based, execute_dsl_and_get_connector_v1, make_cached_algorithm, perform_cgraph_filtering,
+    perform_volume_split, CachedAlgorithm, RoutingResult, VolumeSplitStickinessInput,
+};
+use crate::{
+    core::{errors, routing::helpers as routing_helpers},
+    types::{api::routing as routing_types, domain, storage as oss_storage},
+    utils::{OptionExt, ValueExt},
+    SessionState,
+};
+
+/// Replays a routing algorithm over payments which have already been processed, to find the
+/// connector which the algorithm would have chosen for each of them.
+pub struct RoutingSimulator {
+    profile_id: String,
+    algorithm: CachedAlgorithm,
+    fallback_config: Vec<routing_types::RoutableConnectorChoice>,
+}
+
+impl RoutingSimulator {
+    pub async fn new(
+        state: &SessionState,
+        profile_id: String,
+        algorithm: routing_types::RoutingAlgorithm,
+    ) -> RoutingResult<Self> {
+        let fallback_config = routing_helpers::get_merchant_default_config(
+            &*state.store,
+            &profile_id,
+            &api_enums::TransactionType::Payment,
+        )
+        .await
+        .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
+
+        Ok(Self {
+            profile_id,
+            algorithm: make_cached_algorithm(algorithm)?,
+            fallback_config,
+        })
+    }
+
+    /// Returns the connector which would have been chosen for the payment, after the eligibility
+    /// analysis and the default fallback are applied the same way as in the payment flows.
+    ///
+    /// Success rate algorithms are evaluated in their configured order, as the sliding windows
+    /// of the past are not retained. Volume splits sticky on the card use the saved payment
+    /// method, as the card details of past payments are not available. Cost based algorithms use
+    /// the current fee schedules and exchange rates.
+    pub async fn simulate_payment(
+        &self,
+        state: &SessionState,
+        key_store: &domain::MerchantKeyStore,
+        payment_attempt: &oss_storage::PaymentAttempt,
+        payment_intent: &oss_storage::PaymentIntent,
+    ) -> RoutingResult<Option<routing_types::RoutableConnectorChoice>> {
+        let backend_input = make_dsl_input_for_simulation(payment_attempt, payment_intent)?;
+        let stickiness_input = VolumeSplitStickinessInput::new(payment_intent, payment_attempt);
+
+        let chosen = match &self.algorithm {
+            CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
+            CachedAlgorithm::Priority(plist) => plist.clone(),
+            CachedAlgorithm::VolumeSplit(splits) => {
+                perform_volume_split(splits.to_vec(), None, Some(&stickiness_input))
+                    .change_context(errors::RoutingError::ConnectorSelectionFailed)?
+            }
+            CachedAlgorithm::Advanced(compiled_program) => {
+                execute_dsl_and_get_connector_v1(
+                    backend_input.clone(),
+                    compiled_program,
+                    Some(&stickiness_input),
+                )?
+                .0
+            }
+            CachedAlgorithm::SuccessRate(algorithm) => algorithm.connectors.clone(),
+            CachedAlgorithm::CostBased(algorithm) => {
+                cost_based::perform_cost_based_routing(
+                    state,
+                    key_store,
+                    &self.profile_id,
+                    algorithm,
+                    &backend_input,
+                    &api_enums::TransactionType::Payment,
+                )
+                .await?
+                .0
+            }
+        };
+
+        let (eligible_connectors, _) = perform_cgraph_filtering(
+            state,
+            key_store,
+            chosen,
+            backend_input.clone(),
+            None,
+            Some(self.profile_id.clone()),
+            &api_enums::TransactionType::Payment,
+        )
+        .await?;
+
+        if let Some(connector) = eligible_connectors.into_iter().next() {
+            return Ok(Some(connector));
+        }
+
+        Ok(perform_cgraph_filtering(
+            state,
+            key_store,
+            self.fallback_config.clone(),
+            backend_input,
+            None,
+            Some(self.profile_id.clone()),
+            &api_enums::TransactionType::Payment,
+        )
+        .await?
+        .0
+        .into_iter()
+        .next())
+    }
+}
+
+/// Builds the routing input from the stored payment, with the payment time as the timestamp.
+///
+/// The billing country is not available without decrypting the billing address, so rules on it
+/// do not match during the simulation.
+pub fn make_dsl_input_for_simulation(
+    payment_attempt: &oss_storage::PaymentAttempt,
+    payment_intent: &oss_storage::PaymentIntent,
+) -> RoutingResult<dsl_inputs::BackendInput> {
+    let mandate_data = dsl_inputs::MandateData {
+        mandate_acceptance_type: None,
+        mandate_type: None,
+        payment_type: None,
+    };
+
+    let card_info = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|pm_data| {
+            pm_data
+                .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|pm_data| match pm_data {
+            api_models::payments::AdditionalPaymentData::Card(card_info) => Some(card_info),
+            _ => None,
+        });
+
+    let payment_input = dsl_inputs::PaymentInput {
+        amount: payment_attempt.amount,
+        currency: payment_attempt
+            .currency
+            .get_required_value("currency")
+            .change_context(errors::RoutingError::DslMissingRequiredField {
+                field_name: "currency".to_string(),
+            })?,
+        authentication_type: payment_attempt.authentication_type,
+        card_bin: card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_isin.clone()),
+        capture_method: payment_attempt.capture_method,
+        business_country: payment_intent
+            .business_country
+            .map(api_enums::Country::from_alpha2),
+        billing_country: None,
+        business_label: payment_intent.business_label.clone(),
+        setup_future_usage: payment_intent.setup_future_usage,
+        timestamp: Some(payment_attempt.created_at.assume_utc().unix_timestamp()),
+    };
+
+    let payment_method_input = dsl_inputs::PaymentMethodInput {
+        payment_method: payment_attempt.payment_method,
+        payment_method_type: payment_attempt.payment_method_type,
+        card_network: card_info.and_then(|card_info| card_info.card_network),
+        card_bin_info: None,
+    };
+
+    let metadata = payment_intent
+        .metadata
+        .clone()
+        .map(|val| val.parse_value("routing_parameters"))
+        .transpose()
+        .change_context(errors::RoutingError::MetadataParsingError)
+        .attach_printable("Unable to parse routing_parameters from metadata of payment_intent")
+        .unwrap_or(None);
+
+    Ok(dsl_inputs::BackendInput {
+        metadata,
+        payment: payment_input,
+        payment_method: payment_method_input,
+        mandate: mandate_data,
+    })
+}
+
+#[derive(Default)]
+struct ConnectorTally {
+    payment_counts: HashMap<String, u64>,
+    amounts: HashMap<(String, api_enums::Currency), MinorUnit>,
+}
+
+impl ConnectorTally {
+    fn record(&mut self, connector: &str, currency: api_enums::Currency, amount: MinorUnit) {
+        *self
+            .payment_counts
+            .entry(connector.to_string())
+            .or_insert(0) += 1;
+
+        let total = self
+            .amounts
+            .entry((connector.to_string(), currency))
+            .or_insert(MinorUnit::new(0));
+        *total = *total + amount;
+    }
+
+    #[allow(clippy::as_conversions)]
+    fn into_distribution(
+        self,
+        total_payments: u64,
+        currency_totals: &HashMap<api_enums::Currency, MinorUnit>,
+    ) -> Vec<routing_types::ConnectorDistribution> {
+        let get_percentage = |part: f64, whole: f64| {
+            if whole > 0.0 {
+                part * 100.0 / whole
+            } else {
+                0.0
+            }
+        };
+
+        let mut distribution = self
+            .payment_counts
+            .into_iter()
+            .map(|(connector, payment_count)| {
+                let mut amounts = self
+                    .amounts
+                    .iter()
+                    .filter(|((amount_connector, _), _)| *amount_connector == connector)
+                    .map(|((_, currency), amount)| {
+                        let currency_total = currency_totals
+                            .get(currency)
+                            .map(MinorUnit::get_amount_as_i64)
+                            .unwrap_or_default();
+
+                        routing_types::ConnectorAmountDistribution {
+                            currency: *currency,
+                            amount: *amount,
+                            amount_percentage: get_percentage(
+                                amount.get_amount_as_i64() as f64,
+                                currency_total as f64,
+                            ),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                amounts.sort_by_key(|amount| amount.currency.to_string());
+
+                routing_types::ConnectorDistribution {
+                    volume_percentage: get_percentage(payment_count as f64, total_payments as f64),
+                    connector,
+                    payment_count,
+                    amounts,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        distribution.sort_by(|a, b| {
+            b.payment_count
+                .cmp(&a.payment_count)
+                .then_with(|| a.connector.cmp(&b.connector))
+        });
+
+        distribution
+    }
+}
+
+/// Collects the simulated and the actual connector of every replayed payment into connector
+/// wise distributions.
+#[derive(Default)]
+pub struct RoutingSimulationAggregator {
+    total_payments: u64,
+    rerouted_payments: u64,
+    unroutable_payments: u64,
+    currency_totals: HashMap<api_enums::Currency, MinorUnit>,
+    simulated: ConnectorTally,
+    actual: ConnectorTally,
+}
+
+impl RoutingSimulationAggregator {
+    pub fn record(
+        &mut self,
+        simulated_connector: Option<&routing_types::RoutableConnectorChoice>,
+        actual_connector: &str,
+        currency: api_enums::Currency,
+        amount: MinorUnit,
+    ) {
+        self.total_payments += 1;
+
+        let total = self
+            .currency_totals
+            .entry(currency)
+            .or_insert(MinorUnit::new(0));
+        *total = *total + amount;
+
+        self.actual.record(actual_connector, currency, amount);
+
+        match simulated_connector {
+            Some(choice) => {
+                let simulated_connector = choice.connector.to_string();
+                if simulated_connector != actual_connector {
+                    self.rerouted_payments += 1;
+                }
+                self.simulated
+                    .record(&simulated_connector, currency, amount);
+            }
+            None => self.unroutable_payments += 1,
+        }
+    }
+
+    pub fn into_response(self, profile_id: String) -> RoutingSimulationResponse {
+        RoutingSimulationResponse {
+            profile_id,
+            total_payments: self.total_payments,
+            rerouted_payments: self.rerouted_payments,
+            unroutable_payments: self.unroutable_payments,
+            simulated: self
+                .simulated
+                .into_distribution(self.total_payments, &self.currency_totals),
+            actual: self
+                .actual
+                .into_distribution(self.total_payments, &self.currency_totals),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_simulation_aggregation() {
+        let stripe = routing_types::RoutableConnectorChoice {
+            choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+            connector: api_enums::RoutableConnectors::Stripe,
+            merchant_connector_id: None,
+        };
+
+        let mut aggregator = RoutingSimulationAggregator::default();
+        aggregator.record(
+            Some(&stripe),
+            "stripe",
+            api_enums::Currency::USD,
+            MinorUnit::new(100),
+        );
+        aggregator.record(
+            Some(&stripe),
+            "adyen",
+            api_enums::Currency::USD,
+            MinorUnit::new(300),
+        );
+        aggregator.record(None, "adyen", api_enums::Currency::EUR, MinorUnit::new(50));
+
+        let response = aggregator.into_response("pro_123".to_string());
+
+        assert_eq!(response.total_payments, 3);
+        assert_eq!(response.rerouted_payments, 1);
+        assert_eq!(response.unroutable_payments, 1);
+
+        let simulated = response.simulated.first().unwrap();
+        assert_eq!(simulated.connector, "stripe");
+        assert_eq!(simulated.payment_count, 2);
+        assert_eq!(simulated.amounts.len(), 1);
+        let simulated_amount = simulated.amounts.first().unwrap();
+        assert_eq!(simulated_amount.amount, MinorUnit::new(400));
+        assert!((simulated_amount.amount_percentage - 100.0).abs() < f64::EPSILON);
+
+        let actual = response.actual.first().unwrap();
+        assert_eq!(actual.connector, "adyen");
+        assert_eq!(actual.payment_count, 2);
+        assert_eq!(actual.amounts.len(), 2);
+    }
+}
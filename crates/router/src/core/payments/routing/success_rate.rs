@@ -0,0 +1,460 @@
+use std::str::FromStr;
+
+use api_models::routing::{SuccessRateAlgorithm, SuccessRateConfig, SuccessRateDimension};
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use euclid::backend::inputs as dsl_inputs;
+use rand::Rng;
+use rustc_hash::FxHashMap;
+
+use super::{CachedAlgorithm, RoutingResult};
+use crate::{
+    consts,
+    core::{errors, payments as payments_oss},
+    logger,
+    types::api::routing as routing_types,
+    SessionState,
+};
+
+const SUCCESS_FIELD_SUFFIX: &str = "success";
+const TOTAL_FIELD_SUFFIX: &str = "total";
+
+/// Records an authorization outcome in a bucket of the window.
+///
+/// KEYS[1] is the bucket, and ARGV holds the total field, the success field (empty for a failed
+/// authorization) and the expiry of the bucket in seconds.
+const RECORD_OUTCOME_SCRIPT: &str = r#"
+redis.call('HINCRBY', KEYS[1], ARGV[1], 1)
+if ARGV[2] ~= '' then
+    redis.call('HINCRBY', KEYS[1], ARGV[2], 1)
+end
+redis.call('EXPIRE', KEYS[1], ARGV[3])
+return 1
+"#;
+
+/// Reads every bucket of the window.
+///
+/// KEYS are the buckets. Returns the fields and counts of all the buckets as a flat list of
+/// field and count pairs, with a field repeated once for every bucket it is present in.
+const READ_WINDOW_SCRIPT: &str = r#"
+local fields = {}
+for _, key in ipairs(KEYS) do
+    local bucket = redis.call('HGETALL', key)
+    for index = 1, #bucket do
+        fields[#fields + 1] = bucket[index]
+    end
+end
+return fields
+"#;
+
+/// Ranks the connectors of the algorithm by their success rate in the current sliding window,
+/// and moves a random connector to the front for `exploration_percent` of the payments.
+///
+/// Connectors which do not have `min_aggregates_size` attempts in the window are ranked with the
+/// `default_success_rate`. Ties keep the order in which the connectors were configured.
+///
+/// Returns the ranked connectors along with the dimension of the window the payment was routed
+/// on, which is stored on the attempt for its outcome to be recorded in the same window.
+pub async fn perform_success_rate_routing(
+    state: &SessionState,
+    profile_id: &str,
+    algorithm: &SuccessRateAlgorithm,
+    backend_input: &dsl_inputs::BackendInput,
+) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, String)> {
+    let config = &algorithm.config;
+    let dimension_key = get_dimension_key(&config.dimensions, backend_input);
+
+    // Routing should not fail because the windows could not be read, the configured order is used instead
+    let aggregates = get_window_aggregates(state, profile_id, &dimension_key, config)
+        .await
+        .map_err(|error| logger::error!(success_rate_window_fetch_error=?error))
+        .unwrap_or_default();
+
+    let mut ranked_connectors = algorithm
+        .connectors
+        .iter()
+        .map(|choice| {
+            let label = get_connector_label(choice);
+            let total = aggregates
+                .get(&get_field_name(&label, TOTAL_FIELD_SUFFIX))
+                .copied()
+                .unwrap_or_default();
+            let success = aggregates
+                .get(&get_field_name(&label, SUCCESS_FIELD_SUFFIX))
+                .copied()
+                .unwrap_or_default();
+
+            let success_rate = if total > 0 && total >= i64::from(config.min_aggregates_size) {
+                #[allow(clippy::as_conversions)]
+                let success_rate = (success as f64 / total as f64) * 100.0;
+                success_rate
+            } else {
+                config.default_success_rate
+            };
+
+            (choice.clone(), success_rate)
+        })
+        .collect::<Vec<_>>();
+
+    ranked_connectors.sort_by(|(_, sr1), (_, sr2)| sr2.total_cmp(sr1));
+
+    logger::debug!(
+        success_rate_ranking=?ranked_connectors
+            .iter()
+            .map(|(choice, success_rate)| (choice.connector, *success_rate))
+            .collect::<Vec<_>>(),
+        "Connectors ranked by success rate"
+    );
+
+    let mut ranked_connectors = ranked_connectors
+        .into_iter()
+        .map(|(choice, _)| choice)
+        .collect::<Vec<_>>();
+
+    let mut rng = rand::thread_rng();
+    if ranked_connectors.len() > 1 && rng.gen_range(0..100) < config.exploration_percent {
+        let explored_connector =
+            ranked_connectors.remove(rng.gen_range(0..ranked_connectors.len()));
+        logger::debug!(explored_connector=%explored_connector, "Exploring connector for success rate routing");
+        ranked_connectors.insert(0, explored_connector);
+    }
+
+    Ok((ranked_connectors, dimension_key))
+}
+
+/// Records the outcome of an authorization with the connector in the current bucket of the
+/// sliding window.
+pub async fn update_success_rate_window(
+    state: &SessionState,
+    profile_id: &str,
+    config: &SuccessRateConfig,
+    dimension_key: &str,
+    connector_choice: &routing_types::RoutableConnectorChoice,
+    is_success: bool,
+) -> RoutingResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::RoutingError::SuccessRateWindowUpdateFailed)
+        .attach_printable("Failed to get redis connection")?;
+
+    let bucket_duration = get_bucket_duration_in_secs(config);
+    let current_bucket = common_utils::date_time::now_unix_timestamp() / bucket_duration;
+    let bucket_key = get_bucket_key(profile_id, dimension_key, current_bucket);
+    let label = get_connector_label(connector_choice);
+
+    let args = vec![
+        get_field_name(&label, TOTAL_FIELD_SUFFIX),
+        if is_success {
+            get_field_name(&label, SUCCESS_FIELD_SUFFIX)
+        } else {
+            String::new()
+        },
+        // The bucket is only read as long as it is a part of the window
+        (i64::from(config.window_duration_in_secs) + bucket_duration).to_string(),
+    ];
+
+    redis_conn
+        .evaluate_redis_script::<_, i64>(RECORD_OUTCOME_SCRIPT, vec![bucket_key], args)
+        .await
+        .map(|_| ())
+        .change_context(errors::RoutingError::SuccessRateWindowUpdateFailed)
+}
+
+/// Feeds the outcome of the payment attempt into the success rate window it was routed on, when
+/// the attempt was routed by a success rate based routing algorithm.
+///
+/// The outcome is credited to the algorithm and the dimension recorded in the routing decision
+/// trace of the attempt, rather than to the algorithm active on the profile now. Only the first
+/// transition of the attempt into an authorization outcome is recorded, so that subsequent syncs
+/// and captures of the attempt are not counted again.
+pub async fn record_payment_attempt_outcome<F: Clone>(
+    state: &SessionState,
+    payment_data: &payments_oss::PaymentData<F>,
+    previous_attempt_status: storage_enums::AttemptStatus,
+) -> RoutingResult<()> {
+    let is_success = match (
+        get_authorization_outcome(previous_attempt_status),
+        get_authorization_outcome(payment_data.payment_attempt.status),
+    ) {
+        (None, Some(is_success)) => is_success,
+        _ => return Ok(()),
+    };
+
+    let Some((algorithm_id, dimension_key)) = get_success_rate_routing_decision(
+        payment_data.payment_attempt.routing_decision_trace.clone(),
+    ) else {
+        return Ok(());
+    };
+
+    let Some(connector) = payment_data.payment_attempt.connector.as_ref() else {
+        return Ok(());
+    };
+    let connector = api_models::enums::RoutableConnectors::from_str(connector)
+        .map_err(|_| errors::RoutingError::InvalidConnectorName(connector.to_string()))?;
+
+    let profile_id = payment_data
+        .payment_intent
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")
+        .change_context(errors::RoutingError::ProfileIdMissing)?;
+
+    let cached_algorithm = super::ensure_algorithm_cached_v1(
+        state,
+        &payment_data.payment_attempt.merchant_id,
+        &algorithm_id,
+        Some(profile_id.clone()),
+        &api_models::enums::TransactionType::Payment,
+    )
+    .await?;
+
+    let CachedAlgorithm::SuccessRate(algorithm) = cached_algorithm.as_ref() else {
+        return Ok(());
+    };
+
+    let merchant_connector_id = payment_data.payment_attempt.merchant_connector_id.as_ref();
+    let Some(connector_choice) = algorithm.connectors.iter().find(|choice| {
+        choice.connector == connector
+            && choice
+                .merchant_connector_id
+                .as_ref()
+                .map_or(true, |mca_id| Some(mca_id) == merchant_connector_id)
+    }) else {
+        return Ok(());
+    };
+
+    update_success_rate_window(
+        state,
+        profile_id,
+        &algorithm.config,
+        &dimension_key,
+        connector_choice,
+        is_success,
+    )
+    .await
+}
+
+/// Returns the success rate routing algorithm and the dimension of the window the attempt was
+/// routed on, from its routing decision trace. Returns `None` when the attempt was not routed by
+/// a success rate routing algorithm.
+fn get_success_rate_routing_decision(
+    routing_decision_trace: Option<serde_json::Value>,
+) -> Option<(String, String)> {
+    let trace = routing_decision_trace?
+        .parse_value::<routing_types::RoutingDecisionTrace>("RoutingDecisionTrace")
+        .map_err(|error| logger::error!(routing_decision_trace_parsing_error=?error))
+        .ok()?;
+
+    match trace {
+        routing_types::RoutingDecisionTrace {
+            approach: routing_types::RoutingApproach::RoutingAlgorithm,
+            algorithm_kind: Some(routing_types::RoutingAlgorithmKind::SuccessRate),
+            algorithm_id: Some(algorithm_id),
+            success_rate_dimension_key: Some(dimension_key),
+            ..
+        } => Some((algorithm_id, dimension_key)),
+        _ => None,
+    }
+}
+
+async fn get_window_aggregates(
+    state: &SessionState,
+    profile_id: &str,
+    dimension_key: &str,
+    config: &SuccessRateConfig,
+) -> RoutingResult<FxHashMap<String, i64>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::RoutingError::SuccessRateWindowUpdateFailed)
+        .attach_printable("Failed to get redis connection")?;
+
+    let current_bucket =
+        common_utils::date_time::now_unix_timestamp() / get_bucket_duration_in_secs(config);
+    let first_bucket = current_bucket - i64::from(config.window_bucket_count) + 1;
+
+    let bucket_keys = (first_bucket..=current_bucket)
+        .map(|bucket| get_bucket_key(profile_id, dimension_key, bucket))
+        .collect::<Vec<_>>();
+
+    let window_fields = redis_conn
+        .evaluate_redis_script::<_, Vec<String>>(
+            READ_WINDOW_SCRIPT,
+            bucket_keys,
+            Vec::<String>::new(),
+        )
+        .await
+        .change_context(errors::RoutingError::SuccessRateWindowUpdateFailed)
+        .attach_printable("Failed to fetch success rate window")?;
+
+    Ok(sum_window_fields(window_fields))
+}
+
+/// Sums the counts of every field across the buckets of the window, skipping counts which are
+/// not integers.
+fn sum_window_fields(window_fields: Vec<String>) -> FxHashMap<String, i64> {
+    let mut aggregates = FxHashMap::<String, i64>::default();
+    let mut window_fields = window_fields.into_iter();
+    while let (Some(field), Some(count)) = (window_fields.next(), window_fields.next()) {
+        if let Ok(count) = count.parse::<i64>() {
+            *aggregates.entry(field).or_default() += count;
+        }
+    }
+    aggregates
+}
+
+/// Returns `Some(true)` for a successful authorization, `Some(false)` for a failed one and `None`
+/// when the attempt has not reached an authorization outcome yet.
+fn get_authorization_outcome(status: storage_enums::AttemptStatus) -> Option<bool> {
+    match status {
+        storage_enums::AttemptStatus::Authorized
+        | storage_enums::AttemptStatus::Charged
+        | storage_enums::AttemptStatus::PartialCharged
+        | storage_enums::AttemptStatus::PartialChargedAndChargeable => Some(true),
+        storage_enums::AttemptStatus::AuthorizationFailed
+        | storage_enums::AttemptStatus::Failure => Some(false),
+        _ => None,
+    }
+}
+
+fn get_bucket_duration_in_secs(config: &SuccessRateConfig) -> i64 {
+    (i64::from(config.window_duration_in_secs) / i64::from(config.window_bucket_count.max(1)))
+        .max(1)
+}
+
+fn get_dimension_key(
+    dimensions: &[SuccessRateDimension],
+    backend_input: &dsl_inputs::BackendInput,
+) -> String {
+    if dimensions.is_empty() {
+        return "all".to_string();
+    }
+
+    dimensions
+        .iter()
+        .map(|dimension| {
+            let value = match dimension {
+                SuccessRateDimension::PaymentMethodType => backend_input
+                    .payment_method
+                    .payment_method_type
+                    .map(|payment_method_type| payment_method_type.to_string()),
+                SuccessRateDimension::CardNetwork => backend_input
+                    .payment_method
+                    .card_network
+                    .as_ref()
+                    .map(ToString::to_string),
+                SuccessRateDimension::Currency => Some(backend_input.payment.currency.to_string()),
+            };
+
+            format!("{dimension}:{}", value.as_deref().unwrap_or("none"))
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// All the buckets of a window share the same hash tag, so that they can be read together in a
+/// script on a redis cluster.
+fn get_bucket_key(profile_id: &str, dimension_key: &str, bucket: i64) -> String {
+    format!(
+        "{}_{{{profile_id}_{dimension_key}}}_{bucket}",
+        consts::SUCCESS_RATE_WINDOW_PREFIX
+    )
+}
+
+fn get_connector_label(choice: &routing_types::RoutableConnectorChoice) -> String {
+    match choice.merchant_connector_id.as_ref() {
+        Some(merchant_connector_id) => format!("{}:{merchant_connector_id}", choice.connector),
+        None => choice.connector.to_string(),
+    }
+}
+
+fn get_field_name(label: &str, suffix: &str) -> String {
+    format!("{label}_{suffix}")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_authorization_outcome_of_attempt_status() {
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::Started),
+            None
+        );
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::Charged),
+            Some(true)
+        );
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::Failure),
+            Some(false)
+        );
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::CaptureFailed),
+            None
+        );
+    }
+
+    #[test]
+    fn test_window_fields_are_summed_across_buckets() {
+        let window_fields = [
+            "stripe_total",
+            "3",
+            "stripe_success",
+            "2",
+            "stripe_total",
+            "4",
+        ]
+        .into_iter()
+        .map(ToString::to_string)
+        .collect();
+
+        let aggregates = sum_window_fields(window_fields);
+
+        assert_eq!(aggregates.get("stripe_total"), Some(&7));
+        assert_eq!(aggregates.get("stripe_success"), Some(&2));
+    }
+
+    #[test]
+    fn test_bucket_duration() {
+        let config = SuccessRateConfig {
+            window_duration_in_secs: 3600,
+            window_bucket_count: 12,
+            ..Default::default()
+        };
+        assert_eq!(get_bucket_duration_in_secs(&config), 300);
+    }
+
+    #[test]
+    fn test_only_success_rate_routing_decisions_are_recorded() {
+        use common_utils::ext_traits::Encode;
+
+        let success_rate_trace = routing_types::RoutingDecisionTrace {
+            approach: routing_types::RoutingApproach::RoutingAlgorithm,
+            algorithm_id: Some("routing_sr".to_string()),
+            algorithm_kind: Some(routing_types::RoutingAlgorithmKind::SuccessRate),
+            success_rate_dimension_key: Some("currency:USD".to_string()),
+            ..Default::default()
+        };
+        let priority_trace = routing_types::RoutingDecisionTrace {
+            algorithm_id: Some("routing_priority".to_string()),
+            algorithm_kind: Some(routing_types::RoutingAlgorithmKind::Priority),
+            success_rate_dimension_key: None,
+            ..success_rate_trace.clone()
+        };
+
+        assert_eq!(
+            get_success_rate_routing_decision(Some(success_rate_trace.encode_to_value().unwrap())),
+            Some(("routing_sr".to_string(), "currency:USD".to_string()))
+        );
+        assert_eq!(
+            get_success_rate_routing_decision(Some(priority_trace.encode_to_value().unwrap())),
+            None
+        );
+        assert_eq!(get_success_rate_routing_decision(None), None);
+    }
+}
@@ -715,6 +715,38 @@ where
             )
         }
 
+        let routing_decision_trace = if payment_data.expose_routing_decision_trace {
+            payment_attempt
+                .routing_decision_trace
+                .clone()
+                .and_then(|trace| {
+                    trace
+                        .parse_value::<api_models::routing::RoutingDecisionTrace>(
+                            "RoutingDecisionTrace",
+                        )
+                        .map_err(|error| {
+                            router_env::logger::error!("Failed to parse 'RoutingDecisionTrace' from payment attempt. Error: {error:?}");
+                        })
+                        .ok()
+                })
+        } else {
+            None
+        };
+
+        let retry_policy = payment_attempt
+            .retry_policy
+            .clone()
+            .and_then(|retry_policy| {
+                retry_policy
+                    .parse_value::<api_models::admin::RetryPolicy>("RetryPolicy")
+                    .map_err(|error| {
+                        router_env::logger::error!(
+                            "Failed to parse 'RetryPolicy' from payment attempt. Error: {error:?}"
+                        );
+                    })
+                    .ok()
+            });
+
         let mut response: api::PaymentsResponse = Default::default();
         let routed_through = payment_attempt.connector.clone();
 
@@ -903,6 +935,11 @@ where
                 .set_charges(charges_response)
                 .set_frm_metadata(payment_intent.frm_metadata)
                 .set_merchant_order_reference_id(payment_intent.merchant_order_reference_id)
+                .set_routing_decision_trace(
+                    routing_decision_trace,
+                    auth_flow == services::AuthFlow::Merchant,
+                )
+                .set_retry_policy(retry_policy, auth_flow == services::AuthFlow::Merchant)
                 .to_owned(),
             headers,
         ))
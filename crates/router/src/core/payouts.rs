@@ -2565,6 +2565,67 @@ pub async fn add_external_account_addition_task(
     Ok(())
 }
 
+const PAYOUTS_REQUEUE_TASK: &str = "PAYOUTS_REQUEUE";
+
+/// The requeue task is keyed on the payout rather than on the attempt, so that it also records
+/// that the payout has been requeued once
+fn get_payout_requeue_process_tracker_id(
+    payout_id: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::PayoutsRequeueWorkflow,
+        PAYOUTS_REQUEUE_TASK,
+        payout_id,
+        merchant_id,
+    )
+}
+
+/// Checks whether a requeue task has ever been added for the payout, a payout is requeued only once
+pub async fn is_payout_requeued(
+    db: &dyn StorageInterface,
+    payout_data: &PayoutData,
+) -> CustomResult<bool, errors::StorageError> {
+    Ok(db
+        .find_process_by_id(&get_payout_requeue_process_tracker_id(
+            &payout_data.payouts.payout_id,
+            &payout_data.payouts.merchant_id,
+        ))
+        .await?
+        .is_some())
+}
+
+pub async fn add_payout_requeue_task(
+    db: &dyn StorageInterface,
+    payout_data: &PayoutData,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PayoutsRequeueWorkflow;
+    let task = PAYOUTS_REQUEUE_TASK;
+    let tag = ["REQUEUE", "PAYOUTS"];
+    let process_tracker_id = get_payout_requeue_process_tracker_id(
+        &payout_data.payouts.payout_id,
+        &payout_data.payouts.merchant_id,
+    );
+    let tracking_data = api::PayoutRetrieveRequest {
+        payout_id: payout_data.payouts.payout_id.to_owned(),
+        force_sync: None,
+        merchant_id: Some(payout_data.payouts.merchant_id.to_owned()),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
 async fn validate_and_get_business_profile(
     state: &SessionState,
     merchant_key_store: &domain::MerchantKeyStore,
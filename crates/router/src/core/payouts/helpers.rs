@@ -23,6 +23,7 @@ use crate::{
     consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        gsm,
         payment_methods::{
             cards,
             transformers::{DataDuplicationCheck, StoreCardReq, StoreGenericReq, StoreLockerReq},
@@ -747,10 +748,15 @@ pub async fn decide_payout_connector(
 
     // 2. Check routing algorithm passed in the request
     if let Some(routing_algorithm) = request_straight_through {
-        let (mut connectors, check_eligibility) =
-            routing::perform_straight_through_routing(&routing_algorithm, None)
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed execution of straight through routing")?;
+        let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
+            &routing_algorithm,
+            None,
+            Some(&routing::VolumeSplitStickinessInput::from(
+                &TransactionData::<()>::Payout(payout_data),
+            )),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed execution of straight through routing")?;
 
         if check_eligibility {
             connectors = routing::perform_eligibility_analysis_with_fallback(
@@ -760,6 +766,7 @@ pub async fn decide_payout_connector(
                 &TransactionData::<()>::Payout(payout_data),
                 eligible_connectors,
                 Some(payout_attempt.profile_id.clone()),
+                None,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -795,10 +802,15 @@ pub async fn decide_payout_connector(
 
     // 3. Check algorithm passed in routing data
     if let Some(ref routing_algorithm) = routing_data.algorithm {
-        let (mut connectors, check_eligibility) =
-            routing::perform_straight_through_routing(routing_algorithm, None)
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed execution of straight through routing")?;
+        let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
+            routing_algorithm,
+            None,
+            Some(&routing::VolumeSplitStickinessInput::from(
+                &TransactionData::<()>::Payout(payout_data),
+            )),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed execution of straight through routing")?;
 
         if check_eligibility {
             connectors = routing::perform_eligibility_analysis_with_fallback(
@@ -808,6 +820,7 @@ pub async fn decide_payout_connector(
                 &TransactionData::<()>::Payout(payout_data),
                 eligible_connectors,
                 Some(payout_attempt.profile_id.clone()),
+                None,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -903,7 +916,8 @@ pub async fn get_gsm_record(
     flow: String,
 ) -> Option<storage::gsm::GatewayStatusMap> {
     let get_gsm = || async {
-        state.store.find_gsm_rule(
+        gsm::find_matching_gsm_rule(
+                state,
                 connector_name.clone().unwrap_or_default(),
                 flow.clone(),
                 "sub_flow".to_string(),
@@ -11,7 +11,7 @@ use super::{call_connector_payout, PayoutData};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payouts,
+        payouts, utils as core_utils,
     },
     db::StorageInterface,
     routes::{self, app, metrics},
@@ -74,11 +74,14 @@ pub async fn do_gsm_multiple_connector_actions(
                 retries = retries.map(|i| i - 1);
             }
             api_models::gsm::GsmDecision::Requeue => {
-                Err(report!(errors::ApiErrorResponse::NotImplemented {
-                    message: errors::NotImplementedMessage::Reason(
-                        "Requeue not implemented".to_string(),
-                    ),
-                }))?
+                Box::pin(do_requeue(
+                    &state.clone(),
+                    connector.to_owned(),
+                    merchant_account,
+                    payout_data,
+                ))
+                .await?;
+                break;
             }
             api_models::gsm::GsmDecision::DoDefault => break,
         }
@@ -138,11 +141,14 @@ pub async fn do_gsm_single_connector_actions(
                 retries = retries.map(|i| i - 1);
             }
             api_models::gsm::GsmDecision::Requeue => {
-                Err(report!(errors::ApiErrorResponse::NotImplemented {
-                    message: errors::NotImplementedMessage::Reason(
-                        "Requeue not implemented".to_string(),
-                    ),
-                }))?
+                Box::pin(do_requeue(
+                    &state.clone(),
+                    original_connector_data.to_owned(),
+                    merchant_account,
+                    payout_data,
+                ))
+                .await?;
+                break;
             }
             api_models::gsm::GsmDecision::DoDefault => break,
         }
@@ -243,6 +249,76 @@ pub async fn do_retry(
     .await
 }
 
+/// Moves the failed attempt into a new pending attempt on the same connector, and schedules the
+/// payouts requeue workflow to make the connector call for it after the configured delay
+///
+/// A payout is requeued only once, the requeued attempt falls back to the default action
+#[instrument(skip_all)]
+pub async fn do_requeue(
+    state: &routes::SessionState,
+    connector: api::ConnectorData,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let is_requeued = payouts::is_payout_requeued(db, payout_data)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to check whether the payout was requeued")?;
+    if is_requeued {
+        logger::info!("requeue skipped for an already requeued payout");
+        return Ok(());
+    }
+
+    metrics::AUTO_PAYOUT_RETRY_REQUEUE_COUNT.add(&metrics::CONTEXT, 1, &[]);
+
+    modify_trackers(state, &connector, merchant_account, payout_data).await?;
+
+    let status = common_enums::PayoutStatus::Pending;
+    let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
+        connector_payout_id: None,
+        status,
+        error_message: None,
+        error_code: None,
+        is_eligible: None,
+    };
+    payout_data.payout_attempt = db
+        .update_payout_attempt(
+            &payout_data.payout_attempt,
+            updated_payout_attempt,
+            &payout_data.payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payout_attempt in db")?;
+    payout_data.payouts = db
+        .update_payout(
+            &payout_data.payouts,
+            storage::PayoutsUpdate::StatusUpdate { status },
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts in db")?;
+
+    let schedule_time = core_utils::get_requeue_schedule_time(db, merchant_account.get_id()).await;
+
+    payouts::add_payout_requeue_task(db, payout_data, schedule_time)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while adding requeue task to process tracker")?;
+
+    logger::info!(
+        payout_attempt_id = %payout_data.payout_attempt.payout_attempt_id,
+        ?schedule_time,
+        "payout requeued"
+    );
+
+    Ok(())
+}
+
 #[instrument(skip_all)]
 pub async fn modify_trackers(
     state: &routes::SessionState,
@@ -0,0 +1,404 @@
+use std::{collections::HashMap, str::FromStr};
+
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, metrics::add_attributes, tracing, Flow};
+use serde::Deserialize;
+
+use super::errors::{self, RouterResult};
+use crate::{
+    configs::settings::{RateLimit, RateLimitAlgorithm, RateLimitConfig},
+    consts,
+    routes::{lock_utils, metrics},
+    services::authentication as auth,
+    SessionState,
+};
+
+/// Takes a token from every bucket, or none of them if any bucket is empty.
+///
+/// KEYS are the buckets, and ARGV holds the capacity and the refill window in milliseconds of
+/// every bucket. Returns whether the request is allowed, the least number of tokens left and the
+/// milliseconds after which the request can be retried.
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local tokens = {}
+local remaining = -1
+local retry_after = 0
+
+for index, key in ipairs(KEYS) do
+    local capacity = tonumber(ARGV[2 * index - 1])
+    local window = tonumber(ARGV[2 * index])
+    local bucket = redis.call('HMGET', key, 'tokens', 'updated_at')
+    local available = tonumber(bucket[1]) or capacity
+    local updated_at = tonumber(bucket[2]) or now
+    available = math.min(capacity, available + math.max(0, now - updated_at) * capacity / window)
+    if available < 1 then
+        retry_after = math.max(retry_after, math.ceil((1 - available) * window / capacity))
+    end
+    tokens[index] = available
+end
+
+if retry_after > 0 then
+    return {0, 0, retry_after}
+end
+
+for index, key in ipairs(KEYS) do
+    local available = tokens[index] - 1
+    redis.call('HSET', key, 'tokens', tostring(available), 'updated_at', now)
+    redis.call('PEXPIRE', key, ARGV[2 * index])
+    if remaining < 0 or available < remaining then
+        remaining = math.floor(available)
+    end
+end
+
+return {1, remaining, 0}
+"#;
+
+/// Records the request in every window, or in none of them if any window is full.
+///
+/// KEYS are the windows, and ARGV holds the limit and the window length in milliseconds of every
+/// window, followed by a member unique to the request. Returns the same values as the token
+/// bucket script.
+const SLIDING_WINDOW_SCRIPT: &str = r#"
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local member = ARGV[#ARGV]
+local remaining = -1
+local retry_after = 0
+
+for index, key in ipairs(KEYS) do
+    local limit = tonumber(ARGV[2 * index - 1])
+    local window = tonumber(ARGV[2 * index])
+    redis.call('ZREMRANGEBYSCORE', key, '-inf', now - window)
+    local count = redis.call('ZCARD', key)
+    if count >= limit then
+        local oldest = redis.call('ZRANGE', key, 0, 0, 'WITHSCORES')
+        local oldest_at = tonumber(oldest[2]) or now
+        retry_after = math.max(retry_after, oldest_at + window - now, 1)
+    elseif remaining < 0 or limit - count - 1 < remaining then
+        remaining = limit - count - 1
+    end
+end
+
+if retry_after > 0 then
+    return {0, 0, retry_after}
+end
+
+for index, key in ipairs(KEYS) do
+    redis.call('ZADD', key, now, member)
+    redis.call('PEXPIRE', key, ARGV[2 * index])
+end
+
+return {1, remaining, 0}
+"#;
+
+/// Overrides of the configured rate limits for a merchant, stored in the
+/// `rate_limit_{merchant_id}` config. Every limit set here replaces the configured one.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimitOverrides {
+    pub merchant: Option<RateLimit>,
+    pub api_key: Option<RateLimit>,
+    pub profile: Option<RateLimit>,
+    pub route_groups: HashMap<String, RateLimit>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RateLimitInput {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub api_key_id: Option<String>,
+    pub profile_id: Option<String>,
+    pub route_group: Option<lock_utils::ApiIdentifier>,
+}
+
+impl RateLimitInput {
+    /// Builds the scopes of the request from its authentication and flow.
+    ///
+    /// Requests which are not made on behalf of a merchant, and webhooks sent by connectors, are
+    /// not throttled. The profile is the one the request was authenticated for, so that clients
+    /// can't evade the limit of their profile through the request body.
+    pub fn new(auth_type: &auth::AuthenticationType, flow: &impl ToString) -> Option<Self> {
+        if matches!(auth_type, auth::AuthenticationType::WebhookAuth { .. }) {
+            return None;
+        }
+        let merchant_id = auth_type.get_merchant_id()?.to_owned();
+
+        let api_key_id = match auth_type {
+            auth::AuthenticationType::ApiKey { key_id, .. } => Some(key_id.clone()),
+            _ => None,
+        };
+
+        Some(Self {
+            merchant_id,
+            api_key_id,
+            profile_id: auth_type.get_profile_id().cloned(),
+            route_group: Flow::from_str(&flow.to_string())
+                .ok()
+                .map(lock_utils::ApiIdentifier::from),
+        })
+    }
+
+    /// Pairs the redis key of every scope the request falls in with the limit of the scope.
+    ///
+    /// All the keys of a merchant share the same hash tag, so that they can be used together in a
+    /// script on a redis cluster.
+    fn get_limits(
+        &self,
+        config: &RateLimitConfig,
+        overrides: RateLimitOverrides,
+    ) -> Vec<(String, RateLimit)> {
+        let key_prefix = format!(
+            "{}_{{{}}}",
+            consts::RATE_LIMIT_PREFIX,
+            self.merchant_id.get_string_repr()
+        );
+        let mut limits = Vec::new();
+
+        if let Some(limit) = overrides.merchant.or(config.merchant) {
+            limits.push((format!("{key_prefix}_merchant"), limit));
+        }
+
+        if let Some((api_key_id, limit)) = self
+            .api_key_id
+            .as_ref()
+            .zip(overrides.api_key.or(config.api_key))
+        {
+            limits.push((format!("{key_prefix}_api_key_{api_key_id}"), limit));
+        }
+
+        if let Some((profile_id, limit)) = self
+            .profile_id
+            .as_ref()
+            .zip(overrides.profile.or(config.profile))
+        {
+            limits.push((format!("{key_prefix}_profile_{profile_id}"), limit));
+        }
+
+        if let Some(route_group) = self.route_group.as_ref().map(ToString::to_string) {
+            let limit = overrides
+                .route_groups
+                .get(&route_group)
+                .or_else(|| config.route_groups.get(&route_group));
+            if let Some(limit) = limit {
+                limits.push((format!("{key_prefix}_route_{route_group}"), *limit));
+            }
+        }
+
+        limits
+    }
+
+    async fn get_overrides(&self, state: &SessionState) -> RateLimitOverrides {
+        let config_key = self.merchant_id.get_rate_limit_config_key();
+
+        // Merchants without overrides get an empty config, so that the lookups of their requests
+        // are served from the config cache as well
+        match state
+            .store
+            .find_config_by_key_unwrap_or(&config_key, Some("{}".to_string()))
+            .await
+        {
+            Ok(config) => serde_json::from_str(&config.config)
+                .map_err(|error| {
+                    logger::warn!(?error, "Invalid rate limit overrides in {config_key}");
+                })
+                .unwrap_or_default(),
+            Err(error) => {
+                logger::warn!(?error, "Failed to fetch rate limit overrides");
+                RateLimitOverrides::default()
+            }
+        }
+    }
+
+    /// Consumes the allowance of the request in every scope it falls in, and fails with
+    /// [`errors::ApiErrorResponse::RateLimitExceeded`] if any of the scopes is exhausted.
+    ///
+    /// The request is let through when the limits cannot be checked, so that a redis outage does
+    /// not throttle all the traffic.
+    #[instrument(skip_all)]
+    pub async fn perform_rate_limit_check(&self, state: &SessionState) -> RouterResult<()> {
+        let config = &state.conf.rate_limit;
+        if !config.enabled {
+            return Ok(());
+        }
+
+        let overrides = self.get_overrides(state).await;
+        let limits = self.get_limits(config, overrides);
+        if limits.is_empty() {
+            return Ok(());
+        }
+
+        let reply = match self.evaluate_limits(state, config.algorithm, limits).await {
+            Ok(reply) => reply,
+            Err(error) => {
+                logger::error!(rate_limit_error=?error);
+                metrics::RATE_LIMIT_CHECK_FAILURE_COUNT.add(&metrics::CONTEXT, 1, &[]);
+                return Ok(());
+            }
+        };
+
+        match reply.as_slice() {
+            [0, _, retry_after_ms] => {
+                metrics::RATE_LIMITED_REQUESTS_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &add_attributes([(
+                        "route_group",
+                        self.route_group
+                            .as_ref()
+                            .map(ToString::to_string)
+                            .unwrap_or_default(),
+                    )]),
+                );
+
+                Err(report!(errors::ApiErrorResponse::RateLimitExceeded {
+                    retry_after_secs: get_retry_after_secs(*retry_after_ms),
+                }))
+            }
+            [_, remaining, _] => {
+                logger::debug!(rate_limit_remaining = remaining);
+                Ok(())
+            }
+            _ => {
+                logger::error!(?reply, "Unexpected reply from the rate limit script");
+                metrics::RATE_LIMIT_CHECK_FAILURE_COUNT.add(&metrics::CONTEXT, 1, &[]);
+                Ok(())
+            }
+        }
+    }
+
+    async fn evaluate_limits(
+        &self,
+        state: &SessionState,
+        algorithm: RateLimitAlgorithm,
+        limits: Vec<(String, RateLimit)>,
+    ) -> RouterResult<Vec<i64>> {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        let (keys, mut args) = limits.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut keys, mut args), (key, limit)| {
+                keys.push(key);
+                args.push(limit.requests.to_string());
+                args.push((u64::from(limit.window_in_secs) * 1000).to_string());
+                (keys, args)
+            },
+        );
+
+        let script = match algorithm {
+            RateLimitAlgorithm::TokenBucket => TOKEN_BUCKET_SCRIPT,
+            RateLimitAlgorithm::SlidingWindow => {
+                args.push(uuid::Uuid::new_v4().to_string());
+                SLIDING_WINDOW_SCRIPT
+            }
+        };
+
+        redis_conn
+            .evaluate_redis_script::<_, Vec<i64>>(script, keys, args)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to evaluate the rate limits")
+    }
+}
+
+/// Rounds the wait up to whole seconds, as required by the `Retry-After` header.
+fn get_retry_after_secs(retry_after_ms: i64) -> u64 {
+    u64::try_from(retry_after_ms)
+        .unwrap_or_default()
+        .div_ceil(1000)
+        .max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn make_input() -> RateLimitInput {
+        RateLimitInput {
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            api_key_id: Some("key_1".to_string()),
+            profile_id: None,
+            route_group: Some(lock_utils::ApiIdentifier::Payments),
+        }
+    }
+
+    #[test]
+    fn test_limits_with_overrides() {
+        let config = RateLimitConfig {
+            enabled: true,
+            algorithm: RateLimitAlgorithm::TokenBucket,
+            merchant: Some(RateLimit {
+                requests: 100,
+                window_in_secs: 60,
+            }),
+            api_key: Some(RateLimit {
+                requests: 50,
+                window_in_secs: 60,
+            }),
+            profile: Some(RateLimit {
+                requests: 50,
+                window_in_secs: 60,
+            }),
+            route_groups: HashMap::from([(
+                "payments".to_string(),
+                RateLimit {
+                    requests: 20,
+                    window_in_secs: 1,
+                },
+            )]),
+        };
+        let overrides = RateLimitOverrides {
+            api_key: Some(RateLimit {
+                requests: 500,
+                window_in_secs: 60,
+            }),
+            ..Default::default()
+        };
+
+        let limits = make_input().get_limits(&config, overrides);
+        let limits = limits
+            .iter()
+            .map(|(key, limit)| (key.rsplit('}').next().unwrap_or_default(), limit.requests))
+            .collect::<Vec<_>>();
+
+        // The profile is not limited, as the request was not authenticated for a profile
+        assert_eq!(
+            limits,
+            vec![
+                ("_merchant", 100),
+                ("_api_key_key_1", 500),
+                ("_route_payments", 20)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_profile_is_taken_from_authentication() {
+        let auth_type = auth::AuthenticationType::ApiKey {
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            key_id: "key_1".to_string(),
+            permissions: None,
+            profile_id: Some("pro_1".to_string()),
+        };
+
+        let input = RateLimitInput::new(&auth_type, &Flow::PaymentsCreate).unwrap();
+        assert_eq!(input.api_key_id.as_deref(), Some("key_1"));
+        assert_eq!(input.profile_id.as_deref(), Some("pro_1"));
+
+        let auth_type = auth::AuthenticationType::WebhookAuth {
+            merchant_id: common_utils::id_type::MerchantId::default(),
+        };
+        assert!(RateLimitInput::new(&auth_type, &Flow::IncomingWebhookReceive).is_none());
+    }
+
+    #[test]
+    fn test_retry_after_secs() {
+        assert_eq!(get_retry_after_secs(1), 1);
+        assert_eq!(get_retry_after_secs(1000), 1);
+        assert_eq!(get_retry_after_secs(1001), 2);
+        assert_eq!(get_retry_after_secs(-5), 1);
+    }
+}
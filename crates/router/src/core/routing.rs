@@ -7,20 +7,39 @@ use api_models::{
 };
 use diesel_models::routing_algorithm::RoutingAlgorithm;
 use error_stack::ResultExt;
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "routing_v2")
+))]
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
 use rustc_hash::FxHashSet;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(any(feature = "routing_v2", feature = "business_profile_v2"))
+))]
+use scheduler::utils as pt_utils;
 
 use super::payments;
 #[cfg(feature = "payouts")]
 use super::payouts;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(any(feature = "routing_v2", feature = "business_profile_v2"))
+))]
+use crate::types::storage;
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "routing_v2")))]
 use crate::utils::ValueExt;
 use crate::{
     consts,
     core::{
+        audit_log::{self, ConfigChange},
         errors::{self, RouterResponse, StorageErrorExt},
         metrics, utils as core_utils,
     },
-    routes::SessionState,
+    routes::{app::ReqState, SessionState},
     services::api as service_api,
     types::{
         domain,
@@ -117,6 +136,7 @@ pub async fn retrieve_merchant_routing_dictionary(
 #[cfg(all(feature = "v2", feature = "routing_v2"))]
 pub async fn create_routing_algorithm_under_profile(
     state: SessionState,
+    req_state: ReqState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     request: routing_types::RoutingConfigRequest,
@@ -160,6 +180,7 @@ pub async fn create_routing_algorithm_under_profile(
     };
 
     algorithm_helper.validate_connectors_in_routing_config()?;
+    let warnings = helpers::get_routing_algorithm_warnings(&request.algorithm);
 
     let algo = RoutingAlgorithmUpdate::create_new_routing_algorithm(
         &request,
@@ -175,7 +196,24 @@ pub async fn create_routing_algorithm_under_profile(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let new_record = record.foreign_into();
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_account.get_id()),
+            entity_type: enums::AuditEntityType::RoutingAlgorithm,
+            entity_id: record.algorithm_id.clone(),
+            action: enums::AuditAction::Create,
+            before: None,
+            after: Some(&record),
+        },
+    )
+    .await;
+
+    let new_record = routing_types::RoutingDictionaryRecord {
+        warnings,
+        ..record.foreign_into()
+    };
 
     metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
     Ok(service_api::ApplicationResponse::Json(new_record))
@@ -184,6 +222,7 @@ pub async fn create_routing_algorithm_under_profile(
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "routing_v2")))]
 pub async fn create_routing_algorithm_under_profile(
     state: SessionState,
+    req_state: ReqState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     request: routing_types::RoutingConfigRequest,
@@ -245,6 +284,7 @@ pub async fn create_routing_algorithm_under_profile(
         &algorithm,
     )
     .await?;
+    let warnings = helpers::get_routing_algorithm_warnings(&algorithm);
 
     let timestamp = common_utils::date_time::now();
     let algo = RoutingAlgorithm {
@@ -264,7 +304,24 @@ pub async fn create_routing_algorithm_under_profile(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let new_record = record.foreign_into();
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_account.get_id()),
+            entity_type: enums::AuditEntityType::RoutingAlgorithm,
+            entity_id: record.algorithm_id.clone(),
+            action: enums::AuditAction::Create,
+            before: None,
+            after: Some(&record),
+        },
+    )
+    .await;
+
+    let new_record = routing_types::RoutingDictionaryRecord {
+        warnings,
+        ..record.foreign_into()
+    };
 
     metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
     Ok(service_api::ApplicationResponse::Json(new_record))
@@ -350,14 +407,70 @@ pub async fn link_routing_config_under_profile(
 ))]
 pub async fn link_routing_config(
     state: SessionState,
+    req_state: ReqState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     algorithm_id: String,
     transaction_type: &enums::TransactionType,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_LINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+
+    let routing_algorithm = activate_routing_algorithm(
+        &state,
+        &req_state,
+        &merchant_account,
+        &key_store,
+        algorithm_id,
+        transaction_type,
+        enums::RoutingActivationKind::Link,
+        req_state.get_actor(),
+    )
+    .await?;
+
+    metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_algorithm.foreign_into(),
+    ))
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(any(feature = "routing_v2", feature = "business_profile_v2"))
+))]
+fn get_active_algorithm_ref(
+    business_profile: &domain::BusinessProfile,
+    transaction_type: &enums::TransactionType,
+) -> errors::RouterResult<routing_types::RoutingAlgorithmRef> {
+    Ok(match transaction_type {
+        enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
+        #[cfg(feature = "payouts")]
+        enums::TransactionType::Payout => business_profile.payout_routing_algorithm.clone(),
+    }
+    .map(|val| val.parse_value("RoutingAlgorithmRef"))
+    .transpose()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+    .unwrap_or_default())
+}
+
+/// Makes the routing algorithm the active one of its profile, and records the activation in the
+/// activation history of the profile
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(any(feature = "routing_v2", feature = "business_profile_v2"))
+))]
+pub async fn activate_routing_algorithm(
+    state: &SessionState,
+    req_state: &ReqState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    algorithm_id: String,
+    transaction_type: &enums::TransactionType,
+    activation_kind: enums::RoutingActivationKind,
+    activated_by: Option<String>,
+) -> errors::RouterResult<RoutingAlgorithm> {
     let db = state.store.as_ref();
-    let key_manager_state = &(&state).into();
+    let key_manager_state = &state.into();
 
     let routing_algorithm = db
         .find_routing_algorithm_by_algorithm_id_merchant_id(
@@ -370,7 +483,7 @@ pub async fn link_routing_config(
     let business_profile = core_utils::validate_and_get_business_profile(
         db,
         key_manager_state,
-        &key_store,
+        key_store,
         Some(&routing_algorithm.profile_id),
         merchant_account.get_id(),
     )
@@ -380,14 +493,7 @@ pub async fn link_routing_config(
         id: routing_algorithm.profile_id.clone(),
     })?;
 
-    let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
-        .routing_algorithm
-        .clone()
-        .map(|val| val.parse_value("RoutingAlgorithmRef"))
-        .transpose()
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("unable to deserialize routing algorithm ref from merchant account")?
-        .unwrap_or_default();
+    let mut routing_ref = get_active_algorithm_ref(&business_profile, transaction_type)?;
 
     utils::when(routing_algorithm.algorithm_for != *transaction_type, || {
         Err(errors::ApiErrorResponse::PreconditionFailed {
@@ -406,21 +512,21 @@ pub async fn link_routing_config(
             })
         },
     )?;
+    let previous_algorithm_id = routing_ref.algorithm_id.clone();
     routing_ref.update_algorithm_id(algorithm_id);
-    helpers::update_business_profile_active_algorithm_ref(
+    let activation = helpers::update_active_algorithm_ref_and_record_activation(
         db,
-        key_manager_state,
-        &key_store,
         business_profile,
         routing_ref,
         transaction_type,
+        previous_algorithm_id,
+        activation_kind,
+        activated_by,
     )
     .await?;
+    helpers::record_routing_activation_audit_log(state, req_state, &activation).await;
 
-    metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-    Ok(service_api::ApplicationResponse::Json(
-        routing_algorithm.foreign_into(),
-    ))
+    Ok(routing_algorithm)
 }
 
 #[cfg(all(feature = "v2", feature = "routing_v2",))]
@@ -494,6 +600,172 @@ pub async fn retrieve_active_routing_config(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "routing_v2")
+))]
+pub async fn simulate_routing_algorithm(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: routing_types::RoutingSimulationRequest,
+) -> RouterResponse<routing_types::RoutingSimulationResponse> {
+    metrics::ROUTING_SIMULATE_REQUEST_RECEIVED.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&request.profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("BusinessProfile")
+    .change_context(errors::ApiErrorResponse::BusinessProfileNotFound {
+        id: request.profile_id.clone(),
+    })?;
+
+    let algorithm = match request.algorithm {
+        routing_types::RoutingSimulationAlgorithm::Stored(algorithm_id) => {
+            let routing_algorithm = db
+                .find_routing_algorithm_by_algorithm_id_merchant_id(
+                    &algorithm_id,
+                    merchant_account.get_id(),
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+            utils::when(
+                routing_algorithm.profile_id != request.profile_id
+                    || routing_algorithm.algorithm_for != enums::TransactionType::Payment,
+                || {
+                    Err(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "Only payment routing algorithms of the profile can be simulated"
+                            .to_string(),
+                    })
+                },
+            )?;
+
+            routing_algorithm
+                .algorithm_data
+                .parse_value::<routing_types::RoutingAlgorithm>("RoutingAlgorithm")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("unable to deserialize routing algorithm")?
+        }
+        routing_types::RoutingSimulationAlgorithm::Inline(algorithm) => {
+            helpers::validate_connectors_in_routing_config(
+                &state,
+                &key_store,
+                merchant_account.get_id(),
+                &request.profile_id,
+                &algorithm,
+            )
+            .await?;
+
+            algorithm
+        }
+    };
+
+    let simulator = payments::routing::simulation::RoutingSimulator::new(
+        &state,
+        request.profile_id.clone(),
+        algorithm,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to initialize the routing simulation")?;
+
+    let max_payments = request
+        .limit
+        .map_or(consts::ROUTING_SIMULATION_MAX_PAYMENTS, |limit| {
+            limit.min(consts::ROUTING_SIMULATION_MAX_PAYMENTS)
+        });
+    let mut aggregator = payments::routing::simulation::RoutingSimulationAggregator::default();
+    let mut offset = 0;
+
+    while offset < max_payments {
+        let batch_size = consts::ROUTING_SIMULATION_BATCH_SIZE.min(max_payments - offset);
+        let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset,
+            starting_at: Some(request.time_range.start_time),
+            ending_at: Some(
+                request
+                    .time_range
+                    .end_time
+                    .unwrap_or_else(common_utils::date_time::now),
+            ),
+            amount_filter: None,
+            connector: None,
+            currency: None,
+            status: None,
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: Some(request.profile_id.clone()),
+            customer_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(batch_size),
+            order: Default::default(),
+        }));
+
+        let payments = db
+            .get_filtered_payment_intents_attempt(
+                key_manager_state,
+                merchant_account.get_id(),
+                &constraints,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let fetched_payments = u32::try_from(payments.len())
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        for (payment_intent, payment_attempt) in payments.iter() {
+            // Payments which never reached a connector have nothing to be compared against
+            let (Some(actual_connector), Some(currency)) =
+                (payment_attempt.connector.as_ref(), payment_attempt.currency)
+            else {
+                continue;
+            };
+
+            let simulated_connector = simulator
+                .simulate_payment(&state, &key_store, payment_attempt, payment_intent)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed to simulate routing for payment {}",
+                        payment_attempt.payment_id
+                    )
+                })?;
+
+            aggregator.record(
+                simulated_connector.as_ref(),
+                actual_connector,
+                currency,
+                payment_attempt.amount,
+            );
+        }
+
+        if fetched_payments < batch_size {
+            break;
+        }
+        offset += fetched_payments;
+    }
+
+    metrics::ROUTING_SIMULATE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        aggregator.into_response(request.profile_id),
+    ))
+}
+
 #[cfg(all(
     feature = "v2",
     feature = "routing_v2",
@@ -558,6 +830,7 @@ pub async fn unlink_routing_config_under_profile(
 ))]
 pub async fn unlink_routing_config(
     state: SessionState,
+    req_state: ReqState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     request: routing_types::RoutingConfigRequest,
@@ -615,15 +888,18 @@ pub async fn unlink_routing_config(
                         .await
                         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
                     let response = record.foreign_into();
-                    helpers::update_business_profile_active_algorithm_ref(
+                    let activation = helpers::update_active_algorithm_ref_and_record_activation(
                         db,
-                        key_manager_state,
-                        &key_store,
                         business_profile,
                         routing_algorithm,
                         transaction_type,
+                        Some(algorithm_id),
+                        enums::RoutingActivationKind::Unlink,
+                        req_state.get_actor(),
                     )
                     .await?;
+                    helpers::record_routing_activation_audit_log(&state, &req_state, &activation)
+                        .await;
 
                     metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
                     Ok(service_api::ApplicationResponse::Json(response))
@@ -640,6 +916,338 @@ pub async fn unlink_routing_config(
     }
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(any(feature = "routing_v2", feature = "business_profile_v2"))
+))]
+pub async fn list_routing_activation_history(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    query_params: routing_types::RoutingActivationHistoryQuery,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<Vec<routing_types::RoutingActivationRecord>> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&query_params.profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("BusinessProfile")
+    .change_context(errors::ApiErrorResponse::BusinessProfileNotFound {
+        id: query_params.profile_id.clone(),
+    })?;
+
+    let activations = db
+        .list_routing_algorithm_activations_by_profile_id_transaction_type(
+            &query_params.profile_id,
+            transaction_type,
+            i64::from(
+                query_params
+                    .limit
+                    .unwrap_or(consts::DEFAULT_ROUTING_HISTORY_LIMIT),
+            ),
+            i64::from(query_params.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch routing algorithm activations")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        activations
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+    ))
+}
+
+/// Restores the routing algorithm which was active before the latest activation of the profile,
+/// or the one made active by the given activation.
+///
+/// The active algorithm is replaced in a single update of the business profile, so the profile
+/// is never left without an algorithm while rolling back.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(any(feature = "routing_v2", feature = "business_profile_v2"))
+))]
+pub async fn rollback_routing_config(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: routing_types::RoutingRollbackRequest,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<routing_types::RoutingActivationRecord> {
+    metrics::ROUTING_ROLLBACK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&request.profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("BusinessProfile")
+    .change_context(errors::ApiErrorResponse::BusinessProfileNotFound {
+        id: request.profile_id.clone(),
+    })?;
+
+    let mut routing_ref = get_active_algorithm_ref(&business_profile, transaction_type)?;
+
+    let rollback_algorithm_id = match request.activation_id {
+        Some(activation_id) => {
+            let activation = db
+                .find_routing_algorithm_activation_by_activation_id_profile_id(
+                    &activation_id,
+                    &request.profile_id,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+            utils::when(activation.transaction_type != *transaction_type, || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "Cannot use {}'s routing activation for {} operation",
+                        activation.transaction_type, transaction_type
+                    ),
+                })
+            })?;
+            activation.algorithm_id
+        }
+        None => {
+            db.list_routing_algorithm_activations_by_profile_id_transaction_type(
+                &request.profile_id,
+                transaction_type,
+                1,
+                0,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch routing algorithm activations")?
+            .pop()
+            .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+                message: "No routing algorithm was activated for the profile".to_string(),
+            })?
+            .previous_algorithm_id
+        }
+    };
+
+    utils::when(rollback_algorithm_id == routing_ref.algorithm_id, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Algorithm is already active".to_string(),
+        })
+    })?;
+
+    let previous_algorithm_id = routing_ref.algorithm_id.clone();
+    match rollback_algorithm_id {
+        Some(algorithm_id) => {
+            // The algorithm could have been removed since it was active
+            db.find_routing_algorithm_by_profile_id_algorithm_id(
+                &request.profile_id,
+                &algorithm_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+            routing_ref.update_algorithm_id(algorithm_id);
+        }
+        None => {
+            routing_ref.algorithm_id = None;
+            routing_ref.timestamp = common_utils::date_time::now_unix_timestamp();
+        }
+    }
+
+    let activation = helpers::update_active_algorithm_ref_and_record_activation(
+        db,
+        business_profile,
+        routing_ref,
+        transaction_type,
+        previous_algorithm_id,
+        enums::RoutingActivationKind::Rollback,
+        req_state.get_actor(),
+    )
+    .await?;
+    helpers::record_routing_activation_audit_log(&state, &req_state, &activation).await;
+
+    metrics::ROUTING_ROLLBACK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        activation.foreign_into(),
+    ))
+}
+
+/// Schedules the activation of the routing algorithm, which is performed by the scheduler at the
+/// requested time
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(any(feature = "routing_v2", feature = "business_profile_v2"))
+))]
+pub async fn schedule_routing_config_activation(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    algorithm_id: String,
+    request: routing_types::RoutingActivationScheduleRequest,
+    scheduled_by: Option<String>,
+    transaction_type: &enums::TransactionType,
+) -> RouterResponse<routing_types::RoutingScheduledActivation> {
+    metrics::ROUTING_SCHEDULE_ACTIVATION.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    utils::when(
+        request.activate_at <= common_utils::date_time::now(),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "activate_at must be in the future".to_string(),
+            })
+        },
+    )?;
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_account.get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&routing_algorithm.profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("BusinessProfile")
+    .change_context(errors::ApiErrorResponse::BusinessProfileNotFound {
+        id: routing_algorithm.profile_id.clone(),
+    })?;
+
+    utils::when(routing_algorithm.algorithm_for != *transaction_type, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Cannot use {}'s routing algorithm for {} operation",
+                routing_algorithm.algorithm_for, transaction_type
+            ),
+        })
+    })?;
+
+    let tracking_data = storage::RoutingActivationTrackingData {
+        merchant_id: merchant_account.get_id().clone(),
+        profile_id: routing_algorithm.profile_id.clone(),
+        algorithm_id: algorithm_id.clone(),
+        transaction_type: *transaction_type,
+        scheduled_by: scheduled_by.clone(),
+    };
+    let runner = storage::ProcessTrackerRunner::RoutingActivationWorkflow;
+    let task = "ROUTING_ACTIVATION";
+    let tag = ["ROUTING", "ACTIVATION"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &format!(
+            "{algorithm_id}_{}",
+            request.activate_at.assume_utc().unix_timestamp()
+        ),
+        merchant_account.get_id(),
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        request.activate_at,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct routing activation process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Activation of the algorithm is already scheduled at this time".to_string(),
+        })?;
+
+    metrics::ROUTING_SCHEDULE_ACTIVATION_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingScheduledActivation {
+            algorithm_id,
+            profile_id: routing_algorithm.profile_id,
+            transaction_type: *transaction_type,
+            activate_at: request.activate_at,
+            scheduled_by,
+        },
+    ))
+}
+
+pub async fn diff_routing_configs(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    query_params: routing_types::RoutingDiffQuery,
+) -> RouterResponse<routing_types::RoutingAlgorithmDiff> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let mut algorithms = Vec::with_capacity(2);
+    for algorithm_id in [
+        &query_params.base_algorithm_id,
+        &query_params.target_algorithm_id,
+    ] {
+        let routing_algorithm = db
+            .find_routing_algorithm_by_algorithm_id_merchant_id(
+                algorithm_id,
+                merchant_account.get_id(),
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+        core_utils::validate_and_get_business_profile(
+            db,
+            key_manager_state,
+            &key_store,
+            Some(&routing_algorithm.profile_id),
+            merchant_account.get_id(),
+        )
+        .await?
+        .get_required_value("BusinessProfile")
+        .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+        let algorithm = routing_algorithm
+            .algorithm_data
+            .parse_value::<routing_types::RoutingAlgorithm>("RoutingAlgorithm")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to parse routing algorithm")?;
+        algorithms.push(algorithm);
+    }
+
+    let (Some(target), Some(base)) = (algorithms.pop(), algorithms.pop()) else {
+        return Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the routing algorithms to compare");
+    };
+
+    let (default_selection, rules) = helpers::diff_routing_algorithms(&base, &target);
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingAlgorithmDiff {
+            base_algorithm_id: query_params.base_algorithm_id,
+            target_algorithm_id: query_params.target_algorithm_id,
+            base_kind: base.get_kind(),
+            target_kind: target.get_kind(),
+            default_selection,
+            rules,
+        },
+    ))
+}
+
 #[cfg(all(
     feature = "v2",
     feature = "routing_v2",
@@ -647,6 +1255,7 @@ pub async fn unlink_routing_config(
 ))]
 pub async fn update_default_fallback_routing(
     state: SessionState,
+    req_state: ReqState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     profile_id: String,
@@ -714,6 +1323,20 @@ pub async fn update_default_fallback_routing(
         )
         .await?;
 
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_account.get_id()),
+            entity_type: enums::AuditEntityType::DefaultFallbackRouting,
+            entity_id: profile_id,
+            action: enums::AuditAction::Update,
+            before: Some(&default_list_of_connectors),
+            after: Some(&updated_list_of_connectors),
+        },
+    )
+    .await;
+
     metrics::ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
     Ok(service_api::ApplicationResponse::Json(
         updated_list_of_connectors,
@@ -726,6 +1349,7 @@ pub async fn update_default_fallback_routing(
 ))]
 pub async fn update_default_routing_config(
     state: SessionState,
+    req_state: ReqState,
     merchant_account: domain::MerchantAccount,
     updated_config: Vec<routing_types::RoutableConnectorChoice>,
     transaction_type: &enums::TransactionType,
@@ -772,6 +1396,23 @@ pub async fn update_default_routing_config(
     )
     .await?;
 
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_account.get_id()),
+            entity_type: enums::AuditEntityType::DefaultFallbackRouting,
+            entity_id: helpers::get_default_config_key(
+                merchant_account.get_id().get_string_repr(),
+                transaction_type,
+            ),
+            action: enums::AuditAction::Update,
+            before: Some(&default_config),
+            after: Some(&updated_config),
+        },
+    )
+    .await;
+
     metrics::ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
     Ok(service_api::ApplicationResponse::Json(updated_config))
 }
@@ -997,6 +1638,7 @@ pub async fn retrieve_default_routing_config_for_profiles(
 
 pub async fn update_default_routing_config_for_profile(
     state: SessionState,
+    req_state: ReqState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     updated_config: Vec<routing_types::RoutableConnectorChoice>,
@@ -1064,6 +1706,23 @@ pub async fn update_default_routing_config_for_profile(
     )
     .await?;
 
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_account.get_id()),
+            entity_type: enums::AuditEntityType::DefaultFallbackRouting,
+            entity_id: helpers::get_default_config_key(
+                &business_profile.profile_id,
+                transaction_type,
+            ),
+            action: enums::AuditAction::Update,
+            before: Some(&default_config),
+            after: Some(&updated_config),
+        },
+    )
+    .await;
+
     metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
     Ok(service_api::ApplicationResponse::Json(
         routing_types::ProfileDefaultRoutingConfig {
@@ -6,15 +6,20 @@ use api_models::routing as routing_types;
 use common_utils::{ext_traits::Encode, types::keymanager::KeyManagerState};
 use diesel_models::configs;
 use error_stack::ResultExt;
+use router_env::logger;
 use rustc_hash::FxHashSet;
 use storage_impl::redis::cache;
 
 #[cfg(all(feature = "v2", feature = "routing_v2"))]
 use crate::types::domain::MerchantConnectorAccount;
 use crate::{
-    core::errors::{self, RouterResult},
+    consts,
+    core::{
+        audit_log::{self, ConfigChange},
+        errors::{self, RouterResult},
+    },
     db::StorageInterface,
-    routes::SessionState,
+    routes::{app::ReqState, SessionState},
     types::{domain, storage},
     utils::StringExt,
 };
@@ -196,33 +201,8 @@ pub async fn update_business_profile_active_algorithm_ref(
     algorithm_id: routing_types::RoutingAlgorithmRef,
     transaction_type: &storage::enums::TransactionType,
 ) -> RouterResult<()> {
-    let ref_val = algorithm_id
-        .encode_to_value()
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to convert routing ref to value")?;
-
-    let merchant_id = current_business_profile.merchant_id.clone();
-
-    let profile_id = current_business_profile.profile_id.clone();
-
-    let routing_cache_key = cache::CacheKind::Routing(
-        format!(
-            "routing_config_{}_{profile_id}",
-            merchant_id.get_string_repr()
-        )
-        .into(),
-    );
-
-    let (routing_algorithm, payout_routing_algorithm) = match transaction_type {
-        storage::enums::TransactionType::Payment => (Some(ref_val), None),
-        #[cfg(feature = "payouts")]
-        storage::enums::TransactionType::Payout => (None, Some(ref_val)),
-    };
-
-    let business_profile_update = domain::BusinessProfileUpdate::RoutingAlgorithmUpdate {
-        routing_algorithm,
-        payout_routing_algorithm,
-    };
+    let routing_cache_key = get_routing_cache_key(&current_business_profile);
+    let business_profile_update = get_routing_algorithm_update(algorithm_id, transaction_type)?;
 
     db.update_business_profile_by_profile_id(
         key_manager_state,
@@ -241,6 +221,92 @@ pub async fn update_business_profile_active_algorithm_ref(
     Ok(())
 }
 
+/// Updates the active routing algorithm of the profile to the one of `algorithm_ref`, and records
+/// the change in the activation history of the profile in the same transaction
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(any(feature = "routing_v2", feature = "business_profile_v2"))
+))]
+pub async fn update_active_algorithm_ref_and_record_activation(
+    db: &dyn StorageInterface,
+    current_business_profile: domain::BusinessProfile,
+    algorithm_ref: routing_types::RoutingAlgorithmRef,
+    transaction_type: &storage::enums::TransactionType,
+    previous_algorithm_id: Option<String>,
+    activation_kind: storage::enums::RoutingActivationKind,
+    activated_by: Option<String>,
+) -> RouterResult<storage::RoutingAlgorithmActivation> {
+    let routing_cache_key = get_routing_cache_key(&current_business_profile);
+    let activation = storage::RoutingAlgorithmActivation {
+        activation_id: common_utils::generate_id(consts::ROUTING_CONFIG_ID_LENGTH, "routing_act"),
+        merchant_id: current_business_profile.merchant_id.clone(),
+        profile_id: current_business_profile.profile_id.clone(),
+        transaction_type: *transaction_type,
+        algorithm_id: algorithm_ref.algorithm_id.clone(),
+        previous_algorithm_id,
+        activation_kind,
+        activated_by,
+        created_at: common_utils::date_time::now(),
+    };
+    let business_profile_update = get_routing_algorithm_update(algorithm_ref, transaction_type)?;
+
+    let activation = db
+        .update_business_profile_and_insert_routing_activation(
+            current_business_profile,
+            business_profile_update,
+            activation,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update routing algorithm ref and record its activation")?;
+
+    cache::publish_into_redact_channel(db.get_cache_store().as_ref(), [routing_cache_key])
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to invalidate routing cache")?;
+    Ok(activation)
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(any(feature = "routing_v2", feature = "business_profile_v2"))
+))]
+fn get_routing_algorithm_update(
+    algorithm_ref: routing_types::RoutingAlgorithmRef,
+    transaction_type: &storage::enums::TransactionType,
+) -> RouterResult<domain::BusinessProfileUpdate> {
+    let ref_val = algorithm_ref
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert routing ref to value")?;
+
+    let (routing_algorithm, payout_routing_algorithm) = match transaction_type {
+        storage::enums::TransactionType::Payment => (Some(ref_val), None),
+        #[cfg(feature = "payouts")]
+        storage::enums::TransactionType::Payout => (None, Some(ref_val)),
+    };
+
+    Ok(domain::BusinessProfileUpdate::RoutingAlgorithmUpdate {
+        routing_algorithm,
+        payout_routing_algorithm,
+    })
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(any(feature = "routing_v2", feature = "business_profile_v2"))
+))]
+fn get_routing_cache_key(business_profile: &domain::BusinessProfile) -> cache::CacheKind<'static> {
+    cache::CacheKind::Routing(
+        format!(
+            "routing_config_{}_{}",
+            business_profile.merchant_id.get_string_repr(),
+            business_profile.profile_id
+        )
+        .into(),
+    )
+}
+
 #[cfg(all(feature = "v2", feature = "routing_v2"))]
 #[derive(Clone, Debug)]
 pub struct RoutingAlgorithmHelpers<'h> {
@@ -359,6 +425,18 @@ impl<'h> RoutingAlgorithmHelpers<'h> {
                 }
             }
 
+            routing_types::RoutingAlgorithm::SuccessRate(algorithm) => {
+                for choice in &algorithm.connectors {
+                    self.connector_choice(choice)?;
+                }
+            }
+
+            routing_types::RoutingAlgorithm::CostBased(algorithm) => {
+                for choice in &algorithm.connectors {
+                    self.connector_choice(choice)?;
+                }
+            }
+
             routing_types::RoutingAlgorithm::Advanced(program) => {
                 let check_connector_selection =
                     |selection: &routing_types::ConnectorSelection| -> RouterResult<()> {
@@ -467,6 +545,18 @@ pub async fn validate_connectors_in_routing_config(
             }
         }
 
+        routing_types::RoutingAlgorithm::SuccessRate(algorithm) => {
+            for choice in &algorithm.connectors {
+                connector_choice(choice)?;
+            }
+        }
+
+        routing_types::RoutingAlgorithm::CostBased(algorithm) => {
+            for choice in &algorithm.connectors {
+                connector_choice(choice)?;
+            }
+        }
+
         routing_types::RoutingAlgorithm::Advanced(program) => {
             let check_connector_selection =
                 |selection: &routing_types::ConnectorSelection| -> RouterResult<()> {
@@ -516,3 +606,329 @@ pub fn get_default_config_key(
         storage::enums::TransactionType::Payout => format!("routing_default_po_{merchant_id}"),
     }
 }
+
+/// Records the change of the active routing algorithm of the profile in the audit log
+pub async fn record_routing_activation_audit_log(
+    state: &SessionState,
+    req_state: &ReqState,
+    activation: &storage::RoutingAlgorithmActivation,
+) {
+    let (entity_id, action) = match (&activation.algorithm_id, &activation.previous_algorithm_id) {
+        (Some(algorithm_id), _) => (algorithm_id.clone(), storage::enums::AuditAction::Activate),
+        (None, Some(previous_algorithm_id)) => (
+            previous_algorithm_id.clone(),
+            storage::enums::AuditAction::Deactivate,
+        ),
+        (None, None) => return,
+    };
+
+    let get_active_algorithm = |algorithm_id: &Option<String>| {
+        serde_json::json!({
+            "profile_id": activation.profile_id,
+            "transaction_type": activation.transaction_type,
+            "active_algorithm_id": algorithm_id,
+        })
+    };
+
+    audit_log::record_config_change(
+        state,
+        req_state,
+        ConfigChange {
+            merchant_id: Some(&activation.merchant_id),
+            entity_type: storage::enums::AuditEntityType::RoutingAlgorithm,
+            entity_id,
+            action,
+            before: Some(&get_active_algorithm(&activation.previous_algorithm_id)),
+            after: Some(&get_active_algorithm(&activation.algorithm_id)),
+        },
+    )
+    .await;
+}
+
+/// Provides the connectors chosen when no rule of the algorithm matches, which for algorithms
+/// without rules are all the connectors they choose
+fn get_default_connector_selection(
+    algorithm: &routing_types::RoutingAlgorithm,
+) -> routing_types::ConnectorSelection {
+    match algorithm {
+        routing_types::RoutingAlgorithm::Single(connector) => {
+            routing_types::ConnectorSelection::Priority(vec![(**connector).clone()])
+        }
+        routing_types::RoutingAlgorithm::Priority(connectors) => {
+            routing_types::ConnectorSelection::Priority(connectors.clone())
+        }
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
+            routing_types::ConnectorSelection::VolumeSplit(splits.clone())
+        }
+        routing_types::RoutingAlgorithm::Advanced(program) => program.default_selection.clone(),
+        routing_types::RoutingAlgorithm::SuccessRate(algorithm) => {
+            routing_types::ConnectorSelection::Priority(algorithm.connectors.clone())
+        }
+        routing_types::RoutingAlgorithm::CostBased(algorithm) => {
+            routing_types::ConnectorSelection::Priority(algorithm.connectors.clone())
+        }
+    }
+}
+
+fn get_rules(
+    algorithm: &routing_types::RoutingAlgorithm,
+) -> &[routing_types::ast::Rule<routing_types::ConnectorSelection>] {
+    match algorithm {
+        routing_types::RoutingAlgorithm::Advanced(program) => &program.rules,
+        routing_types::RoutingAlgorithm::Single(_)
+        | routing_types::RoutingAlgorithm::Priority(_)
+        | routing_types::RoutingAlgorithm::VolumeSplit(_)
+        | routing_types::RoutingAlgorithm::SuccessRate(_)
+        | routing_types::RoutingAlgorithm::CostBased(_) => &[],
+    }
+}
+
+/// Compares the conditions of two rules, ignoring the metadata which is only used by the
+/// dashboard
+fn are_statements_equal(
+    base: &[routing_types::ast::IfStatement],
+    target: &[routing_types::ast::IfStatement],
+) -> bool {
+    base.len() == target.len()
+        && base.iter().zip(target).all(|(base, target)| {
+            base.condition.len() == target.condition.len()
+                && base
+                    .condition
+                    .iter()
+                    .zip(&target.condition)
+                    .all(|(base, target)| {
+                        base.lhs == target.lhs
+                            && base.comparison == target.comparison
+                            && base.value == target.value
+                    })
+                && match (&base.nested, &target.nested) {
+                    (Some(base), Some(target)) => are_statements_equal(base, target),
+                    (None, None) => true,
+                    (Some(_), None) | (None, Some(_)) => false,
+                }
+        })
+}
+
+fn diff_connector_selection(
+    base: Option<&routing_types::ConnectorSelection>,
+    target: Option<&routing_types::ConnectorSelection>,
+) -> Option<routing_types::ConnectorSelectionDiff> {
+    (base != target).then(|| routing_types::ConnectorSelectionDiff {
+        base: base.cloned(),
+        target: target.cloned(),
+    })
+}
+
+/// Compares the rules and the connector selections of two routing algorithms. Rules are matched
+/// by their names, so a renamed rule shows up as removed and added.
+pub fn diff_routing_algorithms(
+    base: &routing_types::RoutingAlgorithm,
+    target: &routing_types::RoutingAlgorithm,
+) -> (
+    Option<routing_types::ConnectorSelectionDiff>,
+    Vec<routing_types::RoutingRuleDiff>,
+) {
+    let default_selection = diff_connector_selection(
+        Some(&get_default_connector_selection(base)),
+        Some(&get_default_connector_selection(target)),
+    );
+
+    let base_rules = get_rules(base);
+    let target_rules = get_rules(target);
+
+    let mut rules = base_rules
+        .iter()
+        .filter_map(|base_rule| {
+            match target_rules
+                .iter()
+                .find(|target_rule| target_rule.name == base_rule.name)
+            {
+                Some(target_rule) => {
+                    let conditions_changed =
+                        !are_statements_equal(&base_rule.statements, &target_rule.statements);
+                    let connector_selection = diff_connector_selection(
+                        Some(&base_rule.connector_selection),
+                        Some(&target_rule.connector_selection),
+                    );
+
+                    (conditions_changed || connector_selection.is_some()).then(|| {
+                        routing_types::RoutingRuleDiff {
+                            rule_name: base_rule.name.clone(),
+                            change: routing_types::RoutingRuleChange::Modified,
+                            conditions_changed,
+                            connector_selection,
+                        }
+                    })
+                }
+                None => Some(routing_types::RoutingRuleDiff {
+                    rule_name: base_rule.name.clone(),
+                    change: routing_types::RoutingRuleChange::Removed,
+                    conditions_changed: true,
+                    connector_selection: diff_connector_selection(
+                        Some(&base_rule.connector_selection),
+                        None,
+                    ),
+                }),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rules.extend(
+        target_rules
+            .iter()
+            .filter(|target_rule| {
+                !base_rules
+                    .iter()
+                    .any(|base_rule| base_rule.name == target_rule.name)
+            })
+            .map(|target_rule| routing_types::RoutingRuleDiff {
+                rule_name: target_rule.name.clone(),
+                change: routing_types::RoutingRuleChange::Added,
+                conditions_changed: true,
+                connector_selection: diff_connector_selection(
+                    None,
+                    Some(&target_rule.connector_selection),
+                ),
+            }),
+    );
+
+    (default_selection, rules)
+}
+
+/// Returns the rules and nested statements of an advanced routing algorithm which have no effect
+/// on the routing decision, such as rules shadowed by the rules before them.
+pub fn get_routing_algorithm_warnings(
+    algorithm: &routing_types::RoutingAlgorithm,
+) -> Vec<routing_types::AnalysisWarning> {
+    match algorithm {
+        routing_types::RoutingAlgorithm::Advanced(program) => {
+            euclid::dssa::analyzer::analyze_rule_reachability(program.clone())
+                .map_err(|error| logger::warn!(routing_reachability_analysis_error=?error))
+                .unwrap_or_default()
+        }
+        routing_types::RoutingAlgorithm::Single(_)
+        | routing_types::RoutingAlgorithm::Priority(_)
+        | routing_types::RoutingAlgorithm::VolumeSplit(_)
+        | routing_types::RoutingAlgorithm::SuccessRate(_)
+        | routing_types::RoutingAlgorithm::CostBased(_) => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::ext_traits::ValueExt;
+
+    use super::*;
+
+    fn make_advanced_algorithm(rules: serde_json::Value) -> routing_types::RoutingAlgorithm {
+        serde_json::json!({
+            "type": "advanced",
+            "data": {
+                "defaultSelection": {
+                    "type": "priority",
+                    "data": [{ "connector": "stripe", "merchant_connector_id": null }]
+                },
+                "rules": rules,
+                "metadata": {}
+            }
+        })
+        .parse_value("RoutingAlgorithm")
+        .unwrap()
+    }
+
+    fn make_rule(name: &str, connector: &str, min_amount: i64) -> serde_json::Value {
+        serde_json::json!({
+            "name": name,
+            "connectorSelection": {
+                "type": "priority",
+                "data": [{ "connector": connector, "merchant_connector_id": null }]
+            },
+            "statements": [{
+                "condition": [{
+                    "lhs": "amount",
+                    "comparison": "greater_than",
+                    "value": { "type": "number", "value": min_amount },
+                    "metadata": {}
+                }],
+                "nested": null
+            }]
+        })
+    }
+
+    #[test]
+    fn test_routing_algorithm_diff() {
+        let base = make_advanced_algorithm(serde_json::json!([
+            make_rule("high_value", "adyen", 1000),
+            make_rule("low_value", "stripe", 10),
+            make_rule("legacy", "checkout", 10),
+        ]));
+        let target = make_advanced_algorithm(serde_json::json!([
+            make_rule("high_value", "adyen", 5000),
+            make_rule("low_value", "checkout", 10),
+            make_rule("eu", "adyen", 10),
+        ]));
+
+        let (default_selection, rules) = diff_routing_algorithms(&base, &target);
+        assert!(default_selection.is_none());
+
+        let changes = rules
+            .iter()
+            .map(|rule| {
+                (
+                    rule.rule_name.as_str(),
+                    rule.change,
+                    rule.conditions_changed,
+                    rule.connector_selection.is_some(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                (
+                    "high_value",
+                    routing_types::RoutingRuleChange::Modified,
+                    true,
+                    false
+                ),
+                (
+                    "low_value",
+                    routing_types::RoutingRuleChange::Modified,
+                    false,
+                    true
+                ),
+                (
+                    "legacy",
+                    routing_types::RoutingRuleChange::Removed,
+                    true,
+                    true
+                ),
+                ("eu", routing_types::RoutingRuleChange::Added, true, true),
+            ]
+        );
+
+        let (_, rules) = diff_routing_algorithms(&base, &base);
+        assert!(rules.is_empty());
+    }
+
+    #[test]
+    fn test_routing_algorithm_diff_across_kinds() {
+        let base =
+            make_advanced_algorithm(serde_json::json!([make_rule("high_value", "adyen", 1000)]));
+        let target: routing_types::RoutingAlgorithm = serde_json::json!({
+            "type": "priority",
+            "data": [{ "connector": "adyen", "merchant_connector_id": null }]
+        })
+        .parse_value("RoutingAlgorithm")
+        .unwrap();
+
+        let (default_selection, rules) = diff_routing_algorithms(&base, &target);
+        assert!(default_selection.is_some());
+        assert_eq!(rules.len(), 1);
+        assert_eq!(
+            rules.first().unwrap().change,
+            routing_types::RoutingRuleChange::Removed
+        );
+    }
+}
@@ -1,12 +1,13 @@
 use api_models::routing::{
-    MerchantRoutingAlgorithm, RoutingAlgorithm as Algorithm, RoutingAlgorithmKind,
-    RoutingDictionaryRecord,
+    MerchantRoutingAlgorithm, RoutingActivationRecord, RoutingAlgorithm as Algorithm,
+    RoutingAlgorithmKind, RoutingDictionaryRecord,
 };
 use common_utils::ext_traits::ValueExt;
 use diesel_models::{
     enums as storage_enums,
-    routing_algorithm::{RoutingAlgorithm, RoutingProfileMetadata},
+    routing_algorithm::{RoutingAlgorithm, RoutingAlgorithmActivation, RoutingProfileMetadata},
 };
+use error_stack::ResultExt;
 
 use crate::{
     core::{errors, routing},
@@ -25,6 +26,7 @@ impl ForeignFrom<RoutingProfileMetadata> for RoutingDictionaryRecord {
             created_at: value.created_at.assume_utc().unix_timestamp(),
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
             algorithm_for: Some(value.algorithm_for),
+            warnings: Vec::new(),
         }
     }
 }
@@ -41,6 +43,7 @@ impl ForeignFrom<RoutingAlgorithm> for RoutingDictionaryRecord {
             created_at: value.created_at.assume_utc().unix_timestamp(),
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
             algorithm_for: Some(value.algorithm_for),
+            warnings: Vec::new(),
         }
     }
 }
@@ -49,15 +52,21 @@ impl ForeignTryFrom<RoutingAlgorithm> for MerchantRoutingAlgorithm {
     type Error = error_stack::Report<errors::ParsingError>;
 
     fn foreign_try_from(value: RoutingAlgorithm) -> Result<Self, Self::Error> {
+        let algorithm = value
+            .algorithm_data
+            .parse_value::<Algorithm>("RoutingAlgorithm")?;
+        let program_text = algorithm
+            .get_program_text()
+            .change_context(errors::ParsingError::EncodeError("routing DSL text"))?;
+
         Ok(Self {
             id: value.algorithm_id,
             name: value.name,
 
             profile_id: value.profile_id,
             description: value.description.unwrap_or_default(),
-            algorithm: value
-                .algorithm_data
-                .parse_value::<Algorithm>("RoutingAlgorithm")?,
+            program_text,
+            algorithm,
             created_at: value.created_at.assume_utc().unix_timestamp(),
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
             algorithm_for: value.algorithm_for,
@@ -65,6 +74,21 @@ impl ForeignTryFrom<RoutingAlgorithm> for MerchantRoutingAlgorithm {
     }
 }
 
+impl ForeignFrom<RoutingAlgorithmActivation> for RoutingActivationRecord {
+    fn foreign_from(value: RoutingAlgorithmActivation) -> Self {
+        Self {
+            activation_id: value.activation_id,
+            profile_id: value.profile_id,
+            transaction_type: value.transaction_type,
+            algorithm_id: value.algorithm_id,
+            previous_algorithm_id: value.previous_algorithm_id,
+            activation_kind: value.activation_kind,
+            activated_by: value.activated_by,
+            activated_at: value.created_at,
+        }
+    }
+}
+
 impl ForeignFrom<storage_enums::RoutingAlgorithmKind> for RoutingAlgorithmKind {
     fn foreign_from(value: storage_enums::RoutingAlgorithmKind) -> Self {
         match value {
@@ -72,6 +96,8 @@ impl ForeignFrom<storage_enums::RoutingAlgorithmKind> for RoutingAlgorithmKind {
             storage_enums::RoutingAlgorithmKind::Priority => Self::Priority,
             storage_enums::RoutingAlgorithmKind::VolumeSplit => Self::VolumeSplit,
             storage_enums::RoutingAlgorithmKind::Advanced => Self::Advanced,
+            storage_enums::RoutingAlgorithmKind::SuccessRate => Self::SuccessRate,
+            storage_enums::RoutingAlgorithmKind::CostBased => Self::CostBased,
         }
     }
 }
@@ -83,6 +109,8 @@ impl ForeignFrom<RoutingAlgorithmKind> for storage_enums::RoutingAlgorithmKind {
             RoutingAlgorithmKind::Priority => Self::Priority,
             RoutingAlgorithmKind::VolumeSplit => Self::VolumeSplit,
             RoutingAlgorithmKind::Advanced => Self::Advanced,
+            RoutingAlgorithmKind::SuccessRate => Self::SuccessRate,
+            RoutingAlgorithmKind::CostBased => Self::CostBased,
         }
     }
 }
@@ -5,10 +5,13 @@ use common_utils::ext_traits::StringExt;
 use error_stack::ResultExt;
 
 use crate::{
-    core::errors::{self, RouterResponse},
-    routes::SessionState,
+    core::{
+        audit_log::{self, ConfigChange},
+        errors::{self, RouterResponse},
+    },
+    routes::{app::ReqState, SessionState},
     services::api as service_api,
-    types::domain,
+    types::{domain, storage::enums},
 };
 
 #[cfg(all(
@@ -17,6 +20,7 @@ use crate::{
 ))]
 pub async fn upsert_surcharge_decision_config(
     state: SessionState,
+    req_state: ReqState,
     key_store: domain::MerchantKeyStore,
     merchant_account: domain::MerchantAccount,
     request: SurchargeDecisionConfigReq,
@@ -69,7 +73,7 @@ pub async fn upsert_surcharge_decision_config(
                 .attach_printable("The Payment Config Key Not Found")?;
 
             let new_algo = SurchargeDecisionManagerRecord {
-                name: name.unwrap_or(previous_record.name),
+                name: name.unwrap_or(previous_record.name.clone()),
                 algorithm: program,
                 modified_at: timestamp,
                 created_at: previous_record.created_at,
@@ -91,12 +95,26 @@ pub async fn upsert_surcharge_decision_config(
                 .attach_printable("Error serializing the config")?;
 
             algo_id.update_surcharge_config_id(key.clone());
-            let config_key = cache::CacheKind::Surcharge(key.into());
+            let config_key = cache::CacheKind::Surcharge(key.clone().into());
             update_merchant_active_algorithm_ref(&state, &key_store, config_key, algo_id)
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Failed to update routing algorithm ref")?;
 
+            audit_log::record_config_change(
+                &state,
+                &req_state,
+                ConfigChange {
+                    merchant_id: Some(merchant_account.get_id()),
+                    entity_type: enums::AuditEntityType::SurchargeDecisionConfig,
+                    entity_id: key,
+                    action: enums::AuditAction::Update,
+                    before: Some(&previous_record),
+                    after: Some(&new_algo),
+                },
+            )
+            .await;
+
             Ok(service_api::ApplicationResponse::Json(new_algo))
         }
         Err(e) if e.current_context().is_db_not_found() => {
@@ -134,6 +152,20 @@ pub async fn upsert_surcharge_decision_config(
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Failed to update routing algorithm ref")?;
 
+            audit_log::record_config_change(
+                &state,
+                &req_state,
+                ConfigChange {
+                    merchant_id: Some(merchant_account.get_id()),
+                    entity_type: enums::AuditEntityType::SurchargeDecisionConfig,
+                    entity_id: key,
+                    action: enums::AuditAction::Create,
+                    before: None,
+                    after: Some(&new_rec),
+                },
+            )
+            .await;
+
             Ok(service_api::ApplicationResponse::Json(new_rec))
         }
         Err(e) => Err(e)
@@ -145,6 +177,7 @@ pub async fn upsert_surcharge_decision_config(
 #[cfg(all(feature = "v2", feature = "merchant_account_v2"))]
 pub async fn upsert_surcharge_decision_config(
     _state: SessionState,
+    _req_state: ReqState,
     _key_store: domain::MerchantKeyStore,
     _merchant_account: domain::MerchantAccount,
     _request: SurchargeDecisionConfigReq,
@@ -158,6 +191,7 @@ pub async fn upsert_surcharge_decision_config(
 ))]
 pub async fn delete_surcharge_decision_config(
     state: SessionState,
+    req_state: ReqState,
     key_store: domain::MerchantKeyStore,
     merchant_account: domain::MerchantAccount,
 ) -> RouterResponse<()> {
@@ -170,6 +204,12 @@ pub async fn delete_surcharge_decision_config(
     let key = merchant_account
         .get_id()
         .get_payment_method_surcharge_routing_id();
+    let record_before_delete = db.find_config_by_key(&key).await.ok().and_then(|config| {
+        config
+            .config
+            .parse_struct::<SurchargeDecisionManagerRecord>("SurchargeDecisionManagerRecord")
+            .ok()
+    });
     let mut algo_id: api_models::routing::RoutingAlgorithmRef = merchant_account
         .routing_algorithm
         .clone()
@@ -189,12 +229,28 @@ pub async fn delete_surcharge_decision_config(
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to delete routing config from DB")?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(merchant_account.get_id()),
+            entity_type: enums::AuditEntityType::SurchargeDecisionConfig,
+            entity_id: key,
+            action: enums::AuditAction::Delete,
+            before: record_before_delete.as_ref(),
+            after: None,
+        },
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::StatusOk)
 }
 
 #[cfg(all(feature = "v2", feature = "merchant_account_v2"))]
 pub async fn delete_surcharge_decision_config(
     _state: SessionState,
+    _req_state: ReqState,
     _key_store: domain::MerchantKeyStore,
     _merchant_account: domain::MerchantAccount,
 ) -> RouterResponse<()> {
@@ -9,14 +9,17 @@ use error_stack::{report, ResultExt};
 use once_cell::sync::Lazy;
 
 use crate::{
-    core::errors::{StorageErrorExt, UserErrors, UserResponse},
+    core::{
+        audit_log::{self, ConfigChange},
+        errors::{StorageErrorExt, UserErrors, UserResponse},
+    },
     routes::{app::ReqState, SessionState},
     services::{
         authentication as auth,
         authorization::{info, roles},
         ApplicationResponse,
     },
-    types::domain,
+    types::{domain, storage::enums},
     utils,
 };
 pub mod role;
@@ -86,7 +89,7 @@ pub async fn update_user_role(
     state: SessionState,
     user_from_token: auth::UserFromToken,
     req: user_role_api::UpdateUserRoleRequest,
-    _req_state: ReqState,
+    req_state: ReqState,
 ) -> UserResponse<()> {
     let role_info = roles::RoleInfo::from_role_id(
         &state,
@@ -123,6 +126,7 @@ pub async fn update_user_role(
     .change_context(UserErrors::InternalServerError)?;
 
     let mut is_updated = false;
+    let mut previous_role_id = None;
 
     let v2_user_role_to_be_updated = match state
         .store
@@ -187,6 +191,7 @@ pub async fn update_user_role(
             .change_context(UserErrors::InternalServerError)?;
 
         is_updated = true;
+        previous_role_id = Some(user_role.role_id);
     }
 
     let v1_user_role_to_be_updated = match state
@@ -252,6 +257,7 @@ pub async fn update_user_role(
             .change_context(UserErrors::InternalServerError)?;
 
         is_updated = true;
+        previous_role_id = Some(user_role.role_id);
     }
 
     if !is_updated {
@@ -259,6 +265,20 @@ pub async fn update_user_role(
             .attach_printable("User with given email is not found in the organization")?;
     }
 
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(&user_from_token.merchant_id),
+            entity_type: enums::AuditEntityType::UserRole,
+            entity_id: user_to_be_updated.get_user_id().to_string(),
+            action: enums::AuditAction::Update,
+            before: Some(&serde_json::json!({ "role_id": previous_role_id })),
+            after: Some(&serde_json::json!({ "role_id": req.role_id })),
+        },
+    )
+    .await;
+
     auth::blacklist::insert_user_in_blacklist(&state, user_to_be_updated.get_user_id()).await?;
 
     Ok(ApplicationResponse::StatusOk)
@@ -454,7 +474,7 @@ pub async fn delete_user_role(
     state: SessionState,
     user_from_token: auth::UserFromToken,
     request: user_role_api::DeleteUserRoleRequest,
-    _req_state: ReqState,
+    req_state: ReqState,
 ) -> UserResponse<()> {
     let user_from_db: domain::UserFromStorage = state
         .global_store
@@ -485,6 +505,7 @@ pub async fn delete_user_role(
     .change_context(UserErrors::InternalServerError)?;
 
     let mut user_role_deleted_flag = false;
+    let mut deleted_role_id = None;
 
     // Find in V2
     let user_role_v2 = match state
@@ -546,6 +567,7 @@ pub async fn delete_user_role(
             .await
             .change_context(UserErrors::InternalServerError)
             .attach_printable("Error while deleting user role")?;
+        deleted_role_id = Some(role_to_be_deleted.role_id);
     }
 
     // Find in V1
@@ -608,6 +630,7 @@ pub async fn delete_user_role(
             .await
             .change_context(UserErrors::InternalServerError)
             .attach_printable("Error while deleting user role")?;
+        deleted_role_id = Some(role_to_be_deleted.role_id);
     }
 
     if !user_role_deleted_flag {
@@ -615,6 +638,20 @@ pub async fn delete_user_role(
             .attach_printable("User is not associated with the merchant");
     }
 
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(&user_from_token.merchant_id),
+            entity_type: enums::AuditEntityType::UserRole,
+            entity_id: user_from_db.get_user_id().to_string(),
+            action: enums::AuditAction::Delete,
+            before: Some(&serde_json::json!({ "role_id": deleted_role_id })),
+            after: None,
+        },
+    )
+    .await;
+
     // Check if user has any more role associations
     let user_roles_v2 = state
         .store
@@ -1267,6 +1267,37 @@ pub fn get_flow_name<F>() -> RouterResult<String> {
         .to_string())
 }
 
+/// Get the time at which a payment or payout requeued by GSM should be attempted again
+///
+/// The delay can be configured per merchant in configs by the key `requeue_delay_in_secs_{merchant_id}`,
+/// and is capped at `MAX_REQUEUE_DELAY_IN_SECS`
+pub async fn get_requeue_schedule_time(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> time::PrimitiveDateTime {
+    let delay_in_secs = db
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_requeue_delay_key(),
+            Some(consts::DEFAULT_REQUEUE_DELAY_IN_SECS.to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|requeue_delay_config| {
+            requeue_delay_config
+                .config
+                .parse::<i64>()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Requeue delay config parsing failed")
+        })
+        .map_err(|error| {
+            router_env::logger::error!(requeue_delay_error=?error);
+        })
+        .unwrap_or(consts::DEFAULT_REQUEUE_DELAY_IN_SECS)
+        .clamp(0, consts::MAX_REQUEUE_DELAY_IN_SECS);
+
+    common_utils::date_time::now().saturating_add(time::Duration::seconds(delay_in_secs))
+}
+
 pub fn get_request_incremental_authorization_value(
     request_incremental_authorization: Option<bool>,
     capture_method: Option<common_enums::CaptureMethod>,
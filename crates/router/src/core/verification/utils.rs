@@ -87,6 +87,7 @@ pub async fn check_existence_and_add_domain_to_db(
         connector_label: None,
         status: None,
         connector_wallets_details: None,
+        fee_schedule: None,
     };
     #[cfg(all(feature = "v2", feature = "merchant_connector_account_v2"))]
     let updated_mca = storage::MerchantConnectorAccountUpdate::Update {
@@ -102,6 +103,7 @@ pub async fn check_existence_and_add_domain_to_db(
         connector_label: None,
         status: None,
         connector_wallets_details: None,
+        fee_schedule: None,
     };
     state
         .store
@@ -1,9 +1,17 @@
+pub mod archive_retention;
+#[cfg(feature = "olap")]
+pub mod archived_webhooks;
+mod endpoint_health;
 mod incoming;
 mod outgoing;
 pub mod types;
 pub mod utils;
 #[cfg(feature = "olap")]
+pub mod webhook_endpoints;
+#[cfg(feature = "olap")]
 pub mod webhook_events;
+#[cfg(feature = "olap")]
+pub mod webhook_replay;
 
 pub(crate) use self::{
     incoming::incoming_webhooks_wrapper,
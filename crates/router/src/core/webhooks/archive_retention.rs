@@ -0,0 +1,113 @@
+use error_stack::ResultExt;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    core::errors::{self, CustomResult, RouterResult},
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    types::storage,
+};
+
+const ARCHIVED_WEBHOOK_PURGE_TASK: &str = "ARCHIVED_WEBHOOK_PURGE";
+
+const ARCHIVED_WEBHOOK_PURGE_TAG: &str = "INCOMING_WEBHOOKS";
+
+/// Interval (in seconds) at which the archived webhooks of a merchant are purged
+pub(crate) const PURGE_INTERVAL_SECS: i64 = 86400;
+
+fn get_archived_webhook_purge_process_tracker_id(
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> String {
+    format!(
+        "{}_{ARCHIVED_WEBHOOK_PURGE_TASK}_{}",
+        storage::ProcessTrackerRunner::ArchivedWebhookPurgeWorkflow,
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Ensures that the task purging the archived webhooks of the merchant is scheduled. The task is
+/// rescheduled if it has finished, such as after it failed to purge the archived webhooks.
+#[instrument(skip(db))]
+pub(super) async fn add_archived_webhook_purge_task_if_absent(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> CustomResult<(), errors::StorageError> {
+    let process_tracker_id = get_archived_webhook_purge_process_tracker_id(merchant_id);
+    let schedule_time =
+        common_utils::date_time::now().saturating_add(time::Duration::seconds(PURGE_INTERVAL_SECS));
+
+    match db.find_process_by_id(&process_tracker_id).await? {
+        Some(process) if process.status == storage::enums::ProcessTrackerStatus::Finish => {
+            db.as_scheduler()
+                .reset_process(process, schedule_time)
+                .await
+        }
+        Some(_) => Ok(()),
+        None => {
+            let runner = storage::ProcessTrackerRunner::ArchivedWebhookPurgeWorkflow;
+            let tracking_data = storage::ArchivedWebhookPurgeTrackingData {
+                merchant_id: merchant_id.clone(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                ARCHIVED_WEBHOOK_PURGE_TASK,
+                runner,
+                [ARCHIVED_WEBHOOK_PURGE_TAG],
+                tracking_data,
+                schedule_time,
+            )
+            .map_err(errors::StorageError::from)?;
+
+            match db.insert_process(process_tracker_entry).await {
+                Ok(_) => {
+                    metrics::TASKS_ADDED_COUNT.add(
+                        &metrics::CONTEXT,
+                        1,
+                        &add_attributes([("flow", "ArchivedWebhookPurge")]),
+                    );
+                    Ok(())
+                }
+                // The task was added by a webhook of the merchant archived concurrently
+                Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+                Err(error) => {
+                    metrics::TASK_ADDITION_FAILURES_COUNT.add(
+                        &metrics::CONTEXT,
+                        1,
+                        &add_attributes([("flow", "ArchivedWebhookPurge")]),
+                    );
+                    Err(error)
+                }
+            }
+        }
+    }
+}
+
+/// Deletes the archived webhooks of the merchant which were received before the retention period,
+/// and returns the number of archived webhooks deleted.
+#[instrument(skip(state))]
+pub(crate) async fn purge_expired_archived_webhooks(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<usize> {
+    let retention_period = time::Duration::days(i64::from(
+        state
+            .conf
+            .webhooks
+            .incoming_archive
+            .retention_period_in_days,
+    ));
+
+    let purged_count = state
+        .store
+        .delete_archived_webhooks_by_merchant_id_created_before(
+            merchant_id,
+            common_utils::date_time::now().saturating_sub(retention_period),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to purge expired archived webhooks")?;
+
+    logger::info!(purged_count, "Purged expired archived webhooks");
+
+    Ok(purged_count)
+}
@@ -0,0 +1,216 @@
+use std::str::FromStr;
+
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
+use api_models::archived_webhooks as archived_webhook_types;
+use base64::Engine;
+use common_utils::ext_traits::StringExt;
+use error_stack::ResultExt;
+use hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use super::{incoming, types};
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::{app::ReqState, SessionState},
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+const ARCHIVED_WEBHOOKS_LIST_MAX_LIMIT: i64 = 100;
+
+#[instrument(skip(state))]
+pub async fn list_archived_webhooks(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    constraints: archived_webhook_types::ArchivedWebhookListConstraints,
+) -> RouterResponse<Vec<archived_webhook_types::ArchivedWebhookResponse>> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_key_store = get_merchant_key_store(&state, &merchant_id).await?;
+
+    let limit = match constraints.limit.map(i64::from) {
+        Some(limit) if limit <= ARCHIVED_WEBHOOKS_LIST_MAX_LIMIT => Ok(limit),
+        Some(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`limit` must be a number less than {ARCHIVED_WEBHOOKS_LIST_MAX_LIMIT}"
+            ),
+        }),
+        None => Ok(ARCHIVED_WEBHOOKS_LIST_MAX_LIMIT),
+    }?;
+    let offset = constraints
+        .offset
+        .map(i64::from)
+        .filter(|offset| *offset > 0);
+
+    let archived_webhooks = store
+        .list_archived_webhooks_by_merchant_id_constraints(
+            key_manager_state,
+            &merchant_id,
+            constraints.merchant_connector_id,
+            constraints.status,
+            constraints.created_after,
+            constraints.created_before,
+            Some(limit),
+            offset,
+            &merchant_key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list archived webhooks")?;
+
+    Ok(ApplicationResponse::Json(
+        archived_webhooks
+            .into_iter()
+            .map(get_archived_webhook_response)
+            .collect(),
+    ))
+}
+
+/// Re-runs an archived webhook through the incoming webhook flow, such as after a bug in
+/// processing the webhook has been fixed. The webhook is processed regardless of whether it was
+/// processed successfully earlier.
+#[instrument(skip(state, req_state))]
+pub async fn replay_archived_webhook(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_id: common_utils::id_type::MerchantId,
+    webhook_id: String,
+) -> RouterResponse<archived_webhook_types::ArchivedWebhookResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_key_store = get_merchant_key_store(&state, &merchant_id).await?;
+    let merchant_account = store
+        .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &merchant_key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let archived_webhook = store
+        .find_archived_webhook_by_merchant_id_webhook_id(
+            key_manager_state,
+            &merchant_id,
+            &webhook_id,
+            &merchant_key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Archived webhook `{webhook_id}` not found"),
+        })?;
+
+    let payload: types::ArchivedWebhookPayload = archived_webhook
+        .payload
+        .get_inner()
+        .peek()
+        .parse_struct("ArchivedWebhookPayload")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse archived webhook payload")?;
+    let headers = get_archived_webhook_headers(&payload)?;
+    let body = consts::BASE64_ENGINE
+        .decode(&payload.body)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decode archived webhook body")?;
+    let request_details = IncomingWebhookRequestDetails {
+        method: actix_web::http::Method::from_str(&payload.method)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse archived webhook method")?,
+        uri: actix_web::http::Uri::from_str(&payload.uri)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse archived webhook URI")?,
+        headers: &headers,
+        body: &body,
+        query_params: payload.query_params,
+    };
+
+    let replay_result = Box::pin(incoming::incoming_webhooks_core::<
+        api_models::webhooks::OutgoingWebhook,
+    >(
+        state.clone(),
+        req_state,
+        request_details,
+        merchant_account,
+        merchant_key_store.clone(),
+        &archived_webhook.merchant_connector_id,
+        Some(&archived_webhook),
+    ))
+    .await;
+
+    let status = if replay_result.is_ok() {
+        storage::enums::ArchivedWebhookStatus::Processed
+    } else {
+        storage::enums::ArchivedWebhookStatus::Failed
+    };
+    let archived_webhook = store
+        .update_archived_webhook_by_merchant_id_webhook_id(
+            key_manager_state,
+            &merchant_id,
+            &webhook_id,
+            storage::ArchivedWebhookUpdate::ReplayUpdate {
+                status,
+                replay_count: archived_webhook.replay_count.saturating_add(1),
+                last_replayed_at: common_utils::date_time::now(),
+            },
+            &merchant_key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update archived webhook with outcome of replay")?;
+
+    let (_, webhook_effect, _) = replay_result?;
+    logger::info!(?webhook_effect, "Replayed archived webhook");
+
+    Ok(ApplicationResponse::Json(get_archived_webhook_response(
+        archived_webhook,
+    )))
+}
+
+async fn get_merchant_key_store(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<domain::MerchantKeyStore> {
+    let store = state.store.as_ref();
+    store
+        .get_merchant_key_store_by_merchant_id(
+            &state.into(),
+            merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+}
+
+fn get_archived_webhook_headers(
+    payload: &types::ArchivedWebhookPayload,
+) -> RouterResult<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    for (name, value) in &payload.headers {
+        headers.append(
+            HeaderName::from_str(name)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse archived webhook header name")?,
+            HeaderValue::from_str(value)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse archived webhook header value")?,
+        );
+    }
+    Ok(headers)
+}
+
+fn get_archived_webhook_response(
+    archived_webhook: domain::ArchivedWebhook,
+) -> archived_webhook_types::ArchivedWebhookResponse {
+    archived_webhook_types::ArchivedWebhookResponse {
+        webhook_id: archived_webhook.webhook_id,
+        merchant_id: archived_webhook.merchant_id,
+        profile_id: archived_webhook.profile_id,
+        merchant_connector_id: archived_webhook.merchant_connector_id,
+        connector: archived_webhook.connector_name,
+        deduplication_key: archived_webhook.deduplication_key,
+        event_type: archived_webhook.event_type,
+        status: archived_webhook.status,
+        source_verified: archived_webhook.source_verified,
+        replay_count: u32::try_from(archived_webhook.replay_count).unwrap_or_default(),
+        last_replayed_at: archived_webhook.last_replayed_at,
+        created_at: archived_webhook.created_at,
+    }
+}
@@ -0,0 +1,320 @@
+#[cfg(feature = "email")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "olap")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "olap")]
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+#[cfg(feature = "olap")]
+use super::outgoing;
+#[cfg(feature = "olap")]
+use crate::core::errors::{self, RouterResult, StorageErrorExt};
+use crate::{
+    configs::settings::WebhookEndpointHealthSettings,
+    routes::SessionState,
+    types::{domain, storage},
+};
+#[cfg(feature = "email")]
+use crate::{services::email::types::WebhookEndpointSuspendedNotice, types::api};
+
+/// Records the outcome of a delivery to the webhook endpoint, and suspends deliveries to the
+/// endpoint if it has been failing repeatedly. Failures to record the outcome are only logged, so
+/// that they do not affect the delivery itself.
+#[instrument(skip_all, fields(endpoint_id = %webhook_endpoint.endpoint_id))]
+pub(super) async fn record_delivery_outcome(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: storage::WebhookEndpoint,
+    is_delivered: bool,
+) {
+    let settings = &state.conf.webhooks.endpoint_health;
+
+    // The suspension is decided on the health as updated by this delivery, which accounts for
+    // the outcomes of any concurrent deliveries to the endpoint
+    let webhook_endpoint = match state
+        .store
+        .record_webhook_endpoint_delivery_outcome(
+            &webhook_endpoint.merchant_id,
+            &webhook_endpoint.endpoint_id,
+            is_delivered,
+            i32::from(settings.success_rate_window),
+        )
+        .await
+    {
+        Ok(webhook_endpoint) => webhook_endpoint,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to update delivery health of webhook endpoint"
+            );
+            return;
+        }
+    };
+
+    if webhook_endpoint.suspended_at.is_some() || !should_suspend(settings, &webhook_endpoint) {
+        return;
+    }
+
+    match state
+        .store
+        .suspend_webhook_endpoint_by_merchant_id_endpoint_id(
+            &webhook_endpoint.merchant_id,
+            &webhook_endpoint.endpoint_id,
+            common_utils::date_time::now(),
+        )
+        .await
+    {
+        Ok(Some(webhook_endpoint)) => {
+            logger::warn!(
+                consecutive_failures = webhook_endpoint.consecutive_failures,
+                success_rate = ?get_success_rate(&webhook_endpoint.recent_delivery_outcomes),
+                "Suspended deliveries to failing webhook endpoint"
+            );
+            notify_merchant_of_suspension(state, merchant_key_store, &webhook_endpoint).await;
+        }
+        // Deliveries to the endpoint were suspended by a concurrent delivery
+        Ok(None) => {}
+        Err(error) => {
+            logger::error!(?error, "Failed to suspend deliveries to webhook endpoint");
+        }
+    }
+}
+
+/// Schedules the delivery of the webhooks queued while deliveries to the endpoint were suspended,
+/// by rescheduling the retry tasks of the queued webhooks to run immediately. Returns the number
+/// of webhooks scheduled for delivery.
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(endpoint_id = %webhook_endpoint.endpoint_id))]
+pub(super) async fn schedule_queued_webhooks(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: &storage::WebhookEndpoint,
+) -> RouterResult<usize> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let queued_events = store
+        .list_webhook_endpoint_queued_events_by_endpoint_id(&webhook_endpoint.endpoint_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list queued webhooks of webhook endpoint")?;
+
+    let mut cached_business_profile = None;
+    for queued_event in &queued_events {
+        let process_tracker_id = outgoing::get_outgoing_webhook_retry_process_tracker_id(
+            &queued_event.event_id,
+            &queued_event.merchant_id,
+        );
+        let process_tracker = store
+            .as_scheduler()
+            .find_process_by_id(&process_tracker_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find retry task of queued webhook")?;
+
+        match process_tracker {
+            Some(process_tracker)
+                if process_tracker.business_status
+                    == business_status::WEBHOOK_ENDPOINT_SUSPENDED =>
+            {
+                let now = common_utils::date_time::now();
+                store
+                    .as_scheduler()
+                    .update_process(
+                        process_tracker,
+                        storage::ProcessTrackerUpdate::Update {
+                            name: None,
+                            retry_count: Some(0),
+                            schedule_time: Some(now),
+                            tracking_data: None,
+                            business_status: Some(String::from(business_status::PENDING)),
+                            status: Some(storage::enums::ProcessTrackerStatus::New),
+                            updated_at: Some(now),
+                        },
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to reschedule retry task of queued webhook")?;
+            }
+            // The retry task is not waiting for the endpoint to be re-enabled, and is left as is
+            Some(_) => {}
+            // The retry task could not be created when the webhook was queued in its initial
+            // delivery attempt
+            None => {
+                let business_profile = match cached_business_profile.take() {
+                    Some(business_profile) => business_profile,
+                    None => store
+                        .find_business_profile_by_profile_id(
+                            key_manager_state,
+                            merchant_key_store,
+                            &webhook_endpoint.profile_id,
+                        )
+                        .await
+                        .to_not_found_response(
+                            errors::ApiErrorResponse::BusinessProfileNotFound {
+                                id: webhook_endpoint.profile_id.clone(),
+                            },
+                        )?,
+                };
+                let event = store
+                    .find_event_by_merchant_id_event_id(
+                        key_manager_state,
+                        &queued_event.merchant_id,
+                        &queued_event.event_id,
+                        merchant_key_store,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+                outgoing::add_outgoing_webhook_retry_task_to_process_tracker(
+                    store,
+                    &business_profile,
+                    &event,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to add retry task of queued webhook")?;
+                cached_business_profile.replace(business_profile);
+            }
+        }
+
+        store
+            .delete_webhook_endpoint_queued_event_by_endpoint_id_event_id(
+                &queued_event.endpoint_id,
+                &queued_event.event_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete queued webhook")?;
+    }
+
+    Ok(queued_events.len())
+}
+
+/// Obtains the percentage of the recent deliveries to the endpoint which were successful, if any
+/// deliveries have been made.
+pub(crate) fn get_success_rate(recent_delivery_outcomes: &[bool]) -> Option<u8> {
+    let total = recent_delivery_outcomes.len();
+    let successful = recent_delivery_outcomes
+        .iter()
+        .filter(|is_delivered| **is_delivered)
+        .count();
+
+    successful
+        .saturating_mul(100)
+        .checked_div(total)
+        .and_then(|success_rate| u8::try_from(success_rate).ok())
+}
+
+/// An endpoint is suspended once the number of consecutive failed deliveries reaches the
+/// threshold, or once its success rate over an entire window of deliveries drops below the
+/// minimum success rate.
+fn should_suspend(
+    settings: &WebhookEndpointHealthSettings,
+    webhook_endpoint: &storage::WebhookEndpoint,
+) -> bool {
+    if !settings.auto_suspend_enabled {
+        return false;
+    }
+
+    let consecutive_failures = u32::try_from(webhook_endpoint.consecutive_failures).unwrap_or(0);
+    let is_window_full = webhook_endpoint.recent_delivery_outcomes.len()
+        >= usize::from(settings.success_rate_window);
+
+    consecutive_failures >= settings.consecutive_failures_threshold
+        || (is_window_full
+            && get_success_rate(&webhook_endpoint.recent_delivery_outcomes)
+                .is_some_and(|success_rate| success_rate < settings.min_success_rate_percent))
+}
+
+#[cfg(feature = "email")]
+async fn notify_merchant_of_suspension(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: &storage::WebhookEndpoint,
+) {
+    let merchant_account = match state
+        .store
+        .find_merchant_account_by_merchant_id(
+            &state.into(),
+            &webhook_endpoint.merchant_id,
+            merchant_key_store,
+        )
+        .await
+    {
+        Ok(merchant_account) => merchant_account,
+        Err(error) => {
+            logger::error!(?error, "Failed to find merchant account to notify");
+            return;
+        }
+    };
+
+    let Some(email_id) = merchant_account
+        .merchant_details
+        .and_then(|merchant_details| {
+            merchant_details
+                .parse_value::<api::MerchantDetails>("MerchantDetails")
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to parse merchant details");
+                })
+                .ok()
+        })
+        .and_then(|merchant_details| merchant_details.primary_email)
+    else {
+        logger::warn!("Merchant has no primary email to notify of webhook endpoint suspension");
+        return;
+    };
+
+    let recipient_email = match domain::UserEmail::from_pii_email(email_id) {
+        Ok(recipient_email) => recipient_email,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to convert recipient's email to UserEmail from pii::Email"
+            );
+            return;
+        }
+    };
+
+    let email_contents = WebhookEndpointSuspendedNotice {
+        recipient_email,
+        subject: "Webhook Endpoint Suspended",
+        endpoint_id: webhook_endpoint.endpoint_id.clone(),
+        endpoint_url: webhook_endpoint.url.clone(),
+    };
+
+    let _ = state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to notify merchant of webhook endpoint suspension"
+            );
+        });
+}
+
+#[cfg(not(feature = "email"))]
+async fn notify_merchant_of_suspension(
+    _state: &SessionState,
+    _merchant_key_store: &domain::MerchantKeyStore,
+    _webhook_endpoint: &storage::WebhookEndpoint,
+) {
+    logger::warn!("Email is not enabled, not notifying merchant of webhook endpoint suspension");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_success_rate() {
+        assert_eq!(get_success_rate(&[]), None);
+        assert_eq!(get_success_rate(&[true, false, false, false]), Some(25));
+        assert_eq!(get_success_rate(&[true, true]), Some(100));
+    }
+}
@@ -7,14 +7,22 @@ use api_models::{
     payments::HeaderPayload,
     webhooks::{self, WebhookResponseTracker},
 };
-use common_utils::{errors::ReportSwitchExt, events::ApiEventsType};
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    errors::ReportSwitchExt,
+    events::ApiEventsType,
+    ext_traits::Encode,
+    type_name,
+    types::keymanager::Identifier,
+};
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::{
     router_request_types::VerifyWebhookSourceRequestData,
     router_response_types::{VerifyWebhookSourceResponseData, VerifyWebhookStatus},
+    type_encryption::{crypto_operation, CryptoOperation},
 };
 use hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails;
-use masking::ExposeInterface;
+use masking::{ExposeInterface, PeekInterface, Secret};
 use router_env::{instrument, metrics::add_attributes, tracing, tracing_actix_web::RequestId};
 
 use super::{types, utils, MERCHANT_ID};
@@ -63,15 +71,22 @@ pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
     body: actix_web::web::Bytes,
 ) -> RouterResponse<serde_json::Value> {
     let start_instant = Instant::now();
+    let request_details = IncomingWebhookRequestDetails {
+        method: req.method().clone(),
+        uri: req.uri().clone(),
+        headers: req.headers(),
+        query_params: req.query_string().to_string(),
+        body: &body,
+    };
     let (application_response, webhooks_response_tracker, serialized_req) =
         Box::pin(incoming_webhooks_core::<W>(
             state.clone(),
             req_state,
-            req,
+            request_details,
             merchant_account.clone(),
             key_store,
             connector_name_or_mca_id,
-            body.clone(),
+            None,
         ))
         .await?;
 
@@ -116,15 +131,17 @@ pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
     Ok(application_response)
 }
 
+/// Processes an incoming webhook received from the connector, or an archived webhook being
+/// replayed. Archived webhooks are not deduplicated or archived again.
 #[instrument(skip_all)]
-async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
+pub(super) async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     state: SessionState,
     req_state: ReqState,
-    req: &actix_web::HttpRequest,
+    request_details: IncomingWebhookRequestDetails<'_>,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     connector_name_or_mca_id: &str,
-    body: actix_web::web::Bytes,
+    replayed_webhook: Option<&domain::ArchivedWebhook>,
 ) -> errors::RouterResult<(
     services::ApplicationResponse<serde_json::Value>,
     WebhookResponseTracker,
@@ -140,13 +157,8 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
             merchant_account.get_id().get_string_repr().to_owned(),
         )],
     );
-    let mut request_details = IncomingWebhookRequestDetails {
-        method: req.method().clone(),
-        uri: req.uri().clone(),
-        headers: req.headers(),
-        query_params: req.query_string().to_string(),
-        body: &body,
-    };
+    let raw_body = request_details.body;
+    let mut request_details = request_details;
 
     // Fetch the merchant connector account to get the webhooks source secret
     // `webhooks source secret` is a secret shared between the merchant and connector
@@ -270,7 +282,11 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
             }
         };
 
-        let source_verified = if connectors_with_source_verification_call
+        let source_verified = if let Some(replayed_webhook) = replayed_webhook {
+            // The source of an archived webhook may no longer be verifiable, such as when its
+            // signature has expired, and the outcome of verifying it when received is used instead
+            replayed_webhook.source_verified
+        } else if connectors_with_source_verification_call
             .connectors_with_webhook_source_verification_call
             .contains(&connector_enum)
         {
@@ -331,144 +347,243 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
 
         logger::info!(source_verified=?source_verified);
 
-        event_object = connector
-            .get_webhook_resource_object(&request_details)
-            .switch()
-            .attach_printable("Could not find resource object in incoming webhook body")?;
-
-        let webhook_details = api::IncomingWebhookDetails {
-            object_reference_id: object_ref_id.clone(),
-            resource_object: serde_json::to_vec(&event_object)
-                .change_context(errors::ParsingError::EncodeError("byte-vec"))
-                .attach_printable("Unable to convert webhook payload to a value")
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable(
-                    "There was an issue when encoding the incoming webhook body to bytes",
-                )?,
-        };
+        let deduplication_key =
+            if replayed_webhook.is_none() && state.conf.webhooks.incoming_archive.enabled {
+                Some(get_webhook_deduplication_key(&connector, &request_details)?)
+            } else {
+                None
+            };
 
-        let profile_id = merchant_connector_account.profile_id.as_ref();
+        // Only source-verified webhooks are deduplicated, so that webhooks with forged sources
+        // cannot cause genuine webhooks resent by the connector to be ignored
+        let deduplication_lock_key = match deduplication_key.as_deref() {
+            Some(deduplication_key) if source_verified => {
+                let deduplication_lock_key = get_webhook_deduplication_lock_key(
+                    merchant_account.get_id(),
+                    &merchant_connector_account.get_id(),
+                    deduplication_key,
+                );
 
-        let business_profile = state
-            .store
-            .find_business_profile_by_profile_id(key_manager_state, &key_store, profile_id)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
-                id: profile_id.to_string(),
-            })?;
+                if !claim_webhook_deduplication_key(&state, &deduplication_lock_key).await {
+                    logger::info!(%deduplication_key, "Ignoring duplicate incoming webhook");
 
-        match flow_type {
-            api::WebhookFlow::Payment => Box::pin(payments_incoming_webhook_flow(
-                state.clone(),
-                req_state,
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for payments failed")?,
+                    metrics::WEBHOOK_INCOMING_DUPLICATE_COUNT.add(
+                        &metrics::CONTEXT,
+                        1,
+                        &[
+                            metrics::KeyValue::new(MERCHANT_ID, merchant_account.get_id().clone()),
+                            metrics::KeyValue::new("connector", connector_name.to_string()),
+                        ],
+                    );
 
-            api::WebhookFlow::Refund => Box::pin(refunds_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                connector_name.as_str(),
-                source_verified,
-                event_type,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for refunds failed")?,
+                    let response = connector
+                        .get_webhook_api_response(&request_details)
+                        .switch()
+                        .attach_printable(
+                            "Failed while early return in case of duplicate webhook",
+                        )?;
+
+                    return Ok((
+                        response,
+                        WebhookResponseTracker::NoEffect,
+                        serde_json::Value::Null,
+                    ));
+                }
 
-            api::WebhookFlow::Dispute => Box::pin(disputes_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
-                &connector,
-                &request_details,
-                event_type,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for disputes failed")?,
+                Some(deduplication_lock_key)
+            }
+            _ => None,
+        };
 
-            api::WebhookFlow::BankTransfer => Box::pin(bank_transfer_webhook_flow(
-                state.clone(),
-                req_state,
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
+        let archived_webhook = match deduplication_key {
+            Some(deduplication_key) => archive_incoming_webhook(
+                &state,
+                &key_store,
+                &merchant_connector_account,
+                &connector_name,
+                deduplication_key,
+                event_type,
                 source_verified,
-            ))
+                types::ArchivedWebhookPayload::new(&request_details, raw_body),
+            )
             .await
-            .attach_printable("Incoming bank-transfer webhook flow failed")?,
+            // The key store is moved into the webhook flows, and is required to update the
+            // archived webhook with the outcome of the flow
+            .map(|archived_webhook| (archived_webhook, key_store.clone())),
+            None => None,
+        };
 
-            api::WebhookFlow::ReturnResponse => WebhookResponseTracker::NoEffect,
+        let webhook_effect: errors::RouterResult<WebhookResponseTracker> = async {
+            event_object = connector
+                .get_webhook_resource_object(&request_details)
+                .switch()
+                .attach_printable("Could not find resource object in incoming webhook body")?;
+
+            let webhook_details = api::IncomingWebhookDetails {
+                object_reference_id: object_ref_id.clone(),
+                resource_object: serde_json::to_vec(&event_object)
+                    .change_context(errors::ParsingError::EncodeError("byte-vec"))
+                    .attach_printable("Unable to convert webhook payload to a value")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "There was an issue when encoding the incoming webhook body to bytes",
+                    )?,
+            };
 
-            api::WebhookFlow::Mandate => Box::pin(mandates_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
-                event_type,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for mandates failed")?,
+            let profile_id = merchant_connector_account.profile_id.as_ref();
+
+            let business_profile = state
+                .store
+                .find_business_profile_by_profile_id(key_manager_state, &key_store, profile_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                    id: profile_id.to_string(),
+                })?;
 
-            api::WebhookFlow::ExternalAuthentication => {
-                Box::pin(external_authentication_incoming_webhook_flow(
+            Ok(match flow_type {
+                api::WebhookFlow::Payment => Box::pin(payments_incoming_webhook_flow(
                     state.clone(),
                     req_state,
                     merchant_account,
+                    business_profile,
                     key_store,
+                    webhook_details,
+                    source_verified,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for payments failed")?,
+
+                api::WebhookFlow::Refund => Box::pin(refunds_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    connector_name.as_str(),
                     source_verified,
                     event_type,
-                    &request_details,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for refunds failed")?,
+
+                api::WebhookFlow::Dispute => Box::pin(disputes_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verified,
                     &connector,
+                    &request_details,
+                    event_type,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for disputes failed")?,
+
+                api::WebhookFlow::BankTransfer => Box::pin(bank_transfer_webhook_flow(
+                    state.clone(),
+                    req_state,
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verified,
+                ))
+                .await
+                .attach_printable("Incoming bank-transfer webhook flow failed")?,
+
+                api::WebhookFlow::ReturnResponse => WebhookResponseTracker::NoEffect,
+
+                api::WebhookFlow::Mandate => Box::pin(mandates_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verified,
+                    event_type,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for mandates failed")?,
+
+                api::WebhookFlow::ExternalAuthentication => {
+                    Box::pin(external_authentication_incoming_webhook_flow(
+                        state.clone(),
+                        req_state,
+                        merchant_account,
+                        key_store,
+                        source_verified,
+                        event_type,
+                        &request_details,
+                        &connector,
+                        object_ref_id,
+                        business_profile,
+                        merchant_connector_account,
+                    ))
+                    .await
+                    .attach_printable("Incoming webhook flow for external authentication failed")?
+                }
+                api::WebhookFlow::FraudCheck => Box::pin(frm_incoming_webhook_flow(
+                    state.clone(),
+                    req_state,
+                    merchant_account,
+                    key_store,
+                    source_verified,
+                    event_type,
                     object_ref_id,
                     business_profile,
-                    merchant_connector_account,
                 ))
                 .await
-                .attach_printable("Incoming webhook flow for external authentication failed")?
-            }
-            api::WebhookFlow::FraudCheck => Box::pin(frm_incoming_webhook_flow(
-                state.clone(),
-                req_state,
-                merchant_account,
-                key_store,
-                source_verified,
-                event_type,
-                object_ref_id,
-                business_profile,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for fraud check failed")?,
+                .attach_printable("Incoming webhook flow for fraud check failed")?,
 
-            #[cfg(feature = "payouts")]
-            api::WebhookFlow::Payout => Box::pin(payouts_incoming_webhook_flow(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                event_type,
-                source_verified,
-            ))
-            .await
-            .attach_printable("Incoming webhook flow for payouts failed")?,
+                #[cfg(feature = "payouts")]
+                api::WebhookFlow::Payout => Box::pin(payouts_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    event_type,
+                    source_verified,
+                ))
+                .await
+                .attach_printable("Incoming webhook flow for payouts failed")?,
+
+                _ => Err(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
+            })
+        }
+        .await;
+
+        if let Some(deduplication_lock_key) = deduplication_lock_key
+            .as_deref()
+            .filter(|_| webhook_effect.is_err())
+        {
+            release_webhook_deduplication_key(&state, deduplication_lock_key).await;
+        }
 
-            _ => Err(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
+        if let Some((archived_webhook, key_store)) = archived_webhook {
+            let status = if webhook_effect.is_ok() {
+                enums::ArchivedWebhookStatus::Processed
+            } else {
+                enums::ArchivedWebhookStatus::Failed
+            };
+            let _ = state
+                .store
+                .update_archived_webhook_by_merchant_id_webhook_id(
+                    key_manager_state,
+                    &archived_webhook.merchant_id,
+                    &archived_webhook.webhook_id,
+                    storage::ArchivedWebhookUpdate::StatusUpdate { status },
+                    &key_store,
+                )
+                .await
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to update status of archived webhook");
+                });
         }
+
+        webhook_effect?
     } else {
         metrics::WEBHOOK_INCOMING_FILTERED_COUNT.add(
             &metrics::CONTEXT,
@@ -553,6 +668,7 @@ async fn payments_incoming_webhook_flow(
                     merchant_connector_details: None,
                     client_secret: None,
                     expand_attempts: None,
+                    expose_routing_decision_trace: false,
                     expand_captures: None,
                 },
                 services::AuthFlow::Merchant,
@@ -1714,3 +1830,187 @@ async fn fetch_optional_mca_and_connector(
         Ok((None, connector, connector_name))
     }
 }
+
+/// Obtains the key incoming webhooks are deduplicated by: the event ID assigned by the connector,
+/// or a hash of the decoded webhook body for connectors which do not assign event IDs.
+fn get_webhook_deduplication_key(
+    connector: &ConnectorEnum,
+    request_details: &IncomingWebhookRequestDetails<'_>,
+) -> errors::RouterResult<String> {
+    match connector.get_webhook_event_id(request_details) {
+        Ok(Some(event_id)) => return Ok(format!("event_id:{event_id}")),
+        Ok(None) => {}
+        Err(error) => {
+            logger::warn!(
+                ?error,
+                "Failed to obtain event ID of incoming webhook, deduplicating by body hash instead"
+            );
+        }
+    }
+
+    let body_hash = crypto::Sha256
+        .generate_digest(request_details.body)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash incoming webhook body")?;
+
+    Ok(format!("body_sha256:{}", hex::encode(body_hash)))
+}
+
+fn get_webhook_deduplication_lock_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    merchant_connector_id: &str,
+    deduplication_key: &str,
+) -> String {
+    format!(
+        "WH_DEDUP_{}_{merchant_connector_id}_{deduplication_key}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Claims the deduplication key of the webhook for the deduplication window, and returns whether
+/// the key was claimed, which is not the case if an identical webhook has claimed it already.
+/// Failures to claim the key are only logged, so that the webhook is processed in that case.
+async fn claim_webhook_deduplication_key(state: &SessionState, lock_key: &str) -> bool {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection to deduplicate webhook"
+            );
+            return true;
+        }
+    };
+
+    match redis_conn
+        .set_key_if_not_exists_with_expiry(
+            lock_key,
+            state.get_request_id(),
+            Some(i64::from(
+                state
+                    .conf
+                    .webhooks
+                    .incoming_archive
+                    .deduplication_window_in_secs,
+            )),
+        )
+        .await
+    {
+        Ok(redis_interface::SetnxReply::KeySet) => true,
+        Ok(redis_interface::SetnxReply::KeyNotSet) => false,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to claim deduplication key of incoming webhook"
+            );
+            true
+        }
+    }
+}
+
+/// Releases the deduplication key claimed by a webhook which failed to be processed, so that the
+/// webhook is processed when resent by the connector.
+async fn release_webhook_deduplication_key(state: &SessionState, lock_key: &str) {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection to release deduplication key"
+            );
+            return;
+        }
+    };
+
+    let _ = redis_conn.delete_key(lock_key).await.inspect_err(|error| {
+        logger::error!(
+            ?error,
+            "Failed to release deduplication key of incoming webhook"
+        );
+    });
+}
+
+/// Archives the incoming webhook, with its request encrypted, and ensures that archived webhooks of
+/// the merchant are purged once they exceed the retention period. Failures to archive the webhook
+/// are only logged, so that they do not affect processing the webhook.
+#[allow(clippy::too_many_arguments)]
+async fn archive_incoming_webhook(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    connector_name: &str,
+    deduplication_key: String,
+    event_type: webhooks::IncomingWebhookEvent,
+    source_verified: bool,
+    payload: types::ArchivedWebhookPayload,
+) -> Option<domain::ArchivedWebhook> {
+    let key_manager_state = &state.into();
+
+    let payload = match payload.encode_to_string_of_json() {
+        Ok(payload) => payload,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to encode incoming webhook payload to archive"
+            );
+            return None;
+        }
+    };
+    let payload = match crypto_operation(
+        key_manager_state,
+        type_name!(domain::ArchivedWebhook),
+        CryptoOperation::Encrypt(Secret::new(payload)),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    {
+        Ok(payload) => payload,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to encrypt incoming webhook payload to archive"
+            );
+            return None;
+        }
+    };
+
+    let now = common_utils::date_time::now();
+    let archived_webhook = domain::ArchivedWebhook {
+        webhook_id: generate_id(consts::ID_LENGTH, "whin"),
+        merchant_id: key_store.merchant_id.clone(),
+        profile_id: merchant_connector_account.profile_id.clone(),
+        merchant_connector_id: merchant_connector_account.get_id(),
+        connector_name: connector_name.to_string(),
+        deduplication_key,
+        event_type: event_type.to_string(),
+        status: enums::ArchivedWebhookStatus::Received,
+        payload,
+        replay_count: 0,
+        last_replayed_at: None,
+        created_at: now,
+        modified_at: now,
+        source_verified,
+    };
+
+    let archived_webhook = state
+        .store
+        .insert_archived_webhook(key_manager_state, archived_webhook, key_store)
+        .await
+        .inspect_err(|error| {
+            logger::error!(?error, "Failed to archive incoming webhook");
+        })
+        .ok()?;
+
+    let _ = super::archive_retention::add_archived_webhook_purge_task_if_absent(
+        state.store.as_ref(),
+        &archived_webhook.merchant_id,
+    )
+    .await
+    .inspect_err(|error| {
+        logger::error!(?error, "Failed to schedule purging of archived webhooks");
+    });
+
+    Some(archived_webhook)
+}
@@ -20,13 +20,13 @@ use router_env::{
     tracing::{self, Instrument},
 };
 
-use super::{types, utils, MERCHANT_ID};
+use super::{endpoint_health, types, utils, MERCHANT_ID};
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
     core::{
-        errors::{self, CustomResult},
-        metrics,
+        errors::{self, CustomResult, RouterResult},
+        metrics, payment_methods,
     },
     db::StorageInterface,
     events::outgoing_webhook_logs::{
@@ -47,6 +47,8 @@ use crate::{
 
 const OUTGOING_WEBHOOK_TIMEOUT_SECS: u64 = 5;
 
+const OUTGOING_WEBHOOK_RETRY_TASK: &str = "OUTGOING_WEBHOOK_RETRY";
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 pub(crate) async fn create_event_and_trigger_outgoing_webhook(
@@ -61,24 +63,183 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
+    if !state.conf.webhooks.outgoing_enabled {
+        logger::debug!(
+            business_profile_id=%business_profile.profile_id,
+            %primary_object_id,
+            "Outgoing webhooks are disabled in application configuration; skipping outgoing \
+             webhooks for event"
+        );
+        return Ok(());
+    }
 
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+    // The webhook URL configured in the business profile is represented by `None`, and each
+    // webhook endpoint subscribed to the event type by `Some`
+    let is_business_profile_webhook_url_configured =
+        get_webhook_url_from_business_profile(&business_profile)
+            .is_ok_and(|webhook_url| !webhook_url.is_empty());
+    let webhook_endpoints =
+        get_webhook_endpoints_for_event(&state, &business_profile, merchant_key_store, event_type)
+            .await;
+    let destinations = is_business_profile_webhook_url_configured
+        .then_some(None)
+        .into_iter()
+        .chain(webhook_endpoints.into_iter().map(Some))
+        .collect::<Vec<_>>();
+
+    if destinations.is_empty() {
         logger::debug!(
             business_profile_id=%business_profile.profile_id,
-            %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            %primary_object_id,
+            "Merchant webhook URL could not be obtained and no webhook endpoints are subscribed \
+             to the event type; skipping outgoing webhooks for event"
         );
         return Ok(());
     }
 
+    // A failure to deliver to one destination must not prevent delivery to the others, so the
+    // first error is returned only after all destinations have been attempted
+    let mut result = Ok(());
+    for webhook_endpoint in destinations {
+        let webhook_endpoint_id = webhook_endpoint
+            .as_ref()
+            .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone());
+        let delivery_result = Box::pin(create_event_and_trigger_outgoing_webhook_for_destination(
+            state.clone(),
+            &merchant_account,
+            business_profile.clone(),
+            merchant_key_store,
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            webhook_endpoint,
+        ))
+        .await;
+
+        if let Err(error) = delivery_result {
+            logger::error!(
+                ?error,
+                ?webhook_endpoint_id,
+                "Failed to create event and trigger outgoing webhook"
+            );
+            result = result.and(Err(error));
+        }
+    }
+
+    result
+}
+
+/// Obtains the enabled webhook endpoints of the business profile which are subscribed to the
+/// event type. An endpoint with no enabled events is subscribed to all event types.
+///
+/// Failures are logged and not propagated, so that they do not prevent delivery to the webhook
+/// URL configured in the business profile.
+async fn get_webhook_endpoints_for_event(
+    state: &SessionState,
+    business_profile: &domain::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    event_type: enums::EventType,
+) -> Vec<types::WebhookEndpointDetails> {
+    let webhook_endpoints = match state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(
+            &business_profile.merchant_id,
+            &business_profile.profile_id,
+        )
+        .await
+    {
+        Ok(webhook_endpoints) => webhook_endpoints,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to list webhook endpoints of business profile"
+            );
+            return Vec::new();
+        }
+    };
+
+    let mut subscribed_webhook_endpoints = Vec::new();
+    for webhook_endpoint in webhook_endpoints.into_iter().filter(|webhook_endpoint| {
+        webhook_endpoint.enabled
+            && (webhook_endpoint.enabled_events.is_empty()
+                || webhook_endpoint.enabled_events.contains(&event_type))
+    }) {
+        let endpoint_id = webhook_endpoint.endpoint_id.clone();
+        match get_webhook_endpoint_details(state, merchant_key_store, webhook_endpoint).await {
+            Ok(webhook_endpoint_details) => {
+                subscribed_webhook_endpoints.push(webhook_endpoint_details)
+            }
+            Err(error) => logger::error!(
+                ?error,
+                %endpoint_id,
+                "Failed to decrypt webhook endpoint details; skipping webhook endpoint"
+            ),
+        }
+    }
+
+    subscribed_webhook_endpoints
+}
+
+pub(crate) async fn get_webhook_endpoint_details(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: storage::WebhookEndpoint,
+) -> RouterResult<types::WebhookEndpointDetails> {
+    let secret = payment_methods::cards::decrypt_generic_data::<Secret<String>>(
+        state,
+        Some(webhook_endpoint.secret),
+        merchant_key_store,
+    )
+    .await?
+    .get_required_value("secret")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt webhook endpoint secret")?;
+
+    let custom_http_headers =
+        payment_methods::cards::decrypt_generic_data::<HashMap<String, Secret<String>>>(
+            state,
+            webhook_endpoint.custom_http_headers,
+            merchant_key_store,
+        )
+        .await
+        .attach_printable("Failed to decrypt webhook endpoint custom HTTP headers")?;
+
+    Ok(types::WebhookEndpointDetails {
+        endpoint_id: webhook_endpoint.endpoint_id,
+        secret,
+        custom_http_headers,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_outgoing_webhook_for_destination(
+    state: SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: domain::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    webhook_endpoint: Option<types::WebhookEndpointDetails>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let webhook_endpoint_id = webhook_endpoint
+        .as_ref()
+        .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone());
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+    let idempotent_event_id = utils::get_idempotent_event_id(
+        &primary_object_id,
+        event_type,
+        delivery_attempt,
+        webhook_endpoint_id.as_deref(),
+    );
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -91,10 +252,14 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&merchant_account, outgoing_webhook, &business_profile)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+    let request_content = get_outgoing_webhook_request(
+        merchant_account,
+        outgoing_webhook,
+        &business_profile,
+        webhook_endpoint.as_ref(),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from((&content, &primary_object_id));
     let key_manager_state = &(&state).into();
@@ -133,6 +298,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         response: None,
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
+        webhook_endpoint_id,
     };
 
     let event_insert_result = state
@@ -243,11 +409,11 @@ async fn trigger_webhook_to_merchant(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+    let (webhook_url, webhook_endpoint) = match (
+        get_webhook_destination(&state, &business_profile, &event).await,
         process_tracker.clone(),
     ) {
-        (Ok(webhook_url), _) => Ok(webhook_url),
+        (Ok(webhook_destination), _) => Ok(webhook_destination),
         (Err(error), Some(process_tracker)) => {
             if !error
                 .current_context()
@@ -268,6 +434,29 @@ async fn trigger_webhook_to_merchant(
         (Err(error), None) => Err(error),
     }?;
 
+    if let Some(webhook_endpoint) = webhook_endpoint
+        .as_ref()
+        .filter(|webhook_endpoint| webhook_endpoint.suspended_at.is_some())
+    {
+        return queue_webhook_for_suspended_endpoint(
+            &state,
+            webhook_endpoint,
+            &event,
+            delivery_attempt,
+            process_tracker,
+        )
+        .await;
+    }
+
+    let request_content = refresh_webhook_signature(
+        &state,
+        &business_profile,
+        merchant_key_store,
+        webhook_endpoint.clone(),
+        request_content,
+    )
+    .await?;
+
     let event_id = event.event_id;
 
     let headers = request_content
@@ -300,6 +489,19 @@ async fn trigger_webhook_to_merchant(
     );
     logger::debug!(outgoing_webhook_response=?response);
 
+    if let Some(webhook_endpoint) = webhook_endpoint {
+        let is_delivered = response
+            .as_ref()
+            .is_ok_and(|response| response.status().is_success());
+        endpoint_health::record_delivery_outcome(
+            &state,
+            merchant_key_store,
+            webhook_endpoint,
+            is_delivered,
+        )
+        .await;
+    }
+
     match delivery_attempt {
         enums::WebhookDeliveryAttempt::InitialAttempt => match response {
             Err(client_error) => {
@@ -542,11 +744,9 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     };
 
     let runner = storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow;
-    let task = "OUTGOING_WEBHOOK_RETRY";
+    let task = OUTGOING_WEBHOOK_RETRY_TASK;
     let tag = ["OUTGOING_WEBHOOKS"];
-    let process_tracker_id = scheduler::utils::get_process_tracker_id(
-        runner,
-        task,
+    let process_tracker_id = get_outgoing_webhook_retry_process_tracker_id(
         &event.event_id,
         &business_profile.merchant_id,
     );
@@ -580,6 +780,162 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     }
 }
 
+pub(crate) fn get_outgoing_webhook_retry_process_tracker_id(
+    initial_attempt_id: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+        OUTGOING_WEBHOOK_RETRY_TASK,
+        initial_attempt_id,
+        merchant_id,
+    )
+}
+
+/// Obtains the URL to which the event must be delivered: the URL of the webhook endpoint the event
+/// was created for, or the webhook URL configured in the business profile otherwise.
+async fn get_webhook_destination(
+    state: &SessionState,
+    business_profile: &domain::BusinessProfile,
+    event: &domain::Event,
+) -> CustomResult<(String, Option<storage::WebhookEndpoint>), errors::WebhooksFlowError> {
+    let Some(webhook_endpoint_id) = &event.webhook_endpoint_id else {
+        return get_webhook_url_from_business_profile(business_profile)
+            .map(|webhook_url| (webhook_url, None));
+    };
+
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_merchant_id_endpoint_id(
+            &business_profile.merchant_id,
+            webhook_endpoint_id,
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::WebhooksFlowError::WebhookEndpointNotFound)
+            } else {
+                error.change_context(errors::WebhooksFlowError::WebhookEndpointFetchFailed)
+            }
+        })?;
+
+    if webhook_endpoint.enabled {
+        Ok((webhook_endpoint.url.clone(), Some(webhook_endpoint)))
+    } else {
+        Err(report!(errors::WebhooksFlowError::WebhookEndpointDisabled))
+    }
+}
+
+/// Deliveries to suspended endpoints are not attempted. Initial attempts and automatic retries are
+/// instead queued, and are retried once the endpoint is re-enabled, while manual retries fail.
+async fn queue_webhook_for_suspended_endpoint(
+    state: &SessionState,
+    webhook_endpoint: &storage::WebhookEndpoint,
+    event: &domain::Event,
+    delivery_attempt: enums::WebhookDeliveryAttempt,
+    process_tracker: Option<storage::ProcessTracker>,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    match delivery_attempt {
+        enums::WebhookDeliveryAttempt::InitialAttempt
+        | enums::WebhookDeliveryAttempt::AutomaticRetry => {
+            let queued_event = storage::WebhookEndpointQueuedEventNew {
+                endpoint_id: webhook_endpoint.endpoint_id.clone(),
+                event_id: event
+                    .initial_attempt_id
+                    .clone()
+                    .unwrap_or_else(|| event.event_id.clone()),
+                merchant_id: webhook_endpoint.merchant_id.clone(),
+                created_at: common_utils::date_time::now(),
+            };
+
+            match state
+                .store
+                .insert_webhook_endpoint_queued_event(queued_event)
+                .await
+            {
+                Ok(_) => {}
+                // The event may have been queued already by an earlier delivery attempt
+                Err(error) if error.current_context().is_db_unique_violation() => {}
+                Err(error) => {
+                    return Err(
+                        error.change_context(errors::WebhooksFlowError::WebhookQueueingFailed)
+                    )
+                }
+            }
+
+            if let Some(process_tracker) = process_tracker {
+                state
+                    .store
+                    .as_scheduler()
+                    .finish_process_with_business_status(
+                        process_tracker,
+                        business_status::WEBHOOK_ENDPOINT_SUSPENDED,
+                    )
+                    .await
+                    .change_context(
+                        errors::WebhooksFlowError::OutgoingWebhookProcessTrackerTaskUpdateFailed,
+                    )?;
+            }
+        }
+        enums::WebhookDeliveryAttempt::ManualRetry => {}
+    }
+
+    Err(report!(errors::WebhooksFlowError::WebhookEndpointSuspended))
+}
+
+/// Signatures of the `v1` scheme include the time at which they were generated, and are hence
+/// generated again using the currently active keys on every delivery attempt, so that retried
+/// webhooks are not rejected by receivers as replayed ones.
+async fn refresh_webhook_signature(
+    state: &SessionState,
+    business_profile: &domain::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: Option<storage::WebhookEndpoint>,
+    mut request_content: OutgoingWebhookRequestContent,
+) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
+    let signature_scheme = enums::WebhookSignatureScheme::V1;
+    let signature_header = types::get_webhook_signature_header(signature_scheme);
+    if !request_content
+        .headers
+        .iter()
+        .any(|(name, _)| name == signature_header)
+    {
+        return Ok(request_content);
+    }
+
+    let signing_keys = match webhook_endpoint {
+        Some(webhook_endpoint) => {
+            let webhook_endpoint_details =
+                get_webhook_endpoint_details(state, merchant_key_store, webhook_endpoint)
+                    .await
+                    .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)?;
+            types::WebhookSigningKeys {
+                current: Some(webhook_endpoint_details.secret.expose()),
+                previous: None,
+            }
+        }
+        None => types::WebhookSigningKeys::from_business_profile(business_profile),
+    };
+
+    let signature = types::sign_webhook_payload(
+        request_content.body.peek(),
+        &signing_keys,
+        signature_scheme,
+        common_utils::date_time::now_unix_timestamp(),
+    )?;
+
+    request_content
+        .headers
+        .retain(|(name, _)| name != signature_header);
+    if let Some(signature) = signature {
+        request_content
+            .headers
+            .push((signature_header.to_string(), Secret::new(signature)));
+    }
+
+    Ok(request_content)
+}
+
 fn get_webhook_url_from_business_profile(
     business_profile: &domain::BusinessProfile,
 ) -> CustomResult<String, errors::WebhooksFlowError> {
@@ -600,11 +956,13 @@ pub(crate) fn get_outgoing_webhook_request(
     merchant_account: &domain::MerchantAccount,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::BusinessProfile,
+    webhook_endpoint: Option<&types::WebhookEndpointDetails>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::BusinessProfile,
+        webhook_endpoint: Option<&types::WebhookEndpointDetails>,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![(
             reqwest::header::CONTENT_TYPE.to_string(),
@@ -612,30 +970,54 @@ pub(crate) fn get_outgoing_webhook_request(
         )];
 
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
-        let payment_response_hash_key = business_profile.payment_response_hash_key.clone();
-        let custom_headers = business_profile
-            .outgoing_webhook_custom_http_headers
-            .clone()
-            .map(|headers| {
-                headers
-                    .into_inner()
-                    .expose()
-                    .parse_value::<HashMap<String, String>>("HashMap<String,String>")
-                    .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
-                    .attach_printable("Failed to deserialize outgoing webhook custom HTTP headers")
-            })
-            .transpose()?;
+        // Webhook endpoints are signed using their own secret and carry their own custom headers
+        let (signing_keys, custom_headers) = match webhook_endpoint {
+            Some(webhook_endpoint) => (
+                types::WebhookSigningKeys {
+                    current: Some(webhook_endpoint.secret.clone().expose()),
+                    previous: None,
+                },
+                webhook_endpoint.custom_http_headers.clone().map(|headers| {
+                    headers
+                        .into_iter()
+                        .map(|(name, value)| (name, value.expose()))
+                        .collect::<HashMap<_, _>>()
+                }),
+            ),
+            None => (
+                types::WebhookSigningKeys::from_business_profile(business_profile),
+                business_profile
+                    .outgoing_webhook_custom_http_headers
+                    .clone()
+                    .map(|headers| {
+                        headers
+                            .into_inner()
+                            .expose()
+                            .parse_value::<HashMap<String, String>>("HashMap<String,String>")
+                            .change_context(
+                                errors::WebhooksFlowError::OutgoingWebhookEncodingFailed,
+                            )
+                            .attach_printable(
+                                "Failed to deserialize outgoing webhook custom HTTP headers",
+                            )
+                    })
+                    .transpose()?,
+            ),
+        };
         if let Some(ref map) = custom_headers {
             headers.extend(
                 map.iter()
                     .map(|(key, value)| (key.clone(), value.clone().into_masked())),
             );
         };
+        let signature_scheme = business_profile
+            .webhook_signature_scheme
+            .unwrap_or_default();
         let outgoing_webhooks_signature = transformed_outgoing_webhook
-            .get_outgoing_webhooks_signature(payment_response_hash_key)?;
+            .get_outgoing_webhooks_signature(&signing_keys, signature_scheme)?;
 
         if let Some(signature) = outgoing_webhooks_signature.signature {
-            WebhookType::add_webhook_header(&mut headers, signature)
+            WebhookType::add_webhook_header(&mut headers, signature, signature_scheme)
         }
 
         Ok(OutgoingWebhookRequestContent {
@@ -649,12 +1031,17 @@ pub(crate) fn get_outgoing_webhook_request(
 
     match merchant_account.get_compatible_connector() {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                webhook_endpoint,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            webhook_endpoint,
         ),
     }
 }
@@ -1,10 +1,20 @@
+use std::collections::HashMap;
+
 use api_models::webhooks;
-use common_utils::{crypto::SignMessage, ext_traits::Encode};
+use base64::Engine;
+use common_utils::{crypto::SignMessage, date_time, ext_traits::Encode};
 use error_stack::ResultExt;
+use hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails;
 use masking::Secret;
 use serde::Serialize;
 
-use crate::{core::errors, headers, services::request::Maskable, types::storage::enums};
+use crate::{
+    consts,
+    core::errors,
+    headers,
+    services::request::Maskable,
+    types::{domain, storage::enums},
+};
 
 pub struct OutgoingWebhookPayloadWithSignature {
     pub payload: Secret<String>,
@@ -16,34 +26,34 @@ pub trait OutgoingWebhookType:
 {
     fn get_outgoing_webhooks_signature(
         &self,
-        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        signing_keys: &WebhookSigningKeys,
+        signature_scheme: enums::WebhookSignatureScheme,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError>;
 
-    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String);
+    fn add_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+        signature_scheme: enums::WebhookSignatureScheme,
+    );
 }
 
 impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     fn get_outgoing_webhooks_signature(
         &self,
-        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        signing_keys: &WebhookSigningKeys,
+        signature_scheme: enums::WebhookSignatureScheme,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
         let webhook_signature_payload = self
             .encode_to_string_of_json()
             .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
             .attach_printable("failed encoding outgoing webhook payload")?;
 
-        let signature = payment_response_hash_key
-            .map(|key| {
-                common_utils::crypto::HmacSha512::sign_message(
-                    &common_utils::crypto::HmacSha512,
-                    key.as_ref(),
-                    webhook_signature_payload.as_bytes(),
-                )
-            })
-            .transpose()
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?
-            .map(hex::encode);
+        let signature = sign_webhook_payload(
+            &webhook_signature_payload,
+            signing_keys,
+            signature_scheme,
+            date_time::now_unix_timestamp(),
+        )?;
 
         Ok(OutgoingWebhookPayloadWithSignature {
             payload: webhook_signature_payload.into(),
@@ -51,11 +61,104 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
         })
     }
 
-    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
-        header.push((headers::X_WEBHOOK_SIGNATURE.to_string(), signature.into()))
+    fn add_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+        signature_scheme: enums::WebhookSignatureScheme,
+    ) {
+        header.push((
+            get_webhook_signature_header(signature_scheme).to_string(),
+            signature.into(),
+        ))
+    }
+}
+
+/// The keys outgoing webhooks are signed with
+#[derive(Clone, Debug, Default)]
+pub struct WebhookSigningKeys {
+    pub current: Option<String>,
+    /// The key which was rotated out, available only until its rotation window expires
+    pub previous: Option<String>,
+}
+
+impl WebhookSigningKeys {
+    pub fn from_business_profile(business_profile: &domain::BusinessProfile) -> Self {
+        let now = date_time::now();
+        let previous = business_profile
+            .previous_payment_response_hash_key
+            .clone()
+            .filter(|_| {
+                business_profile
+                    .previous_payment_response_hash_key_expires_at
+                    .is_some_and(|expires_at| expires_at > now)
+            });
+
+        Self {
+            current: business_profile.payment_response_hash_key.clone(),
+            previous,
+        }
+    }
+}
+
+pub(crate) fn get_webhook_signature_header(
+    signature_scheme: enums::WebhookSignatureScheme,
+) -> &'static str {
+    match signature_scheme {
+        enums::WebhookSignatureScheme::Legacy => headers::X_WEBHOOK_SIGNATURE,
+        enums::WebhookSignatureScheme::V1 => headers::X_WEBHOOK_SIGNATURE_V1,
+    }
+}
+
+/// Signs the outgoing webhook payload using the specified signature scheme. Returns `None` if no
+/// signing key is configured.
+///
+/// The legacy scheme signs only the payload, using the current key. The `v1` scheme signs the
+/// timestamp along with the payload, and includes a signature for the previous key as well while
+/// it is being rotated out, in the format `t=<timestamp>,v1=<signature>[,v1=<signature>]`.
+pub(crate) fn sign_webhook_payload(
+    payload: &str,
+    signing_keys: &WebhookSigningKeys,
+    signature_scheme: enums::WebhookSignatureScheme,
+    timestamp: i64,
+) -> errors::CustomResult<Option<String>, errors::WebhooksFlowError> {
+    let Some(current_key) = &signing_keys.current else {
+        return Ok(None);
+    };
+
+    match signature_scheme {
+        enums::WebhookSignatureScheme::Legacy => {
+            hmac_sha512_hex_signature(current_key, payload).map(Some)
+        }
+        enums::WebhookSignatureScheme::V1 => {
+            let signed_payload = format!("{timestamp}.{payload}");
+            let mut signature = format!(
+                "t={timestamp},v1={}",
+                hmac_sha512_hex_signature(current_key, &signed_payload)?
+            );
+            if let Some(previous_key) = &signing_keys.previous {
+                signature.push_str(",v1=");
+                signature.push_str(&hmac_sha512_hex_signature(previous_key, &signed_payload)?);
+            }
+
+            Ok(Some(signature))
+        }
     }
 }
 
+fn hmac_sha512_hex_signature(
+    key: &str,
+    message: &str,
+) -> errors::CustomResult<String, errors::WebhooksFlowError> {
+    common_utils::crypto::HmacSha512::sign_message(
+        &common_utils::crypto::HmacSha512,
+        key.as_bytes(),
+        message.as_bytes(),
+    )
+    .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+    .attach_printable("Failed to sign the message")
+    .map(hex::encode)
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) merchant_id: common_utils::id_type::MerchantId,
@@ -66,3 +169,117 @@ pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) primary_object_type: enums::EventObjectType,
     pub(crate) initial_attempt_id: Option<String>,
 }
+
+/// The request of an incoming webhook as received from the connector, archived (encrypted) so that
+/// the webhook can be replayed
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ArchivedWebhookPayload {
+    pub(crate) method: String,
+    pub(crate) uri: String,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) query_params: String,
+    /// The base64 encoded request body, before being decoded by the connector
+    pub(crate) body: String,
+}
+
+impl ArchivedWebhookPayload {
+    pub(crate) fn new(
+        request_details: &IncomingWebhookRequestDetails<'_>,
+        raw_body: &[u8],
+    ) -> Self {
+        Self {
+            method: request_details.method.to_string(),
+            uri: request_details.uri.to_string(),
+            headers: request_details
+                .headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    value
+                        .to_str()
+                        .ok()
+                        .map(|value| (name.to_string(), value.to_string()))
+                })
+                .collect(),
+            query_params: request_details.query_params.clone(),
+            body: consts::BASE64_ENGINE.encode(raw_body),
+        }
+    }
+}
+
+/// A webhook endpoint registered on a business profile, with its secret and custom HTTP headers
+/// decrypted
+#[derive(Clone, Debug)]
+pub(crate) struct WebhookEndpointDetails {
+    pub(crate) endpoint_id: String,
+    pub(crate) secret: Secret<String>,
+    pub(crate) custom_http_headers: Option<HashMap<String, Secret<String>>>,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_legacy_signature_signs_only_the_payload() {
+        let signing_keys = WebhookSigningKeys {
+            current: Some("current_key".to_string()),
+            previous: Some("previous_key".to_string()),
+        };
+
+        let signature = sign_webhook_payload(
+            r#"{"event_id":"evt_123"}"#,
+            &signing_keys,
+            enums::WebhookSignatureScheme::Legacy,
+            1_726_000_000,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            signature,
+            hmac_sha512_hex_signature("current_key", r#"{"event_id":"evt_123"}"#).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_v1_signature_includes_timestamp_and_previous_key() {
+        let payload = r#"{"event_id":"evt_123"}"#;
+        let signed_payload = format!("1726000000.{payload}");
+        let signing_keys = WebhookSigningKeys {
+            current: Some("current_key".to_string()),
+            previous: Some("previous_key".to_string()),
+        };
+
+        let signature = sign_webhook_payload(
+            payload,
+            &signing_keys,
+            enums::WebhookSignatureScheme::V1,
+            1_726_000_000,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            signature,
+            format!(
+                "t=1726000000,v1={},v1={}",
+                hmac_sha512_hex_signature("current_key", &signed_payload).unwrap(),
+                hmac_sha512_hex_signature("previous_key", &signed_payload).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_no_signature_without_current_key() {
+        let signature = sign_webhook_payload(
+            "{}",
+            &WebhookSigningKeys::default(),
+            enums::WebhookSignatureScheme::V1,
+            1_726_000_000,
+        )
+        .unwrap();
+
+        assert!(signature.is_none());
+    }
+}
@@ -0,0 +1,436 @@
+use api_models::webhook_endpoints as webhook_endpoints_api;
+use common_utils::id_type;
+use error_stack::ResultExt;
+use masking::Secret;
+use router_env::{instrument, tracing};
+
+use super::{endpoint_health, outgoing::get_webhook_endpoint_details};
+use crate::{
+    core::{
+        audit_log::{self, ConfigChange},
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+    },
+    routes::{app::ReqState, SessionState},
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+const MAX_WEBHOOK_ENDPOINTS_PER_PROFILE: usize = 16;
+const WEBHOOK_ENDPOINT_SECRET_LENGTH: usize = 64;
+
+#[instrument(skip(state, req_state, request))]
+pub async fn create_webhook_endpoint(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_id: id_type::MerchantId,
+    profile_id: String,
+    request: webhook_endpoints_api::WebhookEndpointCreateRequest,
+) -> RouterResponse<webhook_endpoints_api::WebhookEndpointResponse> {
+    let key_store =
+        get_key_store_and_validate_business_profile(&state, &merchant_id, &profile_id).await?;
+
+    let existing_webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(&merchant_id, &profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints of business profile")?;
+    if existing_webhook_endpoints.len() >= MAX_WEBHOOK_ENDPOINTS_PER_PROFILE {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "A business profile can have at most {MAX_WEBHOOK_ENDPOINTS_PER_PROFILE} webhook \
+                 endpoints"
+            ),
+        }
+        .into());
+    }
+
+    let secret = request.secret.unwrap_or_else(|| {
+        Secret::new(
+            common_utils::crypto::generate_cryptographically_secure_random_string(
+                WEBHOOK_ENDPOINT_SECRET_LENGTH,
+            ),
+        )
+    });
+    let now = common_utils::date_time::now();
+    let webhook_endpoint_new = storage::WebhookEndpointNew {
+        endpoint_id: common_utils::generate_id_with_default_len("whe"),
+        merchant_id: merchant_id.clone(),
+        profile_id,
+        url: request.url.to_string(),
+        secret: encrypt_secret(&state, &key_store, &secret).await?,
+        enabled_events: request.enabled_events.unwrap_or_default(),
+        enabled: request.enabled.unwrap_or(true),
+        custom_http_headers: encrypt_custom_http_headers(
+            &state,
+            &key_store,
+            request.custom_http_headers,
+        )
+        .await?,
+        description: request.description,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let webhook_endpoint = state
+        .store
+        .insert_webhook_endpoint(webhook_endpoint_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Webhook endpoint with the specified ID already exists".to_string(),
+        })?;
+
+    let mut response = get_webhook_endpoint_response(&state, &key_store, webhook_endpoint).await?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(&merchant_id),
+            entity_type: enums::AuditEntityType::WebhookEndpoint,
+            entity_id: response.endpoint_id.clone(),
+            action: enums::AuditAction::Create,
+            before: None,
+            after: Some(&response),
+        },
+    )
+    .await;
+
+    // The secret is only returned when the endpoint is created
+    response.secret = Some(secret);
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_webhook_endpoint(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: String,
+    endpoint_id: String,
+) -> RouterResponse<webhook_endpoints_api::WebhookEndpointResponse> {
+    let key_store =
+        get_key_store_and_validate_business_profile(&state, &merchant_id, &profile_id).await?;
+    let webhook_endpoint =
+        find_webhook_endpoint(&state, &merchant_id, &profile_id, &endpoint_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        get_webhook_endpoint_response(&state, &key_store, webhook_endpoint).await?,
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_webhook_endpoints(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: String,
+) -> RouterResponse<Vec<webhook_endpoints_api::WebhookEndpointResponse>> {
+    let key_store =
+        get_key_store_and_validate_business_profile(&state, &merchant_id, &profile_id).await?;
+    let webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(&merchant_id, &profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints of business profile")?;
+
+    let mut response = Vec::with_capacity(webhook_endpoints.len());
+    for webhook_endpoint in webhook_endpoints {
+        response.push(get_webhook_endpoint_response(&state, &key_store, webhook_endpoint).await?);
+    }
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+#[instrument(skip(state, req_state, request))]
+pub async fn update_webhook_endpoint(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_id: id_type::MerchantId,
+    profile_id: String,
+    endpoint_id: String,
+    request: webhook_endpoints_api::WebhookEndpointUpdateRequest,
+) -> RouterResponse<webhook_endpoints_api::WebhookEndpointResponse> {
+    let key_store =
+        get_key_store_and_validate_business_profile(&state, &merchant_id, &profile_id).await?;
+    let existing_webhook_endpoint =
+        find_webhook_endpoint(&state, &merchant_id, &profile_id, &endpoint_id).await?;
+    let before =
+        get_webhook_endpoint_response(&state, &key_store, existing_webhook_endpoint).await?;
+
+    let secret = match request.secret {
+        Some(secret) => Some(encrypt_secret(&state, &key_store, &secret).await?),
+        None => None,
+    };
+    let webhook_endpoint_update = storage::WebhookEndpointUpdate::Update {
+        url: request.url.map(String::from),
+        secret,
+        enabled_events: request.enabled_events,
+        enabled: request.enabled,
+        custom_http_headers: encrypt_custom_http_headers(
+            &state,
+            &key_store,
+            request.custom_http_headers,
+        )
+        .await?,
+        description: request.description,
+    };
+
+    let webhook_endpoint = state
+        .store
+        .update_webhook_endpoint_by_merchant_id_endpoint_id(
+            &merchant_id,
+            &endpoint_id,
+            webhook_endpoint_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Webhook endpoint does not exist in our records".to_string(),
+        })?;
+    let response = get_webhook_endpoint_response(&state, &key_store, webhook_endpoint).await?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(&merchant_id),
+            entity_type: enums::AuditEntityType::WebhookEndpoint,
+            entity_id: endpoint_id,
+            action: enums::AuditAction::Update,
+            before: Some(&before),
+            after: Some(&response),
+        },
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+#[instrument(skip(state, req_state))]
+pub async fn delete_webhook_endpoint(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_id: id_type::MerchantId,
+    profile_id: String,
+    endpoint_id: String,
+) -> RouterResponse<webhook_endpoints_api::WebhookEndpointDeleteResponse> {
+    let key_store =
+        get_key_store_and_validate_business_profile(&state, &merchant_id, &profile_id).await?;
+    let existing_webhook_endpoint =
+        find_webhook_endpoint(&state, &merchant_id, &profile_id, &endpoint_id).await?;
+    let before =
+        get_webhook_endpoint_response(&state, &key_store, existing_webhook_endpoint).await?;
+
+    let deleted = state
+        .store
+        .delete_webhook_endpoint_by_merchant_id_endpoint_id(&merchant_id, &endpoint_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Webhook endpoint does not exist in our records".to_string(),
+        })?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(&merchant_id),
+            entity_type: enums::AuditEntityType::WebhookEndpoint,
+            entity_id: endpoint_id.clone(),
+            action: enums::AuditAction::Delete,
+            before: Some(&before),
+            after: None,
+        },
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoints_api::WebhookEndpointDeleteResponse {
+            endpoint_id,
+            deleted,
+        },
+    ))
+}
+
+/// Resumes deliveries to a webhook endpoint suspended due to repeated delivery failures, and
+/// schedules the delivery of the webhooks queued while the endpoint was suspended.
+#[instrument(skip(state, req_state))]
+pub async fn reenable_webhook_endpoint(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_id: id_type::MerchantId,
+    profile_id: String,
+    endpoint_id: String,
+) -> RouterResponse<webhook_endpoints_api::WebhookEndpointReenableResponse> {
+    let key_store =
+        get_key_store_and_validate_business_profile(&state, &merchant_id, &profile_id).await?;
+    let existing_webhook_endpoint =
+        find_webhook_endpoint(&state, &merchant_id, &profile_id, &endpoint_id).await?;
+    if existing_webhook_endpoint.suspended_at.is_none() {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Deliveries to the webhook endpoint are not suspended".to_string(),
+        }
+        .into());
+    }
+    let before =
+        get_webhook_endpoint_response(&state, &key_store, existing_webhook_endpoint).await?;
+
+    let webhook_endpoint = state
+        .store
+        .update_webhook_endpoint_by_merchant_id_endpoint_id(
+            &merchant_id,
+            &endpoint_id,
+            storage::WebhookEndpointUpdate::Reenable,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Webhook endpoint does not exist in our records".to_string(),
+        })?;
+    let response =
+        get_webhook_endpoint_response(&state, &key_store, webhook_endpoint.clone()).await?;
+
+    audit_log::record_config_change(
+        &state,
+        &req_state,
+        ConfigChange {
+            merchant_id: Some(&merchant_id),
+            entity_type: enums::AuditEntityType::WebhookEndpoint,
+            entity_id: endpoint_id,
+            action: enums::AuditAction::Update,
+            before: Some(&before),
+            after: Some(&response),
+        },
+    )
+    .await;
+
+    let queued_webhooks_scheduled =
+        endpoint_health::schedule_queued_webhooks(&state, &key_store, &webhook_endpoint).await?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoints_api::WebhookEndpointReenableResponse {
+            endpoint: response,
+            queued_webhooks_scheduled: u32::try_from(queued_webhooks_scheduled)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to convert number of queued webhooks scheduled")?,
+        },
+    ))
+}
+
+async fn get_key_store_and_validate_business_profile(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &str,
+) -> RouterResult<domain::MerchantKeyStore> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    store
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &key_store,
+            merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    Ok(key_store)
+}
+
+/// Finds the webhook endpoint, treating endpoints registered on other business profiles as not
+/// found.
+async fn find_webhook_endpoint(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &str,
+    endpoint_id: &str,
+) -> RouterResult<storage::WebhookEndpoint> {
+    let not_found_error = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Webhook endpoint does not exist in our records".to_string(),
+    };
+
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, endpoint_id)
+        .await
+        .to_not_found_response(not_found_error())?;
+
+    if webhook_endpoint.profile_id == profile_id {
+        Ok(webhook_endpoint)
+    } else {
+        Err(not_found_error().into())
+    }
+}
+
+async fn encrypt_secret(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    secret: &Secret<String>,
+) -> RouterResult<common_utils::encryption::Encryption> {
+    cards::create_encrypted_data(state, key_store, secret)
+        .await
+        .map(Into::into)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt webhook endpoint secret")
+}
+
+async fn encrypt_custom_http_headers(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    custom_http_headers: Option<std::collections::HashMap<String, Secret<String>>>,
+) -> RouterResult<Option<common_utils::encryption::Encryption>> {
+    match custom_http_headers {
+        Some(custom_http_headers) => {
+            cards::create_encrypted_data(state, key_store, custom_http_headers)
+                .await
+                .map(|custom_http_headers| Some(custom_http_headers.into()))
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encrypt webhook endpoint custom HTTP headers")
+        }
+        None => Ok(None),
+    }
+}
+
+async fn get_webhook_endpoint_response(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: storage::WebhookEndpoint,
+) -> RouterResult<webhook_endpoints_api::WebhookEndpointResponse> {
+    let webhook_endpoint_details =
+        get_webhook_endpoint_details(state, key_store, webhook_endpoint.clone()).await?;
+
+    Ok(webhook_endpoints_api::WebhookEndpointResponse {
+        endpoint_id: webhook_endpoint.endpoint_id,
+        merchant_id: webhook_endpoint.merchant_id,
+        profile_id: webhook_endpoint.profile_id,
+        url: webhook_endpoint.url,
+        secret: None,
+        enabled_events: webhook_endpoint.enabled_events,
+        enabled: webhook_endpoint.enabled,
+        custom_http_headers: webhook_endpoint_details.custom_http_headers,
+        description: webhook_endpoint.description,
+        health: webhook_endpoints_api::WebhookEndpointHealth {
+            consecutive_failures: u32::try_from(webhook_endpoint.consecutive_failures)
+                .unwrap_or_default(),
+            success_rate: endpoint_health::get_success_rate(
+                &webhook_endpoint.recent_delivery_outcomes,
+            ),
+            suspended_at: webhook_endpoint.suspended_at,
+        },
+        created_at: webhook_endpoint.created_at,
+        modified_at: webhook_endpoint.modified_at,
+    })
+}
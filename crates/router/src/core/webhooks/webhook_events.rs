@@ -3,7 +3,7 @@ use masking::PeekInterface;
 use router_env::{instrument, tracing};
 
 use crate::{
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
     routes::SessionState,
     services::ApplicationResponse,
     types::{api, domain, storage, transformers::ForeignTryFrom},
@@ -181,6 +181,7 @@ pub async fn retry_delivery_attempt(
 
     let business_profile_id = event_to_retry
         .business_profile_id
+        .clone()
         .get_required_value("business_profile_id")
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to read business profile ID from event to retry")?;
@@ -190,12 +191,32 @@ pub async fn retry_delivery_attempt(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to find business profile")?;
 
+    let updated_event =
+        trigger_manual_retry(&state, &key_store, business_profile, event_to_retry).await?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
+    ))
+}
+
+/// Creates a manual retry delivery attempt for the event and delivers it, returning the delivery
+/// attempt with the outcome of the delivery recorded.
+pub(crate) async fn trigger_manual_retry(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: domain::BusinessProfile,
+    event_to_retry: domain::Event,
+) -> RouterResult<domain::Event> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+
     let delivery_attempt = storage::enums::WebhookDeliveryAttempt::ManualRetry;
     let new_event_id = super::utils::generate_event_id();
     let idempotent_event_id = super::utils::get_idempotent_event_id(
         &event_to_retry.primary_object_id,
         event_to_retry.event_type,
         delivery_attempt,
+        event_to_retry.webhook_endpoint_id.as_deref(),
     );
 
     let now = common_utils::date_time::now();
@@ -216,10 +237,11 @@ pub async fn retry_delivery_attempt(
         response: None,
         delivery_attempt: Some(delivery_attempt),
         metadata: event_to_retry.metadata,
+        webhook_endpoint_id: event_to_retry.webhook_endpoint_id,
     };
 
     let event = store
-        .insert_event(key_manager_state, new_event, &key_store)
+        .insert_event(key_manager_state, new_event, key_store)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to insert event")?;
@@ -238,7 +260,7 @@ pub async fn retry_delivery_attempt(
     Box::pin(super::outgoing::trigger_webhook_and_raise_event(
         state.clone(),
         business_profile,
-        &key_store,
+        key_store,
         event,
         request_content,
         delivery_attempt,
@@ -247,19 +269,15 @@ pub async fn retry_delivery_attempt(
     ))
     .await;
 
-    let updated_event = store
+    store
         .find_event_by_merchant_id_event_id(
             key_manager_state,
             &key_store.merchant_id,
             &new_event_id,
-            &key_store,
+            key_store,
         )
         .await
-        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
-
-    Ok(ApplicationResponse::Json(
-        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
-    ))
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)
 }
 
 async fn get_account_and_key_store(
@@ -0,0 +1,526 @@
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use super::webhook_events;
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        api::webhook_events as webhook_events_api,
+        domain,
+        storage::{self, enums},
+    },
+    utils::StringExt,
+};
+
+/// The maximum number of events which can be replayed by a single replay job
+const MAX_EVENTS_PER_REPLAY_JOB: i64 = 10_000;
+
+const DEFAULT_MAX_EVENTS_PER_MINUTE: u16 = 100;
+
+const MAX_EVENTS_PER_MINUTE_LIMIT: u16 = 1000;
+
+const REPLAY_JOB_EVENTS_LIST_MAX_LIMIT: i64 = 100;
+
+/// The interval between the batches of events replayed by a replay job
+pub(crate) const REPLAY_BATCH_INTERVAL_SECS: i64 = 60;
+
+const WEBHOOK_REPLAY_TASK: &str = "WEBHOOK_REPLAY";
+
+const WEBHOOK_REPLAY_TAG: &str = "OUTGOING_WEBHOOKS";
+
+#[instrument(skip(state))]
+pub async fn create_webhook_replay_job(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    request: webhook_events_api::WebhookReplayRequest,
+) -> RouterResponse<webhook_events_api::WebhookReplayJobResponse> {
+    let filters = request.filters;
+    if filters.created_after > filters.created_before {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`created_after` must be earlier than `created_before`".to_string(),
+        }));
+    }
+
+    let max_events_per_minute = request
+        .max_events_per_minute
+        .unwrap_or(DEFAULT_MAX_EVENTS_PER_MINUTE);
+    if max_events_per_minute == 0 || max_events_per_minute > MAX_EVENTS_PER_MINUTE_LIMIT {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`max_events_per_minute` must be a number between 1 and {MAX_EVENTS_PER_MINUTE_LIMIT}"
+            ),
+        }));
+    }
+
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let business_profile = store
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &key_store,
+            &merchant_id,
+            &request.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: request.profile_id.clone(),
+        })?;
+
+    let filters = storage::WebhookReplayJobFilters {
+        created_after: filters.created_after,
+        created_before: filters.created_before,
+        event_types: filters.event_types,
+        delivery_status: filters.delivery_status,
+        object_ids: filters.object_ids,
+    };
+
+    // One more event than allowed is fetched to detect whether the filters match too many events
+    let event_ids = store
+        .list_initial_event_ids_by_profile_id_replay_filters(
+            &business_profile.profile_id,
+            &filters,
+            MAX_EVENTS_PER_REPLAY_JOB + 1,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list events matching the replay filters")?;
+
+    if event_ids.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::EventNotFound))
+            .attach_printable("No events match the specified replay filters");
+    }
+    let total_events = i64::try_from(event_ids.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the number of events to replay")?;
+    if total_events > MAX_EVENTS_PER_REPLAY_JOB {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "More than {MAX_EVENTS_PER_REPLAY_JOB} events match the specified filters, narrow \
+                down the filters to replay fewer events"
+            ),
+        }));
+    }
+
+    let job_id = common_utils::generate_id_with_default_len("wrj");
+    let now = common_utils::date_time::now();
+
+    let webhook_replay_job = store
+        .insert_webhook_replay_job(storage::WebhookReplayJobNew {
+            job_id: job_id.clone(),
+            merchant_id: merchant_id.clone(),
+            profile_id: business_profile.profile_id.clone(),
+            status: enums::WebhookReplayJobStatus::Pending,
+            filters,
+            max_events_per_minute: i32::from(max_events_per_minute),
+            total_events: i32::try_from(total_events)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to convert the number of events to replay")?,
+            delivered_events: 0,
+            failed_events: 0,
+            skipped_events: 0,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert webhook replay job")?;
+
+    store
+        .insert_webhook_replay_job_events(
+            event_ids
+                .into_iter()
+                .map(|event_id| storage::WebhookReplayJobEventNew {
+                    job_id: job_id.clone(),
+                    event_id,
+                    status: enums::WebhookReplayEventStatus::Pending,
+                    replayed_event_id: None,
+                    error_message: None,
+                    created_at: now,
+                    modified_at: now,
+                })
+                .collect(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the events of the webhook replay job")?;
+
+    add_webhook_replay_task_to_process_tracker(store, &webhook_replay_job, now)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule webhook replay job")?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_events_api::WebhookReplayJobResponse::try_from(webhook_replay_job)?,
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_webhook_replay_job(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    job_id: String,
+    constraints: webhook_events_api::WebhookReplayJobEventListConstraints,
+) -> RouterResponse<webhook_events_api::WebhookReplayJobStatusResponse> {
+    let limit = match constraints.limit.map(i64::from) {
+        Some(limit) if limit > REPLAY_JOB_EVENTS_LIST_MAX_LIMIT => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`limit` must be a number less than {REPLAY_JOB_EVENTS_LIST_MAX_LIMIT}"
+                ),
+            })
+        }
+        Some(limit) => Ok(limit),
+        None => Ok(REPLAY_JOB_EVENTS_LIST_MAX_LIMIT),
+    }?;
+
+    let store = state.store.as_ref();
+    let webhook_replay_job = store
+        .find_webhook_replay_job_by_merchant_id_job_id(&merchant_id, &job_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Webhook replay job does not exist in our records".to_string(),
+        })?;
+
+    let events = store
+        .list_webhook_replay_job_events_by_job_id_status(
+            &job_id,
+            constraints.status,
+            Some(limit),
+            constraints.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the events of the webhook replay job")?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_events_api::WebhookReplayJobStatusResponse {
+            job: webhook_events_api::WebhookReplayJobResponse::try_from(webhook_replay_job)?,
+            events: events
+                .into_iter()
+                .map(|event| webhook_events_api::WebhookReplayEventResponse {
+                    event_id: event.event_id,
+                    status: event.status,
+                    replayed_event_id: event.replayed_event_id,
+                    error_message: event.error_message,
+                    modified_at: event.modified_at,
+                })
+                .collect(),
+        },
+    ))
+}
+
+async fn add_webhook_replay_task_to_process_tracker(
+    db: &dyn crate::db::StorageInterface,
+    webhook_replay_job: &storage::WebhookReplayJob,
+    schedule_time: time::PrimitiveDateTime,
+) -> errors::CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::WebhookReplayWorkflow;
+    let tracking_data = storage::WebhookReplayTrackingData {
+        merchant_id: webhook_replay_job.merchant_id.clone(),
+        job_id: webhook_replay_job.job_id.clone(),
+    };
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        WEBHOOK_REPLAY_TASK,
+        &webhook_replay_job.job_id,
+        &webhook_replay_job.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        WEBHOOK_REPLAY_TASK,
+        runner,
+        [WEBHOOK_REPLAY_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "WebhookReplay")]),
+            );
+            Ok(())
+        }
+        Err(error) => {
+            metrics::TASK_ADDITION_FAILURES_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("flow", "WebhookReplay")]),
+            );
+            Err(error)
+        }
+    }
+}
+
+/// Replays the next batch of pending events of the replay job, and returns the replay job with its
+/// progress updated. The replay job is marked as completed once no pending events remain.
+#[instrument(skip_all, fields(job_id = %webhook_replay_job.job_id))]
+pub(crate) async fn replay_next_batch(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    webhook_replay_job: storage::WebhookReplayJob,
+) -> RouterResult<storage::WebhookReplayJob> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+
+    let business_profile = store
+        .find_business_profile_by_profile_id(
+            key_manager_state,
+            key_store,
+            &webhook_replay_job.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: webhook_replay_job.profile_id.clone(),
+        })?;
+
+    let batch_size = i64::from(webhook_replay_job.max_events_per_minute);
+    let pending_events = store
+        .list_webhook_replay_job_events_by_job_id_status(
+            &webhook_replay_job.job_id,
+            Some(enums::WebhookReplayEventStatus::Pending),
+            Some(batch_size),
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the pending events of the webhook replay job")?;
+    let is_last_batch = i64::try_from(pending_events.len())
+        .map(|batch_len| batch_len < batch_size)
+        .unwrap_or(true);
+
+    let mut delivered_events = webhook_replay_job.delivered_events;
+    let mut failed_events = webhook_replay_job.failed_events;
+    let mut skipped_events = webhook_replay_job.skipped_events;
+
+    for pending_event in pending_events {
+        let outcome = replay_event(
+            state,
+            key_store,
+            &business_profile,
+            &webhook_replay_job.filters,
+            &pending_event.event_id,
+        )
+        .await;
+
+        match outcome.status {
+            enums::WebhookReplayEventStatus::Delivered => delivered_events += 1,
+            enums::WebhookReplayEventStatus::Failed => failed_events += 1,
+            enums::WebhookReplayEventStatus::Skipped => skipped_events += 1,
+            enums::WebhookReplayEventStatus::Pending => {}
+        }
+
+        store
+            .update_webhook_replay_job_event_by_job_id_event_id(
+                &webhook_replay_job.job_id,
+                &pending_event.event_id,
+                storage::WebhookReplayJobEventUpdate::OutcomeUpdate {
+                    status: outcome.status,
+                    replayed_event_id: outcome.replayed_event_id,
+                    error_message: outcome.error_message,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the outcome of the replayed event")?;
+    }
+
+    let status = if is_last_batch {
+        enums::WebhookReplayJobStatus::Completed
+    } else {
+        enums::WebhookReplayJobStatus::Processing
+    };
+
+    store
+        .update_webhook_replay_job_by_merchant_id_job_id(
+            &webhook_replay_job.merchant_id,
+            &webhook_replay_job.job_id,
+            storage::WebhookReplayJobUpdate::ProgressUpdate {
+                status,
+                delivered_events,
+                failed_events,
+                skipped_events,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the progress of the webhook replay job")
+}
+
+struct ReplayEventOutcome {
+    status: enums::WebhookReplayEventStatus,
+    replayed_event_id: Option<String>,
+    error_message: Option<String>,
+}
+
+impl ReplayEventOutcome {
+    fn failed(error_message: impl Into<String>) -> Self {
+        Self {
+            status: enums::WebhookReplayEventStatus::Failed,
+            replayed_event_id: None,
+            error_message: Some(error_message.into()),
+        }
+    }
+}
+
+/// Replays a single event, unless its delivery status no longer matches the filters of the replay
+/// job. Failures to replay the event are recorded as its outcome and are not propagated, so that
+/// they do not hold up the rest of the replay job.
+async fn replay_event(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::BusinessProfile,
+    filters: &storage::WebhookReplayJobFilters,
+    event_id: &str,
+) -> ReplayEventOutcome {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+
+    let event = match store
+        .find_event_by_merchant_id_event_id(
+            key_manager_state,
+            &business_profile.merchant_id,
+            event_id,
+            key_store,
+        )
+        .await
+    {
+        Ok(event) => event,
+        Err(error) => {
+            logger::error!(?error, %event_id, "Failed to find event to replay");
+            return ReplayEventOutcome::failed("Failed to find event");
+        }
+    };
+
+    if let Some(delivery_status) = filters.delivery_status {
+        let delivery_attempts = match store
+            .list_events_by_merchant_id_initial_attempt_id(
+                key_manager_state,
+                &business_profile.merchant_id,
+                event_id,
+                key_store,
+            )
+            .await
+        {
+            Ok(delivery_attempts) => delivery_attempts,
+            Err(error) => {
+                logger::error!(?error, %event_id, "Failed to list delivery attempts of event");
+                return ReplayEventOutcome::failed("Failed to determine delivery status of event");
+            }
+        };
+
+        let is_delivered = delivery_attempts
+            .iter()
+            .any(|delivery_attempt| delivery_attempt.is_webhook_notified);
+        let current_delivery_status = if is_delivered {
+            enums::WebhookDeliveryStatus::Delivered
+        } else {
+            enums::WebhookDeliveryStatus::Failed
+        };
+
+        if current_delivery_status != delivery_status {
+            return ReplayEventOutcome {
+                status: enums::WebhookReplayEventStatus::Skipped,
+                replayed_event_id: None,
+                error_message: None,
+            };
+        }
+    }
+
+    match webhook_events::trigger_manual_retry(state, key_store, business_profile.clone(), event)
+        .await
+    {
+        Ok(replayed_event) if replayed_event.is_webhook_notified => ReplayEventOutcome {
+            status: enums::WebhookReplayEventStatus::Delivered,
+            replayed_event_id: Some(replayed_event.event_id),
+            error_message: None,
+        },
+        Ok(replayed_event) => ReplayEventOutcome {
+            status: enums::WebhookReplayEventStatus::Failed,
+            error_message: Some(get_delivery_error_message(&replayed_event)),
+            replayed_event_id: Some(replayed_event.event_id),
+        },
+        Err(error) => {
+            logger::error!(?error, %event_id, "Failed to replay event");
+            ReplayEventOutcome::failed("Failed to replay event")
+        }
+    }
+}
+
+fn get_delivery_error_message(event: &domain::Event) -> String {
+    event
+        .response
+        .as_ref()
+        .and_then(|response| {
+            response
+                .get_inner()
+                .peek()
+                .parse_struct::<webhook_events_api::OutgoingWebhookResponseContent>(
+                    "OutgoingWebhookResponseContent",
+                )
+                .ok()
+        })
+        .and_then(|response| {
+            response.error_message.or_else(|| {
+                response
+                    .status_code
+                    .map(|status_code| format!("Received status code {status_code}"))
+            })
+        })
+        .unwrap_or_else(|| "Webhook could not be delivered".to_string())
+}
+
+impl TryFrom<storage::WebhookReplayJob> for webhook_events_api::WebhookReplayJobResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(item: storage::WebhookReplayJob) -> Result<Self, Self::Error> {
+        let to_u32 = |count: i32| {
+            u32::try_from(count)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to convert webhook replay job event count")
+        };
+        let processed_events = item
+            .delivered_events
+            .saturating_add(item.failed_events)
+            .saturating_add(item.skipped_events);
+
+        Ok(Self {
+            job_id: item.job_id,
+            merchant_id: item.merchant_id,
+            profile_id: item.profile_id,
+            status: item.status,
+            filters: webhook_events_api::WebhookReplayFilters {
+                created_after: item.filters.created_after,
+                created_before: item.filters.created_before,
+                event_types: item.filters.event_types,
+                delivery_status: item.filters.delivery_status,
+                object_ids: item.filters.object_ids,
+            },
+            max_events_per_minute: u16::try_from(item.max_events_per_minute)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to convert webhook replay job rate limit")?,
+            total_events: to_u32(item.total_events)?,
+            pending_events: to_u32(item.total_events.saturating_sub(processed_events))?,
+            delivered_events: to_u32(item.delivered_events)?,
+            failed_events: to_u32(item.failed_events)?,
+            skipped_events: to_u32(item.skipped_events)?,
+            created_at: item.created_at,
+            modified_at: item.modified_at,
+        })
+    }
+}
@@ -1,5 +1,7 @@
 pub mod address;
 pub mod api_keys;
+pub mod archived_webhook;
+pub mod audit_log;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
@@ -37,6 +39,8 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
+pub mod webhook_replay_job;
 use common_utils::id_type;
 use diesel_models::{
     fraud_check::{FraudCheck, FraudCheckUpdate},
@@ -87,6 +91,8 @@ pub trait StorageInterface:
     + dyn_clone::DynClone
     + address::AddressInterface
     + api_keys::ApiKeyInterface
+    + archived_webhook::ArchivedWebhookInterface
+    + audit_log::AuditLogInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface
     + capture::CaptureInterface
@@ -131,6 +137,8 @@ pub trait StorageInterface:
     + user_authentication_method::UserAuthenticationMethodInterface
     + authentication::AuthenticationInterface
     + generic_link::GenericLinkInterface
+    + webhook_endpoint::WebhookEndpointInterface
+    + webhook_replay_job::WebhookReplayJobInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -1,3 +1,4 @@
+use async_bb8_diesel::AsyncConnection;
 use error_stack::report;
 use router_env::{instrument, tracing};
 #[cfg(feature = "accounts_cache")]
@@ -24,6 +25,16 @@ pub trait ApiKeyInterface {
         api_key: storage::ApiKeyUpdate,
     ) -> CustomResult<storage::ApiKey, errors::StorageError>;
 
+    /// Inserts the successor of an API key and marks the API key as rotated to it, returning the
+    /// successor and the rotated API key. Nothing is persisted if the API key has already been
+    /// rotated.
+    async fn rotate_api_key(
+        &self,
+        key_id: &str,
+        expires_at: time::PrimitiveDateTime,
+        successor: storage::ApiKeyNew,
+    ) -> CustomResult<(storage::ApiKey, storage::ApiKey), errors::StorageError>;
+
     async fn revoke_api_key(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -111,6 +122,52 @@ impl ApiKeyInterface for Store {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn rotate_api_key(
+        &self,
+        key_id: &str,
+        expires_at: time::PrimitiveDateTime,
+        successor: storage::ApiKeyNew,
+    ) -> CustomResult<(storage::ApiKey, storage::ApiKey), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let merchant_id = successor.merchant_id.clone();
+        let key_id = key_id.to_owned();
+
+        // The successor is only persisted if the API key has not been rotated concurrently
+        let (successor, predecessor) = conn
+            .transaction_async(|conn| async move {
+                let successor = successor
+                    .insert(&conn)
+                    .await
+                    .map_err(errors::StorageError::from)?;
+                let predecessor = storage::ApiKey::rotate_by_merchant_id_key_id(
+                    &conn,
+                    &merchant_id,
+                    &key_id,
+                    expires_at,
+                    successor.key_id.clone(),
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+
+                Ok((successor, predecessor))
+            })
+            .await
+            .map_err(|error| report!(error))?;
+
+        // The cached API key has to be invalidated as its expiry has changed
+        #[cfg(feature = "accounts_cache")]
+        cache::publish_into_redact_channel(
+            self,
+            [CacheKind::Accounts(
+                predecessor.hashed_api_key.clone().into_inner().into(),
+            )],
+        )
+        .await?;
+
+        Ok((successor, predecessor))
+    }
+
     #[instrument(skip_all)]
     async fn revoke_api_key(
         &self,
@@ -231,6 +288,10 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            permissions: api_key.permissions,
+            ip_allowlist: api_key.ip_allowlist,
+            predecessor_key_id: api_key.predecessor_key_id,
+            successor_key_id: api_key.successor_key_id,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -256,6 +317,8 @@ impl ApiKeyInterface for MockDb {
                 description,
                 expires_at,
                 last_used,
+                permissions,
+                ip_allowlist,
             } => {
                 if let Some(name) = name {
                     key_to_update.name = name;
@@ -270,15 +333,64 @@ impl ApiKeyInterface for MockDb {
                 if last_used.is_some() {
                     key_to_update.last_used = last_used
                 }
+                if let Some(permissions) = permissions {
+                    key_to_update.permissions = permissions;
+                }
+                if let Some(ip_allowlist) = ip_allowlist {
+                    key_to_update.ip_allowlist = ip_allowlist;
+                }
             }
             storage::ApiKeyUpdate::LastUsedUpdate { last_used } => {
                 key_to_update.last_used = Some(last_used);
             }
+            storage::ApiKeyUpdate::RotationUpdate {
+                expires_at,
+                successor_key_id,
+            } => {
+                key_to_update.expires_at = Some(expires_at);
+                key_to_update.successor_key_id = Some(successor_key_id);
+            }
         }
 
         Ok(key_to_update.clone())
     }
 
+    async fn rotate_api_key(
+        &self,
+        key_id: &str,
+        expires_at: time::PrimitiveDateTime,
+        successor: storage::ApiKeyNew,
+    ) -> CustomResult<(storage::ApiKey, storage::ApiKey), errors::StorageError> {
+        let predecessor = self
+            .api_keys
+            .lock()
+            .await
+            .iter()
+            .find(|k| {
+                k.merchant_id == successor.merchant_id
+                    && k.key_id == key_id
+                    && k.successor_key_id.is_none()
+            })
+            .cloned()
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "API key `{key_id}` does not exist or has already been rotated"
+            )))?;
+
+        let successor = self.insert_api_key(successor).await?;
+        let predecessor = self
+            .update_api_key(
+                predecessor.merchant_id,
+                predecessor.key_id,
+                storage::ApiKeyUpdate::RotationUpdate {
+                    expires_at,
+                    successor_key_id: successor.key_id.clone(),
+                },
+            )
+            .await?;
+
+        Ok((successor, predecessor))
+    }
+
     async fn revoke_api_key(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -408,6 +520,10 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                permissions: None,
+                ip_allowlist: None,
+                predecessor_key_id: None,
+                successor_key_id: None,
             })
             .await
             .unwrap();
@@ -423,6 +539,10 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                permissions: None,
+                ip_allowlist: None,
+                predecessor_key_id: None,
+                successor_key_id: None,
             })
             .await
             .unwrap();
@@ -456,6 +576,25 @@ mod tests {
             .unwrap();
         assert_eq!(updated_key1.last_used, Some(datetime!(2023-02-04 1:11)));
 
+        mockdb
+            .update_api_key(
+                merchant_id.clone(),
+                "key_id1".into(),
+                storage::ApiKeyUpdate::RotationUpdate {
+                    expires_at: datetime!(2023-02-05 0:00),
+                    successor_key_id: "key_id2".into(),
+                },
+            )
+            .await
+            .unwrap();
+        let rotated_key1 = mockdb
+            .find_api_key_by_merchant_id_key_id_optional(&merchant_id, "key_id1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(rotated_key1.expires_at, Some(datetime!(2023-02-05 0:00)));
+        assert_eq!(rotated_key1.successor_key_id, Some("key_id2".to_string()));
+
         assert_eq!(
             mockdb
                 .list_api_keys_by_merchant_id(&merchant_id, None, None)
@@ -478,6 +617,65 @@ mod tests {
         );
     }
 
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_mockdb_api_key_is_rotated_only_once() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(Cow::from("merchant1")).unwrap();
+        let new_api_key = |key_id: &str| storage::ApiKeyNew {
+            key_id: key_id.into(),
+            merchant_id: merchant_id.clone(),
+            name: "Key".into(),
+            description: None,
+            hashed_api_key: format!("hashed_{key_id}").into(),
+            prefix: "abc".into(),
+            created_at: datetime!(2023-02-01 0:00),
+            expires_at: None,
+            last_used: None,
+            permissions: None,
+            ip_allowlist: None,
+            predecessor_key_id: None,
+            successor_key_id: None,
+        };
+
+        mockdb.insert_api_key(new_api_key("key_id1")).await.unwrap();
+
+        let (successor, predecessor) = mockdb
+            .rotate_api_key(
+                "key_id1",
+                datetime!(2023-03-01 0:00),
+                new_api_key("key_id2"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(successor.key_id, "key_id2");
+        assert_eq!(predecessor.successor_key_id, Some("key_id2".to_string()));
+        assert_eq!(predecessor.expires_at, Some(datetime!(2023-03-01 0:00)));
+
+        let error = mockdb
+            .rotate_api_key(
+                "key_id1",
+                datetime!(2023-03-01 0:00),
+                new_api_key("key_id3"),
+            )
+            .await
+            .unwrap_err();
+        assert!(error.current_context().is_db_not_found());
+        assert_eq!(
+            mockdb
+                .list_api_keys_by_merchant_id(&merchant_id, None, None)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
     #[allow(clippy::unwrap_used)]
     #[tokio::test]
     async fn test_api_keys_cache() {
@@ -505,6 +703,10 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            permissions: None,
+            ip_allowlist: None,
+            predecessor_key_id: None,
+            successor_key_id: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
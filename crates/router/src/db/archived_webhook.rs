@@ -0,0 +1,260 @@
+use common_utils::{ext_traits::AsyncExt, types::keymanager::KeyManagerState};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::{
+        domain::{
+            self,
+            behaviour::{Conversion, ReverseConversion},
+        },
+        storage::{self, enums},
+    },
+};
+
+#[async_trait::async_trait]
+pub trait ArchivedWebhookInterface
+where
+    domain::ArchivedWebhook:
+        Conversion<DstType = storage::ArchivedWebhook, NewDstType = storage::ArchivedWebhookNew>,
+{
+    async fn insert_archived_webhook(
+        &self,
+        state: &KeyManagerState,
+        archived_webhook: domain::ArchivedWebhook,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::ArchivedWebhook, errors::StorageError>;
+
+    async fn find_archived_webhook_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::ArchivedWebhook, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_archived_webhooks_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: Option<String>,
+        status: Option<enums::ArchivedWebhookStatus>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::ArchivedWebhook>, errors::StorageError>;
+
+    async fn update_archived_webhook_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        archived_webhook_update: storage::ArchivedWebhookUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::ArchivedWebhook, errors::StorageError>;
+
+    async fn delete_archived_webhooks_by_merchant_id_created_before(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ArchivedWebhookInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_archived_webhook(
+        &self,
+        state: &KeyManagerState,
+        archived_webhook: domain::ArchivedWebhook,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::ArchivedWebhook, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        archived_webhook
+            .construct_new()
+            .await
+            .change_context(errors::StorageError::EncryptionError)?
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn find_archived_webhook_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::ArchivedWebhook, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ArchivedWebhook::find_by_merchant_id_webhook_id(&conn, merchant_id, webhook_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn list_archived_webhooks_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: Option<String>,
+        status: Option<enums::ArchivedWebhookStatus>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::ArchivedWebhook>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ArchivedWebhook::list_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            merchant_connector_id,
+            status,
+            created_after,
+            created_before,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|archived_webhooks| async {
+            let mut domain_archived_webhooks = Vec::with_capacity(archived_webhooks.len());
+            for archived_webhook in archived_webhooks.into_iter() {
+                domain_archived_webhooks.push(
+                    archived_webhook
+                        .convert(
+                            state,
+                            merchant_key_store.key.get_inner(),
+                            merchant_key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_archived_webhooks)
+        })
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_archived_webhook_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        webhook_id: &str,
+        archived_webhook_update: storage::ArchivedWebhookUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::ArchivedWebhook, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ArchivedWebhook::update_by_merchant_id_webhook_id(
+            &conn,
+            merchant_id,
+            webhook_id,
+            archived_webhook_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?
+        .convert(
+            state,
+            merchant_key_store.key.get_inner(),
+            merchant_key_store.merchant_id.clone().into(),
+        )
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_archived_webhooks_by_merchant_id_created_before(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ArchivedWebhook::delete_by_merchant_id_created_before(
+            &conn,
+            merchant_id,
+            created_before,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ArchivedWebhookInterface for MockDb {
+    async fn insert_archived_webhook(
+        &self,
+        _state: &KeyManagerState,
+        _archived_webhook: domain::ArchivedWebhook,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::ArchivedWebhook, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_archived_webhook_by_merchant_id_webhook_id(
+        &self,
+        _state: &KeyManagerState,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _webhook_id: &str,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::ArchivedWebhook, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_archived_webhooks_by_merchant_id_constraints(
+        &self,
+        _state: &KeyManagerState,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _merchant_connector_id: Option<String>,
+        _status: Option<enums::ArchivedWebhookStatus>,
+        _created_after: Option<time::PrimitiveDateTime>,
+        _created_before: Option<time::PrimitiveDateTime>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::ArchivedWebhook>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_archived_webhook_by_merchant_id_webhook_id(
+        &self,
+        _state: &KeyManagerState,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _webhook_id: &str,
+        _archived_webhook_update: storage::ArchivedWebhookUpdate,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::ArchivedWebhook, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_archived_webhooks_by_merchant_id_created_before(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,69 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AuditLogInterface {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLog,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError>;
+
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: storage::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLog,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        audit_log
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: storage::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AuditLog::list_by_merchant_id_constraints(&conn, merchant_id, constraints)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for MockDb {
+    async fn insert_audit_log(
+        &self,
+        _audit_log: storage::AuditLog,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _constraints: storage::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
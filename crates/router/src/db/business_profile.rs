@@ -1,3 +1,4 @@
+use async_bb8_diesel::AsyncConnection;
 use common_utils::{ext_traits::AsyncExt, types::keymanager::KeyManagerState};
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
@@ -60,6 +61,15 @@ where
         business_profile_update: domain::BusinessProfileUpdate,
     ) -> CustomResult<domain::BusinessProfile, errors::StorageError>;
 
+    /// Updates the routing algorithm of the business profile and records the change in its
+    /// routing activation history, in a single transaction
+    async fn update_business_profile_and_insert_routing_activation(
+        &self,
+        current_state: domain::BusinessProfile,
+        business_profile_update: domain::BusinessProfileUpdate,
+        routing_activation: storage::RoutingAlgorithmActivation,
+    ) -> CustomResult<storage::RoutingAlgorithmActivation, errors::StorageError>;
+
     async fn delete_business_profile_by_profile_id_merchant_id(
         &self,
         profile_id: &str,
@@ -188,6 +198,36 @@ impl BusinessProfileInterface for Store {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    #[instrument(skip_all)]
+    async fn update_business_profile_and_insert_routing_activation(
+        &self,
+        current_state: domain::BusinessProfile,
+        business_profile_update: domain::BusinessProfileUpdate,
+        routing_activation: storage::RoutingAlgorithmActivation,
+    ) -> CustomResult<storage::RoutingAlgorithmActivation, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let business_profile = Conversion::convert(current_state)
+            .await
+            .change_context(errors::StorageError::EncryptionError)?;
+
+        conn.transaction_async(|conn| async move {
+            business_profile
+                .update_by_profile_id(
+                    &conn,
+                    storage::BusinessProfileUpdateInternal::from(business_profile_update),
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+
+            routing_activation
+                .insert(&conn)
+                .await
+                .map_err(errors::StorageError::from)
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
     #[instrument(skip_all)]
     async fn delete_business_profile_by_profile_id_merchant_id(
         &self,
@@ -368,6 +408,15 @@ impl BusinessProfileInterface for MockDb {
             )
     }
 
+    async fn update_business_profile_and_insert_routing_activation(
+        &self,
+        _current_state: domain::BusinessProfile,
+        _business_profile_update: domain::BusinessProfileUpdate,
+        _routing_activation: storage::RoutingAlgorithmActivation,
+    ) -> CustomResult<storage::RoutingAlgorithmActivation, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn delete_business_profile_by_profile_id_merchant_id(
         &self,
         profile_id: &str,
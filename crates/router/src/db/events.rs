@@ -84,6 +84,13 @@ where
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
+    async fn list_initial_event_ids_by_profile_id_replay_filters(
+        &self,
+        profile_id: &str,
+        filters: &storage::WebhookReplayJobFilters,
+        limit: i64,
+    ) -> CustomResult<Vec<String>, errors::StorageError>;
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         state: &KeyManagerState,
@@ -330,6 +337,21 @@ impl EventInterface for Store {
         .await
     }
 
+    #[instrument(skip_all)]
+    async fn list_initial_event_ids_by_profile_id_replay_filters(
+        &self,
+        profile_id: &str,
+        filters: &storage::WebhookReplayJobFilters,
+        limit: i64,
+    ) -> CustomResult<Vec<String>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_initial_attempt_ids_by_profile_id_replay_filters(
+            &conn, profile_id, filters, limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn update_event_by_merchant_id_event_id(
         &self,
@@ -656,6 +678,45 @@ impl EventInterface for MockDb {
         Ok(domain_events)
     }
 
+    async fn list_initial_event_ids_by_profile_id_replay_filters(
+        &self,
+        profile_id: &str,
+        filters: &storage::WebhookReplayJobFilters,
+        limit: i64,
+    ) -> CustomResult<Vec<String>, errors::StorageError> {
+        let limit: usize = limit
+            .try_into()
+            .map_err(|_| errors::StorageError::MockDbError)?;
+        let locked_events = self.events.lock().await;
+
+        let mut events = locked_events
+            .iter()
+            .filter(|event| {
+                event.business_profile_id.as_deref() == Some(profile_id)
+                    && event.initial_attempt_id.as_ref() == Some(&event.event_id)
+                    && event.created_at >= filters.created_after
+                    && event.created_at <= filters.created_before
+                    && filters
+                        .event_types
+                        .as_ref()
+                        .map_or(true, |event_types| event_types.contains(&event.event_type))
+                    && filters.object_ids.as_ref().map_or(true, |object_ids| {
+                        object_ids.contains(&event.primary_object_id)
+                    })
+                    && (filters.delivery_status
+                        != Some(storage::enums::WebhookDeliveryStatus::Failed)
+                        || !event.is_webhook_notified)
+            })
+            .collect::<Vec<_>>();
+        events.sort_by_key(|event| event.created_at);
+
+        Ok(events
+            .into_iter()
+            .take(limit)
+            .map(|event| event.event_id.clone())
+            .collect())
+    }
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         state: &KeyManagerState,
@@ -795,6 +856,7 @@ mod tests {
                     metadata: Some(EventMetadata::Payment {
                         payment_id: payment_id.into(),
                     }),
+                    webhook_endpoint_id: None,
                 },
                 &merchant_key_store,
             )
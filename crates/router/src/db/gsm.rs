@@ -1,6 +1,10 @@
+use std::collections::HashSet;
+
+use async_bb8_diesel::AsyncConnection;
 use diesel_models::gsm as storage;
 use error_stack::report;
 use router_env::{instrument, tracing};
+use storage_impl::redis::cache::{self, CacheKind};
 
 use super::MockDb;
 use crate::{
@@ -49,6 +53,39 @@ pub trait GsmInterface {
         code: String,
         message: String,
     ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn find_gsm_pattern_rules(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError>;
+
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError>;
+
+    /// Creates the new rules and updates the existing ones in a single transaction, and returns
+    /// the rules as written.
+    async fn import_gsm_rules(
+        &self,
+        new_rules: Vec<storage::GatewayStatusMappingNew>,
+        updated_rules: Vec<storage::GatewayStatusMappingNew>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError>;
+}
+
+/// The key of the pattern rules of a connector flow in the gsm pattern rules cache.
+pub fn get_gsm_pattern_rules_cache_key(connector: &str, flow: &str, sub_flow: &str) -> String {
+    format!("gsm_pattern_rules_{connector}_{flow}_{sub_flow}")
+}
+
+fn get_gsm_pattern_rules_cache_kind(
+    connector: &str,
+    flow: &str,
+    sub_flow: &str,
+) -> CacheKind<'static> {
+    CacheKind::GsmPatternRules(get_gsm_pattern_rules_cache_key(connector, flow, sub_flow).into())
 }
 
 #[async_trait::async_trait]
@@ -58,10 +95,15 @@ impl GsmInterface for Store {
         &self,
         rule: storage::GatewayStatusMappingNew,
     ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        rule.insert(&conn)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
+        let cache_kind =
+            get_gsm_pattern_rules_cache_kind(&rule.connector, &rule.flow, &rule.sub_flow);
+        cache::publish_and_redact(self, cache_kind, || async move {
+            let conn = connection::pg_connection_write(self).await?;
+            rule.insert(&conn)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        })
+        .await
     }
 
     #[instrument(skip_all)]
@@ -106,10 +148,14 @@ impl GsmInterface for Store {
         message: String,
         data: storage::GatewayStatusMappingUpdate,
     ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::GatewayStatusMap::update(&conn, connector, flow, sub_flow, code, message, data)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
+        let cache_kind = get_gsm_pattern_rules_cache_kind(&connector, &flow, &sub_flow);
+        cache::publish_and_redact(self, cache_kind, || async move {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::GatewayStatusMap::update(&conn, connector, flow, sub_flow, code, message, data)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        })
+        .await
     }
 
     #[instrument(skip_all)]
@@ -121,11 +167,107 @@ impl GsmInterface for Store {
         code: String,
         message: String,
     ) -> CustomResult<bool, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::GatewayStatusMap::delete(&conn, connector, flow, sub_flow, code, message)
+        let cache_kind = get_gsm_pattern_rules_cache_kind(&connector, &flow, &sub_flow);
+        cache::publish_and_redact(self, cache_kind, || async move {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::GatewayStatusMap::delete(&conn, connector, flow, sub_flow, code, message)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        })
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_gsm_pattern_rules(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::find_pattern_rules(&conn, connector, flow, sub_flow)
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::list_by_connector(&conn, connector)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn import_gsm_rules(
+        &self,
+        new_rules: Vec<storage::GatewayStatusMappingNew>,
+        updated_rules: Vec<storage::GatewayStatusMappingNew>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        let cache_kinds = new_rules
+            .iter()
+            .chain(updated_rules.iter())
+            .map(|rule| {
+                (
+                    rule.connector.clone(),
+                    rule.flow.clone(),
+                    rule.sub_flow.clone(),
+                )
+            })
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(|(connector, flow, sub_flow)| {
+                get_gsm_pattern_rules_cache_kind(&connector, &flow, &sub_flow)
+            })
+            .collect::<Vec<_>>();
+
+        let conn = connection::pg_connection_write(self).await?;
+        let imported_rules = conn
+            .transaction_async(|conn| async move {
+                let mut imported_rules = Vec::with_capacity(new_rules.len() + updated_rules.len());
+                for rule in new_rules {
+                    imported_rules.push(
+                        rule.insert(&conn)
+                            .await
+                            .map_err(errors::StorageError::from)?,
+                    );
+                }
+
+                for rule in updated_rules {
+                    imported_rules.push(
+                        storage::GatewayStatusMap::update(
+                            &conn,
+                            rule.connector,
+                            rule.flow,
+                            rule.sub_flow,
+                            rule.code,
+                            rule.message,
+                            storage::GatewayStatusMappingUpdate {
+                                decision: Some(rule.decision),
+                                status: Some(rule.status),
+                                router_error: Some(rule.router_error),
+                                step_up_possible: Some(rule.step_up_possible),
+                                unified_code: rule.unified_code,
+                                unified_message: rule.unified_message,
+                                match_type: Some(rule.match_type),
+                            },
+                        )
+                        .await
+                        .map_err(errors::StorageError::from)?,
+                    );
+                }
+
+                Ok(imported_rules)
+            })
+            .await
+            .map_err(|error| report!(error))?;
+
+        cache::publish_into_redact_channel(self, cache_kinds).await?;
+        Ok(imported_rules)
+    }
 }
 
 #[async_trait::async_trait]
@@ -181,4 +323,28 @@ impl GsmInterface for MockDb {
     ) -> CustomResult<bool, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_gsm_pattern_rules(
+        &self,
+        _connector: String,
+        _flow: String,
+        _sub_flow: String,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_gsm_rules(
+        &self,
+        _connector: Option<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn import_gsm_rules(
+        &self,
+        _new_rules: Vec<storage::GatewayStatusMappingNew>,
+        _updated_rules: Vec<storage::GatewayStatusMappingNew>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -44,6 +44,8 @@ use crate::{
     db::{
         address::AddressInterface,
         api_keys::ApiKeyInterface,
+        archived_webhook::ArchivedWebhookInterface,
+        audit_log::AuditLogInterface,
         authentication::AuthenticationInterface,
         authorization::AuthorizationInterface,
         business_profile::BusinessProfileInterface,
@@ -69,6 +71,8 @@ use crate::{
         reverse_lookup::ReverseLookupInterface,
         routing_algorithm::RoutingAlgorithmInterface,
         unified_translations::UnifiedTranslationsInterface,
+        webhook_endpoint::WebhookEndpointInterface,
+        webhook_replay_job::WebhookReplayJobInterface,
         CommonStorageInterface, GlobalStorageInterface, MasterKeyInterface, StorageInterface,
     },
     services::{authentication, kafka::KafkaProducer, Store},
@@ -232,6 +236,17 @@ impl ApiKeyInterface for KafkaStore {
             .await
     }
 
+    async fn rotate_api_key(
+        &self,
+        key_id: &str,
+        expires_at: time::PrimitiveDateTime,
+        successor: storage::ApiKeyNew,
+    ) -> CustomResult<(storage::ApiKey, storage::ApiKey), errors::StorageError> {
+        self.diesel_store
+            .rotate_api_key(key_id, expires_at, successor)
+            .await
+    }
+
     async fn revoke_api_key(
         &self,
         merchant_id: &id_type::MerchantId,
@@ -271,6 +286,113 @@ impl ApiKeyInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl ArchivedWebhookInterface for KafkaStore {
+    async fn insert_archived_webhook(
+        &self,
+        state: &KeyManagerState,
+        archived_webhook: domain::ArchivedWebhook,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::ArchivedWebhook, errors::StorageError> {
+        self.diesel_store
+            .insert_archived_webhook(state, archived_webhook, merchant_key_store)
+            .await
+    }
+
+    async fn find_archived_webhook_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        webhook_id: &str,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::ArchivedWebhook, errors::StorageError> {
+        self.diesel_store
+            .find_archived_webhook_by_merchant_id_webhook_id(
+                state,
+                merchant_id,
+                webhook_id,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn list_archived_webhooks_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: Option<String>,
+        status: Option<enums::ArchivedWebhookStatus>,
+        created_after: Option<PrimitiveDateTime>,
+        created_before: Option<PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::ArchivedWebhook>, errors::StorageError> {
+        self.diesel_store
+            .list_archived_webhooks_by_merchant_id_constraints(
+                state,
+                merchant_id,
+                merchant_connector_id,
+                status,
+                created_after,
+                created_before,
+                limit,
+                offset,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn update_archived_webhook_by_merchant_id_webhook_id(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        webhook_id: &str,
+        archived_webhook_update: storage::ArchivedWebhookUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::ArchivedWebhook, errors::StorageError> {
+        self.diesel_store
+            .update_archived_webhook_by_merchant_id_webhook_id(
+                state,
+                merchant_id,
+                webhook_id,
+                archived_webhook_update,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn delete_archived_webhooks_by_merchant_id_created_before(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        created_before: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .delete_archived_webhooks_by_merchant_id_created_before(merchant_id, created_before)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for KafkaStore {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLog,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        self.diesel_store.insert_audit_log(audit_log).await
+    }
+
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        constraints: storage::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        self.diesel_store
+            .list_audit_logs_by_merchant_id_constraints(merchant_id, constraints)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl CardsInfoInterface for KafkaStore {
     async fn get_card_info(
@@ -752,6 +874,17 @@ impl EventInterface for KafkaStore {
             .await
     }
 
+    async fn list_initial_event_ids_by_profile_id_replay_filters(
+        &self,
+        profile_id: &str,
+        filters: &storage::WebhookReplayJobFilters,
+        limit: i64,
+    ) -> CustomResult<Vec<String>, errors::StorageError> {
+        self.diesel_store
+            .list_initial_event_ids_by_profile_id_replay_filters(profile_id, filters, limit)
+            .await
+    }
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         state: &KeyManagerState,
@@ -2415,6 +2548,21 @@ impl BusinessProfileInterface for KafkaStore {
             .await
     }
 
+    async fn update_business_profile_and_insert_routing_activation(
+        &self,
+        current_state: domain::BusinessProfile,
+        business_profile_update: domain::BusinessProfileUpdate,
+        routing_activation: storage::RoutingAlgorithmActivation,
+    ) -> CustomResult<storage::RoutingAlgorithmActivation, errors::StorageError> {
+        self.diesel_store
+            .update_business_profile_and_insert_routing_activation(
+                current_state,
+                business_profile_update,
+                routing_activation,
+            )
+            .await
+    }
+
     async fn delete_business_profile_by_profile_id_merchant_id(
         &self,
         profile_id: &str,
@@ -2560,6 +2708,36 @@ impl RoutingAlgorithmInterface for KafkaStore {
             )
             .await
     }
+
+    async fn find_routing_algorithm_activation_by_activation_id_profile_id(
+        &self,
+        activation_id: &str,
+        profile_id: &str,
+    ) -> CustomResult<storage::RoutingAlgorithmActivation, errors::StorageError> {
+        self.diesel_store
+            .find_routing_algorithm_activation_by_activation_id_profile_id(
+                activation_id,
+                profile_id,
+            )
+            .await
+    }
+
+    async fn list_routing_algorithm_activations_by_profile_id_transaction_type(
+        &self,
+        profile_id: &str,
+        transaction_type: &enums::TransactionType,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::RoutingAlgorithmActivation>, errors::StorageError> {
+        self.diesel_store
+            .list_routing_algorithm_activations_by_profile_id_transaction_type(
+                profile_id,
+                transaction_type,
+                limit,
+                offset,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -2623,6 +2801,34 @@ impl GsmInterface for KafkaStore {
             .delete_gsm_rule(connector, flow, sub_flow, code, message)
             .await
     }
+
+    async fn find_gsm_pattern_rules(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        self.diesel_store
+            .find_gsm_pattern_rules(connector, flow, sub_flow)
+            .await
+    }
+
+    async fn list_gsm_rules(
+        &self,
+        connector: Option<String>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        self.diesel_store.list_gsm_rules(connector).await
+    }
+
+    async fn import_gsm_rules(
+        &self,
+        new_rules: Vec<storage::GatewayStatusMappingNew>,
+        updated_rules: Vec<storage::GatewayStatusMappingNew>,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        self.diesel_store
+            .import_gsm_rules(new_rules, updated_rules)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3392,3 +3598,193 @@ impl UserAuthenticationMethodInterface for KafkaStore {
             .await
     }
 }
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for KafkaStore {
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .insert_webhook_endpoint(webhook_endpoint)
+            .await
+    }
+
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .find_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, endpoint_id)
+            .await
+    }
+
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        self.diesel_store
+            .list_webhook_endpoints_by_merchant_id_profile_id(merchant_id, profile_id)
+            .await
+    }
+
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .update_webhook_endpoint_by_merchant_id_endpoint_id(
+                merchant_id,
+                endpoint_id,
+                webhook_endpoint_update,
+            )
+            .await
+    }
+
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, endpoint_id)
+            .await
+    }
+
+    async fn record_webhook_endpoint_delivery_outcome(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+        is_delivered: bool,
+        window: i32,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .record_webhook_endpoint_delivery_outcome(
+                merchant_id,
+                endpoint_id,
+                is_delivered,
+                window,
+            )
+            .await
+    }
+
+    async fn suspend_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        endpoint_id: &str,
+        suspended_at: PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::WebhookEndpoint>, errors::StorageError> {
+        self.diesel_store
+            .suspend_webhook_endpoint_by_merchant_id_endpoint_id(
+                merchant_id,
+                endpoint_id,
+                suspended_at,
+            )
+            .await
+    }
+
+    async fn insert_webhook_endpoint_queued_event(
+        &self,
+        queued_event: storage::WebhookEndpointQueuedEventNew,
+    ) -> CustomResult<storage::WebhookEndpointQueuedEvent, errors::StorageError> {
+        self.diesel_store
+            .insert_webhook_endpoint_queued_event(queued_event)
+            .await
+    }
+
+    async fn list_webhook_endpoint_queued_events_by_endpoint_id(
+        &self,
+        endpoint_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookEndpointQueuedEvent>, errors::StorageError> {
+        self.diesel_store
+            .list_webhook_endpoint_queued_events_by_endpoint_id(endpoint_id)
+            .await
+    }
+
+    async fn delete_webhook_endpoint_queued_event_by_endpoint_id_event_id(
+        &self,
+        endpoint_id: &str,
+        event_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_webhook_endpoint_queued_event_by_endpoint_id_event_id(endpoint_id, event_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookReplayJobInterface for KafkaStore {
+    async fn insert_webhook_replay_job(
+        &self,
+        webhook_replay_job: storage::WebhookReplayJobNew,
+    ) -> CustomResult<storage::WebhookReplayJob, errors::StorageError> {
+        self.diesel_store
+            .insert_webhook_replay_job(webhook_replay_job)
+            .await
+    }
+
+    async fn find_webhook_replay_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        job_id: &str,
+    ) -> CustomResult<storage::WebhookReplayJob, errors::StorageError> {
+        self.diesel_store
+            .find_webhook_replay_job_by_merchant_id_job_id(merchant_id, job_id)
+            .await
+    }
+
+    async fn update_webhook_replay_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        job_id: &str,
+        webhook_replay_job_update: storage::WebhookReplayJobUpdate,
+    ) -> CustomResult<storage::WebhookReplayJob, errors::StorageError> {
+        self.diesel_store
+            .update_webhook_replay_job_by_merchant_id_job_id(
+                merchant_id,
+                job_id,
+                webhook_replay_job_update,
+            )
+            .await
+    }
+
+    async fn insert_webhook_replay_job_events(
+        &self,
+        webhook_replay_job_events: Vec<storage::WebhookReplayJobEventNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .insert_webhook_replay_job_events(webhook_replay_job_events)
+            .await
+    }
+
+    async fn list_webhook_replay_job_events_by_job_id_status(
+        &self,
+        job_id: &str,
+        status: Option<enums::WebhookReplayEventStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::WebhookReplayJobEvent>, errors::StorageError> {
+        self.diesel_store
+            .list_webhook_replay_job_events_by_job_id_status(job_id, status, limit, offset)
+            .await
+    }
+
+    async fn update_webhook_replay_job_event_by_job_id_event_id(
+        &self,
+        job_id: &str,
+        event_id: &str,
+        webhook_replay_job_event_update: storage::WebhookReplayJobEventUpdate,
+    ) -> CustomResult<storage::WebhookReplayJobEvent, errors::StorageError> {
+        self.diesel_store
+            .update_webhook_replay_job_event_by_job_id_event_id(
+                job_id,
+                event_id,
+                webhook_replay_job_event_update,
+            )
+            .await
+    }
+}
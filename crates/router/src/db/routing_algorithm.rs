@@ -57,6 +57,20 @@ pub trait RoutingAlgorithmInterface {
         limit: i64,
         offset: i64,
     ) -> StorageResult<Vec<routing_storage::RoutingProfileMetadata>>;
+
+    async fn find_routing_algorithm_activation_by_activation_id_profile_id(
+        &self,
+        activation_id: &str,
+        profile_id: &str,
+    ) -> StorageResult<routing_storage::RoutingAlgorithmActivation>;
+
+    async fn list_routing_algorithm_activations_by_profile_id_transaction_type(
+        &self,
+        profile_id: &str,
+        transaction_type: &common_enums::TransactionType,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<routing_storage::RoutingAlgorithmActivation>>;
 }
 
 #[async_trait::async_trait]
@@ -172,6 +186,42 @@ impl RoutingAlgorithmInterface for Store {
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn find_routing_algorithm_activation_by_activation_id_profile_id(
+        &self,
+        activation_id: &str,
+        profile_id: &str,
+    ) -> StorageResult<routing_storage::RoutingAlgorithmActivation> {
+        let conn = connection::pg_connection_write(self).await?;
+        routing_storage::RoutingAlgorithmActivation::find_by_activation_id_profile_id(
+            &conn,
+            activation_id,
+            profile_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_routing_algorithm_activations_by_profile_id_transaction_type(
+        &self,
+        profile_id: &str,
+        transaction_type: &common_enums::TransactionType,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<routing_storage::RoutingAlgorithmActivation>> {
+        let conn = connection::pg_connection_write(self).await?;
+        routing_storage::RoutingAlgorithmActivation::list_by_profile_id_transaction_type(
+            &conn,
+            profile_id,
+            transaction_type,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -234,4 +284,22 @@ impl RoutingAlgorithmInterface for MockDb {
     ) -> StorageResult<Vec<routing_storage::RoutingProfileMetadata>> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_routing_algorithm_activation_by_activation_id_profile_id(
+        &self,
+        _activation_id: &str,
+        _profile_id: &str,
+    ) -> StorageResult<routing_storage::RoutingAlgorithmActivation> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_routing_algorithm_activations_by_profile_id_transaction_type(
+        &self,
+        _profile_id: &str,
+        _transaction_type: &common_enums::TransactionType,
+        _limit: i64,
+        _offset: i64,
+    ) -> StorageResult<Vec<routing_storage::RoutingAlgorithmActivation>> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -0,0 +1,304 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait WebhookEndpointInterface {
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError>;
+
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn record_webhook_endpoint_delivery_outcome(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        is_delivered: bool,
+        window: i32,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn suspend_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        suspended_at: time::PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::WebhookEndpoint>, errors::StorageError>;
+
+    async fn insert_webhook_endpoint_queued_event(
+        &self,
+        queued_event: storage::WebhookEndpointQueuedEventNew,
+    ) -> CustomResult<storage::WebhookEndpointQueuedEvent, errors::StorageError>;
+
+    async fn list_webhook_endpoint_queued_events_by_endpoint_id(
+        &self,
+        endpoint_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookEndpointQueuedEvent>, errors::StorageError>;
+
+    async fn delete_webhook_endpoint_queued_event_by_endpoint_id_event_id(
+        &self,
+        endpoint_id: &str,
+        event_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        webhook_endpoint
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::find_by_merchant_id_endpoint_id(&conn, merchant_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::list_by_merchant_id_profile_id(&conn, merchant_id, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::update_by_merchant_id_endpoint_id(
+            &conn,
+            merchant_id,
+            endpoint_id,
+            webhook_endpoint_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::delete_by_merchant_id_endpoint_id(&conn, merchant_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn record_webhook_endpoint_delivery_outcome(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        is_delivered: bool,
+        window: i32,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::record_delivery_outcome_by_merchant_id_endpoint_id(
+            &conn,
+            merchant_id,
+            endpoint_id,
+            is_delivered,
+            window,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn suspend_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        endpoint_id: &str,
+        suspended_at: time::PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::WebhookEndpoint>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::suspend_by_merchant_id_endpoint_id(
+            &conn,
+            merchant_id,
+            endpoint_id,
+            suspended_at,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_webhook_endpoint_queued_event(
+        &self,
+        queued_event: storage::WebhookEndpointQueuedEventNew,
+    ) -> CustomResult<storage::WebhookEndpointQueuedEvent, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        queued_event
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_endpoint_queued_events_by_endpoint_id(
+        &self,
+        endpoint_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookEndpointQueuedEvent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpointQueuedEvent::list_by_endpoint_id(&conn, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_webhook_endpoint_queued_event_by_endpoint_id_event_id(
+        &self,
+        endpoint_id: &str,
+        event_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpointQueuedEvent::delete_by_endpoint_id_event_id(
+            &conn,
+            endpoint_id,
+            event_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for MockDb {
+    async fn insert_webhook_endpoint(
+        &self,
+        _webhook_endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_webhook_endpoints_by_merchant_id_profile_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _endpoint_id: &str,
+        _webhook_endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn record_webhook_endpoint_delivery_outcome(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _endpoint_id: &str,
+        _is_delivered: bool,
+        _window: i32,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn suspend_webhook_endpoint_by_merchant_id_endpoint_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _endpoint_id: &str,
+        _suspended_at: time::PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::WebhookEndpoint>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_webhook_endpoint_queued_event(
+        &self,
+        _queued_event: storage::WebhookEndpointQueuedEventNew,
+    ) -> CustomResult<storage::WebhookEndpointQueuedEvent, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_webhook_endpoint_queued_events_by_endpoint_id(
+        &self,
+        _endpoint_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookEndpointQueuedEvent>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_webhook_endpoint_queued_event_by_endpoint_id_event_id(
+        &self,
+        _endpoint_id: &str,
+        _event_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
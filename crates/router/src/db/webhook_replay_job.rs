@@ -0,0 +1,192 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait WebhookReplayJobInterface {
+    async fn insert_webhook_replay_job(
+        &self,
+        webhook_replay_job: storage::WebhookReplayJobNew,
+    ) -> CustomResult<storage::WebhookReplayJob, errors::StorageError>;
+
+    async fn find_webhook_replay_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+    ) -> CustomResult<storage::WebhookReplayJob, errors::StorageError>;
+
+    async fn update_webhook_replay_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        webhook_replay_job_update: storage::WebhookReplayJobUpdate,
+    ) -> CustomResult<storage::WebhookReplayJob, errors::StorageError>;
+
+    async fn insert_webhook_replay_job_events(
+        &self,
+        webhook_replay_job_events: Vec<storage::WebhookReplayJobEventNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn list_webhook_replay_job_events_by_job_id_status(
+        &self,
+        job_id: &str,
+        status: Option<enums::WebhookReplayEventStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::WebhookReplayJobEvent>, errors::StorageError>;
+
+    async fn update_webhook_replay_job_event_by_job_id_event_id(
+        &self,
+        job_id: &str,
+        event_id: &str,
+        webhook_replay_job_event_update: storage::WebhookReplayJobEventUpdate,
+    ) -> CustomResult<storage::WebhookReplayJobEvent, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookReplayJobInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_webhook_replay_job(
+        &self,
+        webhook_replay_job: storage::WebhookReplayJobNew,
+    ) -> CustomResult<storage::WebhookReplayJob, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        webhook_replay_job
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_replay_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+    ) -> CustomResult<storage::WebhookReplayJob, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookReplayJob::find_by_merchant_id_job_id(&conn, merchant_id, job_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_replay_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        webhook_replay_job_update: storage::WebhookReplayJobUpdate,
+    ) -> CustomResult<storage::WebhookReplayJob, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookReplayJob::update_by_merchant_id_job_id(
+            &conn,
+            merchant_id,
+            job_id,
+            webhook_replay_job_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_webhook_replay_job_events(
+        &self,
+        webhook_replay_job_events: Vec<storage::WebhookReplayJobEventNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookReplayJobEventNew::batch_insert(webhook_replay_job_events, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_replay_job_events_by_job_id_status(
+        &self,
+        job_id: &str,
+        status: Option<enums::WebhookReplayEventStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::WebhookReplayJobEvent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookReplayJobEvent::list_by_job_id_status(&conn, job_id, status, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_replay_job_event_by_job_id_event_id(
+        &self,
+        job_id: &str,
+        event_id: &str,
+        webhook_replay_job_event_update: storage::WebhookReplayJobEventUpdate,
+    ) -> CustomResult<storage::WebhookReplayJobEvent, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookReplayJobEvent::update_by_job_id_event_id(
+            &conn,
+            job_id,
+            event_id,
+            webhook_replay_job_event_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookReplayJobInterface for MockDb {
+    async fn insert_webhook_replay_job(
+        &self,
+        _webhook_replay_job: storage::WebhookReplayJobNew,
+    ) -> CustomResult<storage::WebhookReplayJob, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_webhook_replay_job_by_merchant_id_job_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _job_id: &str,
+    ) -> CustomResult<storage::WebhookReplayJob, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_webhook_replay_job_by_merchant_id_job_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _job_id: &str,
+        _webhook_replay_job_update: storage::WebhookReplayJobUpdate,
+    ) -> CustomResult<storage::WebhookReplayJob, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_webhook_replay_job_events(
+        &self,
+        _webhook_replay_job_events: Vec<storage::WebhookReplayJobEventNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_webhook_replay_job_events_by_job_id_status(
+        &self,
+        _job_id: &str,
+        _status: Option<enums::WebhookReplayEventStatus>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::WebhookReplayJobEvent>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_webhook_replay_job_event_by_job_id_event_id(
+        &self,
+        _job_id: &str,
+        _event_id: &str,
+        _webhook_replay_job_event_update: storage::WebhookReplayJobEventUpdate,
+    ) -> CustomResult<storage::WebhookReplayJobEvent, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
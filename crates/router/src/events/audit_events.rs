@@ -1,8 +1,12 @@
+use common_enums::{AuditAction, AuditEntityType};
 use common_utils::types::MinorUnit;
 use diesel_models::fraud_check::FraudCheck;
 use events::{Event, EventInfo};
 use serde::Serialize;
 use time::PrimitiveDateTime;
+
+use crate::core::payments::routing::circuit_breaker::CircuitState;
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "event_type")]
 pub enum AuditEventType {
@@ -27,6 +31,19 @@ pub enum AuditEventType {
         capture_amount: Option<MinorUnit>,
         multiple_capture_count: Option<i16>,
     },
+    ConnectorCircuitStateChanged {
+        connector: String,
+        merchant_connector_id: String,
+        previous_state: CircuitState,
+        current_state: CircuitState,
+    },
+    ConfigurationChanged {
+        audit_id: String,
+        entity_type: AuditEntityType,
+        entity_id: String,
+        action: AuditAction,
+        actor: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -65,6 +82,10 @@ impl Event for AuditEvent {
             AuditEventType::RefundSuccess => "refund_success",
             AuditEventType::RefundFail => "refund_fail",
             AuditEventType::PaymentCancelled { .. } => "payment_cancelled",
+            AuditEventType::ConnectorCircuitStateChanged { .. } => {
+                "connector_circuit_state_changed"
+            }
+            AuditEventType::ConfigurationChanged { .. } => "configuration_changed",
         };
         format!(
             "{event_type}-{}",
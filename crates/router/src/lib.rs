@@ -58,6 +58,7 @@ pub mod headers {
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
     pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+    pub const IDEMPOTENT_REPLAYED: &str = "Idempotent-Replayed";
     pub const NONCE: &str = "nonce";
     pub const TIMESTAMP: &str = "Timestamp";
     pub const TOKEN: &str = "token";
@@ -73,6 +74,7 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURE_V1: &str = "X-Webhook-Signature";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
@@ -161,7 +163,9 @@ pub fn mk_app(
             .service(routes::User::server(state.clone()))
             .service(routes::ConnectorOnboarding::server(state.clone()))
             .service(routes::Verify::server(state.clone()))
-            .service(routes::WebhookEvents::server(state.clone()));
+            .service(routes::WebhookEvents::server(state.clone()))
+            .service(routes::ArchivedWebhooks::server(state.clone()))
+            .service(routes::AuditLog::server(state.clone()));
     }
 
     #[cfg(feature = "payouts")]
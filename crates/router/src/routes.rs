@@ -3,6 +3,8 @@ pub mod api_keys;
 pub mod app;
 pub mod apple_pay_certificates_migration;
 #[cfg(feature = "olap")]
+pub mod audit_log;
+#[cfg(feature = "olap")]
 pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
@@ -49,6 +51,8 @@ pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(feature = "olap")]
+pub mod webhook_endpoints;
+#[cfg(feature = "olap")]
 pub mod webhook_events;
 pub mod webhooks;
 
@@ -65,7 +69,9 @@ pub use self::app::{
     Refunds, SessionState, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
+pub use self::app::{
+    ArchivedWebhooks, AuditLog, Blocklist, Organization, Routing, Verify, WebhookEvents,
+};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
 #[cfg(all(
@@ -327,7 +327,7 @@ pub async fn connector_create(
         state,
         &req,
         payload,
-        |state, _, req, _| create_connector(state, req, &merchant_id),
+        |state, _, req, req_state| create_connector(state, req_state, req, &merchant_id),
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -370,7 +370,7 @@ pub async fn connector_create(
         state,
         &req,
         payload,
-        |state, _, req, _| create_connector(state, req, &merchant_id),
+        |state, _, req, req_state| create_connector(state, req_state, req, &merchant_id),
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -583,9 +583,10 @@ pub async fn connector_update(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth, req, _| {
+        |state, auth, req, req_state| {
             update_connector(
                 state,
+                req_state,
                 &merchant_id,
                 auth.profile_id,
                 &merchant_connector_id,
@@ -642,7 +643,7 @@ pub async fn connector_update(
         state,
         &req,
         payload,
-        |state, _, req, _| update_connector(state, &merchant_id, None, &id, req),
+        |state, _, req, req_state| update_connector(state, req_state, &merchant_id, None, &id, req),
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -697,7 +698,9 @@ pub async fn connector_delete(
         state,
         &req,
         payload,
-        |state, _, req, _| delete_connector(state, req.merchant_id, req.merchant_connector_id),
+        |state, _, req, req_state| {
+            delete_connector(state, req_state, req.merchant_id, req.merchant_connector_id)
+        },
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -753,7 +756,7 @@ pub async fn connector_delete(
         state,
         &req,
         payload,
-        |state, _, req, _| delete_connector(state, merchant_id.clone(), req.id),
+        |state, _, req, req_state| delete_connector(state, req_state, merchant_id.clone(), req.id),
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -837,7 +840,7 @@ pub async fn business_profile_create(
         state,
         &req,
         payload,
-        |state, _, req, _| create_business_profile(state, req, &merchant_id),
+        |state, _, req, req_state| create_business_profile(state, req_state, req, &merchant_id),
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -873,7 +876,7 @@ pub async fn business_profile_create(
         state,
         &req,
         payload,
-        |state, _, req, _| create_business_profile(state, req, &merchant_id),
+        |state, _, req, req_state| create_business_profile(state, req_state, req, &merchant_id),
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -975,7 +978,9 @@ pub async fn business_profile_update(
         state,
         &req,
         json_payload.into_inner(),
-        |state, _, req, _| update_business_profile(state, &profile_id, &merchant_id, req),
+        |state, _, req, req_state| {
+            update_business_profile(state, req_state, &profile_id, &merchant_id, req)
+        },
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -1012,7 +1017,9 @@ pub async fn business_profile_update(
         state,
         &req,
         json_payload.into_inner(),
-        |state, _, req, _| update_business_profile(state, &profile_id, &merchant_id, req),
+        |state, _, req, req_state| {
+            update_business_profile(state, req_state, &profile_id, &merchant_id, req)
+        },
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -1040,7 +1047,9 @@ pub async fn business_profile_delete(
         state,
         &req,
         profile_id,
-        |state, _, profile_id, _| delete_business_profile(state, profile_id, &merchant_id),
+        |state, _, profile_id, req_state| {
+            delete_business_profile(state, req_state, profile_id, &merchant_id)
+        },
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     )
@@ -1099,6 +1108,37 @@ pub async fn toggle_connector_agnostic_mit(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileWebhookSigningKeyRotate))]
+pub async fn business_profile_rotate_webhook_signing_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    json_payload: web::Json<api_models::admin::WebhookSigningKeyRotateRequest>,
+) -> HttpResponse {
+    let flow = Flow::BusinessProfileWebhookSigningKeyRotate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, req_state| {
+            rotate_webhook_signing_key(state, req_state, &merchant_id, &profile_id, req)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Merchant Account - KV Status
 ///
 /// Toggle KV mode for the Merchant Account
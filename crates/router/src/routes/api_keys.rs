@@ -41,8 +41,8 @@ pub async fn api_key_create(
         state,
         &req,
         payload,
-        |state, _, payload, _| async {
-            api_keys::create_api_key(state, payload, merchant_id.clone()).await
+        |state, _, payload, req_state| async {
+            api_keys::create_api_key(state, req_state, payload, merchant_id.clone()).await
         },
         auth::auth_type(
             &auth::AdminApiAuth,
@@ -138,7 +138,7 @@ pub async fn api_key_update(
         state,
         &req,
         payload,
-        |state, _, payload, _| api_keys::update_api_key(state, payload),
+        |state, _, payload, req_state| api_keys::update_api_key(state, req_state, payload),
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -184,7 +184,9 @@ pub async fn api_key_revoke(
         state,
         &req,
         (&merchant_id, &key_id),
-        |state, _, (merchant_id, key_id), _| api_keys::revoke_api_key(state, merchant_id, key_id),
+        |state, _, (merchant_id, key_id), req_state| {
+            api_keys::revoke_api_key(state, req_state, merchant_id, key_id)
+        },
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -197,6 +199,58 @@ pub async fn api_key_revoke(
     )
     .await
 }
+/// API Key - Rotate
+///
+/// Rotate the specified API Key. A new API Key is issued to replace it, while the rotated API Key
+/// remains valid for a grace period, after which it is revoked.
+#[utoipa::path(
+    post,
+    path = "/api_keys/{merchant_id}/{key_id}/rotate",
+    request_body = RotateApiKeyRequest,
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("key_id" = String, Path, description = "The unique identifier for the API Key")
+    ),
+    responses(
+        (status = 200, description = "API Key rotated", body = RotateApiKeyResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "API Key not found")
+    ),
+    tag = "API Key",
+    operation_id = "Rotate an API Key",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ApiKeyRotate))]
+pub async fn api_key_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    json_payload: web::Json<api_types::RotateApiKeyRequest>,
+) -> impl Responder {
+    let flow = Flow::ApiKeyRotate;
+    let (merchant_id, key_id) = path.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.key_id = key_id;
+    payload.merchant_id.clone_from(&merchant_id);
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, req_state| api_keys::rotate_api_key(state, req_state, payload),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::ApiKeyWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
 /// API Key - List
 ///
 /// List all API Keys associated with your merchant account.
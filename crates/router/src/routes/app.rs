@@ -1,4 +1,4 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, net::IpAddr, sync::Arc};
 
 use actix_web::{web, Scope};
 #[cfg(all(
@@ -25,6 +25,8 @@ use tokio::sync::oneshot;
 
 use self::settings::Tenant;
 #[cfg(feature = "olap")]
+use super::audit_log;
+#[cfg(feature = "olap")]
 use super::blocklist;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::currency;
@@ -49,9 +51,12 @@ use super::routing;
 #[cfg(feature = "olap")]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(feature = "olap")]
+use super::webhooks::{list_archived_webhooks, replay_archived_webhook};
+#[cfg(feature = "olap")]
 use super::{
     admin::*, api_keys::*, apple_pay_certificates_migration, connector_onboarding::*, disputes::*,
-    files::*, gsm::*, payment_link::*, user::*, user_role::*, webhook_events::*,
+    files::*, gsm::*, payment_link::*, user::*, user_role::*, webhook_endpoints::*,
+    webhook_events::*,
 };
 use super::{cache::*, health::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -78,11 +83,25 @@ pub use crate::{
 use crate::{
     configs::{secrets_transformers, Settings},
     db::kafka_store::{KafkaStore, TenantID},
+    services::authentication,
 };
 
 #[derive(Clone)]
 pub struct ReqState {
     pub event_context: events::EventContext<crate::events::EventType, EventsHandler>,
+    /// The authentication of the request, not available outside of API requests
+    pub auth_type: Option<authentication::AuthenticationType>,
+    /// The IP address of the client which made the request, not available outside of API requests
+    pub source_ip: Option<String>,
+}
+
+impl ReqState {
+    /// Returns the user or the API key which made the request
+    pub fn get_actor(&self) -> Option<String> {
+        self.auth_type
+            .as_ref()
+            .and_then(authentication::AuthenticationType::get_actor)
+    }
 }
 
 #[derive(Clone)]
@@ -99,6 +118,8 @@ pub struct SessionState {
     pub pool: AnalyticsProvider,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
     pub request_id: Option<RequestId>,
+    /// The IP address of the peer of the connection the request was received over
+    pub peer_ip_address: Option<IpAddr>,
     pub base_url: String,
     pub tenant: Tenant,
     #[cfg(feature = "olap")]
@@ -113,6 +134,8 @@ impl SessionState {
     pub fn get_req_state(&self) -> ReqState {
         ReqState {
             event_context: events::EventContext::new(self.event_handler.clone()),
+            auth_type: None,
+            source_ip: None,
         }
     }
 }
@@ -123,6 +146,7 @@ pub trait SessionStateInfo {
     fn event_handler(&self) -> EventsHandler;
     fn get_request_id(&self) -> Option<String>;
     fn add_request_id(&mut self, request_id: RequestId);
+    fn get_peer_ip_address(&self) -> Option<IpAddr>;
     #[cfg(feature = "partial-auth")]
     fn get_detached_auth(&self) -> RouterResult<(Blake3, &[u8])>;
     fn session_state(&self) -> SessionState;
@@ -146,6 +170,9 @@ impl SessionStateInfo for SessionState {
         self.store.add_request_id(request_id.to_string());
         self.request_id.replace(request_id);
     }
+    fn get_peer_ip_address(&self) -> Option<IpAddr> {
+        self.peer_ip_address
+    }
 
     #[cfg(feature = "partial-auth")]
     fn get_detached_auth(&self) -> RouterResult<(Blake3, &[u8])> {
@@ -439,6 +466,7 @@ impl AppState {
             pool: self.pools.get(tenant).ok_or_else(err)?.clone(),
             file_storage_client: self.file_storage_client.clone(),
             request_id: self.request_id,
+            peer_ip_address: None,
             base_url: tenant_conf.base_url.clone(),
             tenant: tenant_conf.clone(),
             #[cfg(feature = "email")]
@@ -752,7 +780,26 @@ impl Routing {
                         &TransactionType::Payment,
                     )
                 })),
-            );
+            )
+            .service(
+                web::resource("/simulate").route(web::post().to(routing::routing_simulate_config)),
+            )
+            .service(
+                web::resource("/history").route(web::get().to(|state, req, query_params| {
+                    routing::routing_activation_history(
+                        state,
+                        req,
+                        query_params,
+                        &TransactionType::Payment,
+                    )
+                })),
+            )
+            .service(
+                web::resource("/rollback").route(web::post().to(|state, req, payload| {
+                    routing::routing_rollback_config(state, req, payload, &TransactionType::Payment)
+                })),
+            )
+            .service(web::resource("/diff").route(web::get().to(routing::routing_diff_configs)));
 
         #[cfg(feature = "payouts")]
         {
@@ -845,6 +892,38 @@ impl Routing {
                         )
                     })),
                 );
+            route = route
+                .service(web::resource("/payouts/history").route(web::get().to(
+                    |state, req, query_params| {
+                        routing::routing_activation_history(
+                            state,
+                            req,
+                            query_params,
+                            &TransactionType::Payout,
+                        )
+                    },
+                )))
+                .service(web::resource("/payouts/rollback").route(web::post().to(
+                    |state, req, payload| {
+                        routing::routing_rollback_config(
+                            state,
+                            req,
+                            payload,
+                            &TransactionType::Payout,
+                        )
+                    },
+                )));
+            route = route.service(web::resource("/payouts/{algorithm_id}/schedule").route(
+                web::post().to(|state, req, path, payload| {
+                    routing::routing_schedule_config_activation(
+                        state,
+                        req,
+                        path,
+                        payload,
+                        &TransactionType::Payout,
+                    )
+                }),
+            ));
         }
 
         route = route
@@ -858,6 +937,19 @@ impl Routing {
                         routing::routing_link_config(state, req, path, &TransactionType::Payment)
                     },
                 )),
+            )
+            .service(
+                web::resource("/{algorithm_id}/schedule").route(web::post().to(
+                    |state, req, path, payload| {
+                        routing::routing_schedule_config_activation(
+                            state,
+                            req,
+                            path,
+                            payload,
+                            &TransactionType::Payment,
+                        )
+                    },
+                )),
             );
         route
     }
@@ -1106,6 +1198,18 @@ impl Blocklist {
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct AuditLog;
+
+#[cfg(feature = "olap")]
+impl AuditLog {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/audit_logs")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(audit_log::list_audit_logs)))
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct Organization;
 #[cfg(feature = "olap")]
@@ -1358,6 +1462,7 @@ impl ApiKeys {
                     .route(web::post().to(api_key_update))
                     .route(web::delete().to(api_key_revoke)),
             )
+            .service(web::resource("/{key_id}/rotate").route(web::post().to(api_key_rotate)))
     }
 }
 
@@ -1551,6 +1656,25 @@ impl BusinessProfile {
                     .service(
                         web::resource("/toggle_connector_agnostic_mit")
                             .route(web::post().to(toggle_connector_agnostic_mit)),
+                    )
+                    .service(
+                        web::resource("/rotate_webhook_signing_key")
+                            .route(web::post().to(business_profile_rotate_webhook_signing_key)),
+                    )
+                    .service(
+                        web::resource("/webhook_endpoints")
+                            .route(web::post().to(create_webhook_endpoint))
+                            .route(web::get().to(list_webhook_endpoints)),
+                    )
+                    .service(
+                        web::resource("/webhook_endpoints/{endpoint_id}")
+                            .route(web::get().to(retrieve_webhook_endpoint))
+                            .route(web::post().to(update_webhook_endpoint))
+                            .route(web::delete().to(delete_webhook_endpoint)),
+                    )
+                    .service(
+                        web::resource("/webhook_endpoints/{endpoint_id}/reenable")
+                            .route(web::post().to(reenable_webhook_endpoint)),
                     ),
             )
     }
@@ -1567,6 +1691,9 @@ impl Gsm {
             .service(web::resource("/get").route(web::post().to(get_gsm_rule)))
             .service(web::resource("/update").route(web::post().to(update_gsm_rule)))
             .service(web::resource("/delete").route(web::post().to(delete_gsm_rule)))
+            .service(web::resource("/import").route(web::post().to(import_gsm_rules)))
+            .service(web::resource("/import/csv").route(web::post().to(import_gsm_rules_csv)))
+            .service(web::resource("/export").route(web::get().to(export_gsm_rules)))
     }
 }
 
@@ -1773,6 +1900,10 @@ impl WebhookEvents {
         web::scope("/events/{merchant_id}")
             .app_data(web::Data::new(config))
             .service(web::resource("").route(web::get().to(list_initial_webhook_delivery_attempts)))
+            .service(web::resource("/replay").route(web::post().to(replay_webhook_events)))
+            .service(
+                web::resource("/replay/{job_id}").route(web::get().to(retrieve_webhook_replay_job)),
+            )
             .service(
                 web::scope("/{event_id}")
                     .service(
@@ -1786,3 +1917,19 @@ impl WebhookEvents {
             )
     }
 }
+
+#[cfg(feature = "olap")]
+pub struct ArchivedWebhooks;
+
+#[cfg(feature = "olap")]
+impl ArchivedWebhooks {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/archived_webhooks/{merchant_id}")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(list_archived_webhooks)))
+            .service(
+                web::resource("/{webhook_id}/replay")
+                    .route(web::post().to(replay_archived_webhook)),
+            )
+    }
+}
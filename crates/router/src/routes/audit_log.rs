@@ -0,0 +1,35 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::audit_log as audit_log_api;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, audit_log},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::AuditLogList))]
+pub async fn list_audit_logs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<audit_log_api::AuditLogListConstraints>,
+) -> impl Responder {
+    let flow = Flow::AuditLogList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            audit_log::list_audit_logs(state, auth.merchant_account, constraints)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::UsersRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -31,8 +31,8 @@ pub async fn add_entry_to_blocklist(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, body, _| {
-            blocklist::add_entry_to_blocklist(state, auth.merchant_account, body)
+        |state, auth: auth::AuthenticationData, body, req_state| {
+            blocklist::add_entry_to_blocklist(state, req_state, auth.merchant_account, body)
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth),
@@ -67,8 +67,8 @@ pub async fn remove_entry_from_blocklist(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, body, _| {
-            blocklist::remove_entry_from_blocklist(state, auth.merchant_account, body)
+        |state, auth: auth::AuthenticationData, body, req_state| {
+            blocklist::remove_entry_from_blocklist(state, req_state, auth.merchant_account, body)
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth),
@@ -143,8 +143,8 @@ pub async fn toggle_blocklist_guard(
         state,
         &req,
         query_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, query, _| {
-            blocklist::toggle_blocklist_guard(state, auth.merchant_account, query)
+        |state, auth: auth::AuthenticationData, query, req_state| {
+            blocklist::toggle_blocklist_guard(state, req_state, auth.merchant_account, query)
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth),
@@ -1,3 +1,4 @@
+use actix_multipart::form::MultipartForm;
 use actix_web::{web, HttpRequest, Responder};
 use api_models::gsm as gsm_api_types;
 use router_env::{instrument, tracing, Flow};
@@ -39,7 +40,7 @@ pub async fn create_gsm_rule(
         state.clone(),
         &req,
         payload,
-        |state, _, payload, _| gsm::create_gsm_rule(state, payload),
+        |state, _, payload, req_state| gsm::create_gsm_rule(state, req_state, payload),
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     ))
@@ -114,7 +115,7 @@ pub async fn update_gsm_rule(
         state.clone(),
         &req,
         payload,
-        |state, _, payload, _| gsm::update_gsm_rule(state, payload),
+        |state, _, payload, req_state| gsm::update_gsm_rule(state, req_state, payload),
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     ))
@@ -153,7 +154,112 @@ pub async fn delete_gsm_rule(
         state,
         &req,
         payload,
-        |state, _, payload, _| gsm::delete_gsm_rule(state, payload),
+        |state, _, payload, req_state| gsm::delete_gsm_rule(state, req_state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Import
+///
+/// To create or update Gsm Rules in bulk
+#[utoipa::path(
+    post,
+    path = "/gsm/import",
+    request_body(
+        content = GsmImportRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules validated, and imported if all of them are valid", body = GsmImportResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Import Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesImport))]
+pub async fn import_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::GsmImportRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::GsmRulesImport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, req_state| {
+            gsm::import_gsm_rules(state, req_state, payload, gsm::GsmImportSource::Json)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesImport))]
+pub async fn import_gsm_rules_csv(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<gsm_api_types::GsmImportQuery>,
+    MultipartForm(form): MultipartForm<gsm::GsmImportForm>,
+) -> impl Responder {
+    let flow = Flow::GsmRulesImport;
+    let payload = match gsm::get_gsm_import_request(form, query.into_inner().dry_run) {
+        Ok(payload) => payload,
+        Err(e) => return api::log_and_return_error_response(e.into()),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, req_state| {
+            gsm::import_gsm_rules(state, req_state, payload, gsm::GsmImportSource::Csv)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - Export
+///
+/// To export the Gsm Rules as JSON or CSV
+#[utoipa::path(
+    get,
+    path = "/gsm/export",
+    params(
+        ("format" = Option<GsmExportFormat>, Query, description = "The format of the export, json by default"),
+        ("connector" = Option<Connector>, Query, description = "Only export the rules of this connector"),
+    ),
+    responses(
+        (status = 200, description = "Gsm rules exported", body = GsmExportResponse),
+    ),
+    tag = "Gsm",
+    operation_id = "Export Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRulesExport))]
+pub async fn export_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<gsm_api_types::GsmExportRequest>,
+) -> impl Responder {
+    let payload = query.into_inner();
+
+    let flow = Flow::GsmRulesExport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::export_gsm_rules(state, payload),
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     ))
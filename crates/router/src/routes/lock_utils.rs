@@ -37,6 +37,7 @@ pub enum ApiIdentifier {
     Recon,
     Poll,
     ApplePayCertificatesMigration,
+    AuditLog,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -63,6 +64,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingUpdateConfig
             | Flow::RoutingUpdateDefaultConfig
             | Flow::RoutingDeleteConfig
+            | Flow::RoutingSimulateConfig
+            | Flow::RoutingActivationHistory
+            | Flow::RoutingRollbackConfig
+            | Flow::RoutingScheduleConfigActivation
+            | Flow::RoutingDiffConfigs
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
             | Flow::DecisionManagerUpsertConfig => Self::Routing,
@@ -158,12 +164,23 @@ impl From<Flow> for ApiIdentifier {
             | Flow::IncomingWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
-            | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventReplay
+            | Flow::WebhookEventReplayJobRetrieve
+            | Flow::WebhookEndpointCreate
+            | Flow::WebhookEndpointRetrieve
+            | Flow::WebhookEndpointList
+            | Flow::WebhookEndpointUpdate
+            | Flow::WebhookEndpointDelete
+            | Flow::WebhookEndpointReenable
+            | Flow::ArchivedWebhookList
+            | Flow::ArchivedWebhookReplay => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
             | Flow::ApiKeyUpdate
             | Flow::ApiKeyRevoke
+            | Flow::ApiKeyRotate
             | Flow::ApiKeyList => Self::ApiKeys,
 
             Flow::DisputesRetrieve
@@ -184,6 +201,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::BusinessProfileRetrieve
             | Flow::BusinessProfileDelete
             | Flow::BusinessProfileList
+            | Flow::BusinessProfileWebhookSigningKeyRotate
             | Flow::ToggleExtendedCardInfo
             | Flow::ToggleConnectorAgnosticMit => Self::Business,
 
@@ -199,7 +217,9 @@ impl From<Flow> for ApiIdentifier {
             Flow::GsmRuleCreate
             | Flow::GsmRuleRetrieve
             | Flow::GsmRuleUpdate
-            | Flow::GsmRuleDelete => Self::Gsm,
+            | Flow::GsmRuleDelete
+            | Flow::GsmRulesImport
+            | Flow::GsmRulesExport => Self::Gsm,
 
             Flow::ApplePayCertificatesMigration => Self::ApplePayCertificatesMigration,
 
@@ -270,6 +290,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ReconVerifyToken => Self::Recon,
 
             Flow::RetrievePollStatus => Self::Poll,
+
+            Flow::AuditLogList => Self::AuditLog,
         }
     }
 }
@@ -51,6 +51,8 @@ counter_metric!(CUSTOMER_REDACTED, GLOBAL_METER);
 
 counter_metric!(API_KEY_CREATED, GLOBAL_METER);
 counter_metric!(API_KEY_REVOKED, GLOBAL_METER);
+counter_metric!(API_KEY_ROTATED, GLOBAL_METER);
+counter_metric!(ROTATED_API_KEY_USED, GLOBAL_METER);
 
 counter_metric!(MCA_CREATE, GLOBAL_METER);
 
@@ -107,6 +109,9 @@ counter_metric!(AUTO_RETRY_GSM_FETCH_FAILURE_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_MATCH_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_PAYMENT_COUNT, GLOBAL_METER);
+counter_metric!(AUTO_RETRY_REQUEUE_COUNT, GLOBAL_METER);
+counter_metric!(AUTO_RETRY_POLICY_INELIGIBLE_COUNT, GLOBAL_METER);
+counter_metric!(AUTO_RETRY_LATENCY_BUDGET_EXHAUSTED_COUNT, GLOBAL_METER);
 
 // Metrics for Payout Auto Retries
 counter_metric!(AUTO_PAYOUT_RETRY_ELIGIBLE_REQUEST_COUNT, GLOBAL_METER);
@@ -115,6 +120,7 @@ counter_metric!(AUTO_PAYOUT_RETRY_GSM_FETCH_FAILURE_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_PAYOUT_RETRY_GSM_MATCH_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_PAYOUT_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_PAYOUT_COUNT, GLOBAL_METER);
+counter_metric!(AUTO_PAYOUT_RETRY_REQUEUE_COUNT, GLOBAL_METER);
 
 // Scheduler / Process Tracker related metrics
 counter_metric!(TASKS_ADDED_COUNT, GLOBAL_METER); // Tasks added to process tracker
@@ -134,3 +140,7 @@ counter_metric!(ACCESS_TOKEN_CACHE_MISS, GLOBAL_METER);
 
 // A counter to indicate the integrity check failures
 counter_metric!(INTEGRITY_CHECK_FAILED, GLOBAL_METER);
+
+// Counters of the requests throttled by the rate limits, and of the failed rate limit checks
+counter_metric!(RATE_LIMITED_REQUESTS_COUNT, GLOBAL_METER);
+counter_metric!(RATE_LIMIT_CHECK_FAILURE_COUNT, GLOBAL_METER);
@@ -10,10 +10,12 @@ pub fn spawn_metrics_collector(metrics_collection_interval_in_secs: &Option<u16>
         &cache::CONFIG_CACHE,
         &cache::ACCOUNTS_CACHE,
         &cache::ROUTING_CACHE,
+        &cache::COMPILED_ROUTING_CACHE,
         &cache::CGRAPH_CACHE,
         &cache::PM_FILTERS_CGRAPH_CACHE,
         &cache::DECISION_MANAGER_CACHE,
         &cache::SURCHARGE_CACHE,
+        &cache::GSM_PATTERN_RULES_CACHE,
     ];
 
     tokio::spawn(async move {
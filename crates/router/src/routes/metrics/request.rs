@@ -51,6 +51,7 @@ pub fn track_response_status_code<Q>(response: &ApplicationResponse<Q>) -> i64 {
         | ApplicationResponse::PaymentLinkForm(_)
         | ApplicationResponse::FileData(_)
         | ApplicationResponse::JsonWithHeaders(_) => 200,
+        ApplicationResponse::IdempotentReplay(response) => i64::from(response.status_code),
         ApplicationResponse::JsonForRedirection(_) => 302,
     }
 }
@@ -1,5 +1,8 @@
 use crate::{
-    core::api_locking::{self, GetLockingInput},
+    core::{
+        api_locking::{self, GetLockingInput},
+        idempotency,
+    },
     services::authorization::permissions::Permission,
 };
 pub mod helpers;
@@ -102,6 +105,13 @@ pub async fn payments_create(
         return http_not_implemented();
     };
 
+    // The fingerprint has to be taken before a payment_id is generated into the payload
+    let idempotency_action =
+        match idempotency::IdempotencyAction::from_request(req.headers(), &payload, flow.clone()) {
+            Ok(idempotency_action) => idempotency_action,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
     if let Err(err) = get_or_generate_payment_id(&mut payload) {
         return api::log_and_return_error_response(err);
     }
@@ -126,7 +136,7 @@ pub async fn payments_create(
 
     let locking_action = payload.get_locking_input(flow.clone());
 
-    Box::pin(api::server_wrap(
+    Box::pin(api::server_wrap_with_idempotency(
         flow,
         state,
         &req,
@@ -153,6 +163,7 @@ pub async fn payments_create(
             ),
         },
         locking_action,
+        idempotency_action,
     ))
     .await
 }
@@ -260,6 +271,8 @@ pub async fn payments_retrieve(
         client_secret: json_payload.client_secret.clone(),
         expand_attempts: json_payload.expand_attempts,
         expand_captures: json_payload.expand_captures,
+        // The routing decision trace is only returned to dashboard users
+        expose_routing_decision_trace: cfg!(feature = "olap") && auth::is_jwt_auth(req.headers()),
         ..Default::default()
     };
     let header_payload = match HeaderPayload::foreign_try_from(req.headers()) {
@@ -9,7 +9,7 @@ use super::app::AppState;
 #[cfg(feature = "olap")]
 use crate::types::api::payments as payment_types;
 use crate::{
-    core::{api_locking, payouts::*},
+    core::{api_locking, idempotency, payouts::*},
     headers::ACCEPT_LANGUAGE,
     services::{
         api,
@@ -32,16 +32,23 @@ pub async fn payouts_create(
         .flatten()
         .map(|val| val.to_string())
         .unwrap_or(consts::DEFAULT_LOCALE.to_string());
-    Box::pin(api::server_wrap(
+    let payload = json_payload.into_inner();
+    let idempotency_action =
+        match idempotency::IdempotencyAction::from_request(req.headers(), &payload, flow.clone()) {
+            Ok(idempotency_action) => idempotency_action,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+    Box::pin(api::server_wrap_with_idempotency(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth, req, _| {
             payouts_create_core(state, auth.merchant_account, auth.key_store, req, &locale)
         },
         &auth::HeaderAuth(auth::ApiKeyAuth),
         api_locking::LockAction::NotApplicable,
+        idempotency_action,
     ))
     .await
 }
@@ -3,7 +3,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{api_locking, refunds::*},
+    core::{api_locking, idempotency, refunds::*},
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::refunds,
 };
@@ -31,11 +31,17 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
-    Box::pin(api::server_wrap(
+    let payload = json_payload.into_inner();
+    let idempotency_action =
+        match idempotency::IdempotencyAction::from_request(req.headers(), &payload, flow.clone()) {
+            Ok(idempotency_action) => idempotency_action,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+    Box::pin(api::server_wrap_with_idempotency(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth, req, _| {
             refund_create_core(
                 state,
@@ -51,6 +57,7 @@ pub async fn refunds_create(
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
+        idempotency_action,
     ))
     .await
 }
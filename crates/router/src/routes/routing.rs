@@ -29,9 +29,10 @@ pub async fn routing_create_config(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, payload, _| {
+        |state, auth: auth::AuthenticationData, payload, req_state| {
             routing::create_routing_algorithm_under_profile(
                 state,
+                req_state,
                 auth.merchant_account,
                 auth.key_store,
                 payload,
@@ -69,9 +70,10 @@ pub async fn routing_link_config(
         state,
         &req,
         path.into_inner(),
-        |state, auth: auth::AuthenticationData, algorithm_id, _| {
+        |state, auth: auth::AuthenticationData, algorithm_id, req_state| {
             routing::link_routing_config(
                 state,
+                req_state,
                 auth.merchant_account,
                 auth.key_store,
                 algorithm_id.0,
@@ -169,6 +171,44 @@ pub async fn routing_retrieve_config(
     .await
 }
 
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "routing_v2")
+))]
+#[instrument(skip_all)]
+pub async fn routing_simulate_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingSimulationRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingSimulateConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            routing::simulate_routing_algorithm(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::RoutingRead),
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth(Permission::RoutingRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "olap")]
 #[instrument(skip_all)]
 pub async fn list_routing_configs(
@@ -258,9 +298,10 @@ pub async fn routing_unlink_config(
         state,
         &req,
         payload.into_inner(),
-        |state, auth: auth::AuthenticationData, payload_req, _| {
+        |state, auth: auth::AuthenticationData, payload_req, req_state| {
             routing::unlink_routing_config(
                 state,
+                req_state,
                 auth.merchant_account,
                 auth.key_store,
                 payload_req,
@@ -280,6 +321,172 @@ pub async fn routing_unlink_config(
     .await
 }
 
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "routing_v2")
+))]
+#[instrument(skip_all)]
+pub async fn routing_activation_history(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<routing_types::RoutingActivationHistoryQuery>,
+    transaction_type: &enums::TransactionType,
+) -> impl Responder {
+    let flow = Flow::RoutingActivationHistory;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query_params, _| {
+            routing::list_routing_activation_history(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                query_params,
+                transaction_type,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::RoutingRead),
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth(Permission::RoutingRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "routing_v2")
+))]
+#[instrument(skip_all)]
+pub async fn routing_rollback_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingRollbackRequest>,
+    transaction_type: &enums::TransactionType,
+) -> impl Responder {
+    let flow = Flow::RoutingRollbackConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            routing::rollback_routing_config(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+                transaction_type,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::RoutingWrite),
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth(Permission::RoutingWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "routing_v2")
+))]
+#[instrument(skip_all)]
+pub async fn routing_schedule_config_activation(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<routing_types::RoutingActivationScheduleRequest>,
+    transaction_type: &enums::TransactionType,
+) -> impl Responder {
+    let flow = Flow::RoutingScheduleConfigActivation;
+    let wrapper = routing_types::RoutingActivationScheduleWrapper {
+        algorithm_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        wrapper,
+        |state, auth: auth::AuthenticationData, wrapper, req_state| {
+            routing::schedule_routing_config_activation(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                wrapper.algorithm_id,
+                wrapper.request,
+                req_state.get_actor(),
+                transaction_type,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::RoutingWrite),
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth(Permission::RoutingWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "routing_v2")
+))]
+#[instrument(skip_all)]
+pub async fn routing_diff_configs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<routing_types::RoutingDiffQuery>,
+) -> impl Responder {
+    let flow = Flow::RoutingDiffConfigs;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, query_params, _| {
+            routing::diff_routing_configs(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                query_params,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::RoutingRead),
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth(Permission::RoutingRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(
     feature = "olap",
     feature = "v2",
@@ -302,9 +509,10 @@ pub async fn routing_update_default_config(
         state,
         &req,
         wrapper,
-        |state, auth: auth::AuthenticationData, wrapper, _| {
+        |state, auth: auth::AuthenticationData, wrapper, req_state| {
             routing::update_default_fallback_routing(
                 state,
+                req_state,
                 auth.merchant_account,
                 auth.key_store,
                 wrapper.profile_id,
@@ -341,9 +549,10 @@ pub async fn routing_update_default_config(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, updated_config, _| {
+        |state, auth: auth::AuthenticationData, updated_config, req_state| {
             routing::update_default_routing_config(
                 state,
+                req_state,
                 auth.merchant_account,
                 updated_config,
                 transaction_type,
@@ -445,9 +654,10 @@ pub async fn upsert_surcharge_decision_manager_config(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, update_decision, _| {
+        |state, auth: auth::AuthenticationData, update_decision, req_state| {
             surcharge_decision_config::upsert_surcharge_decision_config(
                 state,
+                req_state,
                 auth.key_store,
                 auth.merchant_account,
                 update_decision,
@@ -477,9 +687,10 @@ pub async fn delete_surcharge_decision_manager_config(
         state,
         &req,
         (),
-        |state, auth: auth::AuthenticationData, (), _| {
+        |state, auth: auth::AuthenticationData, (), req_state| {
             surcharge_decision_config::delete_surcharge_decision_config(
                 state,
+                req_state,
                 auth.key_store,
                 auth.merchant_account,
             )
@@ -541,9 +752,10 @@ pub async fn upsert_decision_manager_config(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, update_decision, _| {
+        |state, auth: auth::AuthenticationData, update_decision, req_state| {
             conditional_config::upsert_conditional_config(
                 state,
+                req_state,
                 auth.key_store,
                 auth.merchant_account,
                 update_decision,
@@ -574,9 +786,10 @@ pub async fn delete_decision_manager_config(
         state,
         &req,
         (),
-        |state, auth: auth::AuthenticationData, (), _| {
+        |state, auth: auth::AuthenticationData, (), req_state| {
             conditional_config::delete_conditional_config(
                 state,
+                req_state,
                 auth.key_store,
                 auth.merchant_account,
             )
@@ -766,9 +979,10 @@ pub async fn routing_update_default_config_for_profile(
         state,
         &req,
         routing_payload_wrapper,
-        |state, auth: auth::AuthenticationData, wrapper, _| {
+        |state, auth: auth::AuthenticationData, wrapper, req_state| {
             routing::update_default_routing_config_for_profile(
                 state,
+                req_state,
                 auth.merchant_account,
                 auth.key_store,
                 wrapper.updated_config,
@@ -0,0 +1,221 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::webhook_endpoints::{WebhookEndpointCreateRequest, WebhookEndpointUpdateRequest};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, webhooks::webhook_endpoints},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointCreate))]
+pub async fn create_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    json_payload: web::Json<WebhookEndpointCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointCreate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, req_state| {
+            webhook_endpoints::create_webhook_endpoint(
+                state,
+                req_state,
+                merchant_id.clone(),
+                profile_id.clone(),
+                request,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointRetrieve))]
+pub async fn retrieve_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointRetrieve;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        endpoint_id,
+        |state, _, endpoint_id, _| {
+            webhook_endpoints::retrieve_webhook_endpoint(
+                state,
+                merchant_id.clone(),
+                profile_id.clone(),
+                endpoint_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointList))]
+pub async fn list_webhook_endpoints(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointList;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id,
+        |state, _, profile_id, _| {
+            webhook_endpoints::list_webhook_endpoints(state, merchant_id.clone(), profile_id)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointUpdate))]
+pub async fn update_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String, String)>,
+    json_payload: web::Json<WebhookEndpointUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointUpdate;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, req_state| {
+            webhook_endpoints::update_webhook_endpoint(
+                state,
+                req_state,
+                merchant_id.clone(),
+                profile_id.clone(),
+                endpoint_id.clone(),
+                request,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointDelete))]
+pub async fn delete_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointDelete;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        endpoint_id,
+        |state, _, endpoint_id, req_state| {
+            webhook_endpoints::delete_webhook_endpoint(
+                state,
+                req_state,
+                merchant_id.clone(),
+                profile_id.clone(),
+                endpoint_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointReenable))]
+pub async fn reenable_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointReenable;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        endpoint_id,
+        |state, _, endpoint_id, req_state| {
+            webhook_endpoints::reenable_webhook_endpoint(
+                state,
+                req_state,
+                merchant_id.clone(),
+                profile_id.clone(),
+                endpoint_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -2,12 +2,17 @@ use actix_web::{web, HttpRequest, Responder};
 use router_env::{instrument, tracing, Flow};
 
 use crate::{
-    core::{api_locking, webhooks::webhook_events},
+    core::{
+        api_locking,
+        webhooks::{webhook_events, webhook_replay},
+    },
     routes::AppState,
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::webhook_events::{
         EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+        WebhookDeliveryRetryRequestInternal, WebhookReplayJobEventListConstraints,
+        WebhookReplayJobRetrieveRequestInternal, WebhookReplayRequest,
+        WebhookReplayRequestInternal,
     },
 };
 
@@ -129,3 +134,85 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventReplay))]
+pub async fn replay_webhook_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<WebhookReplayRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventReplay;
+    let merchant_id = path.into_inner();
+
+    let request_internal = WebhookReplayRequestInternal {
+        merchant_id: merchant_id.clone(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_replay::create_webhook_replay_job(
+                state,
+                request_internal.merchant_id,
+                request_internal.request,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::WebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventReplayJobRetrieve))]
+pub async fn retrieve_webhook_replay_job(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+    query: web::Query<WebhookReplayJobEventListConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventReplayJobRetrieve;
+    let (merchant_id, job_id) = path.into_inner();
+
+    let request_internal = WebhookReplayJobRetrieveRequestInternal {
+        merchant_id: merchant_id.clone(),
+        job_id,
+        constraints: query.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_replay::retrieve_webhook_replay_job(
+                state,
+                request_internal.merchant_id,
+                request_internal.job_id,
+                request_internal.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::WebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
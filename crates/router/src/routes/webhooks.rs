@@ -1,7 +1,11 @@
 use actix_web::{web, HttpRequest, Responder};
+#[cfg(feature = "olap")]
+use api_models::archived_webhooks as archived_webhook_types;
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "olap")]
+use crate::core::webhooks::archived_webhooks;
 use crate::{
     core::{
         api_locking,
@@ -42,3 +46,70 @@ pub async fn receive_incoming_webhook<W: types::OutgoingWebhookType>(
     ))
     .await
 }
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::ArchivedWebhookList))]
+pub async fn list_archived_webhooks(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query: web::Query<archived_webhook_types::ArchivedWebhookListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ArchivedWebhookList;
+
+    let request_internal = archived_webhook_types::ArchivedWebhookListRequestInternal {
+        merchant_id: path.into_inner(),
+        constraints: query.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            archived_webhooks::list_archived_webhooks(
+                state,
+                request_internal.merchant_id,
+                request_internal.constraints,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::ArchivedWebhookReplay))]
+pub async fn replay_archived_webhook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::ArchivedWebhookReplay;
+    let (merchant_id, webhook_id) = path.into_inner();
+
+    let request_internal = archived_webhook_types::ArchivedWebhookReplayRequestInternal {
+        merchant_id,
+        webhook_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, req_state| {
+            archived_webhooks::replay_archived_webhook(
+                state,
+                req_state,
+                request_internal.merchant_id,
+                request_internal.webhook_id,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
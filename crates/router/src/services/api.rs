@@ -30,8 +30,8 @@ use hyperswitch_domain_models::router_data_v2::flow_common_types as common_types
 pub use hyperswitch_domain_models::{
     api::{
         ApplicationResponse, GenericExpiredLinkData, GenericLinkFormData, GenericLinkStatusData,
-        GenericLinks, PaymentLinkAction, PaymentLinkFormData, PaymentLinkStatusData,
-        RedirectionFormData,
+        GenericLinks, IdempotentResponse, IdempotentResponseHeader, PaymentLinkAction,
+        PaymentLinkFormData, PaymentLinkStatusData, RedirectionFormData,
     },
     payment_method_data::PaymentMethodData,
     router_response_types::RedirectForm,
@@ -45,7 +45,7 @@ pub use hyperswitch_interfaces::{
         BoxedConnectorIntegrationV2, ConnectorIntegrationAnyV2, ConnectorIntegrationV2,
     },
 };
-use masking::{Maskable, PeekInterface};
+use masking::{ExposeInterface, Maskable, PeekInterface};
 use router_env::{instrument, metrics::add_attributes, tracing, tracing_actix_web::RequestId, Tag};
 use serde::Serialize;
 use serde_json::json;
@@ -53,7 +53,7 @@ use tera::{Context, Error as TeraError, Tera};
 
 use self::request::{HeaderExt, RequestBuilderExt};
 use super::{
-    authentication::AuthenticateAndFetch,
+    authentication::{self, AuthenticateAndFetch},
     connector_integration_interface::BoxedConnectorIntegrationInterface,
 };
 use crate::{
@@ -62,13 +62,13 @@ use crate::{
     core::{
         api_locking,
         errors::{self, CustomResult},
-        payments,
+        idempotency, payments, rate_limit,
     },
     events::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
         connector_api_logs::ConnectorEvent,
     },
-    logger,
+    headers, logger,
     routes::{
         app::{AppStateInfo, ReqState, SessionStateInfo},
         metrics, AppState, SessionState,
@@ -697,6 +697,7 @@ pub async fn server_wrap_util<'a, 'b, U, T, Q, F, Fut, E, OErr>(
     func: F,
     api_auth: &dyn AuthenticateAndFetch<U, SessionState>,
     lock_action: api_locking::LockAction,
+    idempotency_action: idempotency::IdempotencyAction,
 ) -> CustomResult<ApplicationResponse<Q>, OErr>
 where
     F: Fn(SessionState, U, T, ReqState) -> Fut,
@@ -754,6 +755,7 @@ where
             .switch()
         })?;
     session_state.add_request_id(request_id);
+    session_state.peer_ip_address = request.peer_addr().map(|peer_address| peer_address.ip());
     let mut request_state = session_state.get_req_state();
 
     request_state.event_context.record_info(request_id);
@@ -771,7 +773,16 @@ where
         .await
         .switch()?;
 
+    auth_type.check_api_key_permission(flow).switch()?;
+
     request_state.event_context.record_info(auth_type.clone());
+    request_state.auth_type = Some(auth_type.clone());
+    request_state.source_ip = authentication::get_client_ip_address(
+        session_state.peer_ip_address,
+        request.headers(),
+        session_state.conf.server.trusted_proxies_count,
+    )
+    .map(|ip_address| ip_address.to_string());
 
     let merchant_id = auth_type
         .get_merchant_id()
@@ -782,15 +793,46 @@ where
 
     tracing::Span::current().record("merchant_id", merchant_id.get_string_repr().to_owned());
 
+    if let Some(rate_limit_input) = rate_limit::RateLimitInput::new(&auth_type, flow) {
+        rate_limit_input
+            .perform_rate_limit_check(&session_state)
+            .await
+            .switch()?;
+    }
+
     let output = {
         lock_action
             .clone()
             .perform_locking_action(&session_state, merchant_id.to_owned())
             .await
             .switch()?;
-        let res = func(session_state.clone(), auth_out, payload, request_state)
+        let res = match idempotency_action
+            .perform_idempotency_check(&session_state, &merchant_id)
             .await
-            .switch();
+            .switch()
+        {
+            Ok(idempotency::IdempotencyOutcome::Replay(response)) => {
+                Ok(ApplicationResponse::IdempotentReplay(Box::new(response)))
+            }
+            Ok(idempotency::IdempotencyOutcome::Proceed) => {
+                let res = func(session_state.clone(), auth_out, payload, request_state)
+                    .await
+                    .switch();
+                // The request has already been processed at this point, so a failure to store
+                // the response must not fail the request itself
+                idempotency_action
+                    .store_idempotent_response(
+                        &session_state,
+                        &merchant_id,
+                        get_idempotent_response(&res).await,
+                    )
+                    .await
+                    .map_err(|error| logger::error!(idempotency_error=?error))
+                    .ok();
+                res
+            }
+            Err(error) => Err(error),
+        };
         lock_action
             .free_lock_action(&session_state, merchant_id.to_owned())
             .await
@@ -868,11 +910,100 @@ where
     output
 }
 
+/// Builds the response to be replayed for requests carrying an idempotency key. Client errors are
+/// replayed like successful responses, while server errors are not stored so that the request can
+/// be retried with the same key.
+async fn get_idempotent_response<Q, E>(
+    response: &CustomResult<ApplicationResponse<Q>, E>,
+) -> Option<IdempotentResponse>
+where
+    Q: Serialize,
+    E: ResponseError + error_stack::Context,
+{
+    let (data, headers) = match response {
+        Ok(ApplicationResponse::Json(data)) => (data, Vec::new()),
+        Ok(ApplicationResponse::JsonWithHeaders((data, headers))) => (data, headers.clone()),
+        Ok(ApplicationResponse::StatusOk)
+        | Ok(ApplicationResponse::TextPlain(_))
+        | Ok(ApplicationResponse::JsonForRedirection(_))
+        | Ok(ApplicationResponse::Form(_))
+        | Ok(ApplicationResponse::PaymentLinkForm(_))
+        | Ok(ApplicationResponse::FileData(_))
+        | Ok(ApplicationResponse::GenericLinkForm(_))
+        | Ok(ApplicationResponse::IdempotentReplay(_)) => return None,
+        Err(error) => {
+            let error_response = error.current_context().error_response();
+            if !error_response.status().is_client_error() {
+                return None;
+            }
+            let status_code = error_response.status().as_u16();
+            let body = body::to_bytes(error_response.into_body())
+                .await
+                .map_err(|error| logger::error!(?error, "Failed to read idempotent response"))
+                .ok()?;
+            return Some(IdempotentResponse {
+                status_code,
+                body: String::from_utf8_lossy(&body).into_owned().into(),
+                headers: Vec::new(),
+            });
+        }
+    };
+
+    let body = serde_json::to_string(data)
+        .map_err(|error| logger::error!(?error, "Failed to serialize idempotent response"))
+        .ok()?;
+    Some(IdempotentResponse {
+        status_code: http::StatusCode::OK.as_u16(),
+        body: body.into(),
+        headers: headers
+            .into_iter()
+            .map(|(name, value)| IdempotentResponseHeader {
+                name,
+                is_sensitive: value.is_masked(),
+                value: value.into_inner().into(),
+            })
+            .collect(),
+    })
+}
+
+pub async fn server_wrap<'a, T, U, Q, F, Fut, E>(
+    flow: impl router_env::types::FlowMetric,
+    state: web::Data<AppState>,
+    request: &'a HttpRequest,
+    payload: T,
+    func: F,
+    api_auth: &dyn AuthenticateAndFetch<U, SessionState>,
+    lock_action: api_locking::LockAction,
+) -> HttpResponse
+where
+    F: Fn(SessionState, U, T, ReqState) -> Fut,
+    Fut: Future<Output = CustomResult<ApplicationResponse<Q>, E>>,
+    Q: Serialize + Debug + ApiEventMetric + 'a,
+    T: Debug + Serialize + ApiEventMetric,
+    ApplicationResponse<Q>: Debug,
+    E: ErrorSwitch<api_models::errors::types::ApiErrorResponse> + error_stack::Context,
+{
+    server_wrap_with_idempotency(
+        flow,
+        state,
+        request,
+        payload,
+        func,
+        api_auth,
+        lock_action,
+        idempotency::IdempotencyAction::NotApplicable,
+    )
+    .await
+}
+
+/// Same as [`server_wrap`], additionally replaying the stored response for retried requests
+/// carrying the same `Idempotency-Key` header
 #[instrument(
     skip(request, state, func, api_auth, payload),
     fields(request_method, request_url_path, status_code)
 )]
-pub async fn server_wrap<'a, T, U, Q, F, Fut, E>(
+#[allow(clippy::too_many_arguments)]
+pub async fn server_wrap_with_idempotency<'a, T, U, Q, F, Fut, E>(
     flow: impl router_env::types::FlowMetric,
     state: web::Data<AppState>,
     request: &'a HttpRequest,
@@ -880,6 +1011,7 @@ pub async fn server_wrap<'a, T, U, Q, F, Fut, E>(
     func: F,
     api_auth: &dyn AuthenticateAndFetch<U, SessionState>,
     lock_action: api_locking::LockAction,
+    idempotency_action: idempotency::IdempotencyAction,
 ) -> HttpResponse
 where
     F: Fn(SessionState, U, T, ReqState) -> Fut,
@@ -928,6 +1060,7 @@ where
             func,
             api_auth,
             lock_action,
+            idempotency_action,
         ),
         &flow,
     )
@@ -1052,6 +1185,9 @@ where
                 ),
             }
         }
+        Ok(ApplicationResponse::IdempotentReplay(response)) => {
+            http_response_idempotent_replay(*response)
+        }
         Err(error) => log_and_return_error_response(error),
     };
 
@@ -1166,6 +1302,28 @@ pub fn http_response_json_with_headers<T: body::MessageBody + 'static>(
         .body(response)
 }
 
+pub fn http_response_idempotent_replay(response: IdempotentResponse) -> HttpResponse {
+    let status_code =
+        http::StatusCode::from_u16(response.status_code).unwrap_or(http::StatusCode::OK);
+    let mut response_builder = HttpResponse::build(status_code);
+    for header in response.headers {
+        let mut header_value = match HeaderValue::from_str(header.value.peek()) {
+            Ok(header_value) => header_value,
+            Err(error) => {
+                logger::error!(?error);
+                return http_server_error_json_response("Something Went Wrong");
+            }
+        };
+        header_value.set_sensitive(header.is_sensitive);
+        response_builder.append_header((header.name, header_value));
+    }
+
+    response_builder
+        .append_header((headers::IDEMPOTENT_REPLAYED, "true"))
+        .content_type(mime::APPLICATION_JSON)
+        .body(response.body.expose())
+}
+
 pub fn http_response_plaintext<T: body::MessageBody + 'static>(res: T) -> HttpResponse {
     HttpResponse::Ok().content_type(mime::TEXT_PLAIN).body(res)
 }
@@ -1,3 +1,5 @@
+use std::{net::IpAddr, str::FromStr};
+
 use actix_web::http::header::HeaderMap;
 #[cfg(feature = "payouts")]
 use api_models::payouts;
@@ -11,7 +13,7 @@ use common_utils::{date_time, id_type};
 use error_stack::{report, ResultExt};
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
 use masking::PeekInterface;
-use router_env::logger;
+use router_env::{logger, Flow};
 use serde::Serialize;
 
 use self::blacklist::BlackList;
@@ -75,6 +77,10 @@ pub enum AuthenticationType {
     ApiKey {
         merchant_id: id_type::MerchantId,
         key_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        permissions: Option<Vec<Permission>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        profile_id: Option<String>,
     },
     AdminApiKey,
     AdminApiAuthWithMerchantId {
@@ -83,6 +89,8 @@ pub enum AuthenticationType {
     MerchantJwt {
         merchant_id: id_type::MerchantId,
         user_id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        profile_id: Option<String>,
     },
     UserJwt {
         user_id: String,
@@ -101,6 +109,8 @@ pub enum AuthenticationType {
     },
     PublishableKey {
         merchant_id: id_type::MerchantId,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        profile_id: Option<String>,
     },
     WebhookAuth {
         merchant_id: id_type::MerchantId,
@@ -125,13 +135,19 @@ impl AuthenticationType {
             Self::ApiKey {
                 merchant_id,
                 key_id: _,
+                permissions: _,
+                profile_id: _,
             }
             | Self::AdminApiAuthWithMerchantId { merchant_id }
             | Self::MerchantId { merchant_id }
-            | Self::PublishableKey { merchant_id }
+            | Self::PublishableKey {
+                merchant_id,
+                profile_id: _,
+            }
             | Self::MerchantJwt {
                 merchant_id,
                 user_id: _,
+                profile_id: _,
             }
             | Self::WebhookAuth { merchant_id } => Some(merchant_id),
             Self::AdminApiKey
@@ -141,6 +157,78 @@ impl AuthenticationType {
             | Self::NoAuth => None,
         }
     }
+
+    /// Returns the business profile the request was authenticated for. This is the profile of the
+    /// user for JWTs, and the default profile of the merchant otherwise.
+    pub fn get_profile_id(&self) -> Option<&String> {
+        match self {
+            Self::ApiKey { profile_id, .. }
+            | Self::PublishableKey { profile_id, .. }
+            | Self::MerchantJwt { profile_id, .. } => profile_id.as_ref(),
+            Self::AdminApiKey
+            | Self::AdminApiAuthWithMerchantId { .. }
+            | Self::MerchantId { .. }
+            | Self::UserJwt { .. }
+            | Self::SinglePurposeJwt { .. }
+            | Self::SinglePurposeOrLoginJwt { .. }
+            | Self::WebhookAuth { .. }
+            | Self::NoAuth => None,
+        }
+    }
+
+    /// Returns the user or the API key which authenticated the request
+    pub fn get_actor(&self) -> Option<String> {
+        match self {
+            Self::ApiKey { key_id, .. } => Some(format!("api_key:{key_id}")),
+            Self::MerchantJwt {
+                user_id: Some(user_id),
+                ..
+            }
+            | Self::UserJwt { user_id }
+            | Self::SinglePurposeJwt { user_id, .. }
+            | Self::SinglePurposeOrLoginJwt { user_id, .. } => Some(format!("user:{user_id}")),
+            Self::AdminApiKey | Self::AdminApiAuthWithMerchantId { .. } => {
+                Some("admin_api_key".to_string())
+            }
+            Self::MerchantJwt { user_id: None, .. }
+            | Self::MerchantId { .. }
+            | Self::PublishableKey { .. }
+            | Self::WebhookAuth { .. }
+            | Self::NoAuth => None,
+        }
+    }
+
+    /// Checks whether the API key which authenticated the request has been granted the
+    /// permission required by the flow. API keys without any permissions configured have access
+    /// to all the operations available to the merchant account.
+    pub fn check_api_key_permission(&self, flow: &impl ToString) -> RouterResult<()> {
+        let Self::ApiKey {
+            permissions: Some(permissions),
+            ..
+        } = self
+        else {
+            return Ok(());
+        };
+
+        let flow = flow.to_string();
+        let required_permission = Flow::from_str(&flow)
+            .ok()
+            .as_ref()
+            .and_then(authorization::permissions::get_required_permission_for_api_key)
+            .ok_or(report!(errors::ApiErrorResponse::AccessForbidden {
+                resource: flow.clone()
+            }))
+            .attach_printable_lazy(|| {
+                format!("Flow {flow} is not available to API keys with restricted permissions")
+            })?;
+
+        permissions
+            .contains(&required_permission)
+            .then_some(())
+            .ok_or(report!(errors::ApiErrorResponse::ApiKeyPermissionDenied {
+                permission: required_permission.to_string(),
+            }))
+    }
 }
 
 #[cfg(feature = "olap")]
@@ -373,6 +461,16 @@ where
                 .attach_printable("API key has expired");
         }
 
+        check_api_key_ip_allowlist(
+            state,
+            stored_api_key.ip_allowlist.as_deref(),
+            request_headers,
+        )?;
+
+        if stored_api_key.successor_key_id.is_some() {
+            api_keys::track_rotated_api_key_usage(state.store().as_ref(), &stored_api_key).await;
+        }
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -406,6 +504,11 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                permissions: stored_api_key
+                    .permissions
+                    .as_deref()
+                    .map(authorization::permissions::get_permissions_from_strings),
+                profile_id: auth.merchant_account.default_profile.clone(),
             },
         ))
     }
@@ -464,12 +567,33 @@ where
                     merchant_id: Some(merchant_id),
                     key_id: Some(key_id),
                 } => {
+                    // The API key has already been verified by the caller, it is fetched again
+                    // only to apply the restrictions configured on it
+                    let stored_api_key = state
+                        .store()
+                        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to retrieve API key")?
+                        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+                        .attach_printable("API key not found")?;
+                    check_api_key_ip_allowlist(
+                        state,
+                        stored_api_key.ip_allowlist.as_deref(),
+                        request_headers,
+                    )?;
+
                     let auth = construct_authentication_data(state, &merchant_id).await?;
                     Ok((
                         auth.clone(),
                         AuthenticationType::ApiKey {
                             merchant_id: auth.merchant_account.get_id().clone(),
                             key_id,
+                            permissions: stored_api_key
+                                .permissions
+                                .as_deref()
+                                .map(authorization::permissions::get_permissions_from_strings),
+                            profile_id: auth.merchant_account.default_profile.clone(),
                         },
                     ))
                 }
@@ -483,6 +607,7 @@ where
                         auth.clone(),
                         AuthenticationType::PublishableKey {
                             merchant_id: auth.merchant_account.get_id().clone(),
+                            profile_id: auth.merchant_account.default_profile.clone(),
                         },
                     ))
                 }
@@ -885,6 +1010,7 @@ where
                     auth.clone(),
                     AuthenticationType::PublishableKey {
                         merchant_id: auth.merchant_account.get_id().clone(),
+                        profile_id: auth.merchant_account.default_profile.clone(),
                     },
                 )
             })
@@ -923,6 +1049,7 @@ where
             AuthenticationType::MerchantJwt {
                 merchant_id: payload.merchant_id,
                 user_id: Some(payload.user_id),
+                profile_id: payload.profile_id,
             },
         ))
     }
@@ -953,11 +1080,12 @@ where
                 merchant_id: payload.merchant_id.clone(),
                 org_id: payload.org_id,
                 role_id: payload.role_id,
-                profile_id: payload.profile_id,
+                profile_id: payload.profile_id.clone(),
             },
             AuthenticationType::MerchantJwt {
                 merchant_id: payload.merchant_id,
                 user_id: Some(payload.user_id),
+                profile_id: payload.profile_id,
             },
         ))
     }
@@ -1012,6 +1140,7 @@ where
             AuthenticationType::MerchantJwt {
                 merchant_id: payload.merchant_id,
                 user_id: Some(payload.user_id),
+                profile_id: payload.profile_id,
             },
         ))
     }
@@ -1049,6 +1178,7 @@ where
             AuthenticationType::MerchantJwt {
                 merchant_id: payload.merchant_id,
                 user_id: Some(payload.user_id),
+                profile_id: payload.profile_id,
             },
         ))
     }
@@ -1108,6 +1238,10 @@ where
             AuthenticationType::MerchantJwt {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 user_id: Some(payload.user_id),
+                profile_id: auth
+                    .profile_id
+                    .clone()
+                    .or_else(|| auth.merchant_account.default_profile.clone()),
             },
         ))
     }
@@ -1181,6 +1315,10 @@ where
             AuthenticationType::MerchantJwt {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 user_id: Some(payload.user_id),
+                profile_id: auth
+                    .profile_id
+                    .clone()
+                    .or_else(|| auth.merchant_account.default_profile.clone()),
             },
         ))
     }
@@ -1237,6 +1375,10 @@ where
             AuthenticationType::MerchantJwt {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 user_id: None,
+                profile_id: auth
+                    .profile_id
+                    .clone()
+                    .or_else(|| auth.merchant_account.default_profile.clone()),
             },
         ))
     }
@@ -1266,11 +1408,12 @@ where
                 merchant_id: payload.merchant_id.clone(),
                 org_id: payload.org_id,
                 role_id: payload.role_id,
-                profile_id: payload.profile_id,
+                profile_id: payload.profile_id.clone(),
             },
             AuthenticationType::MerchantJwt {
                 merchant_id: payload.merchant_id,
                 user_id: Some(payload.user_id),
+                profile_id: payload.profile_id,
             },
         ))
     }
@@ -1339,6 +1482,10 @@ where
             AuthenticationType::MerchantJwt {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 user_id: Some(payload.user_id),
+                profile_id: auth
+                    .profile_id
+                    .clone()
+                    .or_else(|| auth.merchant_account.default_profile.clone()),
             },
         ))
     }
@@ -1543,6 +1690,68 @@ pub fn get_header_value_by_key(key: String, headers: &HeaderMap) -> RouterResult
         .transpose()
 }
 
+fn check_api_key_ip_allowlist<A: SessionStateInfo>(
+    state: &A,
+    ip_allowlist: Option<&[String]>,
+    request_headers: &HeaderMap,
+) -> RouterResult<()> {
+    let Some(ip_allowlist) = ip_allowlist else {
+        return Ok(());
+    };
+
+    let client_ip_address = get_client_ip_address(
+        state.get_peer_ip_address(),
+        request_headers,
+        state.conf().server.trusted_proxies_count,
+    );
+    client_ip_address
+        .map(|ip_address| api_keys::is_ip_address_allowed(ip_allowlist, ip_address))
+        .unwrap_or(false)
+        .then_some(())
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyIpNotAllowed))
+        .attach_printable_lazy(|| {
+            format!(
+                "API key used from IP address {client_ip_address:?} which is not in its allowlist"
+            )
+        })
+}
+
+/// Obtains the IP address of the client the request was sent by. The peer of the connection is the
+/// client itself when the server is not behind any proxies, and the address appended to the
+/// `X-Forwarded-For` header by the outermost of the trusted proxies is used otherwise.
+pub fn get_client_ip_address(
+    peer_ip_address: Option<IpAddr>,
+    headers: &HeaderMap,
+    trusted_proxies_count: usize,
+) -> Option<IpAddr> {
+    match trusted_proxies_count {
+        0 => peer_ip_address,
+        _ => get_forwarded_client_ip_address(headers, trusted_proxies_count),
+    }
+}
+
+/// Extracts the IP address of the client from the `X-Forwarded-For` header, as appended by the
+/// outermost of the trusted proxies in front of the server.
+///
+/// Every proxy appends the address of its peer to the header, so the entries are read from the
+/// right, skipping the ones appended by the inner proxies. The entries before it are sent by the
+/// client and cannot be trusted. Returns `None` when no proxies are trusted, or when the header
+/// has fewer entries than there are proxies.
+fn get_forwarded_client_ip_address(
+    headers: &HeaderMap,
+    trusted_proxies_count: usize,
+) -> Option<IpAddr> {
+    let client_entry_index = trusted_proxies_count.checked_sub(1)?;
+
+    headers
+        .get_all(headers::X_FORWARDED_FOR)
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .rev()
+        .nth(client_entry_index)
+        .and_then(|ip_address| ip_address.trim().parse().ok())
+}
+
 pub fn get_jwt_from_authorization_header(headers: &HeaderMap) -> RouterResult<&str> {
     headers
         .get(headers::AUTHORIZATION)
@@ -1639,3 +1848,49 @@ impl AuthenticateAndFetch<ReconUser, SessionState> for ReconJWT {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use actix_web::http::header::{HeaderName, HeaderValue};
+
+    use super::*;
+
+    #[test]
+    fn test_client_ip_address_skips_spoofed_forwarded_for_entries() {
+        let mut request_headers = HeaderMap::new();
+        request_headers.insert(
+            HeaderName::from_static("x-forwarded-for"),
+            HeaderValue::from_static("1.1.1.1, 2.2.2.2, 3.3.3.3"),
+        );
+
+        let client_ip_address = |trusted_proxies_count| {
+            get_client_ip_address(None, &request_headers, trusted_proxies_count)
+                .map(|ip_address| ip_address.to_string())
+        };
+
+        assert_eq!(client_ip_address(0), None);
+        assert_eq!(client_ip_address(1), Some("3.3.3.3".to_string()));
+        assert_eq!(client_ip_address(2), Some("2.2.2.2".to_string()));
+        assert_eq!(client_ip_address(4), None);
+    }
+
+    #[test]
+    fn test_client_ip_address_is_peer_address_without_trusted_proxies() {
+        let mut request_headers = HeaderMap::new();
+        request_headers.insert(
+            HeaderName::from_static("x-forwarded-for"),
+            HeaderValue::from_static("1.1.1.1"),
+        );
+        let peer_ip_address = "10.0.0.1".parse().unwrap();
+
+        let client_ip_address =
+            get_client_ip_address(Some(peer_ip_address), &request_headers, 0).unwrap();
+
+        assert_eq!(client_ip_address, peer_ip_address);
+        assert!(api_keys::is_ip_address_allowed(
+            &["10.0.0.0/24".to_string()],
+            client_ip_address
+        ));
+    }
+}
@@ -1,7 +1,17 @@
-use strum::Display;
+use router_env::Flow;
+use strum::{Display, EnumString};
 
 #[derive(
-    PartialEq, Display, Clone, Debug, Copy, Eq, Hash, serde::Deserialize, serde::Serialize,
+    PartialEq,
+    Display,
+    EnumString,
+    Clone,
+    Debug,
+    Copy,
+    Eq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
 )]
 pub enum Permission {
     PaymentRead,
@@ -80,3 +90,195 @@ impl Permission {
         }
     }
 }
+
+/// Parses the permissions stored against an API key. Permissions which are no longer recognized
+/// are skipped, which can only narrow down the access of the API key.
+pub fn get_permissions_from_strings(permissions: &[String]) -> Vec<Permission> {
+    permissions
+        .iter()
+        .filter_map(|permission| permission.parse().ok())
+        .collect()
+}
+
+/// The permission a scoped API key must have been granted in order to perform the operation
+/// identified by the flow. Operations which do not map to a permission are not available to
+/// scoped API keys.
+pub fn get_required_permission_for_api_key(flow: &Flow) -> Option<Permission> {
+    match flow {
+        Flow::PaymentsRetrieve
+        | Flow::PaymentsRetrieveForceSync
+        | Flow::PaymentsList
+        | Flow::PaymentsFilters
+        | Flow::PaymentsAggregate
+        | Flow::PaymentsStart
+        | Flow::GetExtendedCardInfo
+        | Flow::PaymentLinkRetrieve
+        | Flow::PaymentLinkList
+        | Flow::PaymentMethodsList => Some(Permission::PaymentRead),
+
+        Flow::PaymentsCreate
+        | Flow::PaymentsUpdate
+        | Flow::PaymentsConfirm
+        | Flow::PaymentsCapture
+        | Flow::PaymentsCancel
+        | Flow::PaymentsApprove
+        | Flow::PaymentsReject
+        | Flow::PaymentsSessionToken
+        | Flow::PaymentsIncrementalAuthorization
+        | Flow::PaymentsExternalAuthentication
+        | Flow::PaymentsAuthorize
+        | Flow::PaymentsCompleteAuthorize => Some(Permission::PaymentWrite),
+
+        Flow::RefundsRetrieve
+        | Flow::RefundsRetrieveForceSync
+        | Flow::RefundsList
+        | Flow::RefundsFilters => Some(Permission::RefundRead),
+
+        Flow::RefundsCreate | Flow::RefundsUpdate => Some(Permission::RefundWrite),
+
+        Flow::CustomersRetrieve
+        | Flow::CustomersList
+        | Flow::CustomerPaymentMethodsList
+        | Flow::PaymentMethodsRetrieve => Some(Permission::CustomerRead),
+
+        Flow::CustomersCreate
+        | Flow::CustomersUpdate
+        | Flow::CustomersDelete
+        | Flow::PaymentMethodsCreate
+        | Flow::PaymentMethodsUpdate
+        | Flow::PaymentMethodsDelete
+        | Flow::DefaultPaymentMethodsSet
+        | Flow::PaymentMethodCollectLink
+        | Flow::EphemeralKeyCreate
+        | Flow::EphemeralKeyDelete => Some(Permission::CustomerWrite),
+
+        Flow::MandatesRetrieve | Flow::MandatesList | Flow::CustomersGetMandates => {
+            Some(Permission::MandateRead)
+        }
+
+        Flow::MandatesRevoke => Some(Permission::MandateWrite),
+
+        Flow::DisputesRetrieve
+        | Flow::DisputesList
+        | Flow::RetrieveDisputeEvidence
+        | Flow::RetrieveFile => Some(Permission::DisputeRead),
+
+        Flow::DisputesEvidenceSubmit
+        | Flow::AttachDisputeEvidence
+        | Flow::DeleteDisputeEvidence
+        | Flow::CreateFile
+        | Flow::DeleteFile => Some(Permission::DisputeWrite),
+
+        Flow::PayoutsRetrieve | Flow::PayoutsList | Flow::PayoutsFilter | Flow::PayoutsAccounts => {
+            Some(Permission::PayoutRead)
+        }
+
+        Flow::PayoutsCreate
+        | Flow::PayoutsUpdate
+        | Flow::PayoutsCancel
+        | Flow::PayoutsFulfill
+        | Flow::PayoutsConfirm
+        | Flow::PayoutLinkInitiate => Some(Permission::PayoutWrite),
+
+        Flow::ApiKeyRetrieve | Flow::ApiKeyList => Some(Permission::ApiKeyRead),
+
+        Flow::ApiKeyCreate | Flow::ApiKeyUpdate | Flow::ApiKeyRevoke | Flow::ApiKeyRotate => {
+            Some(Permission::ApiKeyWrite)
+        }
+
+        Flow::MerchantsAccountRetrieve
+        | Flow::BusinessProfileRetrieve
+        | Flow::BusinessProfileList
+        | Flow::ListBlocklist
+        | Flow::WebhookEndpointRetrieve
+        | Flow::WebhookEndpointList => Some(Permission::MerchantAccountRead),
+
+        Flow::MerchantsAccountUpdate
+        | Flow::BusinessProfileCreate
+        | Flow::BusinessProfileUpdate
+        | Flow::BusinessProfileDelete
+        | Flow::BusinessProfileWebhookSigningKeyRotate
+        | Flow::ToggleExtendedCardInfo
+        | Flow::AddToBlocklist
+        | Flow::DeleteFromBlocklist
+        | Flow::ToggleBlocklistGuard
+        | Flow::WebhookEndpointCreate
+        | Flow::WebhookEndpointUpdate
+        | Flow::WebhookEndpointDelete
+        | Flow::WebhookEndpointReenable => Some(Permission::MerchantAccountWrite),
+
+        Flow::MerchantConnectorsRetrieve | Flow::MerchantConnectorsList => {
+            Some(Permission::MerchantConnectorAccountRead)
+        }
+
+        Flow::MerchantConnectorsCreate
+        | Flow::MerchantConnectorsUpdate
+        | Flow::MerchantConnectorsDelete
+        | Flow::ListCountriesCurrencies => Some(Permission::MerchantConnectorAccountWrite),
+
+        Flow::RoutingRetrieveConfig
+        | Flow::RoutingRetrieveActiveConfig
+        | Flow::RoutingRetrieveDefaultConfig
+        | Flow::RoutingRetrieveDictionary
+        | Flow::RoutingActivationHistory
+        | Flow::RoutingDiffConfigs
+        | Flow::RoutingSimulateConfig => Some(Permission::RoutingRead),
+
+        Flow::RoutingCreateConfig
+        | Flow::RoutingLinkConfig
+        | Flow::RoutingUnlinkConfig
+        | Flow::RoutingUpdateConfig
+        | Flow::RoutingUpdateDefaultConfig
+        | Flow::RoutingDeleteConfig
+        | Flow::RoutingRollbackConfig
+        | Flow::RoutingScheduleConfigActivation
+        | Flow::ToggleConnectorAgnosticMit => Some(Permission::RoutingWrite),
+
+        Flow::WebhookEventInitialDeliveryAttemptList
+        | Flow::WebhookEventDeliveryAttemptList
+        | Flow::WebhookEventReplayJobRetrieve => Some(Permission::WebhookEventRead),
+
+        Flow::WebhookEventDeliveryRetry | Flow::WebhookEventReplay => {
+            Some(Permission::WebhookEventWrite)
+        }
+
+        Flow::AuditLogList => Some(Permission::UsersRead),
+
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_permissions_from_strings_skips_unknown_permissions() {
+        let permissions = vec![
+            "RefundRead".to_string(),
+            "RefundWrite".to_string(),
+            "SomethingElse".to_string(),
+        ];
+
+        assert_eq!(
+            get_permissions_from_strings(&permissions),
+            vec![Permission::RefundRead, Permission::RefundWrite]
+        );
+    }
+
+    #[test]
+    fn test_get_required_permission_for_api_key() {
+        assert_eq!(
+            get_required_permission_for_api_key(&Flow::RefundsCreate),
+            Some(Permission::RefundWrite)
+        );
+        assert_eq!(
+            get_required_permission_for_api_key(&Flow::PaymentsList),
+            Some(Permission::PaymentRead)
+        );
+        assert_eq!(
+            get_required_permission_for_api_key(&Flow::MerchantsAccountCreate),
+            None
+        );
+    }
+}
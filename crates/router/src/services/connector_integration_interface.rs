@@ -266,6 +266,16 @@ impl api::IncomingWebhook for ConnectorEnum {
         }
     }
 
+    fn get_webhook_event_id(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        match self {
+            Self::Old(connector) => connector.get_webhook_event_id(request),
+            Self::New(connector) => connector.get_webhook_event_id(request),
+        }
+    }
+
     fn get_webhook_resource_object(
         &self,
         request: &IncomingWebhookRequestDetails<'_>,
@@ -56,6 +56,10 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    WebhookEndpointSuspended {
+        endpoint_id: String,
+        endpoint_url: String,
+    },
 }
 
 pub mod html {
@@ -144,6 +148,14 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::WebhookEndpointSuspended {
+                endpoint_id,
+                endpoint_url,
+            } => format!(
+                include_str!("assets/webhook_endpoint_suspended.html"),
+                endpoint_id = endpoint_id,
+                endpoint_url = endpoint_url,
+            ),
         }
     }
 }
@@ -529,3 +541,28 @@ impl EmailData for ApiKeyExpiryReminder {
         })
     }
 }
+
+pub struct WebhookEndpointSuspendedNotice {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub endpoint_id: String,
+    pub endpoint_url: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for WebhookEndpointSuspendedNotice {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::WebhookEndpointSuspended {
+            endpoint_id: self.endpoint_id.clone(),
+            endpoint_url: self.endpoint_url.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
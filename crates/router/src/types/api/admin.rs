@@ -162,6 +162,10 @@ impl ForeignTryFrom<domain::BusinessProfile> for BusinessProfileResponse {
                 .collect_billing_details_from_wallet_connector,
             is_connector_agnostic_mit_enabled: item.is_connector_agnostic_mit_enabled,
             outgoing_webhook_custom_http_headers,
+            retry_policy: item.retry_policy.map(ForeignInto::foreign_into),
+            webhook_signature_scheme: item.webhook_signature_scheme.unwrap_or_default(),
+            previous_payment_response_hash_key_expires_at: item
+                .previous_payment_response_hash_key_expires_at,
         })
     }
 }
@@ -217,6 +221,10 @@ impl ForeignTryFrom<domain::BusinessProfile> for BusinessProfileResponse {
                 .collect_billing_details_from_wallet_connector,
             is_connector_agnostic_mit_enabled: item.is_connector_agnostic_mit_enabled,
             outgoing_webhook_custom_http_headers,
+            retry_policy: item.retry_policy.map(ForeignInto::foreign_into),
+            webhook_signature_scheme: item.webhook_signature_scheme.unwrap_or_default(),
+            previous_payment_response_hash_key_expires_at: item
+                .previous_payment_response_hash_key_expires_at,
             order_fulfillment_time,
             order_fulfillment_time_origin: item.order_fulfillment_time_origin,
         })
@@ -261,6 +269,10 @@ pub async fn create_business_profile_from_merchant_account(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to encrypt outgoing webhook custom HTTP headers")?;
 
+    if let Some(retry_policy) = &request.retry_policy {
+        core::payments::helpers::validate_retry_policy(retry_policy)?;
+    }
+
     let payout_link_config = request
         .payout_link_config
         .map(|payout_conf| match payout_conf.config.validate() {
@@ -331,5 +343,9 @@ pub async fn create_business_profile_from_merchant_account(
             .collect_billing_details_from_wallet_connector
             .or(Some(false)),
         outgoing_webhook_custom_http_headers: outgoing_webhook_custom_http_headers.map(Into::into),
+        retry_policy: request.retry_policy.map(ForeignInto::foreign_into),
+        webhook_signature_scheme: request.webhook_signature_scheme,
+        previous_payment_response_hash_key: None,
+        previous_payment_response_hash_key_expires_at: None,
     })
 }
@@ -1,9 +1,12 @@
 pub use api_models::{
     enums as api_enums,
     routing::{
-        ConnectorVolumeSplit, RoutableChoiceKind, RoutableConnectorChoice, RoutingAlgorithm,
-        RoutingAlgorithmKind, RoutingAlgorithmRef, RoutingConfigRequest, RoutingDictionary,
-        RoutingDictionaryRecord, StraightThroughAlgorithm,
+        ConnectorAmountDistribution, ConnectorDistribution, ConnectorEliminationReason,
+        ConnectorFeeEstimate, ConnectorVolumeSplit, CostBasedAlgorithm, EliminatedConnector,
+        RoutableChoiceKind, RoutableConnectorChoice, RoutingAlgorithm, RoutingAlgorithmKind,
+        RoutingAlgorithmRef, RoutingApproach, RoutingConfigRequest, RoutingDecisionTrace,
+        RoutingDictionary, RoutingDictionaryRecord, StraightThroughAlgorithm, SuccessRateAlgorithm,
+        SuccessRateDimension,
     },
 };
 
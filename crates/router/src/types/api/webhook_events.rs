@@ -2,5 +2,8 @@ pub use api_models::webhook_events::{
     EventListConstraints, EventListConstraintsInternal, EventListItemResponse,
     EventListRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
     OutgoingWebhookResponseContent, WebhookDeliveryAttemptListRequestInternal,
-    WebhookDeliveryRetryRequestInternal,
+    WebhookDeliveryRetryRequestInternal, WebhookReplayEventResponse, WebhookReplayFilters,
+    WebhookReplayJobEventListConstraints, WebhookReplayJobResponse,
+    WebhookReplayJobRetrieveRequestInternal, WebhookReplayJobStatusResponse, WebhookReplayRequest,
+    WebhookReplayRequestInternal,
 };
@@ -20,6 +20,7 @@ pub use customers::*;
 pub use merchant_account::*;
 
 mod address;
+mod archived_webhook;
 mod event;
 mod merchant_connector_account;
 mod merchant_key_store {
@@ -32,6 +33,7 @@ pub mod user;
 pub mod user_key_store;
 
 pub use address::*;
+pub use archived_webhook::*;
 pub use business_profile::*;
 pub use event::*;
 pub use merchant_connector_account::*;
@@ -0,0 +1,111 @@
+use common_utils::{crypto::Encryptable, type_name, types::keymanager::KeyManagerState};
+use diesel_models::enums::ArchivedWebhookStatus;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+
+use crate::{
+    errors::{CustomResult, ValidationError},
+    types::domain::types,
+};
+
+#[derive(Clone, Debug)]
+pub struct ArchivedWebhook {
+    pub webhook_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: String,
+    pub merchant_connector_id: String,
+    pub connector_name: String,
+    pub deduplication_key: String,
+    pub event_type: String,
+    pub status: ArchivedWebhookStatus,
+    pub payload: Encryptable<Secret<String>>,
+    pub replay_count: i32,
+    pub last_replayed_at: Option<time::PrimitiveDateTime>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+    pub source_verified: bool,
+}
+
+#[async_trait::async_trait]
+impl super::behaviour::Conversion for ArchivedWebhook {
+    type DstType = diesel_models::archived_webhook::ArchivedWebhook;
+    type NewDstType = diesel_models::archived_webhook::ArchivedWebhookNew;
+
+    async fn convert(self) -> CustomResult<Self::DstType, ValidationError> {
+        Ok(diesel_models::archived_webhook::ArchivedWebhook {
+            webhook_id: self.webhook_id,
+            merchant_id: self.merchant_id,
+            profile_id: self.profile_id,
+            merchant_connector_id: self.merchant_connector_id,
+            connector_name: self.connector_name,
+            deduplication_key: self.deduplication_key,
+            event_type: self.event_type,
+            status: self.status,
+            payload: self.payload.into(),
+            replay_count: self.replay_count,
+            last_replayed_at: self.last_replayed_at,
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+            source_verified: self.source_verified,
+        })
+    }
+
+    async fn convert_back(
+        state: &KeyManagerState,
+        item: Self::DstType,
+        key: &Secret<Vec<u8>>,
+        key_manager_identifier: common_utils::types::keymanager::Identifier,
+    ) -> CustomResult<Self, ValidationError>
+    where
+        Self: Sized,
+    {
+        let payload = types::crypto_operation(
+            state,
+            type_name!(Self::DstType),
+            types::CryptoOperation::Decrypt(item.payload),
+            key_manager_identifier,
+            key.peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .change_context(ValidationError::InvalidValue {
+            message: "Failed while decrypting archived webhook payload".to_string(),
+        })?;
+
+        Ok(Self {
+            webhook_id: item.webhook_id,
+            merchant_id: item.merchant_id,
+            profile_id: item.profile_id,
+            merchant_connector_id: item.merchant_connector_id,
+            connector_name: item.connector_name,
+            deduplication_key: item.deduplication_key,
+            event_type: item.event_type,
+            status: item.status,
+            payload,
+            replay_count: item.replay_count,
+            last_replayed_at: item.last_replayed_at,
+            created_at: item.created_at,
+            modified_at: item.modified_at,
+            source_verified: item.source_verified,
+        })
+    }
+
+    async fn construct_new(self) -> CustomResult<Self::NewDstType, ValidationError> {
+        Ok(diesel_models::archived_webhook::ArchivedWebhookNew {
+            webhook_id: self.webhook_id,
+            merchant_id: self.merchant_id,
+            profile_id: self.profile_id,
+            merchant_connector_id: self.merchant_connector_id,
+            connector_name: self.connector_name,
+            deduplication_key: self.deduplication_key,
+            event_type: self.event_type,
+            status: self.status,
+            payload: self.payload.into(),
+            replay_count: self.replay_count,
+            last_replayed_at: self.last_replayed_at,
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+            source_verified: self.source_verified,
+        })
+    }
+}
@@ -34,6 +34,7 @@ pub struct Event {
     pub response: OptionalEncryptableSecretString,
     pub delivery_attempt: Option<WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -81,6 +82,7 @@ impl super::behaviour::Conversion for Event {
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 
@@ -132,6 +134,7 @@ impl super::behaviour::Conversion for Event {
             response: encryptable_event.response,
             delivery_attempt: item.delivery_attempt,
             metadata: item.metadata,
+            webhook_endpoint_id: item.webhook_endpoint_id,
         })
     }
 
@@ -153,6 +156,7 @@ impl super::behaviour::Conversion for Event {
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 }
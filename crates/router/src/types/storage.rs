@@ -1,5 +1,7 @@
 pub mod address;
 pub mod api_keys;
+pub mod archived_webhook;
+pub mod audit_log;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
@@ -39,6 +41,8 @@ pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
+pub mod webhook_endpoint;
+pub mod webhook_replay_job;
 
 use std::collections::HashMap;
 
@@ -59,14 +63,15 @@ pub use hyperswitch_domain_models::payouts::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
-    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, generic_link::*, gsm::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
-    user_role::*,
+    address::*, api_keys::*, archived_webhook::*, audit_log::*, authentication::*,
+    authorization::*, blocklist::*, blocklist_fingerprint::*, blocklist_lookup::*,
+    business_profile::*, capture::*, cards_info::*, configs::*, customers::*,
+    dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
+    generic_link::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
+    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
+    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*,
+    unified_translations::*, user::*, user_authentication_method::*, user_role::*,
+    webhook_endpoint::*, webhook_replay_job::*,
 };
 use crate::types::api::routing;
 
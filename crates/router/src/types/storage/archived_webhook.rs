@@ -0,0 +1,4 @@
+pub use diesel_models::archived_webhook::{
+    ArchivedWebhook, ArchivedWebhookNew, ArchivedWebhookPurgeTrackingData, ArchivedWebhookUpdate,
+    ArchivedWebhookUpdateInternal,
+};
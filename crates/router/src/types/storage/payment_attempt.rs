@@ -8,6 +8,24 @@ pub use hyperswitch_domain_models::payments::payment_attempt::{
 use crate::{
     core::errors, errors::RouterResult, types::transformers::ForeignFrom, utils::OptionExt,
 };
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentRequeueTrackingData {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+impl From<&PaymentAttempt> for PaymentRequeueTrackingData {
+    fn from(payment_attempt: &PaymentAttempt) -> Self {
+        Self {
+            payment_id: payment_attempt.payment_id.clone(),
+            attempt_id: payment_attempt.attempt_id.clone(),
+            merchant_id: payment_attempt.merchant_id.clone(),
+        }
+    }
+}
+
 pub trait PaymentAttemptExt {
     fn make_new_capture(
         &self,
@@ -1,3 +1,4 @@
 pub use diesel_models::routing_algorithm::{
-    RoutingAlgorithm, RoutingAlgorithmMetadata, RoutingProfileMetadata,
+    RoutingActivationTrackingData, RoutingAlgorithm, RoutingAlgorithmActivation,
+    RoutingAlgorithmMetadata, RoutingProfileMetadata,
 };
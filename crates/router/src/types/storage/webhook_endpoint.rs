@@ -0,0 +1,4 @@
+pub use diesel_models::webhook_endpoint::{
+    WebhookEndpoint, WebhookEndpointNew, WebhookEndpointQueuedEvent, WebhookEndpointQueuedEventNew,
+    WebhookEndpointUpdate, WebhookEndpointUpdateInternal,
+};
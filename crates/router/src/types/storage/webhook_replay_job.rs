@@ -0,0 +1,5 @@
+pub use diesel_models::webhook_replay_job::{
+    WebhookReplayJob, WebhookReplayJobEvent, WebhookReplayJobEventNew, WebhookReplayJobEventUpdate,
+    WebhookReplayJobEventUpdateInternal, WebhookReplayJobFilters, WebhookReplayJobNew,
+    WebhookReplayJobUpdate, WebhookReplayJobUpdateInternal, WebhookReplayTrackingData,
+};
@@ -769,6 +769,12 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permissions: api_key
+                .permissions
+                .as_deref()
+                .map(get_api_key_permissions_response),
+            ip_allowlist: api_key.ip_allowlist,
+            predecessor_key_id: api_key.predecessor_key_id,
         }
     }
 }
@@ -783,21 +789,25 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permissions: api_key
+                .permissions
+                .as_deref()
+                .map(get_api_key_permissions_response),
+            ip_allowlist: api_key.ip_allowlist,
+            predecessor_key_id: api_key.predecessor_key_id,
+            successor_key_id: api_key.successor_key_id,
+            last_used: api_key.last_used,
         }
     }
 }
 
-impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
-    for diesel_models::api_keys::ApiKeyUpdate
-{
-    fn foreign_from(api_key: api_models::api_keys::UpdateApiKeyRequest) -> Self {
-        Self::Update {
-            name: api_key.name,
-            description: api_key.description,
-            expires_at: api_key.expiration.map(Into::into),
-            last_used: None,
-        }
-    }
+fn get_api_key_permissions_response(
+    permissions: &[String],
+) -> Vec<api_models::user_role::Permission> {
+    crate::services::authorization::permissions::get_permissions_from_strings(permissions)
+        .into_iter()
+        .map(Into::into)
+        .collect()
 }
 
 impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::DisputeStatus {
@@ -1088,6 +1098,15 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
             profile_id: item.profile_id,
             applepay_verified_domains: item.applepay_verified_domains,
             pm_auth_config: item.pm_auth_config,
+            fee_schedule: item
+                .fee_schedule
+                .map(|fee_schedule| {
+                    fee_schedule
+                        .parse_value("ConnectorFeeSchedule")
+                        .attach_printable("Unable to deserialize fee_schedule")
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                })
+                .transpose()?,
             status: item.status,
             additional_merchant_data: item
                 .additional_merchant_data
@@ -1135,6 +1154,15 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
             profile_id: item.profile_id,
             applepay_verified_domains: item.applepay_verified_domains,
             pm_auth_config: item.pm_auth_config,
+            fee_schedule: item
+                .fee_schedule
+                .map(|fee_schedule| {
+                    fee_schedule
+                        .parse_value("ConnectorFeeSchedule")
+                        .attach_printable("Unable to deserialize fee_schedule")
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                })
+                .transpose()?,
             status: item.status,
             additional_merchant_data: item
                 .additional_merchant_data
@@ -1478,6 +1506,7 @@ impl ForeignFrom<gsm_api_types::GsmCreateRequest> for storage::GatewayStatusMapp
             step_up_possible: value.step_up_possible,
             unified_code: value.unified_code,
             unified_message: value.unified_message,
+            match_type: value.match_type,
         }
     }
 }
@@ -1496,6 +1525,7 @@ impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
             step_up_possible: value.step_up_possible,
             unified_code: value.unified_code,
             unified_message: value.unified_message,
+            match_type: value.match_type,
         }
     }
 }
@@ -1585,6 +1615,7 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             event_class: item.event_class,
             is_delivery_successful: item.is_webhook_notified,
             initial_attempt_id,
+            webhook_endpoint_id: item.webhook_endpoint_id,
             created: item.created_at,
         })
     }
@@ -1651,6 +1682,48 @@ impl ForeignFrom<diesel_models::business_profile::AuthenticationConnectorDetails
     }
 }
 
+impl ForeignFrom<api_models::admin::RetryPolicy> for diesel_models::business_profile::RetryPolicy {
+    fn foreign_from(item: api_models::admin::RetryPolicy) -> Self {
+        Self {
+            max_attempts: item.max_attempts,
+            connector_order: item.connector_order,
+            payment_methods: item.payment_methods,
+            amount_ranges: item.amount_ranges.map(|amount_ranges| {
+                amount_ranges
+                    .into_iter()
+                    .map(
+                        |amount_range| diesel_models::business_profile::RetryAmountRange {
+                            min_amount: amount_range.min_amount,
+                            max_amount: amount_range.max_amount,
+                        },
+                    )
+                    .collect()
+            }),
+            latency_budget_in_ms: item.latency_budget_in_ms,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::RetryPolicy> for api_models::admin::RetryPolicy {
+    fn foreign_from(item: diesel_models::business_profile::RetryPolicy) -> Self {
+        Self {
+            max_attempts: item.max_attempts,
+            connector_order: item.connector_order,
+            payment_methods: item.payment_methods,
+            amount_ranges: item.amount_ranges.map(|amount_ranges| {
+                amount_ranges
+                    .into_iter()
+                    .map(|amount_range| api_models::admin::RetryAmountRange {
+                        min_amount: amount_range.min_amount,
+                        max_amount: amount_range.max_amount,
+                    })
+                    .collect()
+            }),
+            latency_budget_in_ms: item.latency_budget_in_ms,
+        }
+    }
+}
+
 impl ForeignFrom<api_models::admin::WebhookDetails>
     for diesel_models::business_profile::WebhookDetails
 {
@@ -540,3 +540,19 @@ pub async fn convert_currency(
         currency: to_currency.to_string(),
     })
 }
+
+/// Fetches the exchange rates with the configured forex api settings, to convert amounts between
+/// currencies with the `currency_conversion` crate
+pub async fn get_exchange_rates(
+    state: &SessionState,
+) -> CustomResult<Arc<ExchangeRates>, ForexCacheError> {
+    let forex_api = state.conf.forex_api.get_inner();
+    get_forex_rates(
+        state,
+        forex_api.call_delay,
+        forex_api.local_fetch_retry_delay,
+        forex_api.local_fetch_retry_count,
+    )
+    .await
+    .map(|rates| rates.data)
+}
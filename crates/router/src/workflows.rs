@@ -1,9 +1,19 @@
-#[cfg(feature = "email")]
 pub mod api_key_expiry;
+pub mod archived_webhook_purge;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
+pub mod payment_requeue;
 pub mod payment_sync;
+#[cfg(feature = "payouts")]
+pub mod payout_requeue;
 pub mod refund_router;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(any(feature = "routing_v2", feature = "business_profile_v2"))
+))]
+pub mod routing_activation;
 pub mod tokenized_data;
+#[cfg(feature = "olap")]
+pub mod webhook_replay;
@@ -1,16 +1,19 @@
-use common_utils::{errors::ValidationError, ext_traits::ValueExt};
-use diesel_models::{
-    enums as storage_enums, process_tracker::business_status, ApiKeyExpiryTrackingData,
-};
+#[cfg(feature = "email")]
+use common_utils::errors::ValidationError;
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "email")]
+use diesel_models::{enums as storage_enums, ApiKeyExpiryTrackingData};
+#[cfg(feature = "email")]
 use router_env::{logger, metrics::add_attributes};
 use scheduler::{workflows::ProcessTrackerWorkflow, SchedulerSessionState};
 
+use crate::{core::api_keys, errors, logger::error, routes::SessionState, types::storage};
+#[cfg(feature = "email")]
 use crate::{
-    errors,
-    logger::error,
-    routes::{metrics, SessionState},
+    routes::metrics,
     services::email::types::ApiKeyExpiryReminder,
-    types::{api, domain::UserEmail, storage},
+    types::{api, domain::UserEmail},
     utils::OptionExt,
 };
 
@@ -23,134 +26,192 @@ impl ProcessTrackerWorkflow<SessionState> for ApiKeyExpiryWorkflow {
         state: &'a SessionState,
         process: storage::ProcessTracker,
     ) -> Result<(), errors::ProcessTrackerError> {
-        let db = &*state.store;
-        let tracking_data: ApiKeyExpiryTrackingData = process
-            .tracking_data
-            .clone()
-            .parse_value("ApiKeyExpiryTrackingData")?;
-        let key_manager_satte = &state.into();
-        let key_store = state
-            .store
-            .get_merchant_key_store_by_merchant_id(
-                key_manager_satte,
-                &tracking_data.merchant_id,
-                &state.store.get_master_key().to_vec().into(),
-            )
-            .await?;
+        match process.name.as_deref() {
+            Some(api_keys::API_KEY_RETIREMENT_NAME) => retire_api_key(state, process).await,
 
-        let merchant_account = db
-            .find_merchant_account_by_merchant_id(
-                key_manager_satte,
-                &tracking_data.merchant_id,
-                &key_store,
-            )
-            .await?;
+            #[cfg(feature = "email")]
+            _ => send_api_key_expiry_reminder(state, process).await,
 
-        let email_id = merchant_account
-            .merchant_details
-            .parse_value::<api::MerchantDetails>("MerchantDetails")?
-            .primary_email
-            .ok_or(errors::ProcessTrackerError::EValidationError(
-                ValidationError::MissingRequiredField {
-                    field_name: "email".to_string(),
-                }
-                .into(),
-            ))?;
+            #[cfg(not(feature = "email"))]
+            _ => Err(errors::ProcessTrackerError::UnexpectedFlow),
+        }
+    }
 
-        let task_id = process.id.clone();
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
 
-        let retry_count = process.retry_count;
+// Revokes an API key which was rotated, once the grace period of the rotation is over
+async fn retire_api_key(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data: storage::ApiKeyRetirementTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("ApiKeyRetirementTrackingData")?;
+
+    let api_key = state
+        .store
+        .find_api_key_by_merchant_id_key_id_optional(
+            &tracking_data.merchant_id,
+            &tracking_data.key_id,
+        )
+        .await?;
+
+    // The API key may have already been revoked manually during the grace period
+    let business_status = match api_key {
+        Some(_) => {
+            api_keys::revoke_api_key(
+                state.clone(),
+                state.get_req_state(),
+                &tracking_data.merchant_id,
+                &tracking_data.key_id,
+            )
+            .await?;
+            business_status::COMPLETED_BY_PT
+        }
+        None => business_status::REVOKED,
+    };
 
-        let api_key_name = tracking_data.api_key_name.clone();
+    state
+        .get_db()
+        .as_scheduler()
+        .finish_process_with_business_status(process, business_status)
+        .await?;
 
-        let prefix = tracking_data.prefix.clone();
+    Ok(())
+}
 
-        let expires_in = tracking_data
+#[cfg(feature = "email")]
+async fn send_api_key_expiry_reminder(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: ApiKeyExpiryTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("ApiKeyExpiryTrackingData")?;
+    let key_manager_satte = &state.into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_satte,
+            &tracking_data.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_satte,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await?;
+
+    let email_id = merchant_account
+        .merchant_details
+        .parse_value::<api::MerchantDetails>("MerchantDetails")?
+        .primary_email
+        .ok_or(errors::ProcessTrackerError::EValidationError(
+            ValidationError::MissingRequiredField {
+                field_name: "email".to_string(),
+            }
+            .into(),
+        ))?;
+
+    let task_id = process.id.clone();
+
+    let retry_count = process.retry_count;
+
+    let api_key_name = tracking_data.api_key_name.clone();
+
+    let prefix = tracking_data.prefix.clone();
+
+    let expires_in = tracking_data
+        .expiry_reminder_days
+        .get(
+            usize::try_from(retry_count)
+                .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?,
+        )
+        .ok_or(errors::ProcessTrackerError::EApiErrorResponse)?;
+
+    let email_contents = ApiKeyExpiryReminder {
+        recipient_email: UserEmail::from_pii_email(email_id).map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to convert recipient's email to UserEmail from pii::Email"
+            );
+            errors::ProcessTrackerError::EApiErrorResponse
+        })?,
+        subject: "API Key Expiry Notice",
+        expires_in: *expires_in,
+        api_key_name,
+        prefix,
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .map_err(errors::ProcessTrackerError::EEmailError)?;
+
+    // If all the mails have been sent, then retry_count would be equal to length of the expiry_reminder_days vector
+    if retry_count
+        == i32::try_from(tracking_data.expiry_reminder_days.len() - 1)
+            .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?
+    {
+        state
+            .get_db()
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?
+    }
+    // If tasks are remaining that has to be scheduled
+    else {
+        let expiry_reminder_day = tracking_data
             .expiry_reminder_days
             .get(
-                usize::try_from(retry_count)
+                usize::try_from(retry_count + 1)
                     .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?,
             )
             .ok_or(errors::ProcessTrackerError::EApiErrorResponse)?;
 
-        let email_contents = ApiKeyExpiryReminder {
-            recipient_email: UserEmail::from_pii_email(email_id).map_err(|error| {
-                logger::error!(
-                    ?error,
-                    "Failed to convert recipient's email to UserEmail from pii::Email"
-                );
-                errors::ProcessTrackerError::EApiErrorResponse
-            })?,
-            subject: "API Key Expiry Notice",
-            expires_in: *expires_in,
-            api_key_name,
-            prefix,
+        let updated_schedule_time = tracking_data.api_key_expiry.map(|api_key_expiry| {
+            api_key_expiry.saturating_sub(time::Duration::days(i64::from(*expiry_reminder_day)))
+        });
+        let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(retry_count + 1),
+            schedule_time: updated_schedule_time,
+            tracking_data: None,
+            business_status: None,
+            status: Some(storage_enums::ProcessTrackerStatus::New),
+            updated_at: Some(common_utils::date_time::now()),
         };
-
-        state
-            .email_client
-            .clone()
-            .compose_and_send_email(
-                Box::new(email_contents),
-                state.conf.proxy.https_url.as_ref(),
-            )
-            .await
-            .map_err(errors::ProcessTrackerError::EEmailError)?;
-
-        // If all the mails have been sent, then retry_count would be equal to length of the expiry_reminder_days vector
-        if retry_count
-            == i32::try_from(tracking_data.expiry_reminder_days.len() - 1)
-                .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?
-        {
-            state
-                .get_db()
-                .as_scheduler()
-                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
-                .await?
-        }
-        // If tasks are remaining that has to be scheduled
-        else {
-            let expiry_reminder_day = tracking_data
-                .expiry_reminder_days
-                .get(
-                    usize::try_from(retry_count + 1)
-                        .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?,
-                )
-                .ok_or(errors::ProcessTrackerError::EApiErrorResponse)?;
-
-            let updated_schedule_time = tracking_data.api_key_expiry.map(|api_key_expiry| {
-                api_key_expiry.saturating_sub(time::Duration::days(i64::from(*expiry_reminder_day)))
-            });
-            let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
-                name: None,
-                retry_count: Some(retry_count + 1),
-                schedule_time: updated_schedule_time,
-                tracking_data: None,
-                business_status: None,
-                status: Some(storage_enums::ProcessTrackerStatus::New),
-                updated_at: Some(common_utils::date_time::now()),
-            };
-            let task_ids = vec![task_id];
-            db.process_tracker_update_process_status_by_ids(task_ids, updated_process_tracker_data)
-                .await?;
-            // Remaining tasks are re-scheduled, so will be resetting the added count
-            metrics::TASKS_RESET_COUNT.add(
-                &metrics::CONTEXT,
-                1,
-                &add_attributes([("flow", "ApiKeyExpiry")]),
-            );
-        }
-
-        Ok(())
+        let task_ids = vec![task_id];
+        db.process_tracker_update_process_status_by_ids(task_ids, updated_process_tracker_data)
+            .await?;
+        // Remaining tasks are re-scheduled, so will be resetting the added count
+        metrics::TASKS_RESET_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("flow", "ApiKeyExpiry")]),
+        );
     }
 
-    async fn error_handler<'a>(
-        &'a self,
-        _state: &'a SessionState,
-        process: storage::ProcessTracker,
-        _error: errors::ProcessTrackerError,
-    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
-        error!(%process.id, "Failed while executing workflow");
-        Ok(())
-    }
+    Ok(())
 }
@@ -0,0 +1,47 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::tracing::{self, instrument};
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{core::webhooks::archive_retention, errors, routes::SessionState, types::storage};
+
+pub struct ArchivedWebhookPurgeWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ArchivedWebhookPurgeWorkflow {
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: storage::ArchivedWebhookPurgeTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ArchivedWebhookPurgeTrackingData")?;
+
+        archive_retention::purge_expired_archived_webhooks(state, &tracking_data.merchant_id)
+            .await?;
+
+        // The task is not finished, so that webhooks archived later are purged as well
+        let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+            archive_retention::PURGE_INTERVAL_SECS,
+        ));
+        state
+            .store
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
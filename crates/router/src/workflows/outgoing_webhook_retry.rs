@@ -60,11 +60,6 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             .await?;
 
         let event_id = webhooks_core::utils::generate_event_id();
-        let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
-            &tracking_data.primary_object_id,
-            tracking_data.event_type,
-            delivery_attempt,
-        );
 
         let initial_event = match &tracking_data.initial_attempt_id {
             Some(initial_attempt_id) => {
@@ -93,6 +88,13 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             }
         };
 
+        let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
+            &tracking_data.primary_object_id,
+            tracking_data.event_type,
+            delivery_attempt,
+            initial_event.webhook_endpoint_id.as_deref(),
+        );
+
         let now = common_utils::date_time::now();
         let new_event = domain::Event {
             event_id,
@@ -111,6 +113,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             response: None,
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
         };
 
         let event = db
@@ -176,6 +179,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             &merchant_account,
                             outgoing_webhook,
                             &business_profile,
+                            None,
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -392,7 +396,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::IdempotentReplay(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -449,7 +454,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::IdempotentReplay(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -481,7 +487,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::IdempotentReplay(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
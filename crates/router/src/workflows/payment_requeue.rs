@@ -0,0 +1,283 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    consts,
+    core::{
+        errors::StorageErrorExt,
+        payments::{self as payment_flows, operations},
+    },
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+pub struct PaymentsRequeueWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentsRequeueWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::payment_attempt::PaymentRequeueTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentRequeueTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &tracking_data.attempt_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // The requeued attempt could have been confirmed already, or moved on by a sync in the meantime
+        if payment_attempt.status != enums::AttemptStatus::Pending
+            || payment_attempt.connector_transaction_id.is_some()
+        {
+            logger::info!(
+                attempt_id = %payment_attempt.attempt_id,
+                status = %payment_attempt.status,
+                "requeued payment attempt is no longer pending"
+            );
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, "PROCESS_ALREADY_COMPLETED")
+                .await
+                .map_err(Into::<sch_errors::ProcessTrackerError>::into);
+        }
+
+        let request = api::PaymentsRequest {
+            payment_id: Some(api::PaymentIdType::PaymentIntentId(
+                tracking_data.payment_id.clone(),
+            )),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            confirm: Some(true),
+            payment_method: payment_attempt.payment_method,
+            payment_method_type: payment_attempt.payment_method_type,
+            payment_token: payment_attempt.payment_token.clone(),
+            retry_action: Some(api_models::enums::RetryAction::Requeue),
+            ..Default::default()
+        };
+
+        // TODO: Add support for ReqState in PT flows
+        let payment_result = Box::pin(payment_flows::payments_operation_core::<
+            api::Authorize,
+            _,
+            _,
+            _,
+        >(
+            state,
+            state.get_req_state(),
+            merchant_account.clone(),
+            None,
+            key_store.clone(),
+            operations::PaymentConfirm,
+            request,
+            payment_flows::CallConnectorAction::Trigger,
+            services::AuthFlow::Merchant,
+            None,
+            api::HeaderPayload::default(),
+        ))
+        .await;
+
+        // Errors are retried by the error handler, as they could be transient, or could have been
+        // raised after the connector was called
+        let (payment_data, _, customer, _, _) = payment_result?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        let profile_id = payment_data
+            .payment_intent
+            .profile_id
+            .as_ref()
+            .get_required_value("profile_id")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Could not find profile_id in payment intent")?;
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(key_manager_state, &key_store, profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.to_string(),
+            })?;
+
+        // Notify the merchant about the outcome of the requeued attempt
+        Box::pin(utils::trigger_payments_webhook(
+            merchant_account,
+            business_profile,
+            &key_store,
+            payment_data,
+            customer,
+            state,
+            operations::PaymentConfirm,
+        ))
+        .await
+        .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+        .ok();
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        logger::error!(?error, "failed to process requeued payment attempt");
+
+        if process.retry_count < consts::MAX_REQUEUE_RETRY_COUNT {
+            let schedule_time = common_utils::date_time::now()
+                .saturating_add(time::Duration::seconds(consts::REQUEUE_RETRY_DELAY_IN_SECS));
+            return state
+                .store
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .map_err(Into::<sch_errors::ProcessTrackerError>::into);
+        }
+
+        // The payment would otherwise be left processing, as nothing else picks up the requeued attempt
+        match fail_requeued_payment(state, &process).await {
+            Ok(()) => state
+                .store
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                .await
+                .map_err(Into::<sch_errors::ProcessTrackerError>::into),
+            Err(error) => {
+                consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+            }
+        }
+    }
+}
+
+/// Fails the requeued attempt and its payment, unless the outcome of the attempt was recorded by
+/// the connector call before processing it failed
+async fn fail_requeued_payment(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::payment_attempt::PaymentRequeueTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("PaymentRequeueTrackingData")?;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &tracking_data.payment_id,
+            &tracking_data.merchant_id,
+            &tracking_data.attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    if payment_attempt.status != enums::AttemptStatus::Pending
+        || payment_attempt.connector_transaction_id.is_some()
+    {
+        return Ok(());
+    }
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &payment_attempt.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempt_update = storage::PaymentAttemptUpdate::ErrorUpdate {
+        connector: None,
+        status: enums::AttemptStatus::Failure,
+        error_code: None,
+        error_message: None,
+        error_reason: Some(Some(consts::REQUEUE_FAILURE_ERROR_MESSAGE.to_string())),
+        amount_capturable: Some(common_utils::types::MinorUnit::new(0)),
+        updated_by: merchant_account.storage_scheme.to_string(),
+        unified_code: None,
+        unified_message: None,
+        connector_transaction_id: None,
+        payment_method_data: None,
+        authentication_type: None,
+    };
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        payment_attempt_update,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
+        status: enums::IntentStatus::Failed,
+        updated_by: merchant_account.storage_scheme.to_string(),
+        incremental_authorization_allowed: Some(false),
+    };
+    db.update_payment_intent(
+        key_manager_state,
+        payment_intent,
+        payment_intent_update,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(())
+}
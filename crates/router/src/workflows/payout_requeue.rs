@@ -0,0 +1,137 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::payouts,
+    errors as core_errors,
+    routes::SessionState,
+    types::{api, storage},
+};
+
+pub struct PayoutsRequeueWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutsRequeueWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        // Gather context
+        let db = &*state.store;
+        let tracking_data: api::PayoutRetrieveRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutRetrieveRequest")?;
+
+        let merchant_id = tracking_data
+            .merchant_id
+            .clone()
+            .get_required_value("merchant_id")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+            .await?;
+
+        let request = api::payouts::PayoutRequest::PayoutRetrieveRequest(tracking_data);
+
+        let mut payout_data =
+            payouts::make_payout_data(state, &merchant_account, None, &key_store, &request).await?;
+
+        // The requeued attempt could have been picked up in the meantime
+        if payout_data.payout_attempt.status != storage::enums::PayoutStatus::Pending {
+            logger::info!(
+                payout_attempt_id = %payout_data.payout_attempt.payout_attempt_id,
+                status = %payout_data.payout_attempt.status,
+                "requeued payout attempt is no longer pending"
+            );
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, "PROCESS_ALREADY_COMPLETED")
+                .await
+                .map_err(Into::<errors::ProcessTrackerError>::into);
+        }
+
+        let connector_name = payout_data
+            .payout_attempt
+            .connector
+            .clone()
+            .get_required_value("connector")?;
+        let connector_data = api::ConnectorData::get_payout_connector_by_name(
+            &state.conf.connectors,
+            &connector_name,
+            api::GetToken::Connector,
+            payout_data.payout_attempt.merchant_connector_id.clone(),
+        )
+        .change_context(core_errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct connector data for requeued payout")?;
+
+        // Move the attempt back to creation, so that the connector call is made again
+        let status = storage::enums::PayoutStatus::RequiresCreation;
+        let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
+            connector_payout_id: None,
+            status,
+            error_message: None,
+            error_code: None,
+            is_eligible: None,
+        };
+        payout_data.payout_attempt = db
+            .update_payout_attempt(
+                &payout_data.payout_attempt,
+                updated_payout_attempt,
+                &payout_data.payouts,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(core_errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating payout_attempt in db")?;
+        payout_data.payouts = db
+            .update_payout(
+                &payout_data.payouts,
+                storage::PayoutsUpdate::StatusUpdate { status },
+                &payout_data.payout_attempt,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(core_errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error updating payouts in db")?;
+
+        Box::pin(payouts::call_connector_payout(
+            state,
+            &merchant_account,
+            &key_store,
+            &connector_data,
+            &mut payout_data,
+        ))
+        .await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,97 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::routing,
+    errors as core_errors,
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+pub struct RoutingActivationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for RoutingActivationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::RoutingActivationTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("RoutingActivationTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let activation_result = routing::activate_routing_algorithm(
+            state,
+            &state.get_req_state(),
+            &merchant_account,
+            &key_store,
+            tracking_data.algorithm_id.clone(),
+            &tracking_data.transaction_type,
+            enums::RoutingActivationKind::Scheduled,
+            tracking_data.scheduled_by,
+        )
+        .await;
+
+        match activation_result {
+            Ok(_) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            // The algorithm could have been activated manually before the scheduled time
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    core_errors::ApiErrorResponse::PreconditionFailed { .. }
+                ) =>
+            {
+                logger::info!(
+                    algorithm_id = %tracking_data.algorithm_id,
+                    ?error,
+                    "skipping scheduled routing activation"
+                );
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, "ACTIVATION_NOT_APPLICABLE")
+                    .await?;
+            }
+            Err(error) => return Err(error.into()),
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
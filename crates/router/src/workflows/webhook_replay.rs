@@ -0,0 +1,86 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::tracing::{self, instrument};
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::webhooks::webhook_replay,
+    errors,
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+pub struct WebhookReplayWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for WebhookReplayWorkflow {
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let run_started_at = common_utils::date_time::now();
+        let tracking_data: storage::WebhookReplayTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("WebhookReplayTrackingData")?;
+
+        let db = &*state.store;
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &state.into(),
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let webhook_replay_job = db
+            .find_webhook_replay_job_by_merchant_id_job_id(
+                &tracking_data.merchant_id,
+                &tracking_data.job_id,
+            )
+            .await?;
+
+        let webhook_replay_job = match webhook_replay_job.status {
+            enums::WebhookReplayJobStatus::Completed => webhook_replay_job,
+            enums::WebhookReplayJobStatus::Pending | enums::WebhookReplayJobStatus::Processing => {
+                Box::pin(webhook_replay::replay_next_batch(
+                    state,
+                    &key_store,
+                    webhook_replay_job,
+                ))
+                .await?
+            }
+        };
+
+        match webhook_replay_job.status {
+            enums::WebhookReplayJobStatus::Completed => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?
+            }
+            // Replay the next batch once a minute has passed since this batch was started, to
+            // honor the rate limit of the replay job
+            enums::WebhookReplayJobStatus::Pending | enums::WebhookReplayJobStatus::Processing => {
+                let schedule_time = run_started_at.saturating_add(time::Duration::seconds(
+                    webhook_replay::REPLAY_BATCH_INTERVAL_SECS,
+                ));
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
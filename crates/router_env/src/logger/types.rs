@@ -55,7 +55,7 @@ pub enum Tag {
 }
 
 /// API Flow
-#[derive(Debug, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Display, Clone, PartialEq, Eq, EnumString)]
 pub enum Flow {
     /// Health check
     HealthCheck,
@@ -242,6 +242,16 @@ pub enum Flow {
     RoutingUpdateDefaultConfig,
     /// Routing delete config
     RoutingDeleteConfig,
+    /// Routing simulate config
+    RoutingSimulateConfig,
+    /// Routing activation history
+    RoutingActivationHistory,
+    /// Routing rollback config
+    RoutingRollbackConfig,
+    /// Routing schedule config activation
+    RoutingScheduleConfigActivation,
+    /// Routing diff configs
+    RoutingDiffConfigs,
     /// Add record to blocklist
     AddToBlocklist,
     /// Delete record from blocklist
@@ -262,8 +272,12 @@ pub enum Flow {
     ApiKeyUpdate,
     /// API Key revoke flow
     ApiKeyRevoke,
+    /// API Key rotate flow
+    ApiKeyRotate,
     /// API Key list flow
     ApiKeyList,
+    /// Audit log list flow
+    AuditLogList,
     /// Dispute Retrieve flow
     DisputesRetrieve,
     /// Dispute List flow
@@ -308,6 +322,8 @@ pub enum Flow {
     BusinessProfileDelete,
     /// List all the business profiles for a merchant
     BusinessProfileList,
+    /// Rotate the key used to sign the outgoing webhooks of a business profile
+    BusinessProfileWebhookSigningKeyRotate,
     /// Different verification flows
     Verification,
     /// Rust locker migration
@@ -322,6 +338,10 @@ pub enum Flow {
     ApplePayCertificatesMigration,
     /// Gsm Rule Delete flow
     GsmRuleDelete,
+    /// Gsm Rules bulk import flow
+    GsmRulesImport,
+    /// Gsm Rules export flow
+    GsmRulesExport,
     /// User Sign Up
     UserSignUp,
     /// User Sign Up
@@ -462,6 +482,26 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Replay the webhook events of a business profile in bulk
+    WebhookEventReplay,
+    /// Retrieve the status of a bulk replay of webhook events
+    WebhookEventReplayJobRetrieve,
+    /// Register a webhook endpoint on a business profile
+    WebhookEndpointCreate,
+    /// Retrieve a webhook endpoint
+    WebhookEndpointRetrieve,
+    /// List the webhook endpoints of a business profile
+    WebhookEndpointList,
+    /// Update a webhook endpoint
+    WebhookEndpointUpdate,
+    /// Delete a webhook endpoint
+    WebhookEndpointDelete,
+    /// Re-enable a suspended webhook endpoint
+    WebhookEndpointReenable,
+    /// List archived incoming webhooks
+    ArchivedWebhookList,
+    /// Replay an archived incoming webhook
+    ArchivedWebhookReplay,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
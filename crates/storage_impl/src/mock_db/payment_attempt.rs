@@ -158,6 +158,8 @@ impl PaymentAttemptInterface for MockDb {
             client_source: payment_attempt.client_source,
             client_version: payment_attempt.client_version,
             customer_acceptance: payment_attempt.customer_acceptance,
+            routing_decision_trace: payment_attempt.routing_decision_trace,
+            retry_policy: payment_attempt.retry_policy,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
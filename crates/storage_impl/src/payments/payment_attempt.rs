@@ -419,6 +419,8 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     client_source: payment_attempt.client_source.clone(),
                     client_version: payment_attempt.client_version.clone(),
                     customer_acceptance: payment_attempt.customer_acceptance.clone(),
+                    routing_decision_trace: payment_attempt.routing_decision_trace.clone(),
+                    retry_policy: payment_attempt.retry_policy.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1215,6 +1217,8 @@ impl DataModelExt for PaymentAttempt {
             client_source: self.client_source,
             client_version: self.client_version,
             customer_acceptance: self.customer_acceptance,
+            routing_decision_trace: self.routing_decision_trace,
+            retry_policy: self.retry_policy,
         }
     }
 
@@ -1282,6 +1286,8 @@ impl DataModelExt for PaymentAttempt {
             client_source: storage_model.client_source,
             client_version: storage_model.client_version,
             customer_acceptance: storage_model.customer_acceptance,
+            routing_decision_trace: storage_model.routing_decision_trace,
+            retry_policy: storage_model.retry_policy,
         }
     }
 }
@@ -1356,6 +1362,8 @@ impl DataModelExt for PaymentAttempt {
             client_source: self.client_source,
             client_version: self.client_version,
             customer_acceptance: self.customer_acceptance,
+            routing_decision_trace: self.routing_decision_trace,
+            retry_policy: self.retry_policy,
         }
     }
 
@@ -1423,6 +1431,8 @@ impl DataModelExt for PaymentAttempt {
             client_source: storage_model.client_source,
             client_version: storage_model.client_version,
             customer_acceptance: storage_model.customer_acceptance,
+            routing_decision_trace: storage_model.routing_decision_trace,
+            retry_policy: storage_model.retry_policy,
         }
     }
 }
@@ -1497,6 +1507,8 @@ impl DataModelExt for PaymentAttemptNew {
             client_source: self.client_source,
             client_version: self.client_version,
             customer_acceptance: self.customer_acceptance,
+            routing_decision_trace: self.routing_decision_trace,
+            retry_policy: self.retry_policy,
         }
     }
 
@@ -1563,6 +1575,8 @@ impl DataModelExt for PaymentAttemptNew {
             client_source: storage_model.client_source,
             client_version: storage_model.client_version,
             customer_acceptance: storage_model.customer_acceptance,
+            routing_decision_trace: storage_model.routing_decision_trace,
+            retry_policy: storage_model.retry_policy,
         }
     }
 }
@@ -1688,6 +1702,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 client_source,
                 client_version,
                 customer_acceptance,
+                routing_decision_trace,
+                retry_policy,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount: amount.get_amount_as_i64(),
                 currency,
@@ -1721,6 +1737,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 client_source,
                 client_version,
                 customer_acceptance,
+                routing_decision_trace,
+                retry_policy,
             },
             Self::VoidUpdate {
                 status,
@@ -2025,6 +2043,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 client_source,
                 client_version,
                 customer_acceptance,
+                routing_decision_trace,
+                retry_policy,
             } => Self::ConfirmUpdate {
                 amount: MinorUnit::new(amount),
                 currency,
@@ -2056,6 +2076,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 client_source,
                 client_version,
                 customer_acceptance,
+                routing_decision_trace,
+                retry_policy,
             },
             DieselPaymentAttemptUpdate::VoidUpdate {
                 status,
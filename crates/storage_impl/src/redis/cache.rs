@@ -44,6 +44,28 @@ pub static ACCOUNTS_CACHE: Lazy<Cache> =
 pub static ROUTING_CACHE: Lazy<Cache> =
     Lazy::new(|| Cache::new("ROUTING_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
 
+/// Compiled Routing Program Cache, keyed by the routing algorithm id. Routing algorithms are
+/// never updated in place, so the entries do not need to be invalidated.
+pub static COMPILED_ROUTING_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new(
+        "COMPILED_ROUTING_CACHE",
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(MAX_CAPACITY),
+    )
+});
+
+/// Gsm Pattern Rules Cache, holding the wildcard, regex and catch-all rules of a connector flow
+/// along with their compiled patterns.
+pub static GSM_PATTERN_RULES_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new(
+        "GSM_PATTERN_RULES_CACHE",
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(MAX_CAPACITY),
+    )
+});
+
 /// 3DS Decision Manager Cache
 pub static DECISION_MANAGER_CACHE: Lazy<Cache> = Lazy::new(|| {
     Cache::new(
@@ -92,6 +114,7 @@ pub enum CacheKind<'a> {
     Surcharge(Cow<'a, str>),
     CGraph(Cow<'a, str>),
     PmFiltersCGraph(Cow<'a, str>),
+    GsmPatternRules(Cow<'a, str>),
     All(Cow<'a, str>),
 }
 
@@ -6,7 +6,8 @@ use router_env::{logger, tracing::Instrument};
 
 use crate::redis::cache::{
     CacheKey, CacheKind, CacheRedact, ACCOUNTS_CACHE, CGRAPH_CACHE, CONFIG_CACHE,
-    DECISION_MANAGER_CACHE, PM_FILTERS_CGRAPH_CACHE, ROUTING_CACHE, SURCHARGE_CACHE,
+    DECISION_MANAGER_CACHE, GSM_PATTERN_RULES_CACHE, PM_FILTERS_CGRAPH_CACHE, ROUTING_CACHE,
+    SURCHARGE_CACHE,
 };
 
 #[async_trait::async_trait]
@@ -164,6 +165,15 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                                 .await;
                             key
                         }
+                        CacheKind::GsmPatternRules(key) => {
+                            GSM_PATTERN_RULES_CACHE
+                                .remove(CacheKey {
+                                    key: key.to_string(),
+                                    prefix: message.tenant.clone(),
+                                })
+                                .await;
+                            key
+                        }
                         CacheKind::All(key) => {
                             CONFIG_CACHE
                                 .remove(CacheKey {
@@ -207,6 +217,12 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                                     prefix: message.tenant.clone(),
                                 })
                                 .await;
+                            GSM_PATTERN_RULES_CACHE
+                                .remove(CacheKey {
+                                    key: key.to_string(),
+                                    prefix: message.tenant.clone(),
+                                })
+                                .await;
 
                             key
                         }
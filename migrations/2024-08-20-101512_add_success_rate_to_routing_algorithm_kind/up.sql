@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TYPE "RoutingAlgorithmKind"
+ADD VALUE IF NOT EXISTS 'success_rate';
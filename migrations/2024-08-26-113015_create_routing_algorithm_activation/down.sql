@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS routing_algorithm_activation_profile_id_index;
+
+DROP TABLE IF EXISTS routing_algorithm_activation;
+
+DROP TYPE IF EXISTS "RoutingActivationKind";
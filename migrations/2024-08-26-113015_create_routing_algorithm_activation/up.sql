@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TYPE "RoutingActivationKind" AS ENUM ('link', 'unlink', 'rollback', 'scheduled');
+
+CREATE TABLE IF NOT EXISTS routing_algorithm_activation (
+    activation_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    transaction_type "TransactionType" NOT NULL,
+    algorithm_id VARCHAR(64),
+    previous_algorithm_id VARCHAR(64),
+    activation_kind "RoutingActivationKind" NOT NULL,
+    activated_by VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS routing_algorithm_activation_profile_id_index ON routing_algorithm_activation (profile_id, transaction_type, created_at);
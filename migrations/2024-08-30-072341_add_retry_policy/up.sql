@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS retry_policy JSONB;
+
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS retry_policy JSONB;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE gateway_status_map ADD COLUMN IF NOT EXISTS match_type VARCHAR(16) NOT NULL DEFAULT 'exact';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE api_keys
+DROP COLUMN IF EXISTS permissions,
+DROP COLUMN IF EXISTS ip_allowlist;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE api_keys
+ADD COLUMN IF NOT EXISTS permissions TEXT[],
+ADD COLUMN IF NOT EXISTS ip_allowlist TEXT[];
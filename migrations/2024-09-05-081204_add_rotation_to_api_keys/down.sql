@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE api_keys
+DROP COLUMN IF EXISTS predecessor_key_id,
+DROP COLUMN IF EXISTS successor_key_id;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE api_keys
+ADD COLUMN IF NOT EXISTS predecessor_key_id VARCHAR(64),
+ADD COLUMN IF NOT EXISTS successor_key_id VARCHAR(64);
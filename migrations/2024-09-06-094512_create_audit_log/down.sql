@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS audit_log_entity_index;
+
+DROP INDEX IF EXISTS audit_log_merchant_id_index;
+
+DROP TABLE IF EXISTS audit_log;
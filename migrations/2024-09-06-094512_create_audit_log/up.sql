@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS audit_log (
+    audit_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64),
+    actor VARCHAR(255),
+    entity_type VARCHAR(64) NOT NULL,
+    entity_id VARCHAR(255) NOT NULL,
+    action VARCHAR(32) NOT NULL,
+    diff JSONB NOT NULL,
+    source_ip VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS audit_log_merchant_id_index ON audit_log (merchant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS audit_log_entity_index ON audit_log (merchant_id, entity_type, entity_id);
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS webhook_signature_scheme VARCHAR(32);
+
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS previous_payment_response_hash_key VARCHAR(255);
+
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS previous_payment_response_hash_key_expires_at TIMESTAMP;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS webhook_replay_job_event_job_id_status_index;
+
+DROP TABLE IF EXISTS webhook_replay_job_event;
+
+DROP INDEX IF EXISTS webhook_replay_job_merchant_id_profile_id_index;
+
+DROP TABLE IF EXISTS webhook_replay_job;
@@ -0,0 +1,30 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS webhook_replay_job (
+    job_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    filters JSONB NOT NULL,
+    max_events_per_minute INTEGER NOT NULL,
+    total_events INTEGER NOT NULL,
+    delivered_events INTEGER NOT NULL DEFAULT 0,
+    failed_events INTEGER NOT NULL DEFAULT 0,
+    skipped_events INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS webhook_replay_job_merchant_id_profile_id_index ON webhook_replay_job (merchant_id, profile_id);
+
+CREATE TABLE IF NOT EXISTS webhook_replay_job_event (
+    job_id VARCHAR(64) NOT NULL,
+    event_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    replayed_event_id VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (job_id, event_id)
+);
+
+CREATE INDEX IF NOT EXISTS webhook_replay_job_event_job_id_status_index ON webhook_replay_job_event (job_id, status);
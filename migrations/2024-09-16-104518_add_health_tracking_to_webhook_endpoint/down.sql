@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS webhook_endpoint_queued_event;
+
+ALTER TABLE webhook_endpoint
+DROP COLUMN IF EXISTS consecutive_failures,
+DROP COLUMN IF EXISTS recent_delivery_outcomes,
+DROP COLUMN IF EXISTS suspended_at;
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+ALTER TABLE webhook_endpoint
+ADD COLUMN IF NOT EXISTS consecutive_failures INTEGER NOT NULL DEFAULT 0,
+ADD COLUMN IF NOT EXISTS recent_delivery_outcomes BOOLEAN [] NOT NULL DEFAULT '{}',
+ADD COLUMN IF NOT EXISTS suspended_at TIMESTAMP;
+
+-- Webhooks which could not be delivered because the endpoint was suspended, delivered once the
+-- endpoint is re-enabled
+CREATE TABLE IF NOT EXISTS webhook_endpoint_queued_event (
+    endpoint_id VARCHAR(64) NOT NULL,
+    event_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (endpoint_id, event_id)
+);
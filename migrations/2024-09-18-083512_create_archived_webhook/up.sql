@@ -0,0 +1,27 @@
+-- Your SQL goes here
+-- Source-verified incoming webhooks, used to deduplicate webhooks resent by connectors and to
+-- replay webhooks through the incoming webhook flow
+CREATE TABLE IF NOT EXISTS archived_webhook (
+    webhook_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    merchant_connector_id VARCHAR(128) NOT NULL,
+    connector_name VARCHAR(64) NOT NULL,
+    deduplication_key VARCHAR(255) NOT NULL,
+    event_type VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    payload BYTEA NOT NULL,
+    replay_count INTEGER NOT NULL DEFAULT 0,
+    last_replayed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS archived_webhook_merchant_id_deduplication_key_index ON archived_webhook (
+    merchant_id,
+    merchant_connector_id,
+    deduplication_key,
+    created_at
+);
+
+CREATE INDEX IF NOT EXISTS archived_webhook_merchant_id_created_at_index ON archived_webhook (merchant_id, created_at);
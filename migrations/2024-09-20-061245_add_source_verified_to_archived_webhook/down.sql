@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+CREATE INDEX IF NOT EXISTS archived_webhook_merchant_id_deduplication_key_index ON archived_webhook (
+    merchant_id,
+    merchant_connector_id,
+    deduplication_key,
+    created_at
+);
+
+ALTER TABLE archived_webhook DROP COLUMN IF EXISTS source_verified;
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+-- Unverified incoming webhooks are archived as well, with the outcome of source verification
+-- recorded so that replays are processed with it
+ALTER TABLE archived_webhook
+ADD COLUMN IF NOT EXISTS source_verified BOOLEAN NOT NULL DEFAULT TRUE;
+
+-- Incoming webhooks are deduplicated by keys claimed in Redis instead of against the archive
+DROP INDEX IF EXISTS archived_webhook_merchant_id_deduplication_key_index;